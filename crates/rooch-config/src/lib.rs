@@ -118,6 +118,29 @@ pub struct RoochOpt {
     /// The address of the relayer account
     #[clap(long)]
     pub relayer_account: Option<String>,
+
+    /// The server mode, `sequencer` or `follower`, default is `sequencer`.
+    /// The follower syncs the transaction batches from the leader and re-executes them,
+    /// the `leader-url` and `leader-sequencer-address` are required in follower mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, arg_enum)]
+    pub mode: Option<ServerMode>,
+
+    /// The RPC URL of the leader node which the follower syncs from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub leader_url: Option<String>,
+
+    /// The sequencer address of the leader, the follower verifies the transaction order signatures with it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub leader_sequencer_address: Option<String>,
+
+    /// Re-execute the failed transactions with the tracer and save the call stack, which can be queried by `rooch_getTransactionTrace`.
+    /// The failed transactions are executed twice, so it is disabled by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub trace_failed_txs: Option<bool>,
 }
 
 impl std::fmt::Display for RoochOpt {
@@ -145,7 +168,29 @@ impl RoochOpt {
             sequencer_account: None,
             proposer_account: None,
             relayer_account: None,
+            mode: None,
+            leader_url: None,
+            leader_sequencer_address: None,
+            trace_failed_txs: None,
+        }
+    }
+
+    pub fn trace_failed_txs(&self) -> bool {
+        self.trace_failed_txs.unwrap_or(false)
+    }
+
+    pub fn mode(&self) -> ServerMode {
+        self.mode.unwrap_or_default()
+    }
+
+    pub fn follower_config(&self) -> Option<FollowerConfig> {
+        if self.mode() != ServerMode::Follower {
+            return None;
         }
+        Some(FollowerConfig {
+            leader_url: self.leader_url.clone()?,
+            leader_sequencer_address: self.leader_sequencer_address.clone()?,
+        })
     }
 
     pub fn ethereum_relayer_config(&self) -> Option<EthereumRelayerConfig> {
@@ -167,6 +212,22 @@ impl RoochOpt {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, clap::ArgEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerMode {
+    /// Sequence and execute the transactions, and propose the blocks
+    #[default]
+    Sequencer,
+    /// Sync the transaction batches from the leader and re-execute them
+    Follower,
+}
+
+#[derive(Debug, Clone)]
+pub struct FollowerConfig {
+    pub leader_url: String,
+    pub leader_sequencer_address: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct EthereumRelayerConfig {
     pub eth_rpc_url: String,
@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::{GetBatchMessage, PutBatchMessage};
+use crate::batch::DABatch;
+use crate::{DAClientMethods, DAGetRequest, DAPutResponse};
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use rooch_types::crypto::RoochKeyPair;
+
+pub struct DAActor {
+    signer: RoochKeyPair,
+    client: Box<dyn DAClientMethods>,
+}
+
+impl DAActor {
+    pub fn new<C: DAClientMethods + 'static>(signer: RoochKeyPair, client: C) -> Self {
+        Self {
+            signer,
+            client: Box::new(client),
+        }
+    }
+}
+
+impl Actor for DAActor {}
+
+#[async_trait]
+impl Handler<PutBatchMessage> for DAActor {
+    async fn handle(
+        &mut self,
+        msg: PutBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<DAPutResponse> {
+        let PutBatchMessage { batch } = msg;
+        let request = batch.to_put_request(&self.signer)?;
+        let response = self.client.put(request).await?;
+        log::info!(
+            "[DA] put batch of block {}, batch_size: {}, value_hash: {}",
+            batch.block.block_number,
+            batch.block.batch_size,
+            hex::encode(&response.value_hash)
+        );
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl Handler<GetBatchMessage> for DAActor {
+    async fn handle(&mut self, msg: GetBatchMessage, _ctx: &mut ActorContext) -> Result<DABatch> {
+        let GetBatchMessage { value_hash } = msg;
+        let response = self.client.get(DAGetRequest { hash: value_hash }).await?;
+        DABatch::decode(&response.data)
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::batch::DABatch;
+use crate::DAPutResponse;
+use anyhow::Result;
+use coerce::actor::message::Message;
+
+/// Put the batch of a proposed block to the DA
+#[derive(Debug)]
+pub struct PutBatchMessage {
+    pub batch: DABatch,
+}
+
+impl Message for PutBatchMessage {
+    type Result = Result<DAPutResponse>;
+}
+
+/// Get the batch from the DA by the value hash in `DAPutResponse`
+#[derive(Debug)]
+pub struct GetBatchMessage {
+    pub value_hash: Vec<u8>,
+}
+
+impl Message for GetBatchMessage {
+    type Result = Result<DABatch>;
+}
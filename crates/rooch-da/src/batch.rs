@@ -0,0 +1,108 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::DAPutRequest;
+use anyhow::{ensure, Result};
+use moveos_types::h256::{self, H256};
+use rooch_types::block::Block;
+use rooch_types::crypto::{RoochKeyPair, Signature};
+use rooch_types::transaction::{
+    decode_with_legacy, LegacyTransactionWithInfo, TransactionWithInfo,
+};
+use serde::{Deserialize, Serialize};
+
+/// The batch of the sequenced transactions in a proposed block, it is the unit of data put to the DA.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DABatch {
+    pub block: Block,
+    pub transactions: Vec<TransactionWithInfo>,
+}
+
+impl DABatch {
+    pub fn new(block: Block, transactions: Vec<TransactionWithInfo>) -> Self {
+        Self {
+            block,
+            transactions,
+        }
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        bcs::to_bytes(self).map_err(Into::into)
+    }
+
+    /// Decode the batch, the batch put to the DA before the tx timestamp is added is decoded with the legacy layout.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let batch = decode_with_legacy::<Self, LegacyDABatch>(bytes)?;
+        batch.check()?;
+        Ok(batch)
+    }
+
+    /// Check the transactions are consistent with the block.
+    pub fn check(&self) -> Result<()> {
+        ensure!(
+            self.transactions.len() as u64 == self.block.batch_size,
+            "The batch size {} of block {} mismatch with the transactions count {}",
+            self.block.batch_size,
+            self.block.block_number,
+            self.transactions.len()
+        );
+        ensure!(
+            self.block.state_roots.len() as u64 == self.block.batch_size,
+            "The batch size {} of block {} mismatch with the state roots count {}",
+            self.block.batch_size,
+            self.block.block_number,
+            self.block.state_roots.len()
+        );
+        let last_tx_accumulator_root = self
+            .transactions
+            .last()
+            .map(|tx| tx.sequence_info.tx_accumulator_root);
+        ensure!(
+            last_tx_accumulator_root == Some(self.block.tx_accumulator_root),
+            "The tx accumulator root of block {} mismatch with the last transaction",
+            self.block.block_number
+        );
+        Ok(())
+    }
+
+    /// Encode the batch and build the `DAPutRequest` signed by the `signer`.
+    pub fn to_put_request(&self, signer: &RoochKeyPair) -> Result<DAPutRequest> {
+        let kv = self.encode()?;
+        let checksum = checksum(&kv);
+        let signature = Signature::new_secure(&kv, signer);
+        Ok(DAPutRequest {
+            kv,
+            checksum,
+            signature: signature.as_ref().to_vec(),
+        })
+    }
+}
+
+/// The layout of `DABatch` before the tx timestamp is added to the sequence info.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LegacyDABatch {
+    pub block: Block,
+    pub transactions: Vec<LegacyTransactionWithInfo>,
+}
+
+impl From<LegacyDABatch> for DABatch {
+    fn from(batch: LegacyDABatch) -> Self {
+        DABatch::new(
+            batch.block,
+            batch.transactions.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+/// The hash of the data in DA, used as the key to get the data.
+pub fn value_hash(data: &[u8]) -> H256 {
+    h256::sha3_256_of(data)
+}
+
+/// The checksum of the data, the first 8 bytes of the data hash in little endian.
+pub fn checksum(data: &[u8]) -> u64 {
+    let hash = value_hash(data);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash.as_bytes()[..8]);
+    u64::from_le_bytes(bytes)
+}
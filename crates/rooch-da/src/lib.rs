@@ -1,10 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+pub mod actor;
+pub mod batch;
+pub mod proxy;
+pub mod server;
 #[cfg(test)]
 mod test;
 
+/// The current version of `DAPutResponse`
+pub const DA_PUT_RESPONSE_VERSION: u8 = 0;
+
 // Request to store data to the DA server
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DAPutRequest {
     // Aggregation of key and value contents.
     pub kv: Vec<u8>,
@@ -15,6 +27,7 @@ pub struct DAPutRequest {
 }
 
 // Data storage response returned by the DA server
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DAPutResponse {
     // The hash of multi-signature collection returned by multiple nodes comprising the DA committee.
     // The actual public key collection is stored in DA and retrieved from it as needed for verification.
@@ -29,15 +42,18 @@ pub struct DAPutResponse {
     pub version: u8,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DAGetRequest {
     pub hash: Vec<u8>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DAGetResponse {
     pub data: Vec<u8>,
 }
 
-pub trait DAClientMethods {
-    fn put(&self, request: DAPutRequest) -> DAPutResponse;
-    fn get(&self, request: DAGetRequest) -> DAGetResponse;
+#[async_trait]
+pub trait DAClientMethods: Send + Sync {
+    async fn put(&self, request: DAPutRequest) -> Result<DAPutResponse>;
+    async fn get(&self, request: DAGetRequest) -> Result<DAGetResponse>;
 }
@@ -0,0 +1,30 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::{
+    da::DAActor,
+    messages::{GetBatchMessage, PutBatchMessage},
+};
+use crate::batch::DABatch;
+use crate::DAPutResponse;
+use anyhow::Result;
+use coerce::actor::ActorRef;
+
+#[derive(Clone)]
+pub struct DAProxy {
+    pub actor: ActorRef<DAActor>,
+}
+
+impl DAProxy {
+    pub fn new(actor: ActorRef<DAActor>) -> Self {
+        Self { actor }
+    }
+
+    pub async fn put_batch(&self, batch: DABatch) -> Result<DAPutResponse> {
+        self.actor.send(PutBatchMessage { batch }).await?
+    }
+
+    pub async fn get_batch(&self, value_hash: Vec<u8>) -> Result<DABatch> {
+        self.actor.send(GetBatchMessage { value_hash }).await?
+    }
+}
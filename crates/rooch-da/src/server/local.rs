@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::batch::{checksum, value_hash};
+use crate::{
+    DAClientMethods, DAGetRequest, DAGetResponse, DAPutRequest, DAPutResponse,
+    DA_PUT_RESPONSE_VERSION,
+};
+use anyhow::{bail, ensure, format_err, Result};
+use async_trait::async_trait;
+use fastcrypto::traits::ToFromBytes;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{RoochSignature, Signature};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::io::AsyncWriteExt;
+
+/// LocalDAServer saves the data as files in a local directory, the file name is the hex of the data hash.
+/// It is a single node DA, so the committee related fields of `DAPutResponse` are empty.
+/// Only the data signed by the sequencer is accepted.
+pub struct LocalDAServer {
+    dir: PathBuf,
+    sequencer_address: RoochAddress,
+    /// The sequence of the temp files, so the concurrent writes do not share a temp file.
+    temp_file_seq: AtomicU64,
+}
+
+impl LocalDAServer {
+    pub fn new(dir: PathBuf, sequencer_address: RoochAddress) -> Result<Self> {
+        if !dir.exists() {
+            std::fs::create_dir_all(dir.as_path())?;
+        }
+        Ok(Self {
+            dir,
+            sequencer_address,
+            temp_file_seq: AtomicU64::new(0),
+        })
+    }
+
+    fn data_file(&self, hash: &[u8]) -> PathBuf {
+        self.dir.join(hex::encode(hash))
+    }
+
+    async fn is_saved(&self, path: &Path, hash: &[u8]) -> Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let data = tokio::fs::read(path).await?;
+        Ok(value_hash(&data).as_bytes() == hash)
+    }
+
+    /// Write the data to a temp file in the same directory and rename it to the data file,
+    /// so the data file is either absent or complete even if the server crashes while writing.
+    async fn write_data_file(&self, path: &Path, data: &[u8]) -> Result<()> {
+        let temp_path = path.with_extension(format!(
+            "{}.tmp",
+            self.temp_file_seq.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = tokio::fs::File::create(temp_path.as_path()).await?;
+        file.write_all(data).await?;
+        file.sync_all().await?;
+        drop(file);
+        if let Err(e) = tokio::fs::rename(temp_path.as_path(), path).await {
+            let _ = tokio::fs::remove_file(temp_path.as_path()).await;
+            return Err(e.into());
+        }
+        // Sync the directory to persist the rename
+        tokio::fs::File::open(self.dir.as_path())
+            .await?
+            .sync_all()
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DAClientMethods for LocalDAServer {
+    async fn put(&self, request: DAPutRequest) -> Result<DAPutResponse> {
+        ensure!(
+            checksum(&request.kv) == request.checksum,
+            "Invalid checksum of the DA put request"
+        );
+        let signature = Signature::from_bytes(&request.signature)
+            .map_err(|e| format_err!("Invalid signature of the DA put request: {:?}", e))?;
+        signature.verify_secure(&request.kv, self.sequencer_address)?;
+        let hash = value_hash(&request.kv).as_bytes().to_vec();
+        let path = self.data_file(&hash);
+        // The data is content addressed, skip the write if the same data is already saved.
+        if !self.is_saved(&path, &hash).await? {
+            self.write_data_file(&path, &request.kv).await?;
+        }
+        Ok(DAPutResponse {
+            keyset_hash: vec![],
+            value_hash: hash,
+            signers_mask: 0,
+            signature: vec![],
+            version: DA_PUT_RESPONSE_VERSION,
+        })
+    }
+
+    async fn get(&self, request: DAGetRequest) -> Result<DAGetResponse> {
+        let path = self.data_file(&request.hash);
+        if !path.exists() {
+            bail!("The data {} not found in DA", hex::encode(&request.hash));
+        }
+        let data = tokio::fs::read(path.as_path()).await?;
+        ensure!(
+            value_hash(&data).as_bytes() == request.hash.as_slice(),
+            "The data {} in DA is corrupted",
+            hex::encode(&request.hash)
+        );
+        Ok(DAGetResponse { data })
+    }
+}
@@ -1,38 +1,190 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::batch::{checksum, DABatch, LegacyDABatch};
+use crate::server::LocalDAServer;
 use crate::*;
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::traits::KeyPair;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+use rand::{rngs::StdRng, SeedableRng};
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::block::Block;
+use rooch_types::crypto::{RoochKeyPair, Signature};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{
+    AbstractTransaction, LegacyTransactionSequenceInfo, LegacyTransactionWithInfo,
+    TypedTransaction, LEGACY_TX_TIMESTAMP,
+};
 
-struct TestDAService {}
-
-impl DAClientMethods for TestDAService {
-    fn put(&self, request: DAPutRequest) -> DAPutResponse {
-        println!(
-            "{:?} {:?} {:?}",
-            request.kv, request.signature, request.checksum
-        );
-        let response = DAPutResponse {
-            keyset_hash: vec![],
-            value_hash: vec![],
-            signers_mask: 0,
-            signature: vec![],
-            version: 0,
-        };
-        println!(
-            "{:?} {:?} {:?} {:?} {:?}",
-            response.keyset_hash,
-            response.value_hash,
-            response.signers_mask,
-            response.signature,
-            response.version
-        );
-        response
+fn generate_key_pair(seed: u64) -> RoochKeyPair {
+    let mut rng = StdRng::seed_from_u64(seed);
+    RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng))
+}
+
+fn put_request(kv: Vec<u8>, signer: &RoochKeyPair) -> DAPutRequest {
+    let signature = Signature::new_secure(&kv, signer);
+    DAPutRequest {
+        checksum: checksum(&kv),
+        kv,
+        signature: signature.as_ref().to_vec(),
     }
+}
+
+fn local_da_server(dir: &tempfile::TempDir, sequencer_key: &RoochKeyPair) -> LocalDAServer {
+    let sequencer: RoochAddress = (&sequencer_key.public()).into();
+    LocalDAServer::new(dir.path().to_path_buf(), sequencer).unwrap()
+}
+
+#[tokio::test]
+async fn test_local_da_server() {
+    let dir = tempfile::tempdir().unwrap();
+    let sequencer_key = generate_key_pair(0);
+    let server = local_da_server(&dir, &sequencer_key);
+    let kv = b"rooch da test data".to_vec();
+    let request = put_request(kv.clone(), &sequencer_key);
+    let response = server.put(request.clone()).await.unwrap();
+    assert_eq!(response.version, DA_PUT_RESPONSE_VERSION);
+    // put the same data again should return the same response
+    assert_eq!(server.put(request).await.unwrap(), response);
+
+    let get_response = server
+        .get(DAGetRequest {
+            hash: response.value_hash,
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_response.data, kv);
+
+    assert!(server
+        .get(DAGetRequest {
+            hash: vec![0u8; 32]
+        })
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_local_da_server_rewrite_corrupted_data() {
+    let dir = tempfile::tempdir().unwrap();
+    let sequencer_key = generate_key_pair(0);
+    let server = local_da_server(&dir, &sequencer_key);
+    let kv = b"rooch da test data".to_vec();
+    let request = put_request(kv.clone(), &sequencer_key);
+    let response = server.put(request.clone()).await.unwrap();
+
+    // The data file is left half written
+    let data_file = dir.path().join(hex::encode(&response.value_hash));
+    std::fs::write(data_file.as_path(), &kv[..kv.len() / 2]).unwrap();
+    let get_request = DAGetRequest {
+        hash: response.value_hash.clone(),
+    };
+    assert!(server.get(get_request.clone()).await.is_err());
 
-    fn get(&self, request: DAGetRequest) -> DAGetResponse {
-        println!("{:?}", request.hash);
-        let response = DAGetResponse { data: vec![] };
-        println!("{:?}", response.data);
-        response
+    // Put the data again should rewrite the corrupted data file
+    assert_eq!(server.put(request).await.unwrap(), response);
+    assert_eq!(server.get(get_request).await.unwrap().data, kv);
+    // No temp file is left in the directory
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[tokio::test]
+async fn test_local_da_server_invalid_checksum() {
+    let dir = tempfile::tempdir().unwrap();
+    let sequencer_key = generate_key_pair(0);
+    let server = local_da_server(&dir, &sequencer_key);
+    let mut request = put_request(b"rooch da test data".to_vec(), &sequencer_key);
+    request.checksum += 1;
+    assert!(server.put(request).await.is_err());
+}
+
+#[tokio::test]
+async fn test_local_da_server_invalid_signature() {
+    let dir = tempfile::tempdir().unwrap();
+    let sequencer_key = generate_key_pair(0);
+    let server = local_da_server(&dir, &sequencer_key);
+    let kv = b"rooch da test data".to_vec();
+
+    // The data is not signed by the sequencer
+    let other_key = generate_key_pair(1);
+    assert!(server
+        .put(put_request(kv.clone(), &other_key))
+        .await
+        .is_err());
+
+    // The signature is not for the data
+    let mut request = put_request(b"other data".to_vec(), &sequencer_key);
+    request.checksum = checksum(&kv);
+    request.kv = kv.clone();
+    assert!(server.put(request).await.is_err());
+
+    // The signature is missing
+    let mut request = put_request(kv, &sequencer_key);
+    request.signature = vec![];
+    assert!(server.put(request).await.is_err());
+}
+
+#[test]
+fn test_decode_legacy_da_batch() {
+    let transactions: Vec<_> = (0..3u64)
+        .map(|tx_order| {
+            let tx_data = RoochTransactionData::new_for_test(
+                RoochAddress::random(),
+                0,
+                MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+            );
+            let transaction = RoochTransaction::new(tx_data, Authenticator::new(0, vec![]));
+            let tx_hash = transaction.tx_hash();
+            LegacyTransactionWithInfo {
+                transaction: TypedTransaction::Rooch(transaction),
+                sequence_info: LegacyTransactionSequenceInfo {
+                    tx_order,
+                    tx_order_signature: Authenticator::new(0, vec![]),
+                    tx_accumulator_root: H256::random(),
+                },
+                execution_info: TransactionExecutionInfo::new(
+                    tx_hash,
+                    H256::random(),
+                    H256::random(),
+                    0,
+                    KeptVMStatus::Executed,
+                ),
+            }
+        })
+        .collect();
+    let block = Block::new(
+        0,
+        3,
+        H256::zero(),
+        transactions
+            .last()
+            .unwrap()
+            .sequence_info
+            .tx_accumulator_root,
+        transactions
+            .iter()
+            .map(|tx| tx.execution_info.state_root)
+            .collect(),
+        0,
+        0,
+    );
+    let legacy_batch = LegacyDABatch {
+        block,
+        transactions,
+    };
+
+    // The batch put to the DA before the tx timestamp is added is still decodable
+    let batch = DABatch::decode(&bcs::to_bytes(&legacy_batch).unwrap()).unwrap();
+    assert_eq!(batch, DABatch::from(legacy_batch.clone()));
+    for (tx, legacy_tx) in batch.transactions.iter().zip(legacy_batch.transactions) {
+        assert_eq!(tx.sequence_info.tx_order, legacy_tx.sequence_info.tx_order);
+        assert_eq!(tx.sequence_info.tx_timestamp, LEGACY_TX_TIMESTAMP);
+        assert_eq!(tx.transaction, legacy_tx.transaction);
     }
+
+    // The batch of the current layout is decoded as is
+    assert_eq!(DABatch::decode(&batch.encode().unwrap()).unwrap(), batch);
 }
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    AnnotatedStatesMessage, ExecuteTransactionMessage, ExecuteTransactionResult,
-    ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-    GetEventsByEventHandleMessage, ResolveMessage, StatesMessage, ValidateTransactionMessage,
+    AnnotatedStatesMessage, DryRunTransactionMessage, DryRunTransactionResult,
+    ExecuteTransactionMessage, ExecuteTransactionResult, ExecuteViewFunctionMessage,
+    GetAnnotatedEventsByEventHandleMessage, GetEventsByEventHandleMessage, ResolveMessage,
+    StatesMessage, ValidateTransactionMessage,
 };
 use crate::actor::messages::{
-    GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
+    ExplainMoveAbortMessage, GetEventsByEventIDsMessage, GetGasPricesMessage,
+    GetTxExecutionInfosByHashMessage, GetTxExecutionTraceMessage, ListAnnotatedStatesMessage,
     ListStatesMessage,
 };
 use accumulator::inmemory::InMemoryAccumulator;
@@ -21,18 +23,21 @@ use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use move_core_types::value::MoveValue;
-use move_core_types::vm_status::{StatusCode, VMStatus};
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
 use move_resource_viewer::MoveValueAnnotator;
-use moveos::gas::table::{initial_cost_schedule, MoveOSGasMeter};
-use moveos::moveos::{GasPaymentAccount, MoveOS};
-use moveos::vm::vm_status_explainer::explain_vm_status;
-use moveos_store::transaction_store::TransactionStore;
+use moveos::gas::table::MoveOSGasMeter;
+use moveos::moveos::{ExecuteOptions, GasPaymentAccount, MoveOS};
+use moveos::vm::vm_status_explainer::{explain_move_abort, explain_vm_status};
+use moveos_store::state_store::statedb::StateDBStore;
+use moveos_store::transaction_store::{TransactionStore, TransactionTraceStore};
 use moveos_store::MoveOSStore;
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::error::MoveAbortExplain;
 use moveos_types::move_types::FunctionId;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
@@ -44,13 +49,14 @@ use moveos_types::transaction::{FunctionCall, MoveAction};
 use moveos_types::transaction::{MoveOSTransaction, TransactionExecutionInfo};
 use moveos_types::transaction::{TransactionOutput, VerifiedMoveAction};
 use moveos_verifier::metadata::load_module_metadata;
-use rooch_genesis::RoochGenesis;
+use rooch_genesis::{get_framework_error_description, RoochGenesis};
 use rooch_store::RoochStore;
 use rooch_types::address::MultiChainAddress;
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::auth_validator::AuthValidatorCaller;
 use rooch_types::framework::auth_validator::TxValidateResult;
 use rooch_types::framework::genesis::GenesisContext;
+use rooch_types::framework::transaction_fee::GasPrice;
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 use rooch_types::transaction::AbstractTransaction;
@@ -72,9 +78,9 @@ impl ExecutorActor {
         rooch_store: RoochStore,
     ) -> Result<Self> {
         let genesis: RoochGenesis = rooch_genesis::RoochGenesis::build(genesis_ctx)?;
-        let moveos = MoveOS::new(
+        let moveos = MoveOS::new_with_natives_builder(
             moveos_store,
-            genesis.all_natives(),
+            genesis.natives_builder(),
             genesis.config.clone(),
             system_pre_execute_functions(),
             system_post_execute_functions(),
@@ -88,6 +94,12 @@ impl ExecutorActor {
         executor.init_or_check_genesis()
     }
 
+    /// Capture the call stack of the failed transactions and save it with the execution info.
+    pub fn with_trace_failed_txs(mut self, trace_failed_txs: bool) -> Self {
+        self.moveos.set_trace_failed_txs(trace_failed_txs);
+        self
+    }
+
     fn init_or_check_genesis(mut self) -> Result<Self> {
         if self.moveos.state().is_genesis() {
             let genesis_result = self
@@ -132,6 +144,16 @@ impl ExecutorActor {
     }
 
     pub fn validate<T: AbstractTransaction>(&self, tx: T) -> Result<VerifiedMoveOSTransaction> {
+        self.validate_with_options(tx, true)
+    }
+
+    /// Validate the transaction, the signature is not verified if `verify_signature` is false,
+    /// so the unsigned transaction can be dry run.
+    fn validate_with_options<T: AbstractTransaction>(
+        &self,
+        tx: T,
+        verify_signature: bool,
+    ) -> Result<VerifiedMoveOSTransaction> {
         let multi_chain_address_sender = tx.sender();
 
         let resolved_sender = self.resolve_or_generate(multi_chain_address_sender.clone())?;
@@ -139,7 +161,8 @@ impl ExecutorActor {
 
         let mut moveos_tx = tx.construct_moveos_transaction(resolved_sender)?;
 
-        let vm_result = self.validate_authenticator(&moveos_tx.ctx, authenticator)?;
+        let vm_result =
+            self.validate_authenticator(&moveos_tx.ctx, authenticator, verify_signature)?;
 
         let can_pay_gas = self.validate_gas_function(&moveos_tx)?;
 
@@ -211,6 +234,7 @@ impl ExecutorActor {
         &self,
         ctx: &TxContext,
         authenticator: AuthenticatorInfo,
+        verify_signature: bool,
     ) -> Result<ValidateAuthenticatorResult> {
         let tx_validator = self.moveos.as_module_binding::<TransactionValidator>();
         let tx_validate_function_result = tx_validator
@@ -224,9 +248,13 @@ impl ExecutorActor {
                     Some(auth_validator) => {
                         let auth_validator_caller =
                             AuthValidatorCaller::new(&self.moveos, auth_validator);
-                        let auth_validator_function_result = auth_validator_caller
-                            .validate(ctx, authenticator.authenticator.payload)?
-                            .into_result();
+                        let auth_validator_function_result = if verify_signature {
+                            auth_validator_caller
+                                .validate(ctx, authenticator.authenticator.payload)?
+                                .into_result()
+                        } else {
+                            Ok(vec![])
+                        };
                         match auth_validator_function_result {
                             Ok(_) => {
                                 // pre_execute_function: AuthValidator
@@ -263,7 +291,7 @@ impl ExecutorActor {
     pub fn validate_gas_function(&self, tx: &MoveOSTransaction) -> VMResult<Option<bool>> {
         let MoveOSTransaction { ctx, .. } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.moveos().cost_table()?;
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
 
@@ -381,7 +409,7 @@ impl ExecutorActor {
     pub fn get_account_balance(&self, tx: &MoveOSTransaction) -> VMResult<u128> {
         let MoveOSTransaction { ctx, .. } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.moveos().cost_table()?;
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
 
@@ -425,6 +453,27 @@ impl ExecutorActor {
         }
     }
 
+    /// Explain the abort code with the error constants in the metadata of the aborting module.
+    pub fn explain_move_abort(
+        &self,
+        location: &AbortLocation,
+        abort_code: u64,
+    ) -> MoveAbortExplain {
+        let explain = explain_move_abort(self.moveos.moveos_resolver(), location, abort_code);
+        if explain.reason_name.is_some() {
+            return explain;
+        }
+        // The framework modules published before the error constants were recorded in the module metadata
+        // are explained by the error maps generated when the framework is built.
+        let description = match location {
+            AbortLocation::Module(module_id) => {
+                get_framework_error_description(module_id, abort_code)
+            }
+            AbortLocation::Script => None,
+        };
+        MoveAbortExplain::new(abort_code, description)
+    }
+
     pub fn get_rooch_store(&self) -> RoochStore {
         self.rooch_store.clone()
     }
@@ -439,6 +488,93 @@ impl ExecutorActor {
         self.handle_tx_output(tx_hash, state_root, output)
     }
 
+    /// Get the gas price at the state roots, the latest state is used if the state root is None.
+    pub fn get_gas_prices(&self, state_roots: Vec<Option<H256>>) -> Result<Vec<Option<GasPrice>>> {
+        state_roots
+            .into_iter()
+            .map(|state_root| {
+                let object = match state_root {
+                    Some(state_root) => StateDBStore::new_with_root(
+                        self.moveos.state().node_store.clone(),
+                        Some(state_root),
+                    )
+                    .get_as_object::<GasPrice>(GasPrice::object_id())?,
+                    None => self
+                        .moveos
+                        .state()
+                        .get_as_object::<GasPrice>(GasPrice::object_id())?,
+                };
+                Ok(object.map(|object| object.value))
+            })
+            .collect()
+    }
+
+    /// Re-execute the transaction against the current state without saving the execution info,
+    /// and return the execution info of the replay.
+    pub fn replay<T: AbstractTransaction>(&mut self, tx: T) -> Result<TransactionExecutionInfo> {
+        let verified_tx = self.validate(tx)?;
+        self.replay_verified(verified_tx)
+    }
+
+    /// Re-execute the verified transaction like `execute`, but the execution info is not saved.
+    /// The output is applied to the store of the executor, so the replay should run on a scratch copy of the store.
+    /// The next replay continues from the new state root, with the natives reloaded if the gas schedule is updated.
+    pub fn replay_verified(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<TransactionExecutionInfo> {
+        let tx_hash = tx.ctx.tx_hash();
+        let (state_root, output) = self.moveos.execute_and_apply(tx)?;
+
+        let event_hashes: Vec<_> = output.events.iter().map(|e| e.hash()).collect();
+        let event_root = InMemoryAccumulator::from_leaves(event_hashes.as_slice()).root_hash();
+        Ok(TransactionExecutionInfo::new(
+            tx_hash,
+            state_root,
+            event_root,
+            output.gas_used,
+            output.status,
+        ))
+    }
+
+    /// Execute the transaction against the current state without applying the output,
+    /// the signature is not verified, so the unsigned transaction can be dry run.
+    /// If `profile_gas` is true, the gas profile is returned in the output.
+    /// The execution trace is always captured if the dry run fails, and returned in the output.
+    pub fn dry_run<T: AbstractTransaction>(
+        &self,
+        tx: T,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResult> {
+        let verified_tx = self.validate_with_options(tx, false)?;
+        let tx_hash = verified_tx.ctx.tx_hash();
+        let raw_output = self.moveos.execute_with_options(
+            verified_tx,
+            ExecuteOptions::new().profile_gas(profile_gas).trace(true),
+        )?;
+        // The types published by the transaction itself can not be resolved before the output is applied,
+        // so the state change set may fail to be decoded.
+        let annotated_state_changeset = match self
+            .moveos
+            .moveos_resolver()
+            .annotate_state_changeset(raw_output.state_changeset.clone())
+        {
+            Ok(annotated_state_changeset) => Some(annotated_state_changeset),
+            Err(e) => {
+                log::debug!(
+                    "dry run tx(hash:{}) decode state change set failed: {:?}",
+                    tx_hash,
+                    e
+                );
+                None
+            }
+        };
+        Ok(DryRunTransactionResult {
+            raw_output,
+            annotated_state_changeset,
+        })
+    }
+
     fn handle_tx_output(
         &mut self,
         tx_hash: H256,
@@ -455,6 +591,11 @@ impl ExecutorActor {
             output.gas_used,
             output.status.clone(),
         );
+        if let Some(execution_trace) = output.execution_trace.clone() {
+            self.moveos
+                .transaction_trace_store()
+                .save_tx_execution_trace(tx_hash, execution_trace)?;
+        }
         self.moveos
             .transaction_store()
             .save_tx_execution_info(transaction_info.clone())
@@ -488,6 +629,20 @@ where
     }
 }
 
+#[async_trait]
+impl<T> Handler<DryRunTransactionMessage<T>> for ExecutorActor
+where
+    T: 'static + AbstractTransaction + Send + Sync,
+{
+    async fn handle(
+        &mut self,
+        msg: DryRunTransactionMessage<T>,
+        _ctx: &mut ActorContext,
+    ) -> Result<DryRunTransactionResult> {
+        self.dry_run(msg.tx, msg.profile_gas)
+    }
+}
+
 #[async_trait]
 impl Handler<ExecuteTransactionMessage> for ExecutorActor {
     async fn handle(
@@ -676,3 +831,44 @@ impl Handler<GetTxExecutionInfosByHashMessage> for ExecutorActor {
             .multi_get_tx_execution_infos(tx_hashes)
     }
 }
+
+#[async_trait]
+impl Handler<GetTxExecutionTraceMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetTxExecutionTraceMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<ExecutionTrace>> {
+        let GetTxExecutionTraceMessage { tx_hash } = msg;
+        self.moveos
+            .transaction_trace_store()
+            .get_tx_execution_trace(tx_hash)
+    }
+}
+
+#[async_trait]
+impl Handler<ExplainMoveAbortMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExplainMoveAbortMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<MoveAbortExplain> {
+        let ExplainMoveAbortMessage {
+            location,
+            abort_code,
+        } = msg;
+        Ok(self.explain_move_abort(&location, abort_code))
+    }
+}
+
+#[async_trait]
+impl Handler<GetGasPricesMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetGasPricesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<GasPrice>>> {
+        let GetGasPricesMessage { state_roots } = msg;
+        self.get_gas_prices(state_roots)
+    }
+}
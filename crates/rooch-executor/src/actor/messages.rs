@@ -5,16 +5,21 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::AbortLocation;
 use moveos_types::access_path::AccessPath;
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
+use moveos_types::move_std::error::MoveAbortExplain;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
-use moveos_types::state::{AnnotatedState, State};
+use moveos_types::state::{AnnotatedState, AnnotatedStateChangeSet, State};
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
 use rooch_types::address::MultiChainAddress;
+use rooch_types::framework::transaction_fee::GasPrice;
 use rooch_types::transaction::AbstractTransaction;
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +35,27 @@ where
     type Result = Result<VerifiedMoveOSTransaction>;
 }
 
+#[derive(Debug)]
+pub struct DryRunTransactionMessage<T> {
+    pub tx: T,
+    /// If true, the gas profile is returned in the output
+    pub profile_gas: bool,
+}
+
+#[derive(Debug)]
+pub struct DryRunTransactionResult {
+    pub raw_output: RawTransactionOutput,
+    /// The decoded state change set, None if the state change set can not be decoded.
+    pub annotated_state_changeset: Option<AnnotatedStateChangeSet>,
+}
+
+impl<T> Message for DryRunTransactionMessage<T>
+where
+    T: 'static + AbstractTransaction + Send + Sync,
+{
+    type Result = Result<DryRunTransactionResult>;
+}
+
 #[derive(Debug)]
 pub struct ExecuteTransactionMessage {
     pub tx: VerifiedMoveOSTransaction,
@@ -142,3 +168,34 @@ pub struct GetTxExecutionInfosByHashMessage {
 impl Message for GetTxExecutionInfosByHashMessage {
     type Result = Result<Vec<Option<TransactionExecutionInfo>>>;
 }
+
+/// Get the execution trace of the failed transaction
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTxExecutionTraceMessage {
+    pub tx_hash: H256,
+}
+
+impl Message for GetTxExecutionTraceMessage {
+    type Result = Result<Option<ExecutionTrace>>;
+}
+
+/// Explain the abort code with the error constants of the aborting module
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExplainMoveAbortMessage {
+    pub location: AbortLocation,
+    pub abort_code: u64,
+}
+
+impl Message for ExplainMoveAbortMessage {
+    type Result = Result<MoveAbortExplain>;
+}
+
+/// Get the gas price at the state roots, the latest state is used if the state root is None
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetGasPricesMessage {
+    pub state_roots: Vec<Option<H256>>,
+}
+
+impl Message for GetGasPricesMessage {
+    type Result = Result<Vec<Option<GasPrice>>>;
+}
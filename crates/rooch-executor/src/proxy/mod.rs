@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage,
-    ListAnnotatedStatesMessage, ListStatesMessage,
+    DryRunTransactionMessage, DryRunTransactionResult, ExplainMoveAbortMessage,
+    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetGasPricesMessage,
+    GetTxExecutionInfosByHashMessage, GetTxExecutionTraceMessage, ListAnnotatedStatesMessage,
+    ListStatesMessage,
 };
 use crate::actor::{
     executor::ExecutorActor,
@@ -16,9 +18,12 @@ use anyhow::Result;
 use coerce::actor::ActorRef;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::AbortLocation;
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::function_return_value::{AnnotatedFunctionResult, FunctionResult};
 use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::error::MoveAbortExplain;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::transaction::FunctionCall;
@@ -30,6 +35,7 @@ use moveos_types::{
     state::{AnnotatedState, State},
 };
 use rooch_types::address::MultiChainAddress;
+use rooch_types::framework::transaction_fee::GasPrice;
 use rooch_types::transaction::AbstractTransaction;
 use tokio::runtime::Handle;
 
@@ -50,6 +56,19 @@ impl ExecutorProxy {
         self.actor.send(ValidateTransactionMessage { tx }).await?
     }
 
+    pub async fn dry_run_transaction<T>(
+        &self,
+        tx: T,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResult>
+    where
+        T: 'static + AbstractTransaction + Send + Sync,
+    {
+        self.actor
+            .send(DryRunTransactionMessage { tx, profile_gas })
+            .await?
+    }
+
     //TODO ensure the execute result
     pub async fn execute_transaction(
         &self,
@@ -163,6 +182,35 @@ impl ExecutorProxy {
             .send(GetTxExecutionInfosByHashMessage { tx_hashes })
             .await?
     }
+
+    pub async fn get_transaction_execution_trace(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<ExecutionTrace>> {
+        self.actor
+            .send(GetTxExecutionTraceMessage { tx_hash })
+            .await?
+    }
+
+    pub async fn explain_move_abort(
+        &self,
+        location: AbortLocation,
+        abort_code: u64,
+    ) -> Result<MoveAbortExplain> {
+        self.actor
+            .send(ExplainMoveAbortMessage {
+                location,
+                abort_code,
+            })
+            .await?
+    }
+
+    pub async fn get_gas_prices(
+        &self,
+        state_roots: Vec<Option<H256>>,
+    ) -> Result<Vec<Option<GasPrice>>> {
+        self.actor.send(GetGasPricesMessage { state_roots }).await?
+    }
 }
 
 impl MoveFunctionCaller for ExecutorProxy {
@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::executor::ExecutorActor;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxReplayResult {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    /// Whether the state_root, event_root and gas_used of the replay match the stored execution info
+    pub matched: bool,
+    pub expected: TransactionExecutionInfo,
+    pub replayed: Option<TransactionExecutionInfo>,
+    /// The error message if the transaction failed to replay
+    pub error: Option<String>,
+}
+
+/// Replay the transactions in order of tx order, and compare the replay results with the expected execution infos.
+/// The executor is built by `new_executor` at the state root before the first transaction.
+/// If a replay mismatches, the next replay continues with a new executor at the expected state root,
+/// so one mismatch is only reported once.
+pub fn replay_txs<I, F>(
+    pre_state_root: H256,
+    txs: I,
+    new_executor: F,
+) -> Result<Vec<TxReplayResult>>
+where
+    I: IntoIterator<Item = Result<(u64, TypedTransaction, TransactionExecutionInfo)>>,
+    F: Fn(H256) -> Result<ExecutorActor>,
+{
+    let mut executor = new_executor(pre_state_root)?;
+    let mut txs = txs.into_iter().peekable();
+    let mut results = vec![];
+    while let Some(tx) = txs.next() {
+        let (tx_order, tx, expected) = tx?;
+        let tx_hash = tx.tx_hash();
+        let (replayed, error) = match executor.replay(tx) {
+            Ok(replayed) => (Some(replayed), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let matched = replayed.as_ref().map_or(false, |replayed| {
+            replayed.state_root == expected.state_root
+                && replayed.event_root == expected.event_root
+                && replayed.gas_used == expected.gas_used
+        });
+        if !matched && txs.peek().is_some() {
+            executor = new_executor(expected.state_root)?;
+        }
+        results.push(TxReplayResult {
+            tx_order,
+            tx_hash,
+            matched,
+            expected,
+            replayed,
+            error,
+        });
+    }
+    Ok(results)
+}
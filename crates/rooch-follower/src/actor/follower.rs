@@ -0,0 +1,194 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::FollowTick;
+use crate::{BatchFetcher, TxReplayer};
+use anyhow::{ensure, format_err, Result};
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use moveos_types::h256::H256;
+use rooch_da::batch::DABatch;
+use rooch_rpc_client::ClientBuilder;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{AbstractTransaction, TransactionWithInfo};
+use tracing::{error, info, warn};
+
+/// The max number of blocks replayed in one tick, avoid blocking the actor too long.
+const MAX_BLOCKS_PER_TICK: u64 = 10;
+
+/// FollowerActor syncs the transaction batches proposed by the leader,
+/// verifies them and re-executes them on the local node.
+/// The follower halts if the local execution result diverges from the leader.
+pub struct FollowerActor {
+    sequencer: RoochAddress,
+    batch_fetcher: Box<dyn BatchFetcher>,
+    tx_replayer: Box<dyn TxReplayer>,
+    rooch_store: RoochStore,
+    halted: bool,
+}
+
+impl FollowerActor {
+    pub async fn new_for_leader<R: TxReplayer + 'static>(
+        leader_url: &str,
+        sequencer: RoochAddress,
+        tx_replayer: R,
+        rooch_store: RoochStore,
+    ) -> Result<Self> {
+        let leader_client = ClientBuilder::default().build(leader_url).await?;
+        Ok(Self::new(
+            sequencer,
+            leader_client,
+            tx_replayer,
+            rooch_store,
+        ))
+    }
+
+    pub fn new<F: BatchFetcher + 'static, R: TxReplayer + 'static>(
+        sequencer: RoochAddress,
+        batch_fetcher: F,
+        tx_replayer: R,
+        rooch_store: RoochStore,
+    ) -> Self {
+        Self {
+            sequencer,
+            batch_fetcher: Box::new(batch_fetcher),
+            tx_replayer: Box::new(tx_replayer),
+            rooch_store,
+            halted: false,
+        }
+    }
+
+    fn next_block_number(&self) -> Result<u128> {
+        Ok(self
+            .rooch_store
+            .get_last_block_number()?
+            .map_or(0, |block_number| block_number + 1))
+    }
+
+    async fn sync(&mut self) -> Result<()> {
+        for _ in 0..MAX_BLOCKS_PER_TICK {
+            let block_number = self.next_block_number()?;
+            let batch = match self.batch_fetcher.get_batch(block_number).await {
+                Ok(Some(batch)) => batch,
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    warn!(
+                        "[Follower] Fetch the batch of block {} error: {:?}",
+                        block_number, e
+                    );
+                    return Ok(());
+                }
+            };
+            let batch_size = batch.block.batch_size;
+            self.replay_batch(block_number, batch).await?;
+            info!(
+                "[Follower] Replayed block {}, batch_size: {}",
+                block_number, batch_size
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the follower is halted because the replay failed or diverged from the leader.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Sync the batches from the leader, halt the follower if the replay failed.
+    pub(crate) async fn follow(&mut self) {
+        if self.halted {
+            return;
+        }
+        if let Err(err) = self.sync().await {
+            error!(
+                "[Follower] Replay error, the follower is halted, please check the node: {:?}",
+                err
+            );
+            self.halted = true;
+        }
+    }
+
+    pub(crate) async fn replay_batch(&self, block_number: u128, batch: DABatch) -> Result<()> {
+        batch.check()?;
+        let DABatch {
+            block,
+            transactions,
+        } = batch;
+        ensure!(
+            block.block_number == block_number,
+            "The block number {} of the batch mismatch with the expected {}",
+            block.block_number,
+            block_number
+        );
+        let prev_tx_accumulator_root = match block_number.checked_sub(1) {
+            Some(prev_block_number) => self
+                .rooch_store
+                .get_block(prev_block_number)?
+                .map(|prev_block| prev_block.tx_accumulator_root)
+                .ok_or_else(|| format_err!("The block {} not found in store", prev_block_number))?,
+            None => H256::zero(),
+        };
+        ensure!(
+            block.prev_tx_accumulator_root == prev_tx_accumulator_root,
+            "The prev tx accumulator root {:?} of block {} mismatch with the local {:?}",
+            block.prev_tx_accumulator_root,
+            block_number,
+            prev_tx_accumulator_root
+        );
+        ensure!(
+            transactions.first().map(|tx| tx.sequence_info.tx_order) == Some(block.first_tx_order),
+            "The first tx order {} of block {} mismatch with the batch",
+            block.first_tx_order,
+            block_number
+        );
+
+        let last_order = self
+            .rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order);
+        for (tx, state_root) in transactions.into_iter().zip(block.state_roots.iter()) {
+            let TransactionWithInfo {
+                transaction,
+                sequence_info,
+                execution_info,
+            } = tx;
+            let tx_order = sequence_info.tx_order;
+            // Skip the transactions replayed before the node restarted in the middle of the batch.
+            if matches!(last_order, Some(last_order) if tx_order <= last_order) {
+                continue;
+            }
+            let tx_hash = transaction.tx_hash();
+            ensure!(
+                tx_hash == execution_info.tx_hash && execution_info.state_root == *state_root,
+                "The execution info of tx order {} mismatch with the block {}",
+                tx_order,
+                block_number
+            );
+            sequence_info.verify_tx_order_signature(tx_hash, self.sequencer)?;
+            let local_execution_info = self
+                .tx_replayer
+                .replay_tx(transaction, sequence_info)
+                .await?;
+            ensure!(
+                local_execution_info.state_root == execution_info.state_root,
+                "State root diverged at tx order {}, local: {:?}, leader: {:?}",
+                tx_order,
+                local_execution_info.state_root,
+                execution_info.state_root
+            );
+        }
+        self.rooch_store.save_block(block)
+    }
+}
+
+impl Actor for FollowerActor {}
+
+#[async_trait]
+impl Handler<FollowTick> for FollowerActor {
+    async fn handle(&mut self, _message: FollowTick, _ctx: &mut ActorContext) {
+        self.follow().await
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_da::batch::DABatch;
+use rooch_rpc_client::Client;
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+
+pub mod actor;
+#[cfg(test)]
+mod tests;
+
+/// BatchFetcher fetches the transaction batches of the blocks proposed by the leader.
+#[async_trait]
+pub trait BatchFetcher: Send + Sync {
+    async fn get_batch(&self, block_number: u128) -> Result<Option<DABatch>>;
+}
+
+#[async_trait]
+impl BatchFetcher for Client {
+    async fn get_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        self.rooch.get_da_batch(block_number).await
+    }
+}
+
+/// TxReplayer re-executes the transaction sequenced by the leader on the local node.
+#[async_trait]
+pub trait TxReplayer: Send + Sync {
+    async fn replay_tx(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<TransactionExecutionInfo>;
+}
@@ -0,0 +1,245 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::follower::FollowerActor;
+use crate::{BatchFetcher, TxReplayer};
+use anyhow::Result;
+use async_trait::async_trait;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+use rooch_da::batch::DABatch;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::block::Block;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::sequencer::SequencerOrder;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{
+    AbstractTransaction, TransactionSequenceInfo, TransactionWithInfo, TypedTransaction,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+struct MockBatchFetcher {
+    batches: HashMap<u128, DABatch>,
+}
+
+#[async_trait]
+impl BatchFetcher for MockBatchFetcher {
+    async fn get_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        Ok(self.batches.get(&block_number).cloned())
+    }
+}
+
+/// MockTxReplayer returns the local state root of the tx order as the replay result,
+/// and saves the sequencer order like the replay of the node.
+#[derive(Clone)]
+struct MockTxReplayer {
+    rooch_store: RoochStore,
+    local_state_roots: Arc<Mutex<HashMap<u64, H256>>>,
+    replayed_orders: Arc<Mutex<Vec<u64>>>,
+}
+
+impl MockTxReplayer {
+    fn new(rooch_store: RoochStore, batches: &[DABatch]) -> Self {
+        let local_state_roots = batches
+            .iter()
+            .flat_map(|batch| batch.transactions.iter())
+            .map(|tx| (tx.sequence_info.tx_order, tx.execution_info.state_root))
+            .collect();
+        Self {
+            rooch_store,
+            local_state_roots: Arc::new(Mutex::new(local_state_roots)),
+            replayed_orders: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    fn replayed_orders(&self) -> Vec<u64> {
+        self.replayed_orders.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl TxReplayer for MockTxReplayer {
+    async fn replay_tx(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<TransactionExecutionInfo> {
+        let tx_order = sequence_info.tx_order;
+        let state_root = self.local_state_roots.lock().unwrap()[&tx_order];
+        self.replayed_orders.lock().unwrap().push(tx_order);
+        self.rooch_store
+            .save_sequencer_order(SequencerOrder::new(tx_order))?;
+        Ok(TransactionExecutionInfo::new(
+            tx.tx_hash(),
+            state_root,
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        ))
+    }
+}
+
+fn sequencer_key() -> RoochKeyPair {
+    RoochKeyPair::try_from_bytes(BuiltinAuthValidator::Rooch, &[1u8; 32]).unwrap()
+}
+
+fn mock_tx(tx_order: u64, sequencer_key: &RoochKeyPair) -> TransactionWithInfo {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        0,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    let transaction = RoochTransaction::new(tx_data, Authenticator::new(0, vec![]));
+    let tx_hash = transaction.tx_hash();
+    let tx_timestamp = 1000 + tx_order;
+    TransactionWithInfo {
+        transaction: TypedTransaction::Rooch(transaction),
+        sequence_info: TransactionSequenceInfo::new(
+            tx_order,
+            TransactionSequenceInfo::sign_tx_order(tx_hash, tx_order, tx_timestamp, sequencer_key),
+            H256::random(),
+            tx_timestamp,
+        ),
+        execution_info: TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        ),
+    }
+}
+
+/// Mock the batches of the blocks proposed by the leader, each block has `batch_size` transactions.
+fn mock_batches(block_count: u128, batch_size: u64, sequencer_key: &RoochKeyPair) -> Vec<DABatch> {
+    let mut prev_tx_accumulator_root = H256::zero();
+    (0..block_count)
+        .map(|block_number| {
+            let first_tx_order = block_number as u64 * batch_size;
+            let transactions: Vec<_> = (first_tx_order..first_tx_order + batch_size)
+                .map(|tx_order| mock_tx(tx_order, sequencer_key))
+                .collect();
+            let last_tx = transactions.last().unwrap();
+            let block = Block::new(
+                block_number,
+                batch_size,
+                prev_tx_accumulator_root,
+                last_tx.sequence_info.tx_accumulator_root,
+                transactions
+                    .iter()
+                    .map(|tx| tx.execution_info.state_root)
+                    .collect(),
+                first_tx_order,
+                last_tx.sequence_info.tx_timestamp,
+            );
+            prev_tx_accumulator_root = block.tx_accumulator_root;
+            DABatch::new(block, transactions)
+        })
+        .collect()
+}
+
+fn new_follower(
+    rooch_store: RoochStore,
+    batches: &[DABatch],
+    tx_replayer: MockTxReplayer,
+) -> FollowerActor {
+    let sequencer = sequencer_key().public().address();
+    let batch_fetcher = MockBatchFetcher {
+        batches: batches
+            .iter()
+            .map(|batch| (batch.block.block_number, batch.clone()))
+            .collect(),
+    };
+    FollowerActor::new(sequencer, batch_fetcher, tx_replayer, rooch_store)
+}
+
+#[tokio::test]
+async fn test_follow_batches() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let batches = mock_batches(2, 3, &sequencer_key());
+    let tx_replayer = MockTxReplayer::new(rooch_store.clone(), &batches);
+    let mut follower = new_follower(rooch_store.clone(), &batches, tx_replayer.clone());
+
+    follower.follow().await;
+    assert!(!follower.is_halted());
+    assert_eq!(tx_replayer.replayed_orders(), (0..6).collect::<Vec<_>>());
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), Some(1));
+    assert_eq!(
+        rooch_store.get_block(1).unwrap(),
+        Some(batches[1].block.clone())
+    );
+}
+
+#[tokio::test]
+async fn test_resume_from_the_middle_of_batch() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let batches = mock_batches(1, 4, &sequencer_key());
+    let tx_replayer = MockTxReplayer::new(rooch_store.clone(), &batches);
+    let follower = new_follower(rooch_store.clone(), &batches, tx_replayer.clone());
+
+    // The node restarted after the tx order 1 is replayed, but before the block is saved
+    rooch_store
+        .save_sequencer_order(SequencerOrder::new(1))
+        .unwrap();
+    follower.replay_batch(0, batches[0].clone()).await.unwrap();
+    assert_eq!(tx_replayer.replayed_orders(), vec![2, 3]);
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), Some(0));
+}
+
+#[tokio::test]
+async fn test_divergence_halts_the_follower() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let batches = mock_batches(2, 3, &sequencer_key());
+    let tx_replayer = MockTxReplayer::new(rooch_store.clone(), &batches);
+    let mut follower = new_follower(rooch_store.clone(), &batches, tx_replayer.clone());
+
+    // The local state root diverges from the leader at tx order 1
+    tx_replayer
+        .local_state_roots
+        .lock()
+        .unwrap()
+        .insert(1, H256::random());
+    follower.follow().await;
+    assert!(follower.is_halted());
+    assert_eq!(tx_replayer.replayed_orders(), vec![0, 1]);
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), None);
+
+    // The halted follower does not replay the following transactions
+    follower.follow().await;
+    assert_eq!(tx_replayer.replayed_orders(), vec![0, 1]);
+}
+
+#[tokio::test]
+async fn test_reject_prev_tx_accumulator_root_mismatch() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let mut batches = mock_batches(2, 3, &sequencer_key());
+    let tx_replayer = MockTxReplayer::new(rooch_store.clone(), &batches);
+    let follower = new_follower(rooch_store.clone(), &batches, tx_replayer.clone());
+
+    follower.replay_batch(0, batches[0].clone()).await.unwrap();
+    batches[1].block.prev_tx_accumulator_root = H256::random();
+    assert!(follower.replay_batch(1, batches[1].clone()).await.is_err());
+    assert_eq!(tx_replayer.replayed_orders(), vec![0, 1, 2]);
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), Some(0));
+}
+
+#[tokio::test]
+async fn test_reject_missing_state_roots() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let mut batches = mock_batches(1, 3, &sequencer_key());
+    let tx_replayer = MockTxReplayer::new(rooch_store.clone(), &batches);
+    let follower = new_follower(rooch_store.clone(), &batches, tx_replayer.clone());
+
+    // The state root of the last transaction is missing in the block
+    batches[0].block.state_roots.pop();
+    assert!(follower.replay_batch(0, batches[0].clone()).await.is_err());
+    assert!(tx_replayer.replayed_orders().is_empty());
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), None);
+}
@@ -2,13 +2,56 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{bail, Result};
-use move_core_types::vm_status::KeptVMStatus;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus, VMStatus};
 use moveos_store::MoveOSStore;
 use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::transaction::{MoveAction, VerifiedMoveOSTransaction};
 use rooch_executor::actor::{executor::ExecutorActor, messages::ExecuteTransactionResult};
 use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
 use rooch_types::{chain_id::RoochChainID, transaction::AbstractTransaction};
 
+/// The reason of `auth_validator::error_invalid_account_auth_key`
+pub const ERROR_INVALID_ACCOUNT_AUTH_KEY: u64 = 1001;
+/// The reason of `auth_validator::error_invalid_authenticator`
+pub const ERROR_INVALID_AUTHENTICATOR: u64 = 1002;
+
+/// The transaction data of the `0x3::empty::empty` call of the sender, it is signed by the auth validator tests.
+pub fn empty_call_tx_data(sender: RoochAddress, sequence_number: u64) -> RoochTransactionData {
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    RoochTransactionData::new_for_test(sender, sequence_number, action)
+}
+
+/// Build the transaction of the `0x3::empty::empty` call of the sender with the authenticator returned by `sign`.
+pub fn sign_empty_call<F>(sender: RoochAddress, sequence_number: u64, sign: F) -> RoochTransaction
+where
+    F: FnOnce(&RoochTransactionData) -> Authenticator,
+{
+    let tx_data = empty_call_tx_data(sender, sequence_number);
+    let authenticator = sign(&tx_data);
+    RoochTransaction::new(tx_data, authenticator)
+}
+
+/// Assert the transaction is rejected by the validation with the Move abort of the module and the reason.
+pub fn assert_validate_abort<T>(result: Result<T>, module_id: &ModuleId, reason: u64) {
+    let error = match result {
+        Ok(_) => panic!("expect transaction validate error"),
+        Err(error) => error,
+    };
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(AbortLocation::Module(abort_module_id), code)) => {
+            assert_eq!(abort_module_id, module_id, "unexpected abort module");
+            let (_category, abort_reason) = moveos_types::move_std::error::explain(*code);
+            assert_eq!(abort_reason, reason, "unexpected abort reason");
+        }
+        _ => panic!("expect move abort, got {:?}", error),
+    }
+}
+
 pub struct RustBindingTest {
     executor: ExecutorActor,
 }
@@ -22,6 +65,10 @@ impl RustBindingTest {
         Ok(Self { executor })
     }
 
+    pub fn executor(&self) -> &ExecutorActor {
+        &self.executor
+    }
+
     pub fn as_module_bundle<'a, M: ModuleBinding<'a>>(&'a self) -> M {
         self.executor.moveos().as_module_binding::<M>()
     }
@@ -43,6 +90,14 @@ impl RustBindingTest {
         tx: T,
     ) -> Result<ExecuteTransactionResult> {
         let verified_tx = self.executor.validate(tx)?;
-        self.executor.execute(verified_tx)
+        self.execute_verified_tx(verified_tx)
+    }
+
+    /// Execute the transaction verified by MoveOS without the auth validation, such as the transaction of the system accounts.
+    pub fn execute_verified_tx(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<ExecuteTransactionResult> {
+        self.executor.execute(tx)
     }
 }
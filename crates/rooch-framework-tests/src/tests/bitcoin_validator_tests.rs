@@ -0,0 +1,315 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::absolute::LockTime;
+use bitcoin::hashes::{sha256, sha256d, Hash};
+use bitcoin::key::TapTweak;
+use bitcoin::secp256k1::{Message, Secp256k1};
+use bitcoin::sighash::{EcdsaSighashType, Prevouts, SighashCache, TapSighashType};
+use bitcoin::transaction::Version;
+use bitcoin::{
+    Address, Amount, Network, OutPoint, PrivateKey, ScriptBuf, Sequence, Transaction, TxIn, TxOut,
+    Witness,
+};
+use move_core_types::ident_str;
+use move_core_types::language_storage::ModuleId;
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use rooch_types::address::{BitcoinAddress, MultiChainAddress};
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::address_mapping::AddressMapping;
+use rooch_types::transaction::authenticator::{Authenticator, BitcoinAuthenticator};
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+use crate::binding_test::{
+    self, assert_validate_abort, empty_call_tx_data, sign_empty_call,
+    ERROR_INVALID_ACCOUNT_AUTH_KEY, ERROR_INVALID_AUTHENTICATOR,
+};
+
+/// The BIP-137 header of the P2WPKH address with recovery id 0
+const BIP137_HEADER_P2WPKH: u8 = 39;
+
+fn bitcoin_validator_module() -> ModuleId {
+    ModuleId::new(
+        ROOCH_FRAMEWORK_ADDRESS,
+        ident_str!("bitcoin_validator").to_owned(),
+    )
+}
+
+fn bitcoin_message_hash(message: &str) -> [u8; 32] {
+    let prefix = b"Bitcoin Signed Message:\n";
+    let mut data = vec![prefix.len() as u8];
+    data.extend_from_slice(prefix);
+    data.push(message.len() as u8);
+    data.extend_from_slice(message.as_bytes());
+    sha256d::Hash::hash(&data).to_byte_array()
+}
+
+fn sign_bip137(private_key: &PrivateKey, tx_data: &RoochTransactionData) -> BitcoinAuthenticator {
+    let secp = Secp256k1::new();
+    let message = BitcoinAuthenticator::sign_message(&tx_data.hash());
+    let signature = secp.sign_ecdsa(
+        &Message::from_digest(bitcoin_message_hash(&message)),
+        &private_key.inner,
+    );
+    let mut bip137_signature = vec![BIP137_HEADER_P2WPKH];
+    bip137_signature.extend_from_slice(&signature.serialize_compact());
+    BitcoinAuthenticator::bip137(bip137_signature, private_key.public_key(&secp).to_bytes())
+}
+
+/// The tagged hash of the message in BIP-322
+fn bip322_message_hash(message: &str) -> [u8; 32] {
+    let tag_hash = sha256::Hash::hash(b"BIP0322-signed-message").to_byte_array();
+    let mut data = tag_hash.to_vec();
+    data.extend_from_slice(&tag_hash);
+    data.extend_from_slice(message.as_bytes());
+    sha256::Hash::hash(&data).to_byte_array()
+}
+
+/// Build the BIP-322 `to_sign` transaction of the message and the output of the `to_spend` transaction it spends.
+fn bip322_to_sign(address: &Address, message: &str) -> (Transaction, TxOut) {
+    let mut script_sig = vec![0x00, 0x20];
+    script_sig.extend_from_slice(&bip322_message_hash(message));
+    let to_spend = Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::from_bytes(script_sig),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: address.script_pubkey(),
+        }],
+    };
+    let to_sign = Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(to_spend.txid(), 0),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: ScriptBuf::from_bytes(vec![0x6a]),
+        }],
+    };
+    (to_sign, to_spend.output[0].clone())
+}
+
+fn p2wpkh_address(private_key: &PrivateKey) -> Address {
+    let secp = Secp256k1::new();
+    Address::p2wpkh(&private_key.public_key(&secp), Network::Bitcoin).unwrap()
+}
+
+/// Sign the transaction with the BIP-322 simple signature of the P2WPKH spending.
+fn sign_bip322_p2wpkh(
+    private_key: &PrivateKey,
+    tx_data: &RoochTransactionData,
+) -> BitcoinAuthenticator {
+    let secp = Secp256k1::new();
+    let message = BitcoinAuthenticator::sign_message(&tx_data.hash());
+    let (to_sign, prevout) = bip322_to_sign(&p2wpkh_address(private_key), &message);
+    let sighash = SighashCache::new(&to_sign)
+        .p2wpkh_signature_hash(
+            0,
+            &prevout.script_pubkey,
+            prevout.value,
+            EcdsaSighashType::All,
+        )
+        .unwrap();
+    let signature = secp.sign_ecdsa(
+        &Message::from_digest(sighash.to_byte_array()),
+        &private_key.inner,
+    );
+    BitcoinAuthenticator::bip322_simple(
+        signature.serialize_compact().to_vec(),
+        private_key.public_key(&secp).to_bytes(),
+    )
+}
+
+fn taproot_address(private_key: &PrivateKey) -> Address {
+    let secp = Secp256k1::new();
+    let (internal_key, _) = private_key.inner.keypair(&secp).x_only_public_key();
+    Address::p2tr(&secp, internal_key, None, Network::Bitcoin)
+}
+
+/// Sign the transaction with the BIP-322 simple signature of the taproot key path spending.
+fn sign_bip322_taproot(
+    private_key: &PrivateKey,
+    tx_data: &RoochTransactionData,
+) -> BitcoinAuthenticator {
+    let secp = Secp256k1::new();
+    let tweaked_keypair = private_key
+        .inner
+        .keypair(&secp)
+        .tap_tweak(&secp, None)
+        .to_inner();
+    let (output_key, _) = tweaked_keypair.x_only_public_key();
+
+    let message = BitcoinAuthenticator::sign_message(&tx_data.hash());
+    let (to_sign, prevout) = bip322_to_sign(&taproot_address(private_key), &message);
+    let sighash = SighashCache::new(&to_sign)
+        .taproot_key_spend_signature_hash(0, &Prevouts::All(&[prevout]), TapSighashType::Default)
+        .unwrap();
+    let signature = secp.sign_schnorr_no_aux_rand(
+        &Message::from_digest(sighash.to_byte_array()),
+        &tweaked_keypair,
+    );
+    BitcoinAuthenticator::bip322_simple(
+        signature.serialize().to_vec(),
+        output_key.serialize().to_vec(),
+    )
+}
+
+#[test]
+fn test_validate_bip137() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let private_key = PrivateKey::generate(Network::Bitcoin);
+    let bitcoin_address = BitcoinAddress(p2wpkh_address(&private_key));
+    let sender = bitcoin_address.to_rooch_address();
+    let multichain_address = MultiChainAddress::from(bitcoin_address);
+    assert_eq!(
+        binding_test
+            .as_module_bundle::<AddressMapping>()
+            .resolve(multichain_address.clone())
+            .unwrap(),
+        None
+    );
+
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        Authenticator::bitcoin(sign_bip137(&private_key, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The Bitcoin address is bound to the sender by the first transaction,
+    // the following transactions are validated with the bound address mapping.
+    assert_eq!(
+        binding_test
+            .as_module_bundle::<AddressMapping>()
+            .resolve(multichain_address)
+            .unwrap(),
+        Some(sender.into())
+    );
+    let tx = sign_empty_call(sender, 1, |tx_data| {
+        Authenticator::bitcoin(sign_bip137(&private_key, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The signer's Bitcoin address is not mapped to the sender
+    let other_private_key = PrivateKey::generate(Network::Bitcoin);
+    let tx = sign_empty_call(sender, 2, |tx_data| {
+        Authenticator::bitcoin(sign_bip137(&other_private_key, tx_data))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &bitcoin_validator_module(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+}
+
+#[test]
+fn test_validate_bip322_taproot() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let private_key = PrivateKey::generate(Network::Bitcoin);
+    let sender = BitcoinAddress(taproot_address(&private_key)).to_rooch_address();
+
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        Authenticator::bitcoin(sign_bip322_taproot(&private_key, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The signature is signed for another transaction
+    let other_tx_data = empty_call_tx_data(sender, 2);
+    let tx = sign_empty_call(sender, 1, |_| {
+        Authenticator::bitcoin(sign_bip322_taproot(&private_key, &other_tx_data))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &bitcoin_validator_module(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+}
+
+#[test]
+fn test_validate_bip322_p2wpkh() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let private_key = PrivateKey::generate(Network::Bitcoin);
+    let sender = BitcoinAddress(p2wpkh_address(&private_key)).to_rooch_address();
+
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        Authenticator::bitcoin(sign_bip322_p2wpkh(&private_key, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The P2WPKH and the taproot addresses of the same key are different accounts
+    let tx = sign_empty_call(sender, 1, |tx_data| {
+        Authenticator::bitcoin(sign_bip322_taproot(&private_key, tx_data))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &bitcoin_validator_module(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+}
+
+#[test]
+fn test_validate_invalid_payload() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let private_key = PrivateKey::generate(Network::Bitcoin);
+    let sender = BitcoinAddress(p2wpkh_address(&private_key)).to_rooch_address();
+    let mut assert_invalid = |tamper: fn(&mut BitcoinAuthenticator)| {
+        let tx = sign_empty_call(sender, 0, |tx_data| {
+            let mut authenticator = sign_bip137(&private_key, tx_data);
+            tamper(&mut authenticator);
+            Authenticator::bitcoin(authenticator)
+        });
+        assert_validate_abort(
+            binding_test.execute_as_result(tx),
+            &bitcoin_validator_module(),
+            ERROR_INVALID_AUTHENTICATOR,
+        );
+    };
+
+    // The BIP-137 signature without the header byte
+    assert_invalid(|authenticator| {
+        authenticator.signature.remove(0);
+    });
+    // The public key is not a compressed public key
+    assert_invalid(|authenticator| {
+        authenticator.public_key.push(0);
+    });
+    // The unknown sign type
+    assert_invalid(|authenticator| {
+        authenticator.sign_type = 2;
+    });
+    // The BIP-137 signature is not a BIP-322 signature
+    assert_invalid(|authenticator| {
+        authenticator.sign_type = BitcoinAuthenticator::SIGN_TYPE_BIP322_SIMPLE;
+    });
+    // The BIP-322 public key is neither a compressed public key nor a x-only output key
+    assert_invalid(|authenticator| {
+        authenticator.sign_type = BitcoinAuthenticator::SIGN_TYPE_BIP322_SIMPLE;
+        authenticator.signature.remove(0);
+        authenticator.public_key.push(0);
+    });
+
+    // The payload is not the bcs bytes of the Bitcoin authenticator
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        let mut authenticator = Authenticator::bitcoin(sign_bip137(&private_key, tx_data));
+        authenticator.payload.pop();
+        authenticator
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &ModuleId::new(MOVEOS_STD_ADDRESS, ident_str!("bcs").to_owned()),
+        1,
+    );
+}
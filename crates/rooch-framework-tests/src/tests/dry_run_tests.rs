@@ -0,0 +1,76 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::gas_config::GasConfig;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+/// A transaction creating a session key of the sender, it changes the state of the sender.
+fn create_session_key_tx(keystore: &mut InMemKeystore) -> RoochTransaction {
+    let sender = keystore.addresses()[0];
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+    let session_scope = SessionScope::new(
+        ROOCH_FRAMEWORK_ADDRESS,
+        Empty::MODULE_NAME.as_str(),
+        Empty::EMPTY_FUNCTION_NAME.as_str(),
+    );
+    let action = SessionKeyModule::create_session_key_action(
+        session_auth_key.as_ref().to_vec(),
+        session_scope,
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    keystore.sign_transaction(&sender, tx_data, None).unwrap()
+}
+
+#[test]
+fn test_dry_run_does_not_apply_output() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let tx = create_session_key_tx(&mut keystore);
+
+    let result = binding_test.executor().dry_run(tx.clone(), false).unwrap();
+    assert_eq!(result.raw_output.status, KeptVMStatus::Executed);
+    assert!(result.raw_output.gas_used > 0);
+    assert!(!result.raw_output.state_changeset.changes.is_empty());
+    let annotated_state_changeset = result
+        .annotated_state_changeset
+        .expect("the state change set should be decoded");
+    assert_eq!(
+        annotated_state_changeset.changes.len(),
+        result.raw_output.state_changeset.changes.len()
+    );
+
+    // The output of the dry run is not applied, so the same transaction can be dry run again with the same result
+    let result_again = binding_test.executor().dry_run(tx.clone(), false).unwrap();
+    assert_eq!(result_again.raw_output.gas_used, result.raw_output.gas_used);
+    assert!(result_again
+        .raw_output
+        .state_changeset
+        .changes
+        .keys()
+        .eq(result.raw_output.state_changeset.changes.keys()));
+
+    // The transaction is executed with the same gas used as the dry run
+    let execute_result = binding_test.execute_as_result(tx.clone()).unwrap();
+    assert_eq!(
+        execute_result.transaction_info.status,
+        KeptVMStatus::Executed
+    );
+    assert_eq!(
+        execute_result.transaction_info.gas_used,
+        result.raw_output.gas_used
+    );
+    let estimated_gas =
+        GasConfig::estimate_max_gas_amount(result.raw_output.gas_used, tx.max_gas_amount());
+    assert!(estimated_gas >= execute_result.transaction_info.gas_used);
+
+    // The sequence number of the sender is increased by the execution, so the transaction can not be dry run anymore
+    assert!(binding_test.executor().dry_run(tx, false).is_err());
+}
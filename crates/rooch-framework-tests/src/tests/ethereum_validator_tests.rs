@@ -1,10 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use ethers::signers::LocalWallet;
 use ethers::types::{Bytes, U256};
 use moveos_types::transaction::MoveAction;
-use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_key::keystore::memory_keystore::InMemKeystore;
 use rooch_types::framework::empty::Empty;
 use rooch_types::transaction::ethereum::EthereumTransaction;
 use rooch_types::transaction::AbstractTransaction;
@@ -20,13 +19,12 @@ fn test_validate() {
     let address_mapping =
         binding_test.as_module_bundle::<rooch_types::framework::address_mapping::AddressMapping>();
 
-    let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
     let sequence_number = U256::zero();
     let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
     let action_bytes =
         Bytes::try_from(bcs::to_bytes(&action).unwrap()).expect("Convert action to bytes failed.");
-    let tx = EthereumTransaction::new_for_test(sender, sequence_number, action_bytes);
+    let tx = EthereumTransaction::new_for_test(&wallet, sequence_number, action_bytes);
 
     let multi_chain_address_sender = tx.sender();
     let resolved_sender = address_mapping
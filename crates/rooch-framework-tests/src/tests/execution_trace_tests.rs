@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_store::MoveOSStore;
+use moveos_types::state::MoveStructType;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_store::RoochStore;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::AbstractTransaction;
+
+/// A transfer from an account without balance, it aborts in the coin module.
+fn failed_transfer_tx(keystore: &InMemKeystore) -> RoochTransaction {
+    let sender = keystore.addresses()[0];
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        AccountAddress::random(),
+        U256::from(1u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    keystore.sign_transaction(&sender, tx_data, None).unwrap()
+}
+
+#[test]
+fn test_dry_run_returns_execution_trace() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let tx = failed_transfer_tx(&keystore);
+    let tx_hash = tx.tx_hash();
+
+    let result = binding_test.executor().dry_run(tx, false).unwrap();
+    assert!(matches!(
+        result.raw_output.status,
+        KeptVMStatus::MoveAbort(_, _)
+    ));
+    let execution_trace = result
+        .raw_output
+        .execution_trace
+        .expect("the failed dry run should return the execution trace");
+    assert!(!execution_trace.frames.is_empty());
+
+    // The dry run does not save anything
+    assert!(binding_test
+        .executor()
+        .moveos()
+        .transaction_trace_store()
+        .get_tx_execution_trace(tx_hash)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_trace_failed_txs_is_opt_in() {
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+
+    // The failed transaction is not traced by default
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let tx = failed_transfer_tx(&keystore);
+    let tx_hash = tx.tx_hash();
+    let result = binding_test.execute_as_result(tx).unwrap();
+    assert!(matches!(
+        result.transaction_info.status,
+        KeptVMStatus::MoveAbort(_, _)
+    ));
+    assert!(result.output.execution_trace.is_none());
+    assert!(binding_test
+        .executor()
+        .moveos()
+        .transaction_trace_store()
+        .get_tx_execution_trace(tx_hash)
+        .unwrap()
+        .is_none());
+
+    // The trace of the failed transaction is saved when tracing is enabled
+    let mut executor = ExecutorActor::new(
+        RoochChainID::LOCAL.genesis_ctx(),
+        MoveOSStore::mock_moveos_store().unwrap(),
+        RoochStore::mock_rooch_store().unwrap(),
+    )
+    .unwrap()
+    .with_trace_failed_txs(true);
+    let tx = failed_transfer_tx(&keystore);
+    let verified_tx = executor.validate(tx).unwrap();
+    let result = executor.execute(verified_tx).unwrap();
+    let execution_trace = result
+        .output
+        .execution_trace
+        .expect("the failed transaction should be traced");
+    assert_eq!(
+        executor
+            .moveos()
+            .transaction_trace_store()
+            .get_tx_execution_trace(tx_hash)
+            .unwrap(),
+        Some(execution_trace)
+    );
+}
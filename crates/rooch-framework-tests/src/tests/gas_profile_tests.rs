@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::u256::U256;
+use moveos::gas::profiler::function_frame_name;
+use moveos_types::gas_profile::GasProfileFrame;
+use moveos_types::state::MoveStructType;
+use rooch_framework::natives::{all_natives, GasParameters};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::collections::BTreeSet;
+
+fn native_function_names() -> BTreeSet<String> {
+    all_natives(GasParameters::zeros())
+        .into_iter()
+        .map(|(address, module_name, func_name, _)| {
+            function_frame_name(&ModuleId::new(address, module_name), func_name.as_str())
+        })
+        .collect()
+}
+
+fn collect_native_frames<'a>(
+    frame: &'a GasProfileFrame,
+    natives: &BTreeSet<String>,
+    native_frames: &mut Vec<&'a GasProfileFrame>,
+) {
+    if natives.contains(&frame.name) {
+        native_frames.push(frame);
+    }
+    for child in &frame.children {
+        collect_native_frames(child, natives, native_frames);
+    }
+}
+
+#[test]
+fn test_profile_native_function_calls() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+    let session_scope = SessionScope::new(
+        ROOCH_FRAMEWORK_ADDRESS,
+        Empty::MODULE_NAME.as_str(),
+        Empty::EMPTY_FUNCTION_NAME.as_str(),
+    );
+    let action = SessionKeyModule::create_session_key_action(
+        session_auth_key.as_ref().to_vec(),
+        session_scope,
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+
+    let result = binding_test.executor().dry_run(tx, true).unwrap();
+    let gas_profile = result
+        .raw_output
+        .gas_profile
+        .expect("the gas profile should be returned");
+
+    let natives = native_function_names();
+    let mut native_frames = vec![];
+    collect_native_frames(&gas_profile.root, &natives, &mut native_frames);
+    assert!(!native_frames.is_empty(), "expect native function calls");
+    for frame in native_frames {
+        // The native function frame is exited after the call, the following calls are not nested in it.
+        assert!(
+            frame.children.is_empty(),
+            "native frame {} should not have children",
+            frame.name
+        );
+        assert_eq!(frame.native_calls, 1);
+    }
+}
+
+#[test]
+fn test_trace_native_function_calls() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    // The transfer from an account without balance aborts in the coin module.
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        AccountAddress::random(),
+        U256::from(1u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+
+    let result = binding_test.executor().dry_run(tx, false).unwrap();
+    let execution_trace = result
+        .raw_output
+        .execution_trace
+        .expect("the failed dry run should return the execution trace");
+    let natives = native_function_names();
+    // The returned native function calls are not left in the call stack of the failure.
+    let (_, callers) = execution_trace
+        .frames
+        .split_last()
+        .expect("the call stack should not be empty");
+    assert!(callers
+        .iter()
+        .all(|frame| !natives.contains(&frame.function)));
+}
@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_store::MoveOSStore;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_framework::natives::gas_parameter::gas_member::{
+    FromOnChainGasSchedule, InitialGasSchedule, ToOnChainGasSchedule,
+};
+use rooch_framework::natives::rooch_framework::crypto::hash::GasParameters as HashGasParameters;
+use rooch_framework::natives::GasParameters;
+use rooch_store::RoochStore;
+use rooch_types::chain_id::RoochChainID;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_restart_executor_after_genesis() {
+    let moveos_store = MoveOSStore::mock_moveos_store().unwrap();
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let executor = ExecutorActor::new(
+        RoochChainID::LOCAL.genesis_ctx(),
+        moveos_store.clone(),
+        rooch_store.clone(),
+    )
+    .unwrap();
+    let gas_schedule = executor
+        .moveos()
+        .gas_schedule()
+        .unwrap()
+        .expect("The gas schedule should be initialized in genesis");
+    // The genesis gas schedule does not contain the native gas parameters
+    assert!(GasParameters::from_on_chain_gas_schedule(&gas_schedule.to_btree_map()).is_none());
+    drop(executor);
+
+    // Restart the executor on the genesis-initialized store, as the node loads the latest state root
+    let state_root = moveos_store
+        .get_config_store()
+        .get_startup_info()
+        .unwrap()
+        .map(|info| info.state_root_hash);
+    let moveos_store = MoveOSStore::new_with_root(moveos_store.moveosdb, state_root).unwrap();
+    let executor =
+        ExecutorActor::new(RoochChainID::LOCAL.genesis_ctx(), moveos_store, rooch_store).unwrap();
+    assert_eq!(
+        executor.moveos().gas_schedule().unwrap(),
+        Some(gas_schedule)
+    );
+}
+
+#[test]
+fn test_native_gas_parameters_with_missing_keys() {
+    assert!(GasParameters::from_on_chain_gas_schedule(&BTreeMap::new()).is_none());
+
+    // A native module with missing keys uses the default gas parameters
+    let default = format!("{:?}", GasParameters::initial());
+    let gas_schedule = BTreeMap::from([("hash.keccak256.base".to_owned(), 1000u64)]);
+    let gas_params = GasParameters::from_on_chain_gas_schedule_with_default(
+        &gas_schedule,
+        GasParameters::initial(),
+    );
+    assert_eq!(format!("{:?}", gas_params), default);
+
+    // A native module with all the keys uses the on-chain gas parameters
+    let gas_schedule: BTreeMap<String, u64> = HashGasParameters::initial()
+        .to_on_chain_gas_schedule()
+        .into_iter()
+        .map(|(key, _)| (key, 1000))
+        .collect();
+    let gas_params = GasParameters::from_on_chain_gas_schedule_with_default(
+        &gas_schedule,
+        GasParameters::initial(),
+    );
+    assert_ne!(format!("{:?}", gas_params), default);
+}
@@ -2,9 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod bitcoin_light_client_test;
+mod bitcoin_validator_tests;
+mod dry_run_tests;
 mod empty_tests;
 mod ethereum_light_client_test;
 mod ethereum_validator_tests;
+mod execution_trace_tests;
+mod gas_profile_tests;
+mod gas_schedule_tests;
+mod module_metadata_tests;
+mod multisig_validator_tests;
 mod native_validator_tests;
+mod nostr_validator_tests;
 mod ord_test;
+mod package_registry_tests;
+mod replay_tests;
+mod secp_validator_tests;
 mod transaction_validator_tests;
+mod webauthn_validator_tests;
@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::{file_format::empty_module, CompiledModule};
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use move_core_types::{
+    language_storage::ModuleId, metadata::Metadata, resolver::ModuleResolver,
+    vm_status::AbortLocation,
+};
+use moveos_types::move_std::error::MoveAbortExplain;
+use moveos_verifier::build::{ROOCH_ERROR_MAP_KEY, ROOCH_METADATA_KEY};
+use moveos_verifier::metadata::RuntimeModuleMetadataV1;
+use moveos_verifier::metadata::{check_metadata_format, get_metadata_from_compiled_module};
+use rooch_genesis::get_framework_error_description;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use std::collections::BTreeMap;
+
+/// The layout of the metadata before the error constants were recorded.
+type LegacyMetadataV1 = (
+    BTreeMap<String, Vec<(u8, Vec<String>)>>,
+    BTreeMap<String, Vec<(u8, Vec<String>)>>,
+    BTreeMap<String, Vec<usize>>,
+    BTreeMap<String, (String, String)>,
+    BTreeMap<String, bool>,
+    BTreeMap<String, Vec<usize>>,
+);
+
+#[test]
+fn test_decode_legacy_metadata() {
+    let mut private_generics_indices = BTreeMap::new();
+    private_generics_indices.insert("0x3::coin::mint".to_string(), vec![0usize]);
+    let legacy_metadata: LegacyMetadataV1 = (
+        BTreeMap::new(),
+        BTreeMap::new(),
+        private_generics_indices.clone(),
+        BTreeMap::new(),
+        BTreeMap::new(),
+        BTreeMap::new(),
+    );
+
+    let mut module = empty_module();
+    module.metadata.push(Metadata {
+        key: ROOCH_METADATA_KEY.to_vec(),
+        value: bcs::to_bytes(&legacy_metadata).unwrap(),
+    });
+
+    check_metadata_format(&module).unwrap();
+    let metadata =
+        get_metadata_from_compiled_module(&module).expect("the legacy metadata should be decoded");
+    assert_eq!(metadata.private_generics_indices, private_generics_indices);
+    assert!(metadata.error_map.is_empty());
+}
+
+#[test]
+fn test_framework_module_metadata() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let coin_module_id = ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, "coin".parse().unwrap());
+    let module_bytes = binding_test
+        .executor()
+        .moveos()
+        .moveos_resolver()
+        .get_module(&coin_module_id)
+        .unwrap()
+        .unwrap();
+    let module = CompiledModule::deserialize(&module_bytes).unwrap();
+    assert_eq!(
+        module
+            .metadata
+            .iter()
+            .filter(|data| data.key == ROOCH_ERROR_MAP_KEY)
+            .count(),
+        1
+    );
+
+    let metadata = get_metadata_from_compiled_module(&module).unwrap();
+    // The private generics are still enforced with the error map in the module metadata.
+    assert!(!metadata.private_generics_indices.is_empty());
+    let description = metadata.get_error_description(3).unwrap();
+    assert_eq!(description.code_name, "ErrorInSufficientBalance");
+}
+
+fn get_module_metadata(
+    binding_test: &binding_test::RustBindingTest,
+    module_id: &ModuleId,
+) -> RuntimeModuleMetadataV1 {
+    let module_bytes = binding_test
+        .executor()
+        .moveos()
+        .moveos_resolver()
+        .get_module(module_id)
+        .unwrap()
+        .unwrap();
+    let module = CompiledModule::deserialize(&module_bytes).unwrap();
+    get_metadata_from_compiled_module(&module).unwrap()
+}
+
+#[test]
+fn test_error_constants_naming_style() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // The `ErrorXxx` constant is preferred over the upper case constant sharing the same code.
+    let ethereum_validator = get_module_metadata(
+        &binding_test,
+        &ModuleId::new(
+            ROOCH_FRAMEWORK_ADDRESS,
+            "ethereum_validator".parse().unwrap(),
+        ),
+    );
+    assert_eq!(
+        ethereum_validator
+            .get_error_description(1)
+            .unwrap()
+            .code_name,
+        "ErrorInvalidPublicKeyLength"
+    );
+    let ecdsa_k1 = get_module_metadata(
+        &binding_test,
+        &ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, "ecdsa_k1".parse().unwrap()),
+    );
+    assert_eq!(
+        ecdsa_k1.get_error_description(1).unwrap().code_name,
+        "ErrorInvalidSignature"
+    );
+
+    // The move-stdlib `EXXX` constants are still recorded.
+    let string = get_module_metadata(
+        &binding_test,
+        &ModuleId::new(CORE_CODE_ADDRESS, "string".parse().unwrap()),
+    );
+    assert_eq!(
+        string.get_error_description(2).unwrap().code_name,
+        "EINVALID_INDEX"
+    );
+}
+
+#[test]
+fn test_explain_framework_abort_with_build_time_error_map() {
+    let coin_module_id = ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, "coin".parse().unwrap());
+    // error::out_of_range(ErrorInSufficientBalance)
+    let abort_code = 0x20003;
+    let description = get_framework_error_description(&coin_module_id, abort_code);
+    let explain = MoveAbortExplain::new(abort_code, description);
+    assert_eq!(explain.reason_code, 3);
+    assert_eq!(
+        explain.reason_name.as_deref(),
+        Some("ErrorInSufficientBalance")
+    );
+
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let explain = binding_test
+        .executor()
+        .explain_move_abort(&AbortLocation::Module(coin_module_id), abort_code);
+    assert_eq!(
+        explain.reason_name.as_deref(),
+        Some("ErrorInSufficientBalance")
+    );
+}
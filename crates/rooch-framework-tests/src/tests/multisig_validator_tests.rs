@@ -0,0 +1,124 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::u256::U256;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::multisig_validator::{
+    multisig_address, MultisigKeyScheme, MultisigPublicKey, MultisigValidatorModule,
+};
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::authenticator::{Authenticator, MultisigSignature};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+use crate::binding_test;
+
+fn multisig_tx_data(
+    multisig_addr: RoochAddress,
+    sequence_number: u64,
+    action: MoveAction,
+) -> RoochTransactionData {
+    RoochTransactionData::new(
+        multisig_addr,
+        sequence_number,
+        RoochChainID::LOCAL.chain_id().id(),
+        GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+        action,
+    )
+}
+
+fn sign_multisig_tx(
+    keystore: &InMemKeystore,
+    signers: &[RoochAddress],
+    tx_data: RoochTransactionData,
+) -> RoochTransaction {
+    let tx_hash = tx_data.hash();
+    let signatures = signers
+        .iter()
+        .map(|signer| {
+            let signature = keystore
+                .sign_hashed(signer, tx_hash.as_bytes(), None)
+                .unwrap();
+            MultisigSignature::from(&signature)
+        })
+        .collect();
+    RoochTransaction::new(tx_data, Authenticator::multisig(signatures))
+}
+
+#[test]
+fn test_multisig_account() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(3);
+    let addresses = keystore.addresses();
+    let sender = addresses[0];
+    let public_keys = addresses
+        .iter()
+        .map(|address| {
+            let kp = keystore.get_key_pair_with_password(address, None).unwrap();
+            MultisigPublicKey::new(MultisigKeyScheme::Ed25519, kp.public().as_ref().to_vec())
+        })
+        .collect::<Vec<_>>();
+    let threshold = 2;
+
+    let action =
+        MultisigValidatorModule::create_multisig_account_action(public_keys.clone(), threshold);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let multisig_addr: RoochAddress = multisig_address(&public_keys, threshold).into();
+
+    // The multisig account is created without gas coin, the sender pays the gas for it
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        multisig_addr.into(),
+        U256::from(50_000_000_000_000_000_000u128),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // One signature does not reach the threshold
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = multisig_tx_data(multisig_addr, 0, action.clone());
+    let tx = sign_multisig_tx(&keystore, &addresses[..1], tx_data);
+    binding_test
+        .execute_as_result(tx)
+        .expect_err("expect transaction validate error");
+
+    // The same key can not sign twice
+    let tx_data = multisig_tx_data(multisig_addr, 0, action.clone());
+    let tx = sign_multisig_tx(&keystore, &[addresses[1], addresses[1]], tx_data);
+    binding_test
+        .execute_as_result(tx)
+        .expect_err("expect transaction validate error");
+
+    let tx_data = multisig_tx_data(multisig_addr, 0, action);
+    let tx = sign_multisig_tx(&keystore, &addresses[1..], tx_data);
+    binding_test.execute(tx).unwrap();
+
+    // The multisig account updates its own threshold
+    let action = MultisigValidatorModule::update_threshold_action(3);
+    let tx_data = multisig_tx_data(multisig_addr, 1, action);
+    let tx = sign_multisig_tx(&keystore, &addresses[..2], tx_data);
+    binding_test.execute(tx).unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = multisig_tx_data(multisig_addr, 2, action.clone());
+    let tx = sign_multisig_tx(&keystore, &addresses[..2], tx_data);
+    binding_test
+        .execute_as_result(tx)
+        .expect_err("expect transaction validate error");
+
+    let tx_data = multisig_tx_data(multisig_addr, 2, action);
+    let tx = sign_multisig_tx(&keystore, &addresses, tx_data);
+    binding_test.execute(tx).unwrap();
+}
@@ -0,0 +1,166 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::{Keypair, Message, Secp256k1};
+use bitcoin::{Network, PrivateKey};
+use move_core_types::ident_str;
+use move_core_types::language_storage::ModuleId;
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use rooch_types::address::{MultiChainAddress, NostrAddress};
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::address_mapping::AddressMapping;
+use rooch_types::transaction::authenticator::{Authenticator, NostrAuthenticator};
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::str::FromStr;
+
+use crate::binding_test::{
+    self, assert_validate_abort, empty_call_tx_data, sign_empty_call,
+    ERROR_INVALID_ACCOUNT_AUTH_KEY, ERROR_INVALID_AUTHENTICATOR,
+};
+
+fn nostr_validator_module() -> ModuleId {
+    ModuleId::new(
+        ROOCH_FRAMEWORK_ADDRESS,
+        ident_str!("nostr_validator").to_owned(),
+    )
+}
+
+fn generate_keypair() -> Keypair {
+    Keypair::from_secret_key(
+        &Secp256k1::new(),
+        &PrivateKey::generate(Network::Bitcoin).inner,
+    )
+}
+
+fn nostr_address(keypair: &Keypair) -> NostrAddress {
+    let (public_key, _parity) = keypair.x_only_public_key();
+    NostrAddress::from_str(&hex::encode(public_key.serialize())).unwrap()
+}
+
+fn sign_nostr(keypair: &Keypair, tx_data: &RoochTransactionData) -> NostrAuthenticator {
+    let secp = Secp256k1::new();
+    let signature = secp.sign_schnorr(&Message::from_digest(tx_data.hash().0), keypair);
+    let (public_key, _parity) = keypair.x_only_public_key();
+    NostrAuthenticator::new(
+        signature.serialize().to_vec(),
+        public_key.serialize().to_vec(),
+    )
+}
+
+#[test]
+fn test_validate_nostr() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keypair = generate_keypair();
+    let nostr_address = nostr_address(&keypair);
+    let sender = nostr_address.to_rooch_address();
+    let multichain_address = MultiChainAddress::from(nostr_address);
+
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        Authenticator::nostr(sign_nostr(&keypair, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The Nostr public key is bound to the sender by the first transaction,
+    // the following transactions are validated with the bound address mapping.
+    assert_eq!(
+        binding_test
+            .as_module_bundle::<AddressMapping>()
+            .resolve(multichain_address)
+            .unwrap(),
+        Some(sender.into())
+    );
+    let tx = sign_empty_call(sender, 1, |tx_data| {
+        Authenticator::nostr(sign_nostr(&keypair, tx_data))
+    });
+    binding_test.execute(tx).unwrap();
+
+    // The signer's Nostr public key is not mapped to the sender
+    let other_keypair = generate_keypair();
+    let tx = sign_empty_call(sender, 2, |tx_data| {
+        Authenticator::nostr(sign_nostr(&other_keypair, tx_data))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &nostr_validator_module(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+
+    // The signature is signed for another transaction
+    let other_tx_data = empty_call_tx_data(sender, 3);
+    let tx = sign_empty_call(sender, 2, |_| {
+        Authenticator::nostr(sign_nostr(&keypair, &other_tx_data))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &nostr_validator_module(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+}
+
+#[test]
+fn test_validate_hashed_tx_hash() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keypair = generate_keypair();
+    let sender = nostr_address(&keypair).to_rooch_address();
+
+    // The transaction hash is signed as is, the signature of the sha256 digest of it is rejected
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        let secp = Secp256k1::new();
+        let digest = sha256::Hash::hash(tx_data.hash().as_bytes());
+        let signature = secp.sign_schnorr(&Message::from_digest(digest.to_byte_array()), &keypair);
+        let (public_key, _parity) = keypair.x_only_public_key();
+        Authenticator::nostr(NostrAuthenticator::new(
+            signature.serialize().to_vec(),
+            public_key.serialize().to_vec(),
+        ))
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &nostr_validator_module(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+}
+
+#[test]
+fn test_validate_invalid_payload() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keypair = generate_keypair();
+    let sender = nostr_address(&keypair).to_rooch_address();
+    let mut assert_invalid = |tamper: fn(&mut NostrAuthenticator)| {
+        let tx = sign_empty_call(sender, 0, |tx_data| {
+            let mut authenticator = sign_nostr(&keypair, tx_data);
+            tamper(&mut authenticator);
+            Authenticator::nostr(authenticator)
+        });
+        assert_validate_abort(
+            binding_test.execute_as_result(tx),
+            &nostr_validator_module(),
+            ERROR_INVALID_AUTHENTICATOR,
+        );
+    };
+
+    // The signature is not 64 bytes
+    assert_invalid(|authenticator| {
+        authenticator.signature.pop();
+    });
+    // The public key is a compressed public key, not a x-only public key
+    assert_invalid(|authenticator| {
+        authenticator.public_key.insert(0, 0x02);
+    });
+
+    // The payload is not the bcs bytes of the Nostr authenticator
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        let mut authenticator = Authenticator::nostr(sign_nostr(&keypair, tx_data));
+        authenticator.payload.pop();
+        authenticator
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &ModuleId::new(MOVEOS_STD_ADDRESS, ident_str!("bcs").to_owned()),
+        1,
+    );
+}
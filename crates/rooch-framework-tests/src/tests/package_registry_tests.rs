@@ -0,0 +1,127 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::file_format::empty_module;
+use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use moveos_types::access_path::AccessPath;
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::package_registry::{
+    package_dependencies, PackageInfo, PackageRegistry,
+};
+use moveos_types::state_resolver::StateReader;
+use moveos_types::transaction::MoveAction;
+use rooch_genesis::ROOCH_LOCAL_GENESIS;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::str::FromStr;
+
+fn get_package(
+    binding_test: &binding_test::RustBindingTest,
+    package_address: AccountAddress,
+) -> Option<PackageInfo> {
+    let resolver = binding_test.executor().moveos().moveos_resolver();
+    let registry = resolver
+        .get_states(AccessPath::object(PackageRegistry::object_id()))
+        .unwrap()
+        .pop()
+        .flatten()?
+        .as_object::<PackageRegistry>()
+        .unwrap()
+        .value;
+    resolver
+        .get_states(registry.package_info_access_path(package_address))
+        .unwrap()
+        .pop()
+        .flatten()
+        .map(|state| state.cast::<PackageInfo>().unwrap())
+}
+
+fn module_bundle(package_address: AccountAddress, module_name: &str) -> Vec<Vec<u8>> {
+    let mut module = empty_module();
+    module.address_identifiers[0] = package_address;
+    module.identifiers[0] = Identifier::new(module_name).unwrap();
+    let mut binary = vec![];
+    module.serialize(&mut binary).unwrap();
+    vec![binary]
+}
+
+#[test]
+fn test_record_genesis_packages() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    // The move_std is published before the package_registry module in genesis
+    assert!(get_package(&binding_test, MOVE_STD_ADDRESS).is_none());
+
+    let info = get_package(&binding_test, ROOCH_FRAMEWORK_ADDRESS)
+        .expect("the rooch framework should be recorded in genesis");
+    assert!(info
+        .modules
+        .contains(&MoveString::from_str("coin").unwrap()));
+    assert!(info.dependencies.contains(&MOVE_STD_ADDRESS));
+    assert!(info.dependencies.contains(&MOVEOS_STD_ADDRESS));
+    assert!(!info.dependencies.contains(&ROOCH_FRAMEWORK_ADDRESS));
+    assert_eq!(info.upgrade_number, 0);
+}
+
+#[test]
+fn test_record_module_bundle() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let package_address: AccountAddress = sender.into();
+    assert!(get_package(&binding_test, package_address).is_none());
+
+    let action = MoveAction::ModuleBundle(module_bundle(package_address, "registry_test"));
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let info = get_package(&binding_test, package_address)
+        .expect("the package published by module bundle should be recorded");
+    assert_eq!(info.name.to_string(), hex::encode(package_address.to_vec()));
+    assert_eq!(
+        info.modules,
+        vec![MoveString::from_str("registry_test").unwrap()]
+    );
+    assert!(info.dependencies.is_empty());
+    assert_eq!(info.upgrade_number, 0);
+
+    // Upgrade the package with the same module
+    let action = MoveAction::ModuleBundle(module_bundle(package_address, "registry_test"));
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+    let info = get_package(&binding_test, package_address).unwrap();
+    assert_eq!(info.upgrade_number, 1);
+}
+
+#[test]
+fn test_package_dependencies() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let modules = ROOCH_LOCAL_GENESIS.modules().unwrap();
+    let framework_modules = modules
+        .iter()
+        .filter(|module| *module.self_id().address() == ROOCH_FRAMEWORK_ADDRESS)
+        .collect::<Vec<_>>();
+
+    // The dependencies of the package published by `publish_package_entry` are the same as the recorded ones
+    // of the package published by module bundle
+    let info = get_package(&binding_test, ROOCH_FRAMEWORK_ADDRESS).unwrap();
+    assert_eq!(
+        package_dependencies(ROOCH_FRAMEWORK_ADDRESS, framework_modules.iter().copied()),
+        info.dependencies
+    );
+
+    // Only the immediate dependencies are included, the move_std which the moveos_std depends on is excluded
+    let empty_module = framework_modules
+        .iter()
+        .copied()
+        .filter(|module| module.self_id().name().as_str() == "empty");
+    assert_eq!(
+        package_dependencies(ROOCH_FRAMEWORK_ADDRESS, empty_module),
+        vec![MOVEOS_STD_ADDRESS]
+    );
+}
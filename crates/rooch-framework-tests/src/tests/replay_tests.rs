@@ -0,0 +1,186 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_store::config_store::ConfigStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::transaction::{MoveAction, MoveOSTransaction};
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::replay::replay_txs;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::TypedTransaction;
+
+/// The address of the rooch association, which can update the gas schedule.
+const ROOCH_ASSOCIATION_ADDRESS: &str = "0xa550c18";
+
+fn state_root(binding_test: &binding_test::RustBindingTest) -> H256 {
+    binding_test
+        .executor()
+        .moveos()
+        .config_store()
+        .get_startup_info()
+        .unwrap()
+        .expect("the startup info should be saved after genesis")
+        .state_root_hash
+}
+
+/// Build a new executor at the state root on the store of the binding test,
+/// the store is dropped with the test, so it is the scratch store of the replay.
+fn new_executor(binding_test: &binding_test::RustBindingTest, state_root: H256) -> ExecutorActor {
+    let moveosdb = binding_test
+        .executor()
+        .moveos()
+        .moveos_resolver()
+        .0
+        .moveosdb
+        .clone();
+    ExecutorActor::new(
+        RoochChainID::LOCAL.genesis_ctx(),
+        MoveOSStore::new_with_root(moveosdb, Some(state_root)).unwrap(),
+        binding_test.executor().get_rooch_store(),
+    )
+    .unwrap()
+}
+
+fn create_session_key_tx(keystore: &mut InMemKeystore, sequence_number: u64) -> TypedTransaction {
+    let sender = keystore.addresses()[0];
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+    let session_scope = SessionScope::new(
+        ROOCH_FRAMEWORK_ADDRESS,
+        Empty::MODULE_NAME.as_str(),
+        Empty::EMPTY_FUNCTION_NAME.as_str(),
+    );
+    let action = SessionKeyModule::create_session_key_action(
+        session_auth_key.as_ref().to_vec(),
+        session_scope,
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    TypedTransaction::Rooch(keystore.sign_transaction(&sender, tx_data, None).unwrap())
+}
+
+#[test]
+fn test_replay_detects_mismatched_execution_info() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let pre_state_root = state_root(&binding_test);
+
+    let mut txs = vec![];
+    for tx_order in 0..4 {
+        let tx = create_session_key_tx(&mut keystore, tx_order);
+        let execution_info = binding_test
+            .execute_as_result(tx.clone())
+            .unwrap()
+            .transaction_info;
+        txs.push((tx_order, tx, execution_info));
+    }
+    let results = replay_txs(
+        pre_state_root,
+        txs.clone().into_iter().map(Ok),
+        |state_root| Ok(new_executor(&binding_test, state_root)),
+    )
+    .unwrap();
+    assert!(results.iter().all(|result| result.matched));
+
+    // Tamper the stored execution infos of the tx order 1 and 3
+    let mut tampered_txs = txs.clone();
+    tampered_txs[1].2.event_root = H256::random();
+    tampered_txs[3].2.state_root = H256::random();
+    let results = replay_txs(
+        pre_state_root,
+        tampered_txs.into_iter().map(Ok),
+        |state_root| Ok(new_executor(&binding_test, state_root)),
+    )
+    .unwrap();
+    let matched: Vec<_> = results.iter().map(|result| result.matched).collect();
+    // The replay continues from the stored state root after the mismatch, so the tx order 2 matches
+    assert_eq!(matched, vec![true, false, true, false]);
+    for (result, (tx_order, _, execution_info)) in results.iter().zip(txs.iter()) {
+        assert_eq!(result.tx_order, *tx_order);
+        assert_eq!(result.tx_hash, execution_info.tx_hash);
+        assert!(result.error.is_none());
+        // The replay results are the same as the original execution
+        assert_eq!(result.replayed.as_ref(), Some(execution_info));
+    }
+}
+
+#[test]
+fn test_replay_gas_schedule_update() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let pre_state_root = state_root(&binding_test);
+
+    // Raise the gas costs of the table natives, which are called by the session key creation
+    let (keys, vals): (Vec<String>, Vec<u64>) = [
+        "common.load_base",
+        "common.load_per_byte",
+        "common.load_failure",
+        "add_box.base",
+        "add_box.per_byte_serialized",
+        "borrow_box.base",
+        "borrow_box.per_byte_serialized",
+        "contains_box.base",
+        "contains_box.per_byte_serialized",
+        "remove_box.base",
+        "remove_box.per_byte_serialized",
+        "drop_unchecked_box.base",
+        "box_length.base",
+    ]
+    .into_iter()
+    .map(|key| (format!("table_extension.{}", key), 1000))
+    .unzip();
+    let action = MoveAction::new_function_call(
+        "0x3::gas_schedule::update_gas_schedule".parse().unwrap(),
+        vec![],
+        vec![
+            bcs::to_bytes(&1u64).unwrap(),
+            bcs::to_bytes(&10000000000u64).unwrap(),
+            bcs::to_bytes(&keys).unwrap(),
+            bcs::to_bytes(&vals).unwrap(),
+        ],
+    );
+    let association = AccountAddress::from_hex_literal(ROOCH_ASSOCIATION_ADDRESS).unwrap();
+    let update_tx = MoveOSTransaction::new_for_test(association, action);
+    let verified_update_tx = binding_test
+        .executor()
+        .moveos()
+        .verify(update_tx.clone())
+        .unwrap();
+    let update_info = binding_test
+        .execute_verified_tx(verified_update_tx)
+        .unwrap()
+        .transaction_info;
+    assert_eq!(update_info.status, KeptVMStatus::Executed);
+
+    let tx = create_session_key_tx(&mut keystore, 0);
+    let tx_info = binding_test
+        .execute_as_result(tx.clone())
+        .unwrap()
+        .transaction_info;
+    assert_eq!(tx_info.status, KeptVMStatus::Executed);
+    // The transaction costs more gas than before the gas schedule update
+    let gas_used_before_update = new_executor(&binding_test, pre_state_root)
+        .dry_run(tx.clone(), false)
+        .unwrap()
+        .raw_output
+        .gas_used;
+    assert!(tx_info.gas_used > gas_used_before_update);
+
+    // Replay the gas schedule update, the following transaction is replayed with the updated native gas costs
+    let mut executor = new_executor(&binding_test, pre_state_root);
+    let verified_update_tx = executor.moveos().verify(update_tx).unwrap();
+    assert_eq!(
+        executor.replay_verified(verified_update_tx).unwrap(),
+        update_info
+    );
+    assert_eq!(executor.replay(tx).unwrap(), tx_info);
+}
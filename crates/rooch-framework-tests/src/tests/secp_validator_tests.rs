@@ -0,0 +1,174 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::language_storage::ModuleId;
+use moveos_types::state::MoveStructType;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{RoochKeyPair, Signature};
+use rooch_types::framework::secp256_validator::{
+    Secp256Validator, Secp256ValidatorModule, Secp256k1Validator, Secp256r1Validator,
+};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::AbstractTransaction;
+
+use crate::binding_test::{
+    self, assert_validate_abort, empty_call_tx_data, sign_empty_call,
+    ERROR_INVALID_ACCOUNT_AUTH_KEY, ERROR_INVALID_AUTHENTICATOR,
+};
+
+fn validator_module<V: Secp256Validator>() -> ModuleId {
+    ModuleId::new(V::ADDRESS, V::MODULE_NAME.to_owned())
+}
+
+/// Generate a key of the scheme of the validator, return the address and the key pair of it.
+fn generate_key_pair<V: Secp256Validator>(
+    keystore: &mut InMemKeystore,
+) -> (RoochAddress, RoochKeyPair) {
+    let address = keystore
+        .generate_and_add_new_key(V::AUTH_VALIDATOR, None, None, None, None)
+        .unwrap()
+        .address;
+    let key_pair = keystore.get_key_pair_with_password(&address, None).unwrap();
+    (address, key_pair)
+}
+
+fn sign(key_pair: &RoochKeyPair, tx_data: &RoochTransactionData) -> Authenticator {
+    Authenticator::rooch(Signature::new_hashed(tx_data.hash().as_bytes(), key_pair))
+}
+
+/// Sign the empty call of the sender by the key pair, tamper the payload
+/// and submit it to the validator `V`, it should be rejected as an invalid authenticator.
+fn assert_invalid_payload<V: Secp256Validator>(
+    binding_test: &mut binding_test::RustBindingTest,
+    sender: RoochAddress,
+    key_pair: &RoochKeyPair,
+    tamper: fn(&mut Vec<u8>),
+) {
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        let mut payload = sign(key_pair, tx_data).payload;
+        tamper(&mut payload);
+        Authenticator::new(V::auth_validator_id(), payload)
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &validator_module::<V>(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+}
+
+fn test_validate<V: Secp256Validator>() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+    let (sender, key_pair) = generate_key_pair::<V>(&mut keystore);
+    let (_, other_key_pair) = generate_key_pair::<V>(&mut keystore);
+
+    let tx = sign_empty_call(sender, 0, |tx_data| sign(&key_pair, tx_data));
+    assert_eq!(
+        tx.authenticator_info()
+            .unwrap()
+            .authenticator
+            .auth_validator_id,
+        V::auth_validator_id()
+    );
+    binding_test.execute(tx).unwrap();
+
+    // The transaction is signed by another key of the same scheme
+    let tx = sign_empty_call(sender, 1, |tx_data| sign(&other_key_pair, tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &validator_module::<V>(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+
+    // The signature is signed for another transaction
+    let other_tx_data = empty_call_tx_data(sender, 2);
+    let tx = sign_empty_call(sender, 1, |_| sign(&key_pair, &other_tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &validator_module::<V>(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+
+    // After the authentication key of the sender is rotated to the other key,
+    // the transactions are validated with the other key instead of the sender's key.
+    let action = Secp256ValidatorModule::<V>::rotate_authentication_key_action(
+        other_key_pair.public().as_ref().to_vec(),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let authenticator = sign(&key_pair, &tx_data);
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+    let tx = sign_empty_call(sender, 2, |tx_data| sign(&other_key_pair, tx_data));
+    binding_test.execute(tx).unwrap();
+    let tx = sign_empty_call(sender, 3, |tx_data| sign(&key_pair, tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &validator_module::<V>(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+}
+
+fn test_validate_invalid_payload<V: Secp256Validator>() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+    let (sender, key_pair) = generate_key_pair::<V>(&mut keystore);
+
+    // The payload is not `scheme flag || signature || public key`
+    assert_invalid_payload::<V>(&mut binding_test, sender, &key_pair, |payload| {
+        payload.pop();
+    });
+    assert_invalid_payload::<V>(&mut binding_test, sender, &key_pair, |payload| {
+        payload.push(0)
+    });
+    // The scheme flag is not the auth validator id
+    assert_invalid_payload::<V>(&mut binding_test, sender, &key_pair, |payload| {
+        payload[0] = 0
+    });
+}
+
+/// The payload signed by the key of `V` is submitted to the validator `T` of the other scheme.
+fn test_validate_mismatched_key_scheme<V: Secp256Validator, T: Secp256Validator>() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+    let (sender, key_pair) = generate_key_pair::<V>(&mut keystore);
+
+    // The scheme flag of the payload is the id of `V`
+    assert_invalid_payload::<T>(&mut binding_test, sender, &key_pair, |_| {});
+    // The signature of `V` can not be verified by `T` even if the scheme flag is replaced
+    assert_invalid_payload::<T>(&mut binding_test, sender, &key_pair, |payload| {
+        payload[0] = T::AUTH_VALIDATOR.flag()
+    });
+}
+
+#[test]
+fn test_validate_secp256k1() {
+    test_validate::<Secp256k1Validator>();
+}
+
+#[test]
+fn test_validate_secp256r1() {
+    test_validate::<Secp256r1Validator>();
+}
+
+#[test]
+fn test_validate_secp256k1_invalid_payload() {
+    test_validate_invalid_payload::<Secp256k1Validator>();
+}
+
+#[test]
+fn test_validate_secp256r1_invalid_payload() {
+    test_validate_invalid_payload::<Secp256r1Validator>();
+}
+
+#[test]
+fn test_validate_mismatched_secp256_key_scheme() {
+    test_validate_mismatched_key_scheme::<Secp256k1Validator, Secp256r1Validator>();
+    test_validate_mismatched_key_scheme::<Secp256r1Validator, Secp256k1Validator>();
+}
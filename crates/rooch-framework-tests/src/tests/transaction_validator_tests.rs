@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use ethers::signers::{LocalWallet, Signer};
 use ethers::types::{Bytes, U256};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
@@ -11,6 +12,7 @@ use moveos_types::move_types::FunctionId;
 use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::ethereum_validator::EthereumValidatorModule;
 use rooch_types::framework::session_key::SessionKeyModule;
 use rooch_types::framework::timestamp::TimestampModule;
 use rooch_types::transaction::ethereum::EthereumTransaction;
@@ -54,13 +56,12 @@ fn test_validate_ethereum() {
     let address_mapping =
         binding_test.as_module_bundle::<rooch_types::framework::address_mapping::AddressMapping>();
 
-    let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
     let sequence_number = U256::zero();
     let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
     let action_bytes =
         Bytes::try_from(bcs::to_bytes(&action).unwrap()).expect("Convert action to bytes failed.");
-    let tx = EthereumTransaction::new_for_test(sender, sequence_number, action_bytes);
+    let tx = EthereumTransaction::new_for_test(&wallet, sequence_number, action_bytes);
 
     let multi_chain_address_sender = tx.sender();
     let resolved_sender = address_mapping
@@ -76,6 +77,142 @@ fn test_validate_ethereum() {
         .unwrap();
 }
 
+fn ethereum_action_bytes(action: &MoveAction) -> Bytes {
+    Bytes::try_from(bcs::to_bytes(action).unwrap()).expect("Convert action to bytes failed.")
+}
+
+fn assert_invalid_account_auth_key(error: anyhow::Error) {
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(l, code)) => {
+            match l {
+                AbortLocation::Module(module_id) => {
+                    assert_eq!(
+                        module_id,
+                        &EthereumValidatorModule::module_id(),
+                        "expect ethereum validator module"
+                    );
+                }
+                _ => panic!("expect move abort in module"),
+            }
+            let (_category, reason) = moveos_types::move_std::error::explain(*code);
+            // ErrorValidateInvalidAccountAuthKey = 1001
+            assert_eq!(reason, 1001, "expect ErrorValidateInvalidAccountAuthKey");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
+
+#[test]
+fn test_validate_ethereum_mismatched_sender() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let ethereum_validator = binding_test.as_module_bundle::<EthereumValidatorModule>();
+
+    let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx =
+        EthereumTransaction::new_for_test(&wallet, U256::zero(), ethereum_action_bytes(&action));
+
+    // The Ethereum address is not mapped to the sender
+    let authenticator = tx.authenticator_info().unwrap();
+    let moveos_tx = tx
+        .construct_moveos_transaction(AccountAddress::random())
+        .unwrap();
+    let error = ethereum_validator
+        .validate(&moveos_tx.ctx, authenticator.authenticator.payload)
+        .expect_err("expect transaction validate error");
+    assert_invalid_account_auth_key(error);
+}
+
+#[test]
+fn test_validate_ethereum_spoofed_from() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let ethereum_validator = binding_test.as_module_bundle::<EthereumValidatorModule>();
+    let address_mapping =
+        binding_test.as_module_bundle::<rooch_types::framework::address_mapping::AddressMapping>();
+
+    let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+    let victim = LocalWallet::from_bytes(&[2u8; 32]).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let mut tx =
+        EthereumTransaction::new_for_test(&wallet, U256::zero(), ethereum_action_bytes(&action));
+
+    // The `from` address is not signed, claim the victim's address as the sender
+    tx.0.from = victim.address();
+    let resolved_sender = address_mapping.resolve_or_generate(tx.sender()).unwrap();
+    let authenticator = tx.authenticator_info().unwrap();
+    let moveos_tx = tx.construct_moveos_transaction(resolved_sender).unwrap();
+    let error = ethereum_validator
+        .validate(&moveos_tx.ctx, authenticator.authenticator.payload)
+        .expect_err("expect transaction validate error");
+    assert_invalid_account_auth_key(error);
+}
+
+#[test]
+fn test_validate_ethereum_signing_hash_as_tx_hash() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let ethereum_validator = binding_test.as_module_bundle::<EthereumValidatorModule>();
+    let address_mapping =
+        binding_test.as_module_bundle::<rooch_types::framework::address_mapping::AddressMapping>();
+
+    let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx =
+        EthereumTransaction::new_for_test(&wallet, U256::zero(), ethereum_action_bytes(&action));
+
+    // The tx hash must be the hash of the signed transaction, not the hash signed by the sender
+    let signing_hash = tx.signing_hash();
+    let resolved_sender = address_mapping.resolve_or_generate(tx.sender()).unwrap();
+    let authenticator = tx.authenticator_info().unwrap();
+    let mut moveos_tx = tx.construct_moveos_transaction(resolved_sender).unwrap();
+    moveos_tx.ctx.tx_hash = signing_hash.as_bytes().to_vec();
+    let error = ethereum_validator
+        .validate(&moveos_tx.ctx, authenticator.authenticator.payload)
+        .expect_err("expect transaction validate error");
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            let (_category, reason) = moveos_types::move_std::error::explain(*code);
+            // ErrorValidateInvalidAuthenticator = 1002
+            assert_eq!(reason, 1002, "expect ErrorValidateInvalidAuthenticator");
+        }
+        _ => panic!("Expect move abort"),
+    }
+}
+
+#[test]
+fn test_validate_ethereum_rotated_key() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let old_wallet = LocalWallet::from_bytes(&[2u8; 32]).unwrap();
+    let new_wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+
+    // Rotate the authentication key to the new Ethereum key, the public key of `new_wallet`
+    let new_public_key =
+        hex::decode("031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f").unwrap();
+    let action = EthereumValidatorModule::rotate_authentication_key_action(new_public_key);
+    let tx = EthereumTransaction::new_for_test(
+        &old_wallet,
+        U256::zero(),
+        ethereum_action_bytes(&action),
+    );
+    binding_test.execute(tx).unwrap();
+
+    // The old Ethereum key does not match the authentication key of the account
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx =
+        EthereumTransaction::new_for_test(&old_wallet, U256::one(), ethereum_action_bytes(&action));
+    let error = binding_test
+        .execute_as_result(tx)
+        .expect_err("expect transaction validate error");
+    assert_invalid_account_auth_key(error);
+
+    // The new Ethereum address is mapped to the account by the rotation
+    let tx =
+        EthereumTransaction::new_for_test(&new_wallet, U256::one(), ethereum_action_bytes(&action));
+    binding_test.execute(tx).unwrap();
+}
+
 #[test]
 fn test_session_key_rooch() {
     // tracing_subscriber::fmt::init();
@@ -0,0 +1,201 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::ident_str;
+use move_core_types::language_storage::ModuleId;
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use moveos_types::state::MoveStructType;
+use rooch_types::crypto::{RoochKeyPair, RoochSignature, Signer};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::framework::webauthn_validator::{WebauthnValidator, WebauthnValidatorModule};
+use rooch_types::transaction::authenticator::{Authenticator, WebauthnAuthenticator};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+use crate::binding_test::{
+    self, assert_validate_abort, empty_call_tx_data, sign_empty_call,
+    ERROR_INVALID_ACCOUNT_AUTH_KEY, ERROR_INVALID_AUTHENTICATOR,
+};
+
+const TEST_ORIGIN: &str = "https://rooch.network";
+
+fn webauthn_validator_module() -> ModuleId {
+    ModuleId::new(
+        WebauthnValidator::ADDRESS,
+        WebauthnValidator::MODULE_NAME.to_owned(),
+    )
+}
+
+fn generate_key_pair(seed: u8) -> RoochKeyPair {
+    RoochKeyPair::try_from_bytes(BuiltinAuthValidator::Secp256r1, &[seed; 32]).unwrap()
+}
+
+/// rpIdHash || flags || signCount
+fn authenticator_data(flags: u8) -> Vec<u8> {
+    let mut authenticator_data = vec![0u8; 32];
+    authenticator_data.extend_from_slice(&[flags, 0, 0, 0, 1]);
+    authenticator_data
+}
+
+/// Sign the assertion of the authenticator data and the client data JSON by the passkey.
+fn sign_assertion(
+    kp: &RoochKeyPair,
+    authenticator_data: Vec<u8>,
+    client_data_json: Vec<u8>,
+) -> WebauthnAuthenticator {
+    let message = WebauthnAuthenticator::sign_message(&authenticator_data, &client_data_json);
+    let signature = kp.sign(&message);
+    WebauthnAuthenticator::new(
+        signature.signature_bytes().to_vec(),
+        kp.public().as_ref().to_vec(),
+        authenticator_data,
+        client_data_json,
+    )
+}
+
+fn sign_webauthn(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Authenticator {
+    // The user is present and verified
+    Authenticator::webauthn(sign_assertion(
+        kp,
+        authenticator_data(0x05),
+        WebauthnAuthenticator::client_data_json(&tx_data.hash(), TEST_ORIGIN),
+    ))
+}
+
+#[test]
+fn test_validate_webauthn() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = generate_key_pair(1);
+    let other_kp = generate_key_pair(2);
+    let sender = WebauthnValidator::public_key_to_address(kp.public().as_ref());
+
+    let tx = sign_empty_call(sender, 0, |tx_data| sign_webauthn(&kp, tx_data));
+    binding_test.execute(tx).unwrap();
+
+    // The passkey of the signer is not the passkey of the sender
+    let tx = sign_empty_call(sender, 1, |tx_data| sign_webauthn(&other_kp, tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &webauthn_validator_module(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+
+    // The challenge of the assertion is the hash of another transaction
+    let other_tx_data = empty_call_tx_data(sender, 2);
+    let tx = sign_empty_call(sender, 1, |_| sign_webauthn(&kp, &other_tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &webauthn_validator_module(),
+        ERROR_INVALID_AUTHENTICATOR,
+    );
+
+    // After the authentication key of the sender is rotated to the other passkey,
+    // the transactions are validated with the other passkey instead of the sender's passkey.
+    let action = WebauthnValidatorModule::rotate_authentication_key_action(
+        other_kp.public().as_ref().to_vec(),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let authenticator = sign_webauthn(&kp, &tx_data);
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+    let tx = sign_empty_call(sender, 2, |tx_data| sign_webauthn(&other_kp, tx_data));
+    binding_test.execute(tx).unwrap();
+    let tx = sign_empty_call(sender, 3, |tx_data| sign_webauthn(&kp, tx_data));
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &webauthn_validator_module(),
+        ERROR_INVALID_ACCOUNT_AUTH_KEY,
+    );
+}
+
+#[test]
+fn test_validate_invalid_assertion() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = generate_key_pair(1);
+    let sender = WebauthnValidator::public_key_to_address(kp.public().as_ref());
+    let mut assert_invalid = |sign: &dyn Fn(&RoochTransactionData) -> WebauthnAuthenticator| {
+        let tx = sign_empty_call(sender, 0, |tx_data| Authenticator::webauthn(sign(tx_data)));
+        assert_validate_abort(
+            binding_test.execute_as_result(tx),
+            &webauthn_validator_module(),
+            ERROR_INVALID_AUTHENTICATOR,
+        );
+    };
+
+    // The user present flag is not set
+    assert_invalid(&|tx_data| {
+        sign_assertion(
+            &kp,
+            authenticator_data(0x04),
+            WebauthnAuthenticator::client_data_json(&tx_data.hash(), TEST_ORIGIN),
+        )
+    });
+    // The authenticator data is shorter than rpIdHash || flags || signCount
+    assert_invalid(&|tx_data| {
+        let mut authenticator_data = authenticator_data(0x05);
+        authenticator_data.pop();
+        sign_assertion(
+            &kp,
+            authenticator_data,
+            WebauthnAuthenticator::client_data_json(&tx_data.hash(), TEST_ORIGIN),
+        )
+    });
+    // The client data is not of a WebAuthn assertion
+    assert_invalid(&|tx_data| {
+        let client_data_json = serde_json::json!({
+            "type": "webauthn.create",
+            "challenge": WebauthnAuthenticator::challenge(&tx_data.hash()),
+            "origin": TEST_ORIGIN,
+        })
+        .to_string()
+        .into_bytes();
+        sign_assertion(&kp, authenticator_data(0x05), client_data_json)
+    });
+}
+
+#[test]
+fn test_validate_invalid_payload() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = generate_key_pair(1);
+    let sender = WebauthnValidator::public_key_to_address(kp.public().as_ref());
+    let mut assert_invalid = |tamper: fn(&mut WebauthnAuthenticator)| {
+        let tx = sign_empty_call(sender, 0, |tx_data| {
+            let mut authenticator = sign_assertion(
+                &kp,
+                authenticator_data(0x05),
+                WebauthnAuthenticator::client_data_json(&tx_data.hash(), TEST_ORIGIN),
+            );
+            tamper(&mut authenticator);
+            Authenticator::webauthn(authenticator)
+        });
+        assert_validate_abort(
+            binding_test.execute_as_result(tx),
+            &webauthn_validator_module(),
+            ERROR_INVALID_AUTHENTICATOR,
+        );
+    };
+
+    // The signature is not 64 bytes
+    assert_invalid(|authenticator| {
+        authenticator.signature.pop();
+    });
+    // The public key is not a 33 bytes compressed public key
+    assert_invalid(|authenticator| {
+        authenticator.public_key.push(0);
+    });
+
+    // The payload is not the bcs bytes of the WebAuthn authenticator
+    let tx = sign_empty_call(sender, 0, |tx_data| {
+        let mut authenticator = sign_webauthn(&kp, tx_data);
+        authenticator.payload.pop();
+        authenticator
+    });
+    assert_validate_abort(
+        binding_test.execute_as_result(tx),
+        &ModuleId::new(MOVEOS_STD_ADDRESS, ident_str!("bcs").to_owned()),
+        1,
+    );
+}
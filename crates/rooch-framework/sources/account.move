@@ -14,6 +14,7 @@ module rooch_framework::account {
    friend rooch_framework::transaction_validator;
    friend rooch_framework::transfer;
    friend rooch_framework::genesis;
+   friend rooch_framework::multisig_validator;
 
    /// Resource representing an account.
    struct Account has key, store {
@@ -50,6 +51,8 @@ module rooch_framework::account {
    const ErrorResourceAccountAlreadyUsed: u64 = 6;
    /// Resource Account can't derive resource account
    const ErrorAccountIsAlreadyResourceAccount: u64 = 7;
+   /// An attempt to create a derived account on an account that has a committed transaction
+   const ErrorDerivedAccountAlreadyUsed: u64 = 8;
    /// Address to create is not a valid reserved address for Rooch framework
    const ErrorNotValidFrameworkReservedAddress: u64 = 11;
 
@@ -193,6 +196,18 @@ module rooch_framework::account {
       (resource_signer, signer_cap)
    }
 
+   /// Create the account whose address is derived by the friend module, such as the multisig account, and return its signer.
+   /// The account may be already created by receiving coins, but it must not have any committed transaction.
+   public(friend) fun create_derived_account(ctx: &mut Context, derived_addr: address): signer {
+      if (exists_at(ctx, derived_addr)) {
+         let account = context::borrow_resource<Account>(ctx, derived_addr);
+         assert!(account.sequence_number == 0, error::invalid_state(ErrorDerivedAccountAlreadyUsed));
+         create_signer(derived_addr)
+      } else {
+         create_account(ctx, derived_addr)
+      }
+   }
+
    /// This is a helper function to generate seed for resource address
    fun generate_seed_bytes(ctx: &Context, addr: &address): vector<u8> {
       let sequence_number = Self::sequence_number(ctx, *addr);
@@ -50,6 +50,14 @@ module rooch_framework::multichain_address {
         }
     }
 
+    /// The Nostr address is the 32 bytes x-only public key, the npub is the bech32 encoding of it.
+    public fun from_nostr(public_key: vector<u8>): MultiChainAddress {
+        MultiChainAddress {
+            multichain_id: MULTICHAIN_ID_NOSTR,
+            raw_address: public_key,
+        }
+    }
+
     public fun multichain_id(self: &MultiChainAddress): u64 {
         self.multichain_id
     }
@@ -70,6 +78,10 @@ module rooch_framework::multichain_address {
         maddress.multichain_id == MULTICHAIN_ID_BITCOIN
     }
 
+    public fun is_nostr_address(maddress: &MultiChainAddress) : bool{
+        maddress.multichain_id == MULTICHAIN_ID_NOSTR
+    }
+
     public fun into_rooch_address(maddress: MultiChainAddress) : address {
         assert!(maddress.multichain_id == MULTICHAIN_ID_ROOCH, error::invalid_argument(ErrorMultiChainIDMismatch));
         moveos_std::bcs::to_address(maddress.raw_address)
@@ -0,0 +1,407 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the Bitcoin validator, the Bitcoin wallets sign the hex string of the transaction hash
+/// as a Bitcoin message, and the sender is the Rooch address mapped from the Bitcoin address of the signer.
+/// The BIP-137 signature is supported for the legacy(P2PKH) and segwit(P2SH-P2WPKH, P2WPKH) addresses,
+/// and the BIP-322 simple signature is supported for the native segwit(P2WPKH) and taproot(P2TR key path spending) addresses.
+module rooch_framework::bitcoin_validator {
+
+    use std::vector;
+    use std::option;
+    use moveos_std::bcs;
+    use moveos_std::hex;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::ecdsa_k1;
+    use rooch_framework::schnorr;
+    use rooch_framework::auth_validator;
+    use rooch_framework::address_mapping;
+    use rooch_framework::bitcoin_script_buf;
+    use rooch_framework::bitcoin_address::{Self, BTCAddress};
+    use rooch_framework::multichain_address;
+
+    /// there defines auth validator id for each blockchain
+    const BITCOIN_AUTH_VALIDATOR_ID: u64 = 3;
+
+    /// The signature types supported by the Bitcoin validator
+    const SIGN_TYPE_BIP137: u8 = 0;
+    const SIGN_TYPE_BIP322_SIMPLE: u8 = 1;
+
+    /// The BIP-137 header byte ranges, the header byte encodes the recovery id and the address type.
+    const BIP137_HEADER_P2PKH_UNCOMPRESSED: u8 = 27;
+    const BIP137_HEADER_P2PKH: u8 = 31;
+    const BIP137_HEADER_P2SH_P2WPKH: u8 = 35;
+    const BIP137_HEADER_P2WPKH: u8 = 39;
+    const BIP137_HEADER_MAX: u8 = 42;
+    const BIP137_SIGNATURE_LENGTH: u64 = 65;
+
+    const BITCOIN_MESSAGE_PREFIX: vector<u8> = b"Bitcoin Signed Message:\n";
+    const BIP322_MESSAGE_TAG: vector<u8> = b"BIP0322-signed-message";
+    const TAP_SIGHASH_TAG: vector<u8> = b"TapSighash";
+
+    struct BitcoinValidator has store, drop {}
+
+    #[data_struct]
+    /// The authenticator payload of the Bitcoin validator, it is the bcs bytes of the struct.
+    struct BitcoinAuthPayload has copy, drop {
+        /// SIGN_TYPE_BIP137 or SIGN_TYPE_BIP322_SIMPLE
+        sign_type: u8,
+        /// BIP-137: the 65 bytes `header || r || s` signature
+        /// BIP-322 simple: the 64 bytes `r || s` ecdsa signature of the P2WPKH witness without the sighash type,
+        /// or the 64 bytes schnorr signature of the taproot key path spending
+        signature: vector<u8>,
+        /// BIP-137: the 33 bytes compressed public key
+        /// BIP-322 simple: the 33 bytes compressed public key of P2WPKH, or the 32 bytes x-only taproot output key
+        public_key: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        BITCOIN_AUTH_VALIDATOR_ID
+    }
+
+    /// The message signed by the Bitcoin wallet, it is the lowercase hex string of the transaction hash.
+    public fun sign_message(tx_hash: &vector<u8>): vector<u8> {
+        hex::encode(*tx_hash)
+    }
+
+    fun hash160(data: &vector<u8>): vector<u8> {
+        hash::ripemd160(&hash::sha2_256(*data))
+    }
+
+    fun tagged_hash_preimage(tag: vector<u8>, msg: &vector<u8>): vector<u8> {
+        let tag_hash = hash::sha2_256(tag);
+        let preimage = tag_hash;
+        vector::append(&mut preimage, tag_hash);
+        vector::append(&mut preimage, *msg);
+        preimage
+    }
+
+    fun tagged_hash(tag: vector<u8>, msg: &vector<u8>): vector<u8> {
+        hash::sha2_256(tagged_hash_preimage(tag, msg))
+    }
+
+    /// Serialize the length of a bitcoin var bytes, only the single byte compact size is needed for the validator.
+    fun push_var_bytes(buf: &mut vector<u8>, bytes: vector<u8>) {
+        let len = vector::length(&bytes);
+        assert!(len < 0xfd, auth_validator::error_invalid_authenticator());
+        vector::push_back(buf, (len as u8));
+        vector::append(buf, bytes);
+    }
+
+    fun p2pkh_script(pubkey_hash: vector<u8>): vector<u8> {
+        let script = x"76a914";
+        vector::append(&mut script, pubkey_hash);
+        vector::append(&mut script, x"88ac");
+        script
+    }
+
+    fun p2sh_script(script_hash: vector<u8>): vector<u8> {
+        let script = x"a914";
+        vector::append(&mut script, script_hash);
+        vector::push_back(&mut script, 0x87);
+        script
+    }
+
+    fun p2wpkh_script(pubkey_hash: vector<u8>): vector<u8> {
+        let script = x"0014";
+        vector::append(&mut script, pubkey_hash);
+        script
+    }
+
+    fun p2tr_script(output_key: vector<u8>): vector<u8> {
+        let script = x"5120";
+        vector::append(&mut script, output_key);
+        script
+    }
+
+    fun script_to_address(script: vector<u8>): BTCAddress {
+        let address_opt = bitcoin_address::from_script(&bitcoin_script_buf::new(script));
+        assert!(option::is_some(&address_opt), auth_validator::error_invalid_authenticator());
+        option::destroy_some(address_opt)
+    }
+
+    /// Verify the BIP-137 signature of the message, return the address of the signer.
+    fun verify_bip137(payload: &BitcoinAuthPayload, message: vector<u8>): BTCAddress {
+        assert!(
+            vector::length(&payload.signature) == BIP137_SIGNATURE_LENGTH,
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            vector::length(&payload.public_key) == ecdsa_k1::public_key_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        let header = *vector::borrow(&payload.signature, 0);
+        assert!(
+            header >= BIP137_HEADER_P2PKH_UNCOMPRESSED && header <= BIP137_HEADER_MAX,
+            auth_validator::error_invalid_authenticator()
+        );
+        // The uncompressed P2PKH address is not supported
+        assert!(header >= BIP137_HEADER_P2PKH, auth_validator::error_invalid_authenticator());
+
+        // The digest is sha256d(var_bytes(prefix) || var_bytes(message)),
+        // `ecdsa_k1::verify` hashes the message with sha256 again.
+        let prefixed_message = vector::empty<u8>();
+        push_var_bytes(&mut prefixed_message, BITCOIN_MESSAGE_PREFIX);
+        push_var_bytes(&mut prefixed_message, message);
+        let digest = hash::sha2_256(prefixed_message);
+
+        let signature = vector::empty<u8>();
+        let i = 1;
+        while (i < BIP137_SIGNATURE_LENGTH) {
+            vector::push_back(&mut signature, *vector::borrow(&payload.signature, i));
+            i = i + 1;
+        };
+        assert!(
+            ecdsa_k1::verify(&signature, &payload.public_key, &digest, ecdsa_k1::sha256()),
+            auth_validator::error_invalid_authenticator()
+        );
+
+        let pubkey_hash = hash160(&payload.public_key);
+        if (header >= BIP137_HEADER_P2WPKH) {
+            script_to_address(p2wpkh_script(pubkey_hash))
+        } else if (header >= BIP137_HEADER_P2SH_P2WPKH) {
+            let redeem_script = p2wpkh_script(pubkey_hash);
+            script_to_address(p2sh_script(hash160(&redeem_script)))
+        } else {
+            script_to_address(p2pkh_script(pubkey_hash))
+        }
+    }
+
+    /// Build the BIP-322 `to_spend` transaction of the message to the `script_pubkey`, return the txid of it.
+    fun bip322_to_spend_txid(script_pubkey: vector<u8>, message: &vector<u8>): vector<u8> {
+        let message_hash = tagged_hash(BIP322_MESSAGE_TAG, message);
+
+        // The `to_spend` transaction: version 0, one input spends 0x00..00:0xFFFFFFFF with `OP_0 PUSH32[message_hash]`,
+        // one output of value 0 to the address, locktime 0.
+        let to_spend = x"00000000";
+        vector::push_back(&mut to_spend, 1);
+        let i = 0;
+        while (i < 32) {
+            vector::push_back(&mut to_spend, 0);
+            i = i + 1;
+        };
+        vector::append(&mut to_spend, x"ffffffff");
+        let script_sig = x"0020";
+        vector::append(&mut script_sig, message_hash);
+        push_var_bytes(&mut to_spend, script_sig);
+        vector::append(&mut to_spend, x"00000000");
+        vector::push_back(&mut to_spend, 1);
+        vector::append(&mut to_spend, x"0000000000000000");
+        push_var_bytes(&mut to_spend, script_pubkey);
+        vector::append(&mut to_spend, x"00000000");
+        hash::sha2_256(hash::sha2_256(to_spend))
+    }
+
+    /// The outputs of the BIP-322 `to_sign` transaction, one `OP_RETURN` output of value 0.
+    fun bip322_to_sign_outputs(): vector<u8> {
+        let outputs = x"0000000000000000";
+        push_var_bytes(&mut outputs, x"6a");
+        outputs
+    }
+
+    /// Build the BIP-143 signature message of the BIP-322 `to_sign` transaction spending the P2WPKH output with SIGHASH_ALL,
+    /// return the sha256 of it. The `ecdsa_k1::verify` hashes it with sha256 again, so the signature is verified against the sighash.
+    fun bip322_p2wpkh_sighash_message(public_key: &vector<u8>, message: &vector<u8>): vector<u8> {
+        let pubkey_hash = hash160(public_key);
+        let to_spend_txid = bip322_to_spend_txid(p2wpkh_script(pubkey_hash), message);
+        let outpoint = to_spend_txid;
+        vector::append(&mut outpoint, x"00000000");
+
+        // version || hash_prevouts || hash_sequence || outpoint
+        let sig_msg = x"00000000";
+        vector::append(&mut sig_msg, hash::sha2_256(hash::sha2_256(outpoint)));
+        vector::append(&mut sig_msg, hash::sha2_256(hash::sha2_256(x"00000000")));
+        vector::append(&mut sig_msg, outpoint);
+        // The script code of P2WPKH is the P2PKH script of the public key hash
+        push_var_bytes(&mut sig_msg, p2pkh_script(pubkey_hash));
+        // amount || sequence || hash_outputs || locktime || hash_type
+        vector::append(&mut sig_msg, x"0000000000000000");
+        vector::append(&mut sig_msg, x"00000000");
+        vector::append(&mut sig_msg, hash::sha2_256(hash::sha2_256(bip322_to_sign_outputs())));
+        vector::append(&mut sig_msg, x"00000000");
+        vector::append(&mut sig_msg, x"01000000");
+        hash::sha2_256(sig_msg)
+    }
+
+    /// Build the BIP-341 signature message of the BIP-322 `to_sign` transaction, return the tagged hash preimage of it.
+    /// The `schnorr::verify` hashes the preimage with sha256, so the schnorr signature is verified against the sighash.
+    fun bip322_taproot_sighash_preimage(output_key: &vector<u8>, message: &vector<u8>): vector<u8> {
+        let script_pubkey = p2tr_script(*output_key);
+        let to_spend_txid = bip322_to_spend_txid(script_pubkey, message);
+
+        // The `to_sign` transaction spends the `to_spend` output with SIGHASH_DEFAULT.
+        let prevouts = to_spend_txid;
+        vector::append(&mut prevouts, x"00000000");
+        let script_pubkeys = vector::empty<u8>();
+        push_var_bytes(&mut script_pubkeys, script_pubkey);
+
+        // epoch || hash_type || version || locktime
+        let sig_msg = x"00000000000000000000";
+        vector::append(&mut sig_msg, hash::sha2_256(prevouts));
+        vector::append(&mut sig_msg, hash::sha2_256(x"0000000000000000"));
+        vector::append(&mut sig_msg, hash::sha2_256(script_pubkeys));
+        vector::append(&mut sig_msg, hash::sha2_256(x"00000000"));
+        vector::append(&mut sig_msg, hash::sha2_256(bip322_to_sign_outputs()));
+        // spend_type || input_index
+        vector::append(&mut sig_msg, x"0000000000");
+        tagged_hash_preimage(TAP_SIGHASH_TAG, &sig_msg)
+    }
+
+    /// Verify the BIP-322 simple signature of the message, return the P2WPKH or taproot address of the signer.
+    /// The address type is decided by the length of the public key.
+    fun verify_bip322_simple(payload: &BitcoinAuthPayload, message: vector<u8>): BTCAddress {
+        let public_key_length = vector::length(&payload.public_key);
+        if (public_key_length == ecdsa_k1::public_key_length()) {
+            assert!(
+                vector::length(&payload.signature) == ecdsa_k1::signature_length(),
+                auth_validator::error_invalid_authenticator()
+            );
+            let sighash_message = bip322_p2wpkh_sighash_message(&payload.public_key, &message);
+            assert!(
+                ecdsa_k1::verify(&payload.signature, &payload.public_key, &sighash_message, ecdsa_k1::sha256()),
+                auth_validator::error_invalid_authenticator()
+            );
+            script_to_address(p2wpkh_script(hash160(&payload.public_key)))
+        } else if (public_key_length == schnorr::public_key_length()) {
+            assert!(
+                vector::length(&payload.signature) == schnorr::signature_length(),
+                auth_validator::error_invalid_authenticator()
+            );
+            let preimage = bip322_taproot_sighash_preimage(&payload.public_key, &message);
+            assert!(
+                schnorr::verify(&payload.signature, &payload.public_key, &preimage, schnorr::sha256()),
+                auth_validator::error_invalid_authenticator()
+            );
+            script_to_address(p2tr_script(payload.public_key))
+        } else {
+            abort auth_validator::error_invalid_authenticator()
+        }
+    }
+
+    /// Only validate the authenticator's signature, return the Bitcoin address of the signer.
+    public fun validate_signature(authenticator_payload: vector<u8>, tx_hash: &vector<u8>): BTCAddress {
+        let payload = bcs::from_bytes<BitcoinAuthPayload>(authenticator_payload);
+        let message = sign_message(tx_hash);
+        if (payload.sign_type == SIGN_TYPE_BIP137) {
+            verify_bip137(&payload, message)
+        } else if (payload.sign_type == SIGN_TYPE_BIP322_SIMPLE) {
+            verify_bip322_simple(&payload, message)
+        } else {
+            abort auth_validator::error_invalid_authenticator()
+        }
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        let bitcoin_addr = validate_signature(authenticator_payload, &tx_hash);
+        let multichain_addr = multichain_address::from_bitcoin(bitcoin_addr);
+        assert!(
+            address_mapping::resolve_or_generate(ctx, multichain_addr) == context::sender(ctx),
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    #[test_only]
+    const TEST_TX_HASH: vector<u8> = x"5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0";
+    #[test_only]
+    const TEST_BIP137_PUBLIC_KEY: vector<u8> = x"020121ef709ce7b0ceea109d4994518aa4a28592591ff5380185356c1d57c03cad";
+    #[test_only]
+    const TEST_BIP137_RS: vector<u8> = x"2752d39138a4b817e16a2c5523d856366f8ba04f81ce128be060db56a7720622620aa23aa3b36bbe8b2dd5051125256676b031819140769e0e56293f9d66190241";
+    #[test_only]
+    const TEST_BIP322_OUTPUT_KEY: vector<u8> = x"11527e8407fa8ea5562f48df653d5aef2b87dd7a9322253a6a004812b4336cfb";
+    #[test_only]
+    const TEST_BIP322_SIGNATURE: vector<u8> = x"4b156854ac7daf3c062163bfeed27d71c48dffced59b30f01675fcb42f1b35e1eac715ada11b0be5c2ea340bce2bd13fdf8fc413bec9e76458890a66c5647a57";
+
+    #[test_only]
+    const TEST_BIP322_P2WPKH_PUBLIC_KEY: vector<u8> = x"02f259b319c4a55110c1568fcfe32def16ddad07924cf6c8f601cd09bd30e51943";
+    #[test_only]
+    const TEST_BIP322_P2WPKH_SIGNATURE: vector<u8> = x"03f65d8cf4cbbc7186a0d566836884f8c6f8bbde2be423bc9bf76e8b019426d32d988c17669cd81dc1cfec68cbd202d0ef26ebfb064e97da0b8aaa51f71a2899";
+
+    #[test_only]
+    fun bip137_payload(header: u8): vector<u8> {
+        let signature = vector::singleton(header);
+        vector::append(&mut signature, TEST_BIP137_RS);
+        bcs::to_bytes(&BitcoinAuthPayload { sign_type: SIGN_TYPE_BIP137, signature, public_key: TEST_BIP137_PUBLIC_KEY })
+    }
+
+    #[test]
+    fun test_validate_bip137_signature() {
+        let addr = validate_signature(bip137_payload(BIP137_HEADER_P2WPKH), &TEST_TX_HASH);
+        assert!(bitcoin_address::into_bytes(addr) == x"36fc98aed6e6fb2e0957efe971c9bf58a15c0096", 1000);
+
+        let addr = validate_signature(bip137_payload(BIP137_HEADER_P2PKH), &TEST_TX_HASH);
+        assert!(bitcoin_address::is_p2pkh(&addr), 1001);
+        assert!(bitcoin_address::into_bytes(addr) == x"0036fc98aed6e6fb2e0957efe971c9bf58a15c0096", 1002);
+
+        let addr = validate_signature(bip137_payload(BIP137_HEADER_P2SH_P2WPKH), &TEST_TX_HASH);
+        assert!(bitcoin_address::is_p2sh(&addr), 1003);
+        assert!(bitcoin_address::into_bytes(addr) == x"05132be12ffd211d72a3861f2f777ee065173ea88f", 1004);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_bip137_uncompressed_header() {
+        validate_signature(bip137_payload(BIP137_HEADER_P2PKH_UNCOMPRESSED), &TEST_TX_HASH);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_bip137_wrong_message() {
+        validate_signature(bip137_payload(BIP137_HEADER_P2WPKH), &x"00");
+    }
+
+    #[test]
+    fun test_validate_bip322_simple_signature() {
+        let payload = bcs::to_bytes(&BitcoinAuthPayload {
+            sign_type: SIGN_TYPE_BIP322_SIMPLE,
+            signature: TEST_BIP322_SIGNATURE,
+            public_key: TEST_BIP322_OUTPUT_KEY,
+        });
+        let addr = validate_signature(payload, &TEST_TX_HASH);
+        assert!(bitcoin_address::into_bytes(addr) == TEST_BIP322_OUTPUT_KEY, 1000);
+    }
+
+    #[test]
+    fun test_validate_bip322_simple_p2wpkh_signature() {
+        let payload = bcs::to_bytes(&BitcoinAuthPayload {
+            sign_type: SIGN_TYPE_BIP322_SIMPLE,
+            signature: TEST_BIP322_P2WPKH_SIGNATURE,
+            public_key: TEST_BIP322_P2WPKH_PUBLIC_KEY,
+        });
+        let addr = validate_signature(payload, &TEST_TX_HASH);
+        assert!(bitcoin_address::into_bytes(addr) == x"57a49d1888515b1f0d95bd123981f118ccfab176", 1000);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_bip322_simple_p2wpkh_wrong_message() {
+        let payload = bcs::to_bytes(&BitcoinAuthPayload {
+            sign_type: SIGN_TYPE_BIP322_SIMPLE,
+            signature: TEST_BIP322_P2WPKH_SIGNATURE,
+            public_key: TEST_BIP322_P2WPKH_PUBLIC_KEY,
+        });
+        validate_signature(payload, &x"00");
+    }
+
+    #[test]
+    fun test_bip322_p2wpkh_sighash() {
+        // The BIP-143 sighash of the `to_sign` transaction is the sha256d of the signature message
+        let sighash_message = bip322_p2wpkh_sighash_message(&TEST_BIP322_P2WPKH_PUBLIC_KEY, &sign_message(&TEST_TX_HASH));
+        assert!(hash::sha2_256(sighash_message) == x"ef7f14d7d734f9be68f700e7b1406f81ca5da1e67ea57b87cf584ae5e1c04e46", 1000);
+    }
+
+    #[test]
+    fun test_bip322_sighash() {
+        let preimage = bip322_taproot_sighash_preimage(&TEST_BIP322_OUTPUT_KEY, &sign_message(&TEST_TX_HASH));
+        assert!(hash::sha2_256(preimage) == x"eb0bbcbdf02e4bd3ac60fa24d845d229c05f75ab8147326ffdba51b25e650bf1", 1000);
+    }
+}
@@ -7,6 +7,12 @@ module rooch_framework::builtin_validators{
     use rooch_framework::auth_validator_registry;
     use rooch_framework::native_validator;
     use rooch_framework::ethereum_validator;
+    use rooch_framework::multisig_validator;
+    use rooch_framework::bitcoin_validator;
+    use rooch_framework::nostr_validator;
+    use rooch_framework::secp256k1_validator;
+    use rooch_framework::secp256r1_validator;
+    use rooch_framework::webauthn_validator;
 
     friend rooch_framework::genesis;
 
@@ -20,10 +26,40 @@ module rooch_framework::builtin_validators{
         // ETHEREUM_AUTH_VALIDATOR_ID: u64 = 1;
         let id = auth_validator_registry::register_internal<ethereum_validator::EthereumValidator>(ctx);
         assert!(id == ethereum_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // MULTISIG_AUTH_VALIDATOR_ID: u64 = 2;
+        let id = auth_validator_registry::register_internal<multisig_validator::MultisigValidator>(ctx);
+        assert!(id == multisig_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // BITCOIN_AUTH_VALIDATOR_ID: u64 = 3;
+        let id = auth_validator_registry::register_internal<bitcoin_validator::BitcoinValidator>(ctx);
+        assert!(id == bitcoin_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // NOSTR_AUTH_VALIDATOR_ID: u64 = 4;
+        let id = auth_validator_registry::register_internal<nostr_validator::NostrValidator>(ctx);
+        assert!(id == nostr_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // SECP256K1_AUTH_VALIDATOR_ID: u64 = 5;
+        let id = auth_validator_registry::register_internal<secp256k1_validator::Secp256k1Validator>(ctx);
+        assert!(id == secp256k1_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // SECP256R1_AUTH_VALIDATOR_ID: u64 = 6;
+        let id = auth_validator_registry::register_internal<secp256r1_validator::Secp256r1Validator>(ctx);
+        assert!(id == secp256r1_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
+
+        // WEBAUTHN_AUTH_VALIDATOR_ID: u64 = 7;
+        let id = auth_validator_registry::register_internal<webauthn_validator::WebauthnValidator>(ctx);
+        assert!(id == webauthn_validator::auth_validator_id(), std::error::internal(ErrorGenesisInit));
     }
 
     public fun is_builtin_auth_validator(auth_validator_id: u64): bool {
         auth_validator_id == native_validator::auth_validator_id()
         || auth_validator_id == ethereum_validator::auth_validator_id()
+        || auth_validator_id == multisig_validator::auth_validator_id()
+        || auth_validator_id == bitcoin_validator::auth_validator_id()
+        || auth_validator_id == nostr_validator::auth_validator_id()
+        || auth_validator_id == secp256k1_validator::auth_validator_id()
+        || auth_validator_id == secp256r1_validator::auth_validator_id()
+        || auth_validator_id == webauthn_validator::auth_validator_id()
     }
 }
@@ -11,12 +11,26 @@ module rooch_framework::ethereum_validator {
     use moveos_std::context::{Self, Context};
     use rooch_framework::account_authentication;
     use rooch_framework::ecdsa_k1_recoverable;
+    use rooch_framework::hash;
     use rooch_framework::auth_validator;
     use rooch_framework::ethereum_address::{Self, ETHAddress};
+    use rooch_framework::address_mapping;
+    use rooch_framework::multichain_address;
 
     /// there defines auth validator id for each blockchain
     const ETHEREUM_AUTH_VALIDATOR_ID: u64 = 1;
 
+    /// The authenticator payload is `signature(65 bytes) || ethereum address(20 bytes) || signing hash(32 bytes) || signing message`,
+    /// the signing message is the unsigned RLP encoding of the transaction, its keccak256 hash is the signing hash.
+    /// The tx hash is the keccak256 hash of the signed encoding of the transaction.
+    const ETHEREUM_ADDR_LENGTH: u64 = 20;
+    const SIGNING_HASH_LENGTH: u64 = 32;
+
+    /// The Legacy transaction signing message with the EIP-155 chain id has 9 RLP items,
+    /// the last 3 items `chain_id, 0, 0` are replaced by `v, r, s` in the signed encoding.
+    const LEGACY_EIP155_SIGNING_MESSAGE_ITEMS: u64 = 9;
+    const LEGACY_SIGNING_MESSAGE_ITEMS: u64 = 6;
+
     // error code
     const ErrorInvalidPublicKeyLength: u64 = 1;
 
@@ -41,6 +55,12 @@ module rooch_framework::ethereum_validator {
         let authentication_key = public_key_to_authentication_key(public_key);
         let account_addr = signer::address_of(account);
         rotate_authentication_key(ctx, account_addr, authentication_key);
+
+        // Map the new Ethereum address to the account, so the transactions signed by the new key are sent from the account.
+        let multichain_addr = multichain_address::from_eth(public_key_to_address(public_key));
+        if (!address_mapping::exists_mapping(ctx, multichain_addr)) {
+            address_mapping::bind(ctx, account, multichain_addr);
+        };
     }
 
     fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
@@ -51,18 +71,6 @@ module rooch_framework::ethereum_validator {
         account_authentication::remove_authentication_key<EthereumValidator>(ctx, signer::address_of(account));
     }
 
-    public fun get_public_key_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
-        let public_key = vector::empty<u8>();
-        let i = ecdsa_k1_recoverable::signature_length();
-        let public_key_position = ecdsa_k1_recoverable::signature_length() + ecdsa_k1_recoverable::public_key_length();
-        while (i < public_key_position) {
-            let value = vector::borrow(authenticator_payload, i);
-            vector::push_back(&mut public_key, *value);
-            i = i + 1;
-        };
-        public_key
-    }
-
     public fun get_signature_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
         let sign = vector::empty<u8>();
         let i = 0;
@@ -72,13 +80,32 @@ module rooch_framework::ethereum_validator {
             vector::push_back(&mut sign, *value);
             i = i + 1;
         };
+        // Normalize the recovery id of the Ethereum signature to be 0 or 1.
+        let v = vector::borrow_mut(&mut sign, signature_position - 1);
+        if (*v == 27 || *v == 28) {
+            *v = *v - 27;
+        } else if (*v >= 35) {
+            *v = (*v - 1) % 2;
+        };
         sign
     }
 
+    /// Get the Ethereum address of the signer from authenticator_payload.
+    public fun get_address_from_authenticator_payload(authenticator_payload: &vector<u8>): ETHAddress {
+        let address_bytes = vector::empty<u8>();
+        let i = ecdsa_k1_recoverable::signature_length();
+        let address_position = ecdsa_k1_recoverable::signature_length() + ETHEREUM_ADDR_LENGTH;
+        while (i < address_position) {
+            let value = vector::borrow(authenticator_payload, i);
+            vector::push_back(&mut address_bytes, *value);
+            i = i + 1;
+        };
+        ethereum_address::from_bytes(address_bytes)
+    }
+
     /// Get the authentication key of the given authenticator from authenticator_payload.
     public fun get_authentication_key_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
-        let public_key = get_public_key_from_authenticator_payload(authenticator_payload);
-        let addr = public_key_to_address(public_key);
+        let addr = get_address_from_authenticator_payload(authenticator_payload);
         ethereum_address::into_bytes(addr)
     }
 
@@ -107,23 +134,230 @@ module rooch_framework::ethereum_validator {
         option::extract(&mut get_authentication_key_option_from_account(ctx, addr))
     }
 
+    /// Get the signing hash of the transaction from authenticator_payload.
+    public fun get_signing_hash_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        let i = ecdsa_k1_recoverable::signature_length() + ETHEREUM_ADDR_LENGTH;
+        sub_vector(authenticator_payload, i, i + SIGNING_HASH_LENGTH)
+    }
+
+    /// Get the signing message of the transaction from authenticator_payload.
+    public fun get_message_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        let i = ecdsa_k1_recoverable::signature_length() + ETHEREUM_ADDR_LENGTH + SIGNING_HASH_LENGTH;
+        sub_vector(authenticator_payload, i, vector::length(authenticator_payload))
+    }
+
+    /// Encode the signed transaction from the signing message and the normalized signature,
+    /// the keccak256 hash of the signed encoding is the Ethereum transaction hash.
+    /// Supports the Legacy transaction (with or without EIP-155 chain id) and the EIP-2718 typed transaction.
+    public fun encode_signed_transaction(message: &vector<u8>, signature: &vector<u8>): vector<u8> {
+        assert!(
+            !vector::is_empty(message) && vector::length(signature) == ecdsa_k1_recoverable::signature_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        let encoded = vector::empty<u8>();
+        let offset = 0;
+        // The typed transaction starts with the transaction type, which is less than 0x80
+        let typed = *vector::borrow(message, 0) < 0x80;
+        if (typed) {
+            vector::push_back(&mut encoded, *vector::borrow(message, 0));
+            offset = 1;
+        };
+        assert!(
+            vector::length(message) > offset && *vector::borrow(message, offset) >= 0xc0,
+            auth_validator::error_invalid_authenticator()
+        );
+        let (items_start, items_length) = decode_rlp_header(message, offset);
+        let items_end = items_start + items_length;
+        assert!(items_end == vector::length(message), auth_validator::error_invalid_authenticator());
+
+        let parity = (*vector::borrow(signature, ecdsa_k1_recoverable::signature_length() - 1) as u256);
+        let v = if (typed) {
+            parity
+        } else {
+            // Find the start of the 7th item, the chain id of the EIP-155 signing message
+            let items = 0;
+            let i = items_start;
+            let chain_id_offset = items_end;
+            while (i < items_end) {
+                if (items == LEGACY_SIGNING_MESSAGE_ITEMS) {
+                    chain_id_offset = i;
+                };
+                let (item_start, item_length) = decode_rlp_header(message, i);
+                i = item_start + item_length;
+                items = items + 1;
+            };
+            assert!(i == items_end, auth_validator::error_invalid_authenticator());
+            if (items == LEGACY_EIP155_SIGNING_MESSAGE_ITEMS) {
+                let (chain_id_start, chain_id_length) = decode_rlp_header(message, chain_id_offset);
+                assert!(chain_id_length <= 8, auth_validator::error_invalid_authenticator());
+                let chain_id = bytes_to_u256(&sub_vector(message, chain_id_start, chain_id_start + chain_id_length));
+                items_end = chain_id_offset;
+                chain_id * 2 + 35 + parity
+            } else {
+                assert!(items == LEGACY_SIGNING_MESSAGE_ITEMS, auth_validator::error_invalid_authenticator());
+                27 + parity
+            }
+        };
+
+        let items = sub_vector(message, items_start, items_end);
+        vector::append(&mut items, encode_rlp_bytes(u256_to_bytes(v)));
+        vector::append(&mut items, encode_rlp_bytes(trim_leading_zeros(sub_vector(signature, 0, 32))));
+        vector::append(&mut items, encode_rlp_bytes(trim_leading_zeros(sub_vector(signature, 32, 64))));
+        vector::append(&mut encoded, encode_rlp_header(0xc0, vector::length(&items)));
+        vector::append(&mut encoded, items);
+        encoded
+    }
+
+    fun sub_vector(bytes: &vector<u8>, start: u64, end: u64): vector<u8> {
+        assert!(start <= end && end <= vector::length(bytes), auth_validator::error_invalid_authenticator());
+        let result = vector::empty<u8>();
+        let i = start;
+        while (i < end) {
+            vector::push_back(&mut result, *vector::borrow(bytes, i));
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Decode the RLP header at `offset`, returns the start and the length of the item's payload.
+    fun decode_rlp_header(bytes: &vector<u8>, offset: u64): (u64, u64) {
+        let length = vector::length(bytes);
+        assert!(offset < length, auth_validator::error_invalid_authenticator());
+        let prefix = (*vector::borrow(bytes, offset) as u64);
+        let start = offset + 1;
+        let payload_length;
+        if (prefix < 0x80) {
+            start = offset;
+            payload_length = 1;
+        } else if (prefix <= 0xb7) {
+            payload_length = prefix - 0x80;
+        } else if (prefix < 0xc0) {
+            let length_of_length = prefix - 0xb7;
+            start = start + length_of_length;
+            payload_length = decode_rlp_length(bytes, offset + 1, length_of_length);
+        } else if (prefix <= 0xf7) {
+            payload_length = prefix - 0xc0;
+        } else {
+            let length_of_length = prefix - 0xf7;
+            start = start + length_of_length;
+            payload_length = decode_rlp_length(bytes, offset + 1, length_of_length);
+        };
+        assert!(start + payload_length <= length, auth_validator::error_invalid_authenticator());
+        (start, payload_length)
+    }
+
+    fun decode_rlp_length(bytes: &vector<u8>, offset: u64, length_of_length: u64): u64 {
+        assert!(length_of_length <= 4, auth_validator::error_invalid_authenticator());
+        (bytes_to_u256(&sub_vector(bytes, offset, offset + length_of_length)) as u64)
+    }
+
+    fun encode_rlp_bytes(bytes: vector<u8>): vector<u8> {
+        let length = vector::length(&bytes);
+        if (length == 1 && *vector::borrow(&bytes, 0) < 0x80) {
+            return bytes
+        };
+        let encoded = encode_rlp_header(0x80, length);
+        vector::append(&mut encoded, bytes);
+        encoded
+    }
+
+    fun encode_rlp_header(prefix: u8, length: u64): vector<u8> {
+        if (length <= 55) {
+            return vector::singleton(prefix + (length as u8))
+        };
+        let length_bytes = u256_to_bytes((length as u256));
+        let header = vector::singleton(prefix + 55 + (vector::length(&length_bytes) as u8));
+        vector::append(&mut header, length_bytes);
+        header
+    }
+
+    /// Big-endian bytes to u256, the bytes must not be longer than 32.
+    fun bytes_to_u256(bytes: &vector<u8>): u256 {
+        let value = 0u256;
+        let i = 0;
+        while (i < vector::length(bytes)) {
+            value = (value << 8) | (*vector::borrow(bytes, i) as u256);
+            i = i + 1;
+        };
+        value
+    }
+
+    /// The minimal big-endian bytes of u256, zero is encoded as empty bytes.
+    fun u256_to_bytes(value: u256): vector<u8> {
+        let bytes = vector::empty<u8>();
+        while (value > 0) {
+            vector::push_back(&mut bytes, ((value & 0xff) as u8));
+            value = value >> 8;
+        };
+        vector::reverse(&mut bytes);
+        bytes
+    }
+
+    fun trim_leading_zeros(bytes: vector<u8>): vector<u8> {
+        let i = 0;
+        let length = vector::length(&bytes);
+        while (i < length && *vector::borrow(&bytes, i) == 0) {
+            i = i + 1;
+        };
+        sub_vector(&bytes, i, length)
+    }
+
     /// Only validate the authenticator's signature.
+    /// The signing message must hash to the signing hash, the Ethereum address recovered from the signature
+    /// must be the signer's Ethereum address in the payload, and the signed encoding of the transaction
+    /// recomputed from the signing message and the signature must hash to the tx hash.
     public fun validate_signature(authenticator_payload: &vector<u8>, tx_hash: &vector<u8>) {
         assert!(
-            ecdsa_k1_recoverable::verify(
-                &get_signature_from_authenticator_payload(authenticator_payload),
-                tx_hash,
-                ecdsa_k1_recoverable::keccak256()
-            ),
+            vector::length(authenticator_payload) > ecdsa_k1_recoverable::signature_length() + ETHEREUM_ADDR_LENGTH + SIGNING_HASH_LENGTH,
             auth_validator::error_invalid_authenticator()
         );
+        let message = get_message_from_authenticator_payload(authenticator_payload);
+        assert!(
+            hash::keccak256(&message) == get_signing_hash_from_authenticator_payload(authenticator_payload),
+            auth_validator::error_invalid_authenticator()
+        );
+        let signature = get_signature_from_authenticator_payload(authenticator_payload);
+        assert!(
+            hash::keccak256(&encode_signed_transaction(&message, &signature)) == *tx_hash,
+            auth_validator::error_invalid_authenticator()
+        );
+        let public_key = ecdsa_k1_recoverable::ecrecover(
+            &signature,
+            &message,
+            ecdsa_k1_recoverable::keccak256()
+        );
+        assert!(
+            public_key_to_address(public_key) == get_address_from_authenticator_payload(authenticator_payload),
+            auth_validator::error_invalid_account_auth_key()
+        );
     }
 
+    /// Validate the authenticator's signature and the signer's Ethereum address.
+    /// If the sender's account has an authentication key, the Ethereum address must be the same as it,
+    /// otherwise the Ethereum address must be mapped to the sender via `address_mapping`.
+    /// Abort with `auth_validator::error_invalid_authenticator()` if the payload is invalid,
+    /// and `auth_validator::error_invalid_account_auth_key()` if the signature is not signed by the Ethereum address
+    /// or the signer does not match the sender.
     public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
         let tx_hash = context::tx_hash(ctx);
         validate_signature(&authenticator_payload, &tx_hash);
 
-        // TODO compare the auth_key from the payload with the auth_key from the account
+        let sender = context::sender(ctx);
+        let auth_key_from_authenticator_payload = get_authentication_key_from_authenticator_payload(&authenticator_payload);
+        if (is_authentication_key_in_account(ctx, sender)) {
+            assert!(
+                get_authentication_key_from_account(ctx, sender) == auth_key_from_authenticator_payload,
+                auth_validator::error_invalid_account_auth_key()
+            );
+        } else {
+            let multichain_addr = multichain_address::from_eth(
+                get_address_from_authenticator_payload(&authenticator_payload)
+            );
+            assert!(
+                address_mapping::resolve_or_generate(ctx, multichain_addr) == sender,
+                auth_validator::error_invalid_account_auth_key()
+            );
+        }
     }
 
     fun pre_execute(
@@ -149,4 +383,101 @@ module rooch_framework::ethereum_validator {
         let expected_address = x"1a642f0e3c3af545e7acbd38b07251b3990914f1";
         assert!(address_bytes == expected_address, 1000);
     }
+
+    #[test]
+    fun test_authenticator_payload() {
+        let payload = x"7e4237ebfbc36613e166bfc5f6229360a9c1949242da97ca04867e4de57b2df30c8340bcb320328cf46d71bda51fcb519e3ce53b348eec62de852e350edbd8861c";
+        vector::append(&mut payload, x"1a642f0e3c3af545e7acbd38b07251b3990914f1");
+        vector::append(&mut payload, hash::keccak256(&b""));
+        let signature = get_signature_from_authenticator_payload(&payload);
+        assert!(*vector::borrow(&signature, 64) == 1, 1000);
+        let addr = get_address_from_authenticator_payload(&payload);
+        assert!(ethereum_address::into_bytes(addr) == x"1a642f0e3c3af545e7acbd38b07251b3990914f1", 1001);
+        assert!(get_signing_hash_from_authenticator_payload(&payload) == hash::keccak256(&b""), 1002);
+        assert!(vector::is_empty(&get_message_from_authenticator_payload(&payload)), 1003);
+    }
+
+    // The EIP-155 Legacy transaction `{nonce: 0, gas_price: 1 gwei, gas: 21000, to: 0x4259abf3f34ab0e5a399494cb1e9a7f8465ae4d6,
+    // value: 1, data: "Hello, world!", chain_id: 20230104}` signed by the private key `[1u8; 32]`.
+    #[test_only]
+    const TEST_LEGACY_SIGNING_MESSAGE: vector<u8> = x"f480843b9aca00825208944259abf3f34ab0e5a399494cb1e9a7f8465ae4d6018d48656c6c6f2c20776f726c6421840134afd88080";
+    #[test_only]
+    const TEST_LEGACY_SIGNATURE: vector<u8> = x"f973a0b87062c389d125d8199e803b832b6ac6bf7867a4f6cd87506060fc4c584dc1b6ada980a8485ef36a470345b221e09bbb3016e8b95c5c2dd7d5ac930dbe00";
+    #[test_only]
+    const TEST_LEGACY_TX_HASH: vector<u8> = x"8566c0d83f9f94a3af6fce8fcd4436a1388ca0321792d66924138f6ab523963d";
+    #[test_only]
+    const TEST_SIGNER_ADDRESS: vector<u8> = x"1a642f0e3c3af545e7acbd38b07251b3990914f1";
+
+    #[test_only]
+    fun build_payload(signature: vector<u8>, addr: vector<u8>, message: vector<u8>): vector<u8> {
+        let payload = signature;
+        vector::append(&mut payload, addr);
+        vector::append(&mut payload, hash::keccak256(&message));
+        vector::append(&mut payload, message);
+        payload
+    }
+
+    #[test]
+    fun test_validate_signature() {
+        let payload = build_payload(TEST_LEGACY_SIGNATURE, TEST_SIGNER_ADDRESS, TEST_LEGACY_SIGNING_MESSAGE);
+        validate_signature(&payload, &TEST_LEGACY_TX_HASH);
+    }
+
+    // The EIP-1559 transaction `{chain_id: 20230104, nonce: 1, max_priority_fee_per_gas: 1 gwei, max_fee_per_gas: 2 gwei,
+    // gas: 21000, to: 0x4259abf3f34ab0e5a399494cb1e9a7f8465ae4d6, value: 0, data: "Hello, world!"}` signed by the private key `[1u8; 32]`.
+    #[test]
+    fun test_validate_signature_eip1559() {
+        let message = x"02f838840134afd801843b9aca008477359400825208944259abf3f34ab0e5a399494cb1e9a7f8465ae4d6808d48656c6c6f2c20776f726c6421c0";
+        let signature = x"50e8aa2a0538a8527928792008a8b0c47674a3e3ac88ed8aa002c645e84e54043552b2302a4e158a095bca4002b6a03c6936c2d19e487b50d0b2c4f2ce3caedb00";
+        let payload = build_payload(signature, TEST_SIGNER_ADDRESS, message);
+        validate_signature(&payload, &x"0d690f70ebfc67466153ca436266e90409d2193f9055bf22489273204006f345");
+    }
+
+    // The same transaction signed by another key has a different tx hash.
+    #[test]
+    fun test_encode_signed_transaction_by_different_signers() {
+        let signature = x"12faae608bd6562562b8f85564664cd1fdcd667f6b24b2b221ef86b9231f4d746db35cf6119a2d88be852f478203d91f01b52b90dadbe98d697001f73860a05100";
+        let tx_hash = hash::keccak256(&encode_signed_transaction(&TEST_LEGACY_SIGNING_MESSAGE, &signature));
+        assert!(tx_hash == x"b8619cfcf4b14d3d928832522e4e88228487e6fabcd801f6fdf20f1eb9fec228", 1000);
+        assert!(tx_hash != TEST_LEGACY_TX_HASH, 1001);
+        let payload = build_payload(signature, x"5050a4f4b3f9338c3472dcc01a87c76a144b3c9c", TEST_LEGACY_SIGNING_MESSAGE);
+        validate_signature(&payload, &tx_hash);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103e9, location = Self)]
+    fun test_validate_signature_spoofed_address() {
+        let payload = build_payload(
+            TEST_LEGACY_SIGNATURE,
+            x"4259abf3f34ab0e5a399494cb1e9a7f8465ae4d6",
+            TEST_LEGACY_SIGNING_MESSAGE
+        );
+        validate_signature(&payload, &TEST_LEGACY_TX_HASH);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_mismatched_tx_hash() {
+        let payload = build_payload(TEST_LEGACY_SIGNATURE, TEST_SIGNER_ADDRESS, TEST_LEGACY_SIGNING_MESSAGE);
+        validate_signature(&payload, &hash::keccak256(&TEST_LEGACY_SIGNING_MESSAGE));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_mismatched_signing_hash() {
+        let payload = TEST_LEGACY_SIGNATURE;
+        vector::append(&mut payload, TEST_SIGNER_ADDRESS);
+        vector::append(&mut payload, TEST_LEGACY_TX_HASH);
+        vector::append(&mut payload, TEST_LEGACY_SIGNING_MESSAGE);
+        validate_signature(&payload, &TEST_LEGACY_TX_HASH);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_invalid_payload_length() {
+        let payload = TEST_LEGACY_SIGNATURE;
+        vector::append(&mut payload, TEST_SIGNER_ADDRESS);
+        vector::append(&mut payload, hash::keccak256(&TEST_LEGACY_SIGNING_MESSAGE));
+        validate_signature(&payload, &TEST_LEGACY_TX_HASH);
+    }
 }
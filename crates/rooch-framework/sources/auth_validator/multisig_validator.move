@@ -0,0 +1,350 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the multisig validator, a transaction of the multisig account is valid
+/// if it is signed by at least `threshold` keys of the account's key set.
+/// The keys can be ed25519, secp256k1 or secp256r1 keys, the key set is managed on chain by the multisig account itself.
+module rooch_framework::multisig_validator {
+
+    use std::error;
+    use std::vector;
+    use std::signer;
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::account;
+    use rooch_framework::hash;
+    use rooch_framework::ed25519;
+    use rooch_framework::ecdsa_k1;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_validator;
+
+    /// there defines auth validator id for each blockchain
+    const MULTISIG_AUTH_VALIDATOR_ID: u64 = 2;
+
+    /// The key schemes supported by the multisig validator
+    const SCHEME_ED25519: u8 = 0;
+    const SCHEME_SECP256K1: u8 = 1;
+    const SCHEME_SECP256R1: u8 = 2;
+
+    /// The max number of the keys of a multisig account
+    const MAX_PUBLIC_KEYS: u64 = 16;
+
+    // error code
+    /// The threshold must be greater than zero and not greater than the number of the keys
+    const ErrorInvalidThreshold: u64 = 1;
+    /// The key scheme is not supported
+    const ErrorInvalidKeyScheme: u64 = 2;
+    /// The public key length does not match the key scheme
+    const ErrorInvalidPublicKeyLength: u64 = 3;
+    /// The public key is already in the key set
+    const ErrorPublicKeyAlreadyExists: u64 = 4;
+    /// The public key is not in the key set
+    const ErrorPublicKeyNotFound: u64 = 5;
+    /// The length of the key schemes and the public keys are not equal
+    const ErrorKeySchemesLengthMismatch: u64 = 6;
+    /// The account is already a multisig account
+    const ErrorMultisigAccountAlreadyExists: u64 = 7;
+    /// The account is not a multisig account
+    const ErrorMultisigAccountNotFound: u64 = 8;
+    /// The key set exceeds the max number of the keys
+    const ErrorTooManyPublicKeys: u64 = 9;
+
+    struct MultisigValidator has store, drop {}
+
+    struct MultisigPublicKey has store, copy, drop {
+        scheme: u8,
+        public_key: vector<u8>,
+    }
+
+    /// The key set of a multisig account, stored under the multisig account.
+    struct MultisigAccount has key {
+        threshold: u64,
+        public_keys: vector<MultisigPublicKey>,
+    }
+
+    #[data_struct]
+    /// A signature of one key of the key set
+    struct MultisigSignature has copy, drop {
+        scheme: u8,
+        public_key: vector<u8>,
+        signature: vector<u8>,
+    }
+
+    #[data_struct]
+    /// The authenticator payload of the multisig validator, it is the bcs bytes of the struct.
+    struct MultisigPayload has copy, drop {
+        signatures: vector<MultisigSignature>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        MULTISIG_AUTH_VALIDATOR_ID
+    }
+
+    public fun scheme_ed25519(): u8 {
+        SCHEME_ED25519
+    }
+
+    public fun scheme_secp256k1(): u8 {
+        SCHEME_SECP256K1
+    }
+
+    public fun scheme_secp256r1(): u8 {
+        SCHEME_SECP256R1
+    }
+
+    public fun new_public_key(scheme: u8, public_key: vector<u8>): MultisigPublicKey {
+        if (scheme == SCHEME_ED25519) {
+            assert!(vector::length(&public_key) == ed25519::public_key_length(), error::invalid_argument(ErrorInvalidPublicKeyLength));
+        } else if (scheme == SCHEME_SECP256K1) {
+            assert!(vector::length(&public_key) == ecdsa_k1::public_key_length(), error::invalid_argument(ErrorInvalidPublicKeyLength));
+        } else if (scheme == SCHEME_SECP256R1) {
+            assert!(vector::length(&public_key) == ecdsa_r1::public_key_length(), error::invalid_argument(ErrorInvalidPublicKeyLength));
+        } else {
+            abort error::invalid_argument(ErrorInvalidKeyScheme)
+        };
+        MultisigPublicKey { scheme, public_key }
+    }
+
+    /// Zip the `schemes` and `public_keys` to the multisig public keys
+    public fun new_public_keys(schemes: vector<u8>, public_keys: vector<vector<u8>>): vector<MultisigPublicKey> {
+        let len = vector::length(&public_keys);
+        assert!(len == vector::length(&schemes), error::invalid_argument(ErrorKeySchemesLengthMismatch));
+        let keys = vector::empty<MultisigPublicKey>();
+        let i = 0;
+        while (i < len) {
+            let key = new_public_key(*vector::borrow(&schemes, i), *vector::borrow(&public_keys, i));
+            assert!(!vector::contains(&keys, &key), error::invalid_argument(ErrorPublicKeyAlreadyExists));
+            vector::push_back(&mut keys, key);
+            i = i + 1;
+        };
+        keys
+    }
+
+    /// The multisig account address is the blake2b256 hash of
+    /// `auth_validator_id || bcs(threshold) || scheme_1 || public_key_1 || ... || scheme_n || public_key_n`.
+    /// The address does not change when the key set is updated.
+    public fun multisig_address(public_keys: &vector<MultisigPublicKey>, threshold: u64): address {
+        let bytes = vector::singleton((MULTISIG_AUTH_VALIDATOR_ID as u8));
+        vector::append(&mut bytes, bcs::to_bytes(&threshold));
+        let i = 0;
+        let len = vector::length(public_keys);
+        while (i < len) {
+            let key = vector::borrow(public_keys, i);
+            vector::push_back(&mut bytes, key.scheme);
+            vector::append(&mut bytes, key.public_key);
+            i = i + 1;
+        };
+        bcs::to_address(hash::blake2b256(&bytes))
+    }
+
+    /// Create a multisig account with the key set, anyone can create the multisig account for the key set.
+    /// The address of the account is derived by `multisig_address`.
+    public entry fun create_multisig_account_entry(
+        ctx: &mut Context,
+        schemes: vector<u8>,
+        public_keys: vector<vector<u8>>,
+        threshold: u64,
+    ) {
+        create_multisig_account(ctx, new_public_keys(schemes, public_keys), threshold);
+    }
+
+    public fun create_multisig_account(ctx: &mut Context, public_keys: vector<MultisigPublicKey>, threshold: u64): address {
+        check_key_set(&public_keys, threshold);
+        let multisig_addr = multisig_address(&public_keys, threshold);
+        assert!(!is_multisig_account(ctx, multisig_addr), error::already_exists(ErrorMultisigAccountAlreadyExists));
+        let multisig_signer = account::create_derived_account(ctx, multisig_addr);
+        context::move_resource_to(ctx, &multisig_signer, MultisigAccount { threshold, public_keys });
+        multisig_addr
+    }
+
+    /// Add a key to the key set, it must be called by the multisig account.
+    public entry fun add_public_key_entry(ctx: &mut Context, account: &signer, scheme: u8, public_key: vector<u8>) {
+        let multisig_account = borrow_mut_multisig_account(ctx, signer::address_of(account));
+        let key = new_public_key(scheme, public_key);
+        assert!(!vector::contains(&multisig_account.public_keys, &key), error::invalid_argument(ErrorPublicKeyAlreadyExists));
+        vector::push_back(&mut multisig_account.public_keys, key);
+        check_key_set(&multisig_account.public_keys, multisig_account.threshold);
+    }
+
+    /// Remove a key from the key set, it must be called by the multisig account.
+    /// The remaining keys must not be less than the threshold.
+    public entry fun remove_public_key_entry(ctx: &mut Context, account: &signer, scheme: u8, public_key: vector<u8>) {
+        let multisig_account = borrow_mut_multisig_account(ctx, signer::address_of(account));
+        let (found, index) = vector::index_of(&multisig_account.public_keys, &MultisigPublicKey { scheme, public_key });
+        assert!(found, error::not_found(ErrorPublicKeyNotFound));
+        vector::remove(&mut multisig_account.public_keys, index);
+        check_key_set(&multisig_account.public_keys, multisig_account.threshold);
+    }
+
+    /// Update the threshold, it must be called by the multisig account.
+    public entry fun update_threshold_entry(ctx: &mut Context, account: &signer, threshold: u64) {
+        let multisig_account = borrow_mut_multisig_account(ctx, signer::address_of(account));
+        check_key_set(&multisig_account.public_keys, threshold);
+        multisig_account.threshold = threshold;
+    }
+
+    fun check_key_set(public_keys: &vector<MultisigPublicKey>, threshold: u64) {
+        let len = vector::length(public_keys);
+        assert!(len <= MAX_PUBLIC_KEYS, error::invalid_argument(ErrorTooManyPublicKeys));
+        assert!(threshold > 0 && threshold <= len, error::invalid_argument(ErrorInvalidThreshold));
+    }
+
+    fun borrow_mut_multisig_account(ctx: &mut Context, addr: address): &mut MultisigAccount {
+        assert!(is_multisig_account(ctx, addr), error::not_found(ErrorMultisigAccountNotFound));
+        context::borrow_mut_resource<MultisigAccount>(ctx, addr)
+    }
+
+    public fun is_multisig_account(ctx: &Context, addr: address): bool {
+        context::exists_resource<MultisigAccount>(ctx, addr)
+    }
+
+    public fun threshold(ctx: &Context, addr: address): u64 {
+        assert!(is_multisig_account(ctx, addr), error::not_found(ErrorMultisigAccountNotFound));
+        context::borrow_resource<MultisigAccount>(ctx, addr).threshold
+    }
+
+    public fun public_keys(ctx: &Context, addr: address): vector<MultisigPublicKey> {
+        assert!(is_multisig_account(ctx, addr), error::not_found(ErrorMultisigAccountNotFound));
+        context::borrow_resource<MultisigAccount>(ctx, addr).public_keys
+    }
+
+    fun verify_signature(signature: &MultisigSignature, tx_hash: &vector<u8>): bool {
+        if (signature.scheme == SCHEME_ED25519) {
+            ed25519::verify(&signature.signature, &signature.public_key, tx_hash)
+        } else if (signature.scheme == SCHEME_SECP256K1) {
+            ecdsa_k1::verify(&signature.signature, &signature.public_key, tx_hash, ecdsa_k1::sha256())
+        } else if (signature.scheme == SCHEME_SECP256R1) {
+            ecdsa_r1::verify(&signature.signature, &signature.public_key, tx_hash, ecdsa_r1::sha256())
+        } else {
+            false
+        }
+    }
+
+    /// Validate the signatures in the payload against the key set, every signature must be signed by a distinct key of the key set,
+    /// and the number of the signatures must reach the threshold.
+    fun validate_signatures(multisig_account: &MultisigAccount, authenticator_payload: vector<u8>, tx_hash: &vector<u8>) {
+        let payload = bcs::from_bytes<MultisigPayload>(authenticator_payload);
+        let signed_keys = vector::empty<MultisigPublicKey>();
+        let i = 0;
+        let len = vector::length(&payload.signatures);
+        while (i < len) {
+            let signature = vector::borrow(&payload.signatures, i);
+            let key = MultisigPublicKey { scheme: signature.scheme, public_key: signature.public_key };
+            assert!(
+                vector::contains(&multisig_account.public_keys, &key),
+                auth_validator::error_invalid_account_auth_key()
+            );
+            assert!(!vector::contains(&signed_keys, &key), auth_validator::error_invalid_authenticator());
+            assert!(verify_signature(signature, tx_hash), auth_validator::error_invalid_authenticator());
+            vector::push_back(&mut signed_keys, key);
+            i = i + 1;
+        };
+        assert!(vector::length(&signed_keys) >= multisig_account.threshold, auth_validator::error_invalid_authenticator());
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let sender = context::sender(ctx);
+        assert!(is_multisig_account(ctx, sender), auth_validator::error_invalid_account_auth_key());
+        let tx_hash = context::tx_hash(ctx);
+        validate_signatures(context::borrow_resource<MultisigAccount>(ctx, sender), authenticator_payload, &tx_hash);
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    #[test_only]
+    const TEST_MSG: vector<u8> = x"315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3";
+    #[test_only]
+    const TEST_ED25519_PUBLIC_KEY: vector<u8> = x"cc62332e34bb2d5cd69f60efbb2a36cb916c7eb458301ea36636c4dbb012bd88";
+    #[test_only]
+    const TEST_ED25519_SIGNATURE: vector<u8> = x"cce72947906dbae4c166fc01fd096432784032be43db540909bc901dbc057992b4d655ca4f4355cf0868e1266baacf6919902969f063e74162f8f04bc4056105";
+    #[test_only]
+    const TEST_SECP256K1_PUBLIC_KEY: vector<u8> = x"033e99a541db69bd32040dfe5037fbf5210dafa8151a71e21c5204b05d95ce0a62";
+
+    #[test_only]
+    fun test_multisig_account(threshold: u64): MultisigAccount {
+        MultisigAccount {
+            threshold,
+            public_keys: new_public_keys(vector[SCHEME_ED25519, SCHEME_SECP256K1], vector[TEST_ED25519_PUBLIC_KEY, TEST_SECP256K1_PUBLIC_KEY]),
+        }
+    }
+
+    #[test_only]
+    fun test_payload(signatures: vector<MultisigSignature>): vector<u8> {
+        bcs::to_bytes(&MultisigPayload { signatures })
+    }
+
+    #[test]
+    fun test_validate_signatures() {
+        let multisig_account = test_multisig_account(1);
+        let payload = test_payload(vector[MultisigSignature { scheme: SCHEME_ED25519, public_key: TEST_ED25519_PUBLIC_KEY, signature: TEST_ED25519_SIGNATURE }]);
+        validate_signatures(&multisig_account, payload, &TEST_MSG);
+        let MultisigAccount { threshold: _, public_keys: _ } = multisig_account;
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = rooch_framework::multisig_validator)]
+    fun test_validate_signatures_below_threshold() {
+        let multisig_account = test_multisig_account(2);
+        let payload = test_payload(vector[MultisigSignature { scheme: SCHEME_ED25519, public_key: TEST_ED25519_PUBLIC_KEY, signature: TEST_ED25519_SIGNATURE }]);
+        validate_signatures(&multisig_account, payload, &TEST_MSG);
+        let MultisigAccount { threshold: _, public_keys: _ } = multisig_account;
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = rooch_framework::multisig_validator)]
+    fun test_validate_duplicated_signatures() {
+        let multisig_account = test_multisig_account(2);
+        let signature = MultisigSignature { scheme: SCHEME_ED25519, public_key: TEST_ED25519_PUBLIC_KEY, signature: TEST_ED25519_SIGNATURE };
+        validate_signatures(&multisig_account, test_payload(vector[signature, signature]), &TEST_MSG);
+        let MultisigAccount { threshold: _, public_keys: _ } = multisig_account;
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103e9, location = rooch_framework::multisig_validator)]
+    fun test_validate_signature_of_unknown_key() {
+        let multisig_account = MultisigAccount {
+            threshold: 1,
+            public_keys: new_public_keys(vector[SCHEME_SECP256K1], vector[TEST_SECP256K1_PUBLIC_KEY]),
+        };
+        let payload = test_payload(vector[MultisigSignature { scheme: SCHEME_ED25519, public_key: TEST_ED25519_PUBLIC_KEY, signature: TEST_ED25519_SIGNATURE }]);
+        validate_signatures(&multisig_account, payload, &TEST_MSG);
+        let MultisigAccount { threshold: _, public_keys: _ } = multisig_account;
+    }
+
+    #[test]
+    fun test_key_set_management() {
+        let ctx = context::new_test_context(@rooch_framework);
+        let keys = new_public_keys(vector[SCHEME_ED25519], vector[TEST_ED25519_PUBLIC_KEY]);
+        let multisig_addr = create_multisig_account(&mut ctx, keys, 1);
+        assert!(multisig_addr == multisig_address(&keys, 1), 1000);
+        assert!(is_multisig_account(&ctx, multisig_addr), 1001);
+
+        let multisig_signer = account::create_signer_for_test(multisig_addr);
+        add_public_key_entry(&mut ctx, &multisig_signer, SCHEME_SECP256K1, TEST_SECP256K1_PUBLIC_KEY);
+        update_threshold_entry(&mut ctx, &multisig_signer, 2);
+        assert!(threshold(&ctx, multisig_addr) == 2, 1002);
+        assert!(vector::length(&public_keys(&ctx, multisig_addr)) == 2, 1003);
+
+        update_threshold_entry(&mut ctx, &multisig_signer, 1);
+        remove_public_key_entry(&mut ctx, &multisig_signer, SCHEME_ED25519, TEST_ED25519_PUBLIC_KEY);
+        assert!(public_keys(&ctx, multisig_addr) == vector[MultisigPublicKey { scheme: SCHEME_SECP256K1, public_key: TEST_SECP256K1_PUBLIC_KEY }], 1004);
+        context::drop_test_context(ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001, location = rooch_framework::multisig_validator)]
+    fun test_remove_public_key_below_threshold() {
+        let ctx = context::new_test_context(@rooch_framework);
+        let keys = new_public_keys(vector[SCHEME_ED25519, SCHEME_SECP256K1], vector[TEST_ED25519_PUBLIC_KEY, TEST_SECP256K1_PUBLIC_KEY]);
+        let multisig_addr = create_multisig_account(&mut ctx, keys, 2);
+        let multisig_signer = account::create_signer_for_test(multisig_addr);
+        remove_public_key_entry(&mut ctx, &multisig_signer, SCHEME_ED25519, TEST_ED25519_PUBLIC_KEY);
+        context::drop_test_context(ctx);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the Nostr validator, the Nostr key signs the 32 bytes transaction hash as is
+/// with the BIP-340 schnorr signature, the same way as the Nostr event id is signed,
+/// and the sender is the Rooch address mapped from the Nostr public key(npub) of the signer.
+module rooch_framework::nostr_validator {
+
+    use std::vector;
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::schnorr;
+    use rooch_framework::auth_validator;
+    use rooch_framework::address_mapping;
+    use rooch_framework::multichain_address;
+
+    /// there defines auth validator id for each blockchain
+    const NOSTR_AUTH_VALIDATOR_ID: u64 = 4;
+
+    struct NostrValidator has store, drop {}
+
+    #[data_struct]
+    /// The authenticator payload of the Nostr validator, it is the bcs bytes of the struct.
+    struct NostrAuthPayload has copy, drop {
+        /// The 64 bytes BIP-340 schnorr signature
+        signature: vector<u8>,
+        /// The 32 bytes x-only public key of the Nostr key
+        public_key: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        NOSTR_AUTH_VALIDATOR_ID
+    }
+
+    /// Only validate the authenticator's signature, return the public key of the signer.
+    public fun validate_signature(authenticator_payload: vector<u8>, tx_hash: &vector<u8>): vector<u8> {
+        let payload = bcs::from_bytes<NostrAuthPayload>(authenticator_payload);
+        assert!(
+            vector::length(&payload.signature) == schnorr::signature_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            vector::length(&payload.public_key) == schnorr::public_key_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        // The transaction hash is the 32 bytes message of BIP-340, it is not hashed again before verifying
+        assert!(
+            schnorr::verify(&payload.signature, &payload.public_key, tx_hash, schnorr::prehashed()),
+            auth_validator::error_invalid_authenticator()
+        );
+        payload.public_key
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        let public_key = validate_signature(authenticator_payload, &tx_hash);
+        let multichain_addr = multichain_address::from_nostr(public_key);
+        assert!(
+            address_mapping::resolve_or_generate(ctx, multichain_addr) == context::sender(ctx),
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    #[test_only]
+    const TEST_TX_HASH: vector<u8> = x"5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0";
+    #[test_only]
+    const TEST_PUBLIC_KEY: vector<u8> = x"fa6f0af3a3074ac1e4b0faedcf492fdb80b5dd39288daad3fe8d9c05dcc5fe8a";
+    #[test_only]
+    const TEST_SIGNATURE: vector<u8> = x"97f862b0bf334d46b04104c4150cd92d143310966ae694af6f2d493dd5b024877de755469e2da8d580d5ca31230516a39bf082f2198a7a43f1b8239fa96e53f4";
+    #[test_only]
+    /// The signature of the sha256 digest of `TEST_TX_HASH`
+    const TEST_DIGEST_SIGNATURE: vector<u8> = x"df8d25571ddf179c32a5e765587d750fa3c776968e5827ccb44fada6ad8004c37fc08aea7bdc7b5f180dbc3b59a8f1300e57ad17dfcdf32a509431a20b4e88c2";
+
+    #[test]
+    fun test_validate_signature() {
+        let payload = bcs::to_bytes(&NostrAuthPayload { signature: TEST_SIGNATURE, public_key: TEST_PUBLIC_KEY });
+        let public_key = validate_signature(payload, &TEST_TX_HASH);
+        assert!(public_key == TEST_PUBLIC_KEY, 1000);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_wrong_message() {
+        let payload = bcs::to_bytes(&NostrAuthPayload { signature: TEST_SIGNATURE, public_key: TEST_PUBLIC_KEY });
+        validate_signature(payload, &x"00");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_of_hashed_message() {
+        // The transaction hash is signed as is, the signature of the hashed transaction hash is rejected
+        let payload = bcs::to_bytes(&NostrAuthPayload { signature: TEST_DIGEST_SIGNATURE, public_key: TEST_PUBLIC_KEY });
+        validate_signature(payload, &TEST_TX_HASH);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_invalid_public_key_length() {
+        let public_key = vector::singleton(0x02);
+        vector::append(&mut public_key, TEST_PUBLIC_KEY);
+        let payload = bcs::to_bytes(&NostrAuthPayload { signature: TEST_SIGNATURE, public_key });
+        validate_signature(payload, &TEST_TX_HASH);
+    }
+}
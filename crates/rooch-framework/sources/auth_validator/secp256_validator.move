@@ -0,0 +1,86 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module contains the logic shared by the secp256k1 and secp256r1 validators.
+/// The authenticator payload is `scheme flag || signature || public key`,
+/// and the account address is derived from the compressed public key as the native validator does.
+module rooch_framework::secp256_validator {
+
+    use std::vector;
+    use std::option;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::account_authentication;
+
+    /// The length of the authenticator payload with the given signature and public key lengths.
+    public fun authenticator_payload_length(signature_length: u64, public_key_length: u64): u64 {
+        1 + signature_length + public_key_length
+    }
+
+    /// The scheme flag of the authenticator payload, it is the auth validator id of the validator which signed the payload.
+    public fun get_scheme_flag_from_authenticator_payload(authenticator_payload: &vector<u8>): u8 {
+        *vector::borrow(authenticator_payload, 0)
+    }
+
+    public fun get_signature_from_authenticator_payload(authenticator_payload: &vector<u8>, signature_length: u64): vector<u8> {
+        sub_vector(authenticator_payload, 1, 1 + signature_length)
+    }
+
+    public fun get_public_key_from_authenticator_payload(
+        authenticator_payload: &vector<u8>,
+        signature_length: u64,
+        public_key_length: u64
+    ): vector<u8> {
+        sub_vector(
+            authenticator_payload,
+            1 + signature_length,
+            authenticator_payload_length(signature_length, public_key_length)
+        )
+    }
+
+    fun sub_vector(bytes: &vector<u8>, start: u64, end: u64): vector<u8> {
+        let result = vector::empty<u8>();
+        let i = start;
+        while (i < end) {
+            vector::push_back(&mut result, *vector::borrow(bytes, i));
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Get the authentication key of the public key of the given auth validator.
+    public fun public_key_to_authentication_key(auth_validator_id: u64, public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton((auth_validator_id as u8));
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    public fun public_key_to_address(auth_validator_id: u64, public_key: vector<u8>): address {
+        moveos_std::bcs::to_address(public_key_to_authentication_key(auth_validator_id, public_key))
+    }
+
+    public fun default_authentication_key(addr: address): vector<u8> {
+        moveos_std::bcs::to_bytes(&addr)
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default<ValidatorType>(ctx: &Context, addr: address): vector<u8> {
+        let auth_key_option = account_authentication::get_authentication_key<ValidatorType>(ctx, addr);
+        if (option::is_some(&auth_key_option)) {
+            option::extract(&mut auth_key_option)
+        }else {
+            default_authentication_key(addr)
+        }
+    }
+
+    /// Check the address of the signer's public key with the authentication key of the sender.
+    public fun is_sender_authentication_key<ValidatorType>(
+        ctx: &Context,
+        auth_validator_id: u64,
+        public_key: vector<u8>
+    ): bool {
+        let addr = public_key_to_address(auth_validator_id, public_key);
+        let auth_key_in_account = get_authentication_key_with_default<ValidatorType>(ctx, context::sender(ctx));
+        auth_key_in_account == moveos_std::bcs::to_bytes(&addr)
+    }
+}
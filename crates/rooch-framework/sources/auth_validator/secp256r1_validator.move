@@ -0,0 +1,190 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the Secp256r1 validator, the transaction is signed by a secp256r1(P-256) key
+/// with the ECDSA signature over the sha256 digest of the transaction hash.
+/// The payload parsing and the authentication key derivation are shared with the other secp256 validator in `secp256_validator`.
+module rooch_framework::secp256r1_validator {
+
+    use std::error;
+    use std::vector;
+    use std::option;
+    use std::signer;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::account_authentication;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_validator;
+    use rooch_framework::secp256_validator;
+
+    /// there defines auth validator id for each blockchain
+    const SECP256R1_AUTH_VALIDATOR_ID: u64 = 6;
+
+    // error code
+    const ErrorInvalidPublicKeyLength: u64 = 1;
+
+    struct Secp256r1Validator has store, drop {}
+
+    public fun auth_validator_id(): u64 {
+        SECP256R1_AUTH_VALIDATOR_ID
+    }
+
+    public entry fun rotate_authentication_key_entry(
+        ctx: &mut Context,
+        account: &signer,
+        public_key: vector<u8>
+    ) {
+        // compare newly passed public key with the compressed Secp256r1 public key length to ensure it's compatible
+        assert!(
+            vector::length(&public_key) == ecdsa_r1::public_key_length(),
+            error::invalid_argument(ErrorInvalidPublicKeyLength)
+        );
+
+        // User can rotate the authentication key arbitrarily, so we do not need to check the new public key with the account address.
+        let authentication_key = public_key_to_authentication_key(public_key);
+        let account_addr = signer::address_of(account);
+        rotate_authentication_key(ctx, account_addr, authentication_key);
+    }
+
+    fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
+        account_authentication::rotate_authentication_key<Secp256r1Validator>(ctx, account_addr, authentication_key);
+    }
+
+    public entry fun remove_authentication_key_entry(ctx: &mut Context, account: &signer) {
+        account_authentication::remove_authentication_key<Secp256r1Validator>(ctx, signer::address_of(account));
+    }
+
+    /// The authenticator payload is `scheme flag || signature || public key`.
+    public fun authenticator_payload_length(): u64 {
+        secp256_validator::authenticator_payload_length(ecdsa_r1::signature_length(), ecdsa_r1::public_key_length())
+    }
+
+    public fun get_public_key_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        secp256_validator::get_public_key_from_authenticator_payload(
+            authenticator_payload,
+            ecdsa_r1::signature_length(),
+            ecdsa_r1::public_key_length()
+        )
+    }
+
+    public fun get_signature_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        secp256_validator::get_signature_from_authenticator_payload(authenticator_payload, ecdsa_r1::signature_length())
+    }
+
+    public fun public_key_to_address(public_key: vector<u8>): address {
+        secp256_validator::public_key_to_address(auth_validator_id(), public_key)
+    }
+
+    /// Get the authentication key of the given public key.
+    public fun public_key_to_authentication_key(public_key: vector<u8>): vector<u8> {
+        secp256_validator::public_key_to_authentication_key(auth_validator_id(), public_key)
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default(ctx: &Context, addr: address): vector<u8> {
+        secp256_validator::get_authentication_key_with_default<Secp256r1Validator>(ctx, addr)
+    }
+
+    /// Only validate the authenticator's signature.
+    public fun validate_signature(authenticator_payload: &vector<u8>, tx_hash: &vector<u8>) {
+        assert!(
+            vector::length(authenticator_payload) == authenticator_payload_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            secp256_validator::get_scheme_flag_from_authenticator_payload(authenticator_payload) == (auth_validator_id() as u8),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            ecdsa_r1::verify(
+                &get_signature_from_authenticator_payload(authenticator_payload),
+                &get_public_key_from_authenticator_payload(authenticator_payload),
+                tx_hash,
+                ecdsa_r1::sha256()
+            ),
+            auth_validator::error_invalid_authenticator()
+        );
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        validate_signature(&authenticator_payload, &tx_hash);
+
+        assert!(
+            secp256_validator::is_sender_authentication_key<Secp256r1Validator>(
+                ctx,
+                auth_validator_id(),
+                get_public_key_from_authenticator_payload(&authenticator_payload)
+            ),
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        ctx: &mut Context,
+    ) {
+        let account_addr = context::sender(ctx);
+        let auth_key_option = account_authentication::get_authentication_key<Secp256r1Validator>(ctx, account_addr);
+        // If the account does not have an authentication key, set the account address as the authentication key after the first transaction is executed.
+        if (option::is_none(&auth_key_option)) {
+            let authentication_key = secp256_validator::default_authentication_key(account_addr);
+            rotate_authentication_key(ctx, account_addr, authentication_key);
+        }
+    }
+
+    #[test_only]
+    const TEST_TX_HASH: vector<u8> = x"5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0";
+    #[test_only]
+    const TEST_PUBLIC_KEY: vector<u8> = x"0206c2209e28f8dc7671d11862f25848e5d8b7eaee9408b498494af7caca8240b1";
+    #[test_only]
+    const TEST_SIGNATURE: vector<u8> = x"2ceb31f1bd4bc3ac791a1447341222fb26a8bc803792588825c7424548643d6062adb1b3cb2e64bc7f3f3db590eb1d9755fefd28d09d312673297fe36422cd21";
+
+    #[test_only]
+    fun test_authenticator_payload(): vector<u8> {
+        let payload = vector::singleton((auth_validator_id() as u8));
+        vector::append(&mut payload, TEST_SIGNATURE);
+        vector::append(&mut payload, TEST_PUBLIC_KEY);
+        payload
+    }
+
+    // this test ensures that the secp256r1 public_key_to_address function is compatible with the one in the rust code
+    #[test]
+    fun test_public_key_to_address() {
+        let addr = public_key_to_address(TEST_PUBLIC_KEY);
+        assert!(addr == @0x351096c468f9453cd82e309e7d5d4746f3ccf86d5f37d7acd61189003d88d733, 1000)
+    }
+
+    #[test]
+    fun test_validate_signature() {
+        let payload = test_authenticator_payload();
+        validate_signature(&payload, &TEST_TX_HASH);
+        assert!(get_public_key_from_authenticator_payload(&payload) == TEST_PUBLIC_KEY, 1000);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_wrong_message() {
+        let payload = test_authenticator_payload();
+        validate_signature(&payload, &x"00");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_invalid_payload_length() {
+        let payload = test_authenticator_payload();
+        vector::pop_back(&mut payload);
+        validate_signature(&payload, &TEST_TX_HASH);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_invalid_scheme_flag() {
+        // The payload of the secp256k1 validator(id 5) is submitted to the secp256r1 validator
+        let payload = test_authenticator_payload();
+        *vector::borrow_mut(&mut payload, 0) = 5;
+        validate_signature(&payload, &TEST_TX_HASH);
+    }
+}
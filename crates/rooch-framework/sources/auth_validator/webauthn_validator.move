@@ -0,0 +1,195 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn validator, the transaction is signed by a passkey(secp256r1 credential) of the device,
+/// the challenge of the WebAuthn assertion is the transaction hash,
+/// and the account address is derived from the compressed public key of the credential.
+module rooch_framework::webauthn_validator {
+
+    use std::error;
+    use std::vector;
+    use std::option;
+    use std::signer;
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::account_authentication;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::webauthn;
+    use rooch_framework::auth_validator;
+
+    /// there defines auth validator id for each blockchain
+    const WEBAUTHN_AUTH_VALIDATOR_ID: u64 = 7;
+
+    // error code
+    const ErrorInvalidPublicKeyLength: u64 = 1;
+
+    struct WebauthnValidator has store, drop {}
+
+    #[data_struct]
+    /// The authenticator payload of the WebAuthn validator, it is the bcs bytes of the struct.
+    struct WebauthnAuthPayload has copy, drop {
+        /// The 64 bytes secp256r1 signature in form (r, s)
+        signature: vector<u8>,
+        /// The 33 bytes compressed secp256r1 public key of the credential
+        public_key: vector<u8>,
+        /// The authenticator data of the assertion
+        authenticator_data: vector<u8>,
+        /// The client data JSON of the assertion, the challenge is the base64url encoded transaction hash
+        client_data_json: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEBAUTHN_AUTH_VALIDATOR_ID
+    }
+
+    public entry fun rotate_authentication_key_entry(
+        ctx: &mut Context,
+        account: &signer,
+        public_key: vector<u8>
+    ) {
+        // compare newly passed public key with the compressed secp256r1 public key length to ensure it's compatible
+        assert!(
+            vector::length(&public_key) == ecdsa_r1::public_key_length(),
+            error::invalid_argument(ErrorInvalidPublicKeyLength)
+        );
+
+        // User can rotate the authentication key arbitrarily, so we do not need to check the new public key with the account address.
+        let authentication_key = public_key_to_authentication_key(public_key);
+        let account_addr = signer::address_of(account);
+        rotate_authentication_key(ctx, account_addr, authentication_key);
+    }
+
+    fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
+        account_authentication::rotate_authentication_key<WebauthnValidator>(ctx, account_addr, authentication_key);
+    }
+
+    public entry fun remove_authentication_key_entry(ctx: &mut Context, account: &signer) {
+        account_authentication::remove_authentication_key<WebauthnValidator>(ctx, signer::address_of(account));
+    }
+
+    public fun public_key_to_address(public_key: vector<u8>): address {
+        moveos_std::bcs::to_address(public_key_to_authentication_key(public_key))
+    }
+
+    /// Get the authentication key of the given public key.
+    public fun public_key_to_authentication_key(public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton((auth_validator_id() as u8));
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default(ctx: &Context, addr: address): vector<u8> {
+        let auth_key_option = account_authentication::get_authentication_key<WebauthnValidator>(ctx, addr);
+        if (option::is_some(&auth_key_option)) {
+            option::extract(&mut auth_key_option)
+        }else {
+            default_authentication_key(addr)
+        }
+    }
+
+    public fun default_authentication_key(addr: address): vector<u8> {
+        moveos_std::bcs::to_bytes(&addr)
+    }
+
+    /// Only validate the authenticator's signature, return the public key of the signer.
+    public fun validate_signature(authenticator_payload: vector<u8>, tx_hash: &vector<u8>): vector<u8> {
+        let payload = bcs::from_bytes<WebauthnAuthPayload>(authenticator_payload);
+        assert!(
+            vector::length(&payload.signature) == ecdsa_r1::signature_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            vector::length(&payload.public_key) == ecdsa_r1::public_key_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            webauthn::verify(
+                &payload.signature,
+                &payload.public_key,
+                &payload.authenticator_data,
+                &payload.client_data_json,
+                tx_hash
+            ),
+            auth_validator::error_invalid_authenticator()
+        );
+        payload.public_key
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        let public_key = validate_signature(authenticator_payload, &tx_hash);
+
+        let auth_key_from_authenticator_payload = bcs::to_bytes(&public_key_to_address(public_key));
+        let auth_key_in_account = get_authentication_key_with_default(ctx, context::sender(ctx));
+        assert!(
+            auth_key_in_account == auth_key_from_authenticator_payload,
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        ctx: &mut Context,
+    ) {
+        let account_addr = context::sender(ctx);
+        let auth_key_option = account_authentication::get_authentication_key<WebauthnValidator>(ctx, account_addr);
+        // If the account does not have an authentication key, set the account address as the authentication key after the first transaction is executed.
+        if (option::is_none(&auth_key_option)) {
+            let authentication_key = default_authentication_key(account_addr);
+            rotate_authentication_key(ctx, account_addr, authentication_key);
+        }
+    }
+
+    #[test_only]
+    const TEST_TX_HASH: vector<u8> = x"5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0";
+    #[test_only]
+    const TEST_PUBLIC_KEY: vector<u8> = x"023793bb08274bcf2cdadaa4669d6cfc3b69bdbe34399b325f54153be6b4c4a8d9";
+    #[test_only]
+    const TEST_SIGNATURE: vector<u8> = x"e7dc3ac69f702a8e695108eeb7d0e62ec43db7cbc51357446bf897ba934682ba74a5122f17ffa06de6961daa31e3021a60950867caf7f203058701e8c39f2739";
+    #[test_only]
+    const TEST_AUTHENTICATOR_DATA: vector<u8> = x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001";
+    #[test_only]
+    const TEST_CLIENT_DATA_JSON: vector<u8> = b"{\"type\":\"webauthn.get\",\"challenge\":\"Xz0rjB56n0C20sjho_W32eDCpPa40OLEpviw0uTGqPA\",\"origin\":\"https://rooch.network\",\"crossOrigin\":false}";
+
+    #[test_only]
+    fun test_payload(signature: vector<u8>, public_key: vector<u8>): vector<u8> {
+        bcs::to_bytes(&WebauthnAuthPayload {
+            signature,
+            public_key,
+            authenticator_data: TEST_AUTHENTICATOR_DATA,
+            client_data_json: TEST_CLIENT_DATA_JSON,
+        })
+    }
+
+    // this test ensures that the webauthn public_key_to_address function is compatible with the one in the rust code
+    #[test]
+    fun test_public_key_to_address() {
+        let addr = public_key_to_address(TEST_PUBLIC_KEY);
+        assert!(addr == @0x3852722e066789c3bea8d6eb11d9d26a697696daf2f48379fbed12b7217b9724, 1000)
+    }
+
+    #[test]
+    fun test_validate_signature() {
+        let public_key = validate_signature(test_payload(TEST_SIGNATURE, TEST_PUBLIC_KEY), &TEST_TX_HASH);
+        assert!(public_key == TEST_PUBLIC_KEY, 1000);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_wrong_challenge() {
+        validate_signature(test_payload(TEST_SIGNATURE, TEST_PUBLIC_KEY), &x"00");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x103ea, location = Self)]
+    fun test_validate_signature_invalid_public_key_length() {
+        let public_key = TEST_PUBLIC_KEY;
+        vector::pop_back(&mut public_key);
+        validate_signature(test_payload(TEST_SIGNATURE, public_key), &TEST_TX_HASH);
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// Hash function name that are valid for verify.
+    const SHA256: u8 = 1;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    public fun sha256(): u8 {
+        SHA256
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa over the secp256r1(P-256) curve. This is an non-recoverable signature without recovery id.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against.
+    /// @param hash: The hash function used to hash the message when signing.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>,
+        hash: u8
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"00010203";
+        let pubkey = x"03cd1cf37b5aa1135a5b721610336ac9ee581c5c3a8596f2e7f84137027da58c70";
+        let sig = x"b82f92f143147edeeb7ff56f064238f563caf3b180ba8f840124b7fdfae983bb6817e92f9ca11715f00fe25d140bb4f1050f834c73bce8ab253f7e511f36d528";
+        let result = verify(&sig, &pubkey, &msg, SHA256);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"00010204";
+        let pubkey = x"03cd1cf37b5aa1135a5b721610336ac9ee581c5c3a8596f2e7f84137027da58c70";
+        let sig = x"b82f92f143147edeeb7ff56f064238f563caf3b180ba8f840124b7fdfae983bb6817e92f9ca11715f00fe25d140bb4f1050f834c73bce8ab253f7e511f36d528";
+        let result = verify(&sig, &pubkey, &msg, SHA256);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 65537)] // std::error::invalid_argument(ErrorInvalidSignature)
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"00010203";
+        let pubkey = x"03cd1cf37b5aa1135a5b721610336ac9ee581c5c3a8596f2e7f84137027da58c70";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg, SHA256);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 65538)] // std::error::invalid_argument(ErrorInvalidPubKey)
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"00010203";
+        let pubkey = x"";
+        let sig = x"b82f92f143147edeeb7ff56f064238f563caf3b180ba8f840124b7fdfae983bb6817e92f9ca11715f00fe25d140bb4f1050f834c73bce8ab253f7e511f36d528";
+        verify(&sig, &pubkey, &msg, SHA256);
+    }
+}
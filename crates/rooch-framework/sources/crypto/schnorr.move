@@ -8,6 +8,8 @@ module rooch_framework::schnorr {
 
     /// Hash function name that are valid for verify.
     const SHA256: u8 = 1;
+    /// The message is a 32 bytes digest, it is verified as is without hashing.
+    const PREHASHED: u8 = 2;
 
     /// Error if the signature is invalid.
     const ErrorInvalidSignature: u64 = 1;
@@ -28,10 +30,14 @@ module rooch_framework::schnorr {
         SHA256
     }
 
+    public fun prehashed(): u8 {
+        PREHASHED
+    }
+
     /// @param signature: A 64-bytes signature that is signed using Schnorr over Secpk256k1 key pairs.
     /// @param public_key: A 32-bytes public key that is used to sign messages.
     /// @param msg: The message that the signature is signed against.
-    /// @param hash: The hash function used to hash the message when signing.
+    /// @param hash: The hash function used to hash the message when signing, or `PREHASHED` if the message is the 32 bytes digest signed as is.
     ///
     /// If the signature is valid to the pubkey and hashed message, return true. Else false.
     native public fun verify(
@@ -80,4 +86,17 @@ module rooch_framework::schnorr {
         let result = verify(&sig, &valid_pk, &msg, SHA256);
         assert!(result, 0)
     }
+
+    #[test]
+    public fun test_schnorr_prehashed() {
+        // The test vector 1 of BIP-340
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pk = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+
+        assert!(verify(&sig, &pk, &msg, PREHASHED), 0);
+        assert!(!verify(&sig, &pk, &msg, SHA256), 1);
+        // The prehashed message must be 32 bytes
+        assert!(!verify(&sig, &pk, &x"243f6a88", PREHASHED), 2);
+    }
 }
@@ -0,0 +1,88 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn(passkey) assertion verification with the secp256r1(ES256) public key.
+module rooch_framework::webauthn {
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// @param signature: A 64-bytes secp256r1 signature in form (r, s), the DER signature of the authenticator should be converted to this form.
+    /// @param public_key: A 33-bytes compressed secp256r1 public key of the credential.
+    /// @param authenticator_data: The authenticator data of the assertion.
+    /// @param client_data_json: The client data JSON of the assertion.
+    /// @param challenge: The expected challenge, it is base64url encoded in the client data JSON.
+    ///
+    /// Return true if the authenticator data is at least 37 bytes with the User Present flag set,
+    /// the client data is a `webauthn.get` assertion of the challenge, and the signature is valid to the pubkey and `authenticator_data || sha256(client_data_json)`. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        authenticator_data: &vector<u8>,
+        client_data_json: &vector<u8>,
+        challenge: &vector<u8>
+    ): bool;
+
+    #[test_only]
+    const TEST_CHALLENGE: vector<u8> = x"5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0";
+    #[test_only]
+    const TEST_PUBLIC_KEY: vector<u8> = x"023793bb08274bcf2cdadaa4669d6cfc3b69bdbe34399b325f54153be6b4c4a8d9";
+    #[test_only]
+    const TEST_SIGNATURE: vector<u8> = x"e7dc3ac69f702a8e695108eeb7d0e62ec43db7cbc51357446bf897ba934682ba74a5122f17ffa06de6961daa31e3021a60950867caf7f203058701e8c39f2739";
+    #[test_only]
+    const TEST_AUTHENTICATOR_DATA: vector<u8> = x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001";
+    #[test_only]
+    /// {"type":"webauthn.get","challenge":"Xz0rjB56n0C20sjho_W32eDCpPa40OLEpviw0uTGqPA","origin":"https://rooch.network","crossOrigin":false}
+    const TEST_CLIENT_DATA_JSON: vector<u8> = b"{\"type\":\"webauthn.get\",\"challenge\":\"Xz0rjB56n0C20sjho_W32eDCpPa40OLEpviw0uTGqPA\",\"origin\":\"https://rooch.network\",\"crossOrigin\":false}";
+
+    #[test]
+    fun test_verify_success() {
+        let result = verify(&TEST_SIGNATURE, &TEST_PUBLIC_KEY, &TEST_AUTHENTICATOR_DATA, &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_challenge() {
+        let result = verify(&TEST_SIGNATURE, &TEST_PUBLIC_KEY, &TEST_AUTHENTICATOR_DATA, &TEST_CLIENT_DATA_JSON, &x"00");
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_authenticator_data() {
+        let result = verify(&TEST_SIGNATURE, &TEST_PUBLIC_KEY, &x"00", &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_short_authenticator_data() {
+        let authenticator_data = TEST_AUTHENTICATOR_DATA;
+        std::vector::pop_back(&mut authenticator_data);
+        let result = verify(&TEST_SIGNATURE, &TEST_PUBLIC_KEY, &authenticator_data, &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_user_not_present() {
+        let authenticator_data = TEST_AUTHENTICATOR_DATA;
+        // Clear the User Present flag, bit 0 of the flags byte
+        let flags = std::vector::borrow_mut(&mut authenticator_data, 32);
+        *flags = *flags - 1;
+        let result = verify(&TEST_SIGNATURE, &TEST_PUBLIC_KEY, &authenticator_data, &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 65537)] // std::error::invalid_argument(ErrorInvalidSignature)
+    fun test_verify_fails_invalid_sig() {
+        verify(&x"", &TEST_PUBLIC_KEY, &TEST_AUTHENTICATOR_DATA, &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 65538)] // std::error::invalid_argument(ErrorInvalidPubKey)
+    fun test_verify_fails_invalid_pubkey() {
+        verify(&TEST_SIGNATURE, &x"", &TEST_AUTHENTICATOR_DATA, &TEST_CLIENT_DATA_JSON, &TEST_CHALLENGE);
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module initializes the MoveOS gas schedule in the genesis,
+/// and guards the gas schedule update with the rooch association.
+module rooch_framework::gas_schedule {
+
+    use std::string::String;
+    use std::vector;
+    use moveos_std::signer;
+    use moveos_std::context::Context;
+    use moveos_std::gas_schedule as moveos_gas_schedule;
+    use rooch_framework::core_addresses;
+
+    friend rooch_framework::genesis;
+
+    /// The default upper bound of the max gas amount of a transaction,
+    /// it is greater than the default max gas amount of the client, so the heavy transactions can raise their budget.
+    const DEFAULT_MAX_GAS_AMOUNT: u64 = 10000000000;
+
+    /// The struct is used to get the framework signer, the gas schedule object is owned by the framework.
+    struct GasScheduleGovernance has drop {}
+
+    /// Initialize the gas schedule with empty entries, so MoveOS uses the builtin gas costs.
+    public(friend) fun genesis_init(ctx: &mut Context, genesis_account: &signer) {
+        moveos_gas_schedule::initialize(ctx, genesis_account, DEFAULT_MAX_GAS_AMOUNT, vector::empty());
+    }
+
+    /// Update the gas schedule, only the rooch association can update it.
+    /// The `keys` and `vals` replace all the entries of the current gas schedule.
+    public entry fun update_gas_schedule(
+        ctx: &mut Context,
+        account: &signer,
+        schedule_version: u64,
+        max_gas_amount: u64,
+        keys: vector<String>,
+        vals: vector<u64>,
+    ) {
+        core_addresses::assert_rooch_association(account);
+        let entries = moveos_gas_schedule::new_gas_entries(keys, vals);
+        let framework_signer = signer::module_signer<GasScheduleGovernance>();
+        moveos_gas_schedule::update(ctx, &framework_signer, schedule_version, max_gas_amount, entries);
+    }
+
+    public fun max_gas_amount(ctx: &Context): u64 {
+        moveos_gas_schedule::max_gas_amount(moveos_gas_schedule::gas_schedule(ctx))
+    }
+}
@@ -14,6 +14,7 @@ module rooch_framework::genesis {
     use rooch_framework::account_coin_store;
     use rooch_framework::gas_coin;
     use rooch_framework::transaction_fee;
+    use rooch_framework::gas_schedule;
     use rooch_framework::timestamp;
     use rooch_framework::address_mapping;
     use rooch_framework::ethereum_light_client;
@@ -43,6 +44,7 @@ module rooch_framework::genesis {
         account_coin_store::genesis_init(ctx, genesis_account);
         gas_coin::genesis_init(ctx, genesis_account);
         transaction_fee::genesis_init(ctx, genesis_account);
+        gas_schedule::genesis_init(ctx, genesis_account);
         timestamp::genesis_init(ctx, genesis_account, genesis_context.timestamp);
         address_mapping::genesis_init(ctx, genesis_account);
         ethereum_light_client::genesis_init(ctx, genesis_account);
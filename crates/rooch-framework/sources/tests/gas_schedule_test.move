@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the gas schedule governance
+module rooch_framework::gas_schedule_test{
+
+    use std::string;
+    use std::vector;
+    use rooch_framework::account;
+    use rooch_framework::gas_schedule;
+    use moveos_std::gas_schedule as moveos_gas_schedule;
+
+    #[test]
+    fun test_gas_schedule_init(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        assert!(moveos_gas_schedule::exists_gas_schedule(&genesis_ctx), 1000);
+        let schedule = moveos_gas_schedule::gas_schedule(&genesis_ctx);
+        assert!(moveos_gas_schedule::schedule_version(schedule) == 0, 1001);
+        assert!(vector::is_empty(moveos_gas_schedule::entries(schedule)), 1002);
+        assert!(gas_schedule::max_gas_amount(&genesis_ctx) == 10000000000, 1003);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_update_gas_schedule(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let association = account::create_signer_for_test(@rooch_association);
+        let keys = vector[string::utf8(b"instruction_tier.0")];
+        let vals = vector[2];
+        gas_schedule::update_gas_schedule(&mut genesis_ctx, &association, 1, 200000000, keys, vals);
+        let schedule = moveos_gas_schedule::gas_schedule(&genesis_ctx);
+        assert!(moveos_gas_schedule::schedule_version(schedule) == 1, 1000);
+        assert!(vector::length(moveos_gas_schedule::entries(schedule)) == 1, 1001);
+        assert!(gas_schedule::max_gas_amount(&genesis_ctx) == 200000000, 1002);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(user = @0x42)]
+    #[expected_failure(abort_code = 327682, location = rooch_framework::core_addresses)]
+    fun test_update_gas_schedule_by_non_association(user: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let user_signer = account::create_signer_for_test(user);
+        gas_schedule::update_gas_schedule(&mut genesis_ctx, &user_signer, 1, 200000000, vector[], vector[]);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65538, location = moveos_std::gas_schedule)]
+    fun test_update_gas_schedule_with_stale_version(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let association = account::create_signer_for_test(@rooch_association);
+        gas_schedule::update_gas_schedule(&mut genesis_ctx, &association, 0, 200000000, vector[], vector[]);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
@@ -1,6 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+/// This module keeps the transaction fee pool and the gas price of Rooch.
+/// The gas price is a base fee per gas unit, it is adjusted at the end of every fee window
+/// according to the gas used by the transactions in the window, like the EIP-1559 base fee.
 module rooch_framework::transaction_fee {
 
     use moveos_std::context::{Self, Context};
@@ -12,22 +15,109 @@ module rooch_framework::transaction_fee {
     friend rooch_framework::genesis;
     friend rooch_framework::transaction_validator;
 
+    const MAX_U64: u64 = 18446744073709551615;
+
+    /// The initial and the minimum base fee per gas unit
+    const MIN_BASE_FEE: u64 = 1;
+    /// How many transactions in a fee window
+    const FEE_WINDOW_SIZE: u64 = 100;
+    /// The target gas used of a fee window, the base fee increases if the window gas used is above the target,
+    /// and decreases if the window gas used is below the target.
+    const TARGET_WINDOW_GAS_USED: u64 = 100_000_000;
+    /// The base fee changes at most 1/8 of the current base fee in a fee window
+    const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
     struct TransactionFeePool has key {
         fee: Object<CoinStore<GasCoin>>,
     }
 
+    /// A object holding the current base fee and the gas usage of the current fee window
+    struct GasPrice has key {
+        base_fee: u64,
+        window_gas_used: u64,
+        window_tx_count: u64,
+    }
+
     public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer)  {
         let fee_store = coin_store::create_coin_store<GasCoin>(ctx);
         let obj = context::new_named_object(ctx, TransactionFeePool{
             fee: fee_store,
         });
         object::transfer_extend(obj, @rooch_framework);
+        let gas_price_obj = context::new_named_object(ctx, GasPrice{
+            base_fee: MIN_BASE_FEE,
+            window_gas_used: 0,
+            window_tx_count: 0,
+        });
+        object::transfer_extend(gas_price_obj, @rooch_framework);
     }
 
-    /// Returns the gas factor of gas.
-    public fun get_gas_factor(_ctx: &Context): u64 {
-        //TODO we should provide a algorithm to cordanate the gas factor based on the network throughput
-        return 1
+    /// Returns the gas factor of gas, it is the current base fee per gas unit.
+    public fun get_gas_factor(ctx: &Context): u64 {
+        base_fee(gas_price(ctx))
+    }
+
+    public fun gas_price(ctx: &Context): &GasPrice {
+        let object_id = object::named_object_id<GasPrice>();
+        let obj = context::borrow_object<GasPrice>(ctx, object_id);
+        object::borrow(obj)
+    }
+
+    public fun base_fee(self: &GasPrice): u64 {
+        self.base_fee
+    }
+
+    public fun window_gas_used(self: &GasPrice): u64 {
+        self.window_gas_used
+    }
+
+    public fun window_tx_count(self: &GasPrice): u64 {
+        self.window_tx_count
+    }
+
+    /// Record the gas used of a transaction, and adjust the base fee when the fee window is full.
+    public(friend) fun record_gas_used(ctx: &mut Context, gas_used: u64) {
+        let object_id = object::named_object_id<GasPrice>();
+        let obj = context::borrow_mut_object_extend<GasPrice>(ctx, object_id);
+        let gas_price = object::borrow_mut(obj);
+        gas_price.window_gas_used = gas_price.window_gas_used + gas_used;
+        gas_price.window_tx_count = gas_price.window_tx_count + 1;
+        if (gas_price.window_tx_count >= FEE_WINDOW_SIZE) {
+            gas_price.base_fee = next_base_fee(gas_price.base_fee, gas_price.window_gas_used);
+            gas_price.window_gas_used = 0;
+            gas_price.window_tx_count = 0;
+        };
+    }
+
+    /// Calculate the base fee of the next fee window
+    fun next_base_fee(base_fee: u64, window_gas_used: u64): u64 {
+        let base_fee = (base_fee as u128);
+        let gas_used = (window_gas_used as u128);
+        let target = (TARGET_WINDOW_GAS_USED as u128);
+        let denominator = (BASE_FEE_MAX_CHANGE_DENOMINATOR as u128);
+        let next = if (gas_used > target) {
+            let excess = gas_used - target;
+            if (excess > target) {
+                excess = target;
+            };
+            let delta = base_fee * excess / target / denominator;
+            // The base fee should increase at least 1 if the window is congested
+            if (delta == 0) {
+                delta = 1;
+            };
+            base_fee + delta
+        } else {
+            let delta = base_fee * (target - gas_used) / target / denominator;
+            base_fee - delta
+        };
+        let max_base_fee = (MAX_U64 as u128);
+        if (next > max_base_fee) {
+            MAX_U64
+        } else if (next < (MIN_BASE_FEE as u128)) {
+            MIN_BASE_FEE
+        } else {
+            (next as u64)
+        }
     }
 
     public fun calculate_gas(ctx: &Context, gas_amount: u64): u256{
@@ -40,4 +130,18 @@ module rooch_framework::transaction_fee {
         let pool = object::borrow_mut(pool_object);
         coin_store::deposit<GasCoin>(&mut pool.fee, gas_coin);
     }
+
+    #[test]
+    fun test_next_base_fee() {
+        // keep the base fee if the gas used is on the target
+        assert!(next_base_fee(1000, TARGET_WINDOW_GAS_USED) == 1000, 1);
+        // increase 1/8 at most if the window is full
+        assert!(next_base_fee(1000, TARGET_WINDOW_GAS_USED * 2) == 1125, 2);
+        assert!(next_base_fee(1000, TARGET_WINDOW_GAS_USED * 10) == 1125, 3);
+        // increase at least 1
+        assert!(next_base_fee(MIN_BASE_FEE, TARGET_WINDOW_GAS_USED + 1) == MIN_BASE_FEE + 1, 4);
+        // decrease 1/8 at most if the window is empty
+        assert!(next_base_fee(1000, 0) == 875, 5);
+        assert!(next_base_fee(MIN_BASE_FEE, 0) == MIN_BASE_FEE, 6);
+    }
 }
@@ -167,5 +167,6 @@ module rooch_framework::transaction_validator {
         let gas = transaction_fee::calculate_gas(ctx, gas_used);
         let gas_coin = gas_coin::deduct_gas(ctx, gas_payment_account, gas);
         transaction_fee::deposit_fee(ctx, gas_coin);
+        transaction_fee::record_gas_used(ctx, gas_used);
     }
 }
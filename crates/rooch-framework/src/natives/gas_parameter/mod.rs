@@ -5,6 +5,7 @@ mod bcd;
 mod decoding;
 mod ecdsa_k1;
 mod ecdsa_k1_recoverable;
+mod ecdsa_r1;
 mod ed25519;
 mod encoding;
 mod events;
@@ -23,3 +24,4 @@ mod signer;
 mod table_extension;
 mod test_helper;
 mod type_info;
+mod webauthn;
@@ -21,6 +21,8 @@ pub struct GasParameters {
     ed25519: rooch_framework::crypto::ed25519::GasParameters,
     ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters,
     ecdsa_k1_recoverable: rooch_framework::crypto::ecdsa_k1_recoverable::GasParameters,
+    ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters,
+    webauthn: rooch_framework::crypto::webauthn::GasParameters,
     schnorr: rooch_framework::crypto::schnorr::GasParameters,
     encoding: rooch_framework::crypto::encoding::GasParameters,
     decoding: rooch_framework::crypto::decoding::GasParameters,
@@ -31,19 +33,19 @@ pub struct GasParameters {
 impl FromOnChainGasSchedule for GasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
-            moveos_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            account: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ecdsa_k1_recoverable: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            schnorr: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            moveos_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            account: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ecdsa_k1_recoverable: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ecdsa_r1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            webauthn: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            schnorr: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
         })
     }
 }
@@ -57,6 +59,8 @@ impl InitialGasSchedule for GasParameters {
             ed25519: InitialGasSchedule::initial(),
             ecdsa_k1: InitialGasSchedule::initial(),
             ecdsa_k1_recoverable: InitialGasSchedule::initial(),
+            ecdsa_r1: InitialGasSchedule::initial(),
+            webauthn: InitialGasSchedule::initial(),
             schnorr: InitialGasSchedule::initial(),
             encoding: InitialGasSchedule::initial(),
             decoding: InitialGasSchedule::initial(),
@@ -69,19 +73,18 @@ impl InitialGasSchedule for GasParameters {
 impl FromOnChainGasSchedule for MoveOSGasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
-            move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_nursery: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            table_extension: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            type_info: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            rlp: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcd: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            events: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            test_helper: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            signer: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_module: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            object: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            json: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            move_nursery: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            table_extension: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            type_info: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            rlp: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            bcd: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            events: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            test_helper: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            signer: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            move_module: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            object: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            json: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
         })
     }
 }
@@ -105,6 +108,62 @@ impl InitialGasSchedule for MoveOSGasParameters {
     }
 }
 
+fn from_on_chain_gas_schedule_or<T: FromOnChainGasSchedule>(
+    gas_schedule: &BTreeMap<String, u64>,
+    default: T,
+) -> T {
+    T::from_on_chain_gas_schedule(gas_schedule).unwrap_or(default)
+}
+
+impl GasParameters {
+    /// Build the native gas parameters from the on-chain gas schedule.
+    /// The gas parameters of a native module are taken from `default` if any of its keys is missing in the gas schedule,
+    /// so a gas schedule written before a native module is added does not break the node.
+    pub fn from_on_chain_gas_schedule_with_default(
+        gas_schedule: &BTreeMap<String, u64>,
+        default: Self,
+    ) -> Self {
+        let moveos_stdlib = default.moveos_stdlib;
+        Self {
+            moveos_stdlib: MoveOSGasParameters {
+                move_stdlib: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.move_stdlib),
+                move_nursery: from_on_chain_gas_schedule_or(
+                    gas_schedule,
+                    moveos_stdlib.move_nursery,
+                ),
+                table_extension: from_on_chain_gas_schedule_or(
+                    gas_schedule,
+                    moveos_stdlib.table_extension,
+                ),
+                type_info: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.type_info),
+                rlp: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.rlp),
+                bcd: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.bcd),
+                events: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.events),
+                test_helper: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.test_helper),
+                signer: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.signer),
+                move_module: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.move_module),
+                object: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.object),
+                json: from_on_chain_gas_schedule_or(gas_schedule, moveos_stdlib.json),
+            },
+            account: from_on_chain_gas_schedule_or(gas_schedule, default.account),
+            hash: from_on_chain_gas_schedule_or(gas_schedule, default.hash),
+            ed25519: from_on_chain_gas_schedule_or(gas_schedule, default.ed25519),
+            ecdsa_k1: from_on_chain_gas_schedule_or(gas_schedule, default.ecdsa_k1),
+            ecdsa_k1_recoverable: from_on_chain_gas_schedule_or(
+                gas_schedule,
+                default.ecdsa_k1_recoverable,
+            ),
+            ecdsa_r1: from_on_chain_gas_schedule_or(gas_schedule, default.ecdsa_r1),
+            webauthn: from_on_chain_gas_schedule_or(gas_schedule, default.webauthn),
+            schnorr: from_on_chain_gas_schedule_or(gas_schedule, default.schnorr),
+            encoding: from_on_chain_gas_schedule_or(gas_schedule, default.encoding),
+            decoding: from_on_chain_gas_schedule_or(gas_schedule, default.decoding),
+            bcs: from_on_chain_gas_schedule_or(gas_schedule, default.bcs),
+            ord: from_on_chain_gas_schedule_or(gas_schedule, default.ord),
+        }
+    }
+}
+
 pub fn get_global_gas_parameter() {
     let gas_parameter = GasParameters::initial();
     println!("global gas parameter {:?}", gas_parameter);
@@ -120,6 +179,8 @@ impl GasParameters {
             ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters::zeros(),
             ecdsa_k1_recoverable:
                 rooch_framework::crypto::ecdsa_k1_recoverable::GasParameters::zeros(),
+            ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters::zeros(),
+            webauthn: rooch_framework::crypto::webauthn::GasParameters::zeros(),
             schnorr: rooch_framework::crypto::schnorr::GasParameters::zeros(),
             encoding: rooch_framework::crypto::encoding::GasParameters::zeros(),
             decoding: rooch_framework::crypto::decoding::GasParameters::zeros(),
@@ -163,6 +224,14 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
         "ecdsa_k1_recoverable",
         rooch_framework::crypto::ecdsa_k1_recoverable::make_all(gas_params.ecdsa_k1_recoverable)
     );
+    add_natives!(
+        "ecdsa_r1",
+        rooch_framework::crypto::ecdsa_r1::make_all(gas_params.ecdsa_r1)
+    );
+    add_natives!(
+        "webauthn",
+        rooch_framework::crypto::webauthn::make_all(gas_params.webauthn)
+    );
     add_natives!(
         "schnorr",
         rooch_framework::crypto::schnorr::make_all(gas_params.schnorr)
@@ -4,7 +4,9 @@
 pub mod decoding;
 pub mod ecdsa_k1;
 pub mod ecdsa_k1_recoverable;
+pub mod ecdsa_r1;
 pub mod ed25519;
 pub mod encoding;
 pub mod hash;
 pub mod schnorr;
+pub mod webauthn;
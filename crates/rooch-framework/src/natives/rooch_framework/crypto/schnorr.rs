@@ -16,6 +16,7 @@ use move_vm_types::{
     pop_arg,
     values::{Value, VectorRef},
 };
+use rust_secp256k1::{schnorr::Signature, Message, XOnlyPublicKey, SECP256K1};
 use smallvec::smallvec;
 use std::collections::VecDeque;
 
@@ -24,6 +25,22 @@ pub const E_INVALID_PUBKEY: u64 = 2;
 
 pub const KECCAK256: u8 = 0;
 pub const SHA256: u8 = 1;
+/// The message is a 32 bytes digest, it is verified as is without hashing.
+pub const PREHASHED: u8 = 2;
+
+/// Verify the BIP-340 signature against the 32 bytes message, the message is not hashed before verifying.
+fn verify_prehashed(signature: &[u8], public_key: &[u8], msg: &[u8]) -> bool {
+    let (Ok(signature), Ok(public_key), Ok(msg)) = (
+        Signature::from_slice(signature),
+        XOnlyPublicKey::from_slice(public_key),
+        Message::from_digest_slice(msg),
+    ) else {
+        return false;
+    };
+    SECP256K1
+        .verify_schnorr(&signature, &msg, &public_key)
+        .is_ok()
+}
 
 pub fn native_verify(
     gas_params: &FromBytesGasParameters,
@@ -62,6 +79,7 @@ pub fn native_verify(
         SHA256 => public_key
             .verify_with_hash::<Sha256>(&msg_ref, &sign)
             .is_ok(),
+        PREHASHED => verify_prehashed(&signature_bytes_ref, &public_key_bytes_ref, &msg_ref),
         _ => false,
     };
 
@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    encoding::{Base64, Encoding},
+    hash::{HashFunction, Sha256},
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::ToFromBytes,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use serde::Deserialize;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+
+/// The type of the client data of a WebAuthn assertion
+pub const WEBAUTHN_GET_TYPE: &str = "webauthn.get";
+
+/// The authenticator data is `rpIdHash(32) || flags(1) || signCount(4) || extensions`.
+pub const MIN_AUTHENTICATOR_DATA_LENGTH: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+/// The User Present flag, bit 0 of the flags.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// The fields of the WebAuthn `clientDataJSON` that need to be checked, other fields are ignored.
+#[derive(Debug, Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    challenge: String,
+}
+
+/// Decode the base64url(without padding) encoded challenge of the client data.
+fn decode_challenge(challenge: &str) -> Option<Vec<u8>> {
+    let mut encoded = challenge.replace('-', "+").replace('_', "/");
+    while encoded.len() % 4 != 0 {
+        encoded.push('=');
+    }
+    Base64::decode(&encoded).ok()
+}
+
+/// Check the client data is a WebAuthn assertion of the given challenge.
+fn verify_client_data(client_data_json: &[u8], challenge: &[u8]) -> bool {
+    let Ok(client_data) = serde_json::from_slice::<ClientData>(client_data_json) else {
+        return false;
+    };
+    client_data.ty == WEBAUTHN_GET_TYPE
+        && decode_challenge(&client_data.challenge).as_deref() == Some(challenge)
+}
+
+/// Check the authenticator data is long enough and the user is present.
+fn verify_authenticator_data(authenticator_data: &[u8]) -> bool {
+    authenticator_data.len() >= MIN_AUTHENTICATOR_DATA_LENGTH
+        && authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & FLAG_USER_PRESENT != 0
+}
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function `webauthn::verify(signature: &vector<u8>, public_key: &vector<u8>, authenticator_data: &vector<u8>, client_data_json: &vector<u8>, challenge: &vector<u8>): bool`
+ *   gas cost: webauthn_verify_cost_base                               | base cost for function call and fixed opers
+ *              + webauthn_verify_per_byte * (authenticator_data + client_data_json) | cost depends on the length of the data
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 5);
+
+    let challenge = pop_arg!(args, VectorRef);
+    let client_data_json = pop_arg!(args, VectorRef);
+    let authenticator_data = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let challenge_ref = challenge.as_bytes_ref();
+    let client_data_json_ref = client_data_json.as_bytes_ref();
+    let authenticator_data_ref = authenticator_data.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte
+            * NumBytes::new((authenticator_data_ref.len() + client_data_json_ref.len()) as u64);
+
+    let Ok(sig) = <Secp256r1Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, moveos_types::move_std::error::invalid_argument(E_INVALID_SIGNATURE)));
+    };
+
+    let Ok(public_key) = <Secp256r1PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref) else {
+        return Ok(NativeResult::err(cost, moveos_types::move_std::error::invalid_argument(E_INVALID_PUBKEY)));
+    };
+
+    if !verify_authenticator_data(&authenticator_data_ref)
+        || !verify_client_data(&client_data_json_ref, &challenge_ref)
+    {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    // The authenticator signs `authenticator_data || sha256(client_data_json)` with the sha256 hash
+    let mut msg = authenticator_data_ref.to_vec();
+    msg.extend_from_slice(&Sha256::digest(client_data_json_ref.as_slice()).digest);
+
+    let result = public_key.verify_with_hash::<Sha256>(&msg, &sig).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("verify", make_native(gas_params.verify, native_verify))];
+
+    make_module_natives(natives)
+}
@@ -3,17 +3,25 @@
 
 use anyhow::Result;
 use move_binary_format::{errors::Location, CompiledModule};
-use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_core_types::{
+    account_address::AccountAddress,
+    errmap::{ErrorDescription, ErrorMapping},
+    identifier::Identifier,
+    language_storage::ModuleId,
+};
 use move_vm_runtime::{config::VMConfig, native_functions::NativeFunction};
-use moveos::moveos::{MoveOS, MoveOSConfig};
+use moveos::moveos::{MoveOS, MoveOSConfig, NativesBuilder};
 use moveos_stdlib_builder::Stdlib;
 use moveos_store::{config_store::ConfigDBStore, MoveOSStore};
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::gas_schedule::GasSchedule;
 use moveos_types::transaction::MoveAction;
 use once_cell::sync::Lazy;
 use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use rooch_types::chain_id::RoochChainID;
 use rooch_types::error::GenesisError;
 use rooch_types::framework::genesis::GenesisContext;
@@ -96,6 +104,24 @@ impl RoochGenesis {
         rooch_framework::natives::all_natives(self.gas_params.clone())
     }
 
+    /// The natives builder for `MoveOS`, the native gas parameters are loaded from the on-chain gas schedule,
+    /// and the genesis gas parameters are used for the native modules which are missing in the gas schedule.
+    pub fn natives_builder(&self) -> NativesBuilder {
+        let gas_params = self.gas_params.clone();
+        Box::new(move |gas_schedule: Option<&GasSchedule>| {
+            let gas_params = match gas_schedule {
+                Some(gas_schedule) => {
+                    rooch_framework::natives::GasParameters::from_on_chain_gas_schedule_with_default(
+                        &gas_schedule.to_btree_map(),
+                        gas_params.clone(),
+                    )
+                }
+                None => gas_params.clone(),
+            };
+            rooch_framework::natives::all_natives(gas_params)
+        })
+    }
+
     pub fn genesis_package_hash(&self) -> H256 {
         h256::sha3_256_of(
             bcs::to_bytes(&self.genesis_package)
@@ -254,11 +280,33 @@ pub fn rooch_framework_error_descriptions() -> &'static [u8] {
     ROOCH_FRAMEWORK_ERROR_DESCRIPTIONS
 }
 
+/// Return the error maps generated when the framework package at the address is built.
+pub fn framework_error_descriptions(address: &AccountAddress) -> Option<&'static [u8]> {
+    match *address {
+        MOVE_STD_ADDRESS => Some(move_std_error_descriptions()),
+        MOVEOS_STD_ADDRESS => Some(moveos_std_error_descriptions()),
+        ROOCH_FRAMEWORK_ADDRESS => Some(rooch_framework_error_descriptions()),
+        _ => None,
+    }
+}
+
+/// Explain the abort code raised from a framework module with the build time error maps.
+pub fn get_framework_error_description(
+    module_id: &ModuleId,
+    abort_code: u64,
+) -> Option<ErrorDescription> {
+    let data = framework_error_descriptions(module_id.address())?;
+    let error_descriptions: ErrorMapping = bcs::from_bytes(data).ok()?;
+    let (_, reason_code) = moveos_types::move_std::error::explain(abort_code);
+    error_descriptions.get_explanation(module_id, reason_code)
+}
+
 #[cfg(test)]
 mod tests {
     use moveos::moveos::MoveOS;
     use moveos_store::MoveOSStore;
     use rooch_framework::natives::all_natives;
+    use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
     use rooch_types::chain_id::RoochChainID;
 
     #[test]
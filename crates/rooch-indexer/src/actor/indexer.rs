@@ -213,16 +213,7 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
             .delete_leaf_states_by_table_handle(remove_leaf_states_by_table_handle)?;
 
         // Store table change set for state sync
-        let mut split_state_change_set = SplitStateChangeSet::default();
-        for (table_handle, table_info) in state_change_set.new_tables {
-            split_state_change_set.add_new_table(table_handle, table_info);
-        }
-        for (table_handle, table_change) in state_change_set.changes.clone() {
-            split_state_change_set.add_table_change(table_handle, table_change);
-        }
-        for table_handle in state_change_set.removed_tables {
-            split_state_change_set.add_remove_table(table_handle);
-        }
+        let split_state_change_set = SplitStateChangeSet::from(state_change_set);
 
         let mut indexed_table_change_sets = vec![];
         for (index, item) in split_state_change_set
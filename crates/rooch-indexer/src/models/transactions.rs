@@ -120,6 +120,7 @@ impl StoredTransaction {
                 payload: self.tx_order_authenticator_payload,
             },
             tx_accumulator_root: H256::from_str(self.tx_accumulator_root.as_str())?,
+            tx_timestamp: self.created_at as u64,
         };
 
         let status: KeptVMStatus = serde_json::from_str(self.status.as_str())?;
@@ -8,6 +8,7 @@ use crate::types::{
 };
 use crate::IndexerStore;
 use anyhow::Result;
+use ethers::signers::LocalWallet;
 use ethers::types::{Bytes, U256};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
@@ -80,12 +81,12 @@ fn random_rooch_transaction() -> RoochTransaction {
 }
 
 fn random_ethereum_transaction() -> EthereumTransaction {
-    let sender = RoochAddress::random();
+    let wallet = LocalWallet::new(&mut thread_rng());
     let sequence_number = U256::zero();
     let action = random_move_action();
     let action_bytes =
         Bytes::try_from(bcs::to_bytes(&action).unwrap()).expect("Convert action to bytes failed.");
-    EthereumTransaction::new_for_test(sender, sequence_number, action_bytes)
+    EthereumTransaction::new_for_test(&wallet, sequence_number, action_bytes)
 }
 
 fn random_move_action() -> MoveAction {
@@ -420,8 +421,12 @@ fn test_transaction_store() -> Result<()> {
     let random_transaction = random_typed_transaction();
 
     let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
-    let random_sequence_info =
-        TransactionSequenceInfo::new(rand::random(), tx_order_signature, H256::random());
+    let random_sequence_info = TransactionSequenceInfo::new(
+        rand::random(),
+        tx_order_signature,
+        H256::random(),
+        rand::random(),
+    );
 
     let random_execution_info = TransactionExecutionInfo::new(
         H256::random(),
@@ -475,8 +480,12 @@ fn test_event_store() -> Result<()> {
     let random_transaction = random_typed_transaction();
 
     let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
-    let random_sequence_info =
-        TransactionSequenceInfo::new(rand::random(), tx_order_signature, H256::random());
+    let random_sequence_info = TransactionSequenceInfo::new(
+        rand::random(),
+        tx_order_signature,
+        H256::random(),
+        rand::random(),
+    );
 
     let tx_context = TxContext::new_readonly_ctx(AccountAddress::random());
     let move_action = random_verified_move_action();
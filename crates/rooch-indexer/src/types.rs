@@ -100,8 +100,7 @@ impl IndexedTransaction {
             tx_order_auth_validator_id: sequence_info.tx_order_signature.auth_validator_id,
             tx_order_authenticator_payload: sequence_info.tx_order_signature.payload,
 
-            //TODO record transaction timestamp
-            created_at: 0,
+            created_at: sequence_info.tx_timestamp,
         };
         Ok(indexed_transaction)
     }
@@ -148,8 +147,7 @@ impl IndexedEvent {
             tx_order: sequence_info.tx_order,
             sender: moveos_tx.ctx.sender,
 
-            //TODO record transaction timestamp
-            created_at: 0,
+            created_at: sequence_info.tx_timestamp,
         }
     }
 }
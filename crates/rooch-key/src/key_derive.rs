@@ -4,17 +4,18 @@
 use argon2::password_hash::{PasswordHash, PasswordHasher, SaltString};
 use argon2::Argon2;
 use argon2::PasswordVerifier;
-use bip32::DerivationPath;
+use bip32::{DerivationPath, XPrv};
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use chacha20poly1305::aead::Aead;
 use chacha20poly1305::{AeadCore, ChaCha20Poly1305, KeyInit};
-use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey};
+use fastcrypto::ed25519::{Ed25519PrivateKey, ED25519_PRIVATE_KEY_LENGTH};
 use fastcrypto::encoding::{Base64, Encoding};
-use fastcrypto::traits::{KeyPair, ToFromBytes};
+use fastcrypto::traits::ToFromBytes;
 use rand::rngs::OsRng;
 use rooch_types::address::RoochAddress;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::RoochError;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use rooch_types::key_struct::{EncryptionData, GenerateNewKeyPair, GeneratedKeyPair};
 use rooch_types::multichain_id::RoochMultiChainID;
 use slip10_ed25519::derive_ed25519_private_key;
@@ -119,24 +120,54 @@ pub fn hash_password(nonce: &[u8], password: Option<String>) -> Result<String, R
     Ok(password_hash)
 }
 
+/// Derive the private key of the given key scheme from the seed, the returned bytes are the private key stored in the keystore.
+/// Ed25519 keys are derived with SLIP-0010 and stored as raw bytes for compatibility with the existing keystores,
+/// Secp256k1 and Secp256r1 keys are derived with BIP-32 and stored as `flag || privkey`.
 pub fn derive_private_key_from_path(
     seed: &[u8],
     derivation_path: Option<DerivationPath>,
+    key_scheme: BuiltinAuthValidator,
 ) -> Result<Vec<u8>, RoochError> {
-    let path = validate_derivation_path(derivation_path)?;
-    let indexes = path.iter().map(|i| i.into()).collect::<Vec<_>>();
-    let derived = derive_ed25519_private_key(seed, &indexes);
-    let sk = Ed25519PrivateKey::from_bytes(&derived)
-        .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?;
-    Ok(sk.as_bytes().to_vec())
+    let path = validate_derivation_path(derivation_path, key_scheme)?;
+    match key_scheme {
+        BuiltinAuthValidator::Rooch => {
+            let indexes = path.iter().map(|i| i.into()).collect::<Vec<_>>();
+            let derived = derive_ed25519_private_key(seed, &indexes);
+            let sk = Ed25519PrivateKey::from_bytes(&derived)
+                .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?;
+            Ok(sk.as_bytes().to_vec())
+        }
+        BuiltinAuthValidator::Secp256k1 | BuiltinAuthValidator::Secp256r1 => {
+            let child_xprv = XPrv::derive_from_path(seed, &path)
+                .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?;
+            let mut private_key = vec![key_scheme.flag()];
+            private_key.extend_from_slice(child_xprv.private_key().to_bytes().as_slice());
+            Ok(private_key)
+        }
+        _ => Err(RoochError::SignatureKeyGenError(format!(
+            "Unsupported key scheme: {}",
+            key_scheme
+        ))),
+    }
+}
+
+/// Parse the private key stored in the keystore, see `derive_private_key_from_path` for the format.
+pub fn key_pair_from_private_key(private_key: &[u8]) -> Result<RoochKeyPair, RoochError> {
+    let (key_scheme, private_key) = if private_key.len() == ED25519_PRIVATE_KEY_LENGTH {
+        (BuiltinAuthValidator::Rooch, private_key)
+    } else {
+        let (flag, private_key) = private_key.split_first().ok_or_else(|| {
+            RoochError::KeyConversionError("Invalid private key length".to_owned())
+        })?;
+        (BuiltinAuthValidator::from_flag_byte(*flag)?, private_key)
+    };
+    RoochKeyPair::try_from_bytes(key_scheme, private_key)
+        .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))
 }
 
 pub fn derive_address_from_private_key(private_key: Vec<u8>) -> Result<RoochAddress, RoochError> {
-    let kp: Ed25519KeyPair = Ed25519KeyPair::from(
-        Ed25519PrivateKey::from_bytes(&private_key)
-            .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?,
-    );
-    let address: RoochAddress = kp.public().into();
+    let kp = key_pair_from_private_key(&private_key)?;
+    let address: RoochAddress = (&kp.public()).into();
     Ok(address)
 }
 
@@ -153,21 +184,31 @@ pub fn retrieve_key_pair(
 
     let private_key = decrypt_key(&nonce, &ciphertext, &tag, password)?;
 
-    let kp = Ed25519KeyPair::from(
-        Ed25519PrivateKey::from_bytes(&private_key)
-            .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?,
-    );
+    key_pair_from_private_key(&private_key)
+}
 
-    Ok(kp.into())
+fn derivation_path_purpose(key_scheme: BuiltinAuthValidator) -> Result<u32, RoochError> {
+    match key_scheme {
+        BuiltinAuthValidator::Rooch => Ok(DERIVATION_PATH_PURPOSE_ED25519),
+        BuiltinAuthValidator::Secp256k1 => Ok(DERIVATION_PATH_PURPOSE_ECDSA),
+        BuiltinAuthValidator::Secp256r1 => Ok(DERIVATION_PATH_PURPOSE_SECP256R1),
+        _ => Err(RoochError::SignatureKeyGenError(format!(
+            "Unsupported key scheme: {}",
+            key_scheme
+        ))),
+    }
 }
 
 pub fn validate_derivation_path(
     path: Option<DerivationPath>,
+    key_scheme: BuiltinAuthValidator,
 ) -> Result<DerivationPath, anyhow::Error> {
     let (purpose, coin_type) = (
-        DERIVATION_PATH_PURPOSE_ED25519,
+        derivation_path_purpose(key_scheme)?,
         RoochMultiChainID::Rooch as u32,
     );
+    // The Ed25519 keys are derived with SLIP-0010 which only supports hardened derivation
+    let hardened = key_scheme == BuiltinAuthValidator::Rooch;
 
     match path {
         Some(p) => {
@@ -175,8 +216,8 @@ pub fn validate_derivation_path(
                 if p_purpose == bip32::ChildNumber::new(purpose, true)?
                     && p_coin_type == bip32::ChildNumber::new(coin_type, true)?
                     && account.is_hardened()
-                    && change.is_hardened()
-                    && address.is_hardened()
+                    && change.is_hardened() == hardened
+                    && address.is_hardened() == hardened
                 {
                     Ok(p)
                 } else {
@@ -186,9 +227,7 @@ pub fn validate_derivation_path(
                 Err(anyhow::anyhow!("Invalid derivation path: {}", p))
             }
         }
-        None => Ok(format!("m/{}'/{}'/0'/0'/0'", purpose, coin_type)
-            .parse()
-            .map_err(|e| anyhow::anyhow!("Cannot parse derivation path, error:{}", e))?),
+        None => Ok(generate_derivation_path(0, key_scheme)?),
     }
 }
 
@@ -197,19 +236,28 @@ pub fn validate_derivation_path(
 /// https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
 /// for ed25529
 /// m / purpose' / coin_type' / account' / change' / address_index'
-pub fn generate_derivation_path(account_index: u32) -> Result<DerivationPath, RoochError> {
+/// for secp256k1 and secp256r1
+/// m / purpose' / coin_type' / account' / change / address_index
+pub fn generate_derivation_path(
+    account_index: u32,
+    key_scheme: BuiltinAuthValidator,
+) -> Result<DerivationPath, RoochError> {
     let (purpose, coin_type) = (
-        DERIVATION_PATH_PURPOSE_ED25519,
+        derivation_path_purpose(key_scheme)?,
         RoochMultiChainID::Rooch as u32,
     );
 
-    DerivationPath::from_str(
-        format!("m/{}'/{}'/0'/0'/{}'", purpose, coin_type, account_index).as_str(),
-    )
-    .map_err(|_| RoochError::SignatureKeyGenError("Cannot parse derivation path".to_owned()))
+    let path = if key_scheme == BuiltinAuthValidator::Rooch {
+        format!("m/{}'/{}'/0'/0'/{}'", purpose, coin_type, account_index)
+    } else {
+        format!("m/{}'/{}'/0'/0/{}", purpose, coin_type, account_index)
+    };
+    DerivationPath::from_str(path.as_str())
+        .map_err(|_| RoochError::SignatureKeyGenError("Cannot parse derivation path".to_owned()))
 }
 
 pub fn generate_new_key_pair(
+    key_scheme: BuiltinAuthValidator,
     mnemonic_phrase: Option<String>,
     derivation_path: Option<DerivationPath>,
     word_length: Option<String>,
@@ -225,7 +273,7 @@ pub fn generate_new_key_pair(
     };
     let seed = Seed::new(&mnemonic, "");
 
-    let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path)?;
+    let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path, key_scheme)?;
 
     let private_key_encryption =
         encrypt_key(&sk, password.clone()).expect("Encryption failed for private key");
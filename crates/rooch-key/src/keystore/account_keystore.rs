@@ -9,6 +9,7 @@ use crate::keystore::ImportedMnemonic;
 use bip32::DerivationPath;
 use bip39::{Language, Mnemonic, Seed};
 use fastcrypto::encoding::{Base64, Encoding};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -84,8 +85,10 @@ pub trait AccountKeystore {
 
     fn addresses(&self) -> Vec<RoochAddress>;
 
+    /// Generate a new key of the given key scheme, Rooch for Ed25519, Secp256k1 or Secp256r1.
     fn generate_and_add_new_key(
         &mut self,
+        key_scheme: BuiltinAuthValidator,
         mnemonic_phrase: Option<String>,
         derivation_path: Option<DerivationPath>,
         word_length: Option<String>,
@@ -102,13 +105,18 @@ pub trait AccountKeystore {
         };
         let derivation_path = if derivation_path.is_none() && one_mnemonic.is_some() {
             let account_index = one_mnemonic.clone().unwrap().mnemonic_data.addresses.len() as u32;
-            Some(generate_derivation_path(account_index)?)
+            Some(generate_derivation_path(account_index, key_scheme)?)
         } else {
             None
         };
 
-        let result =
-            generate_new_key_pair(mnemonic_phrase, derivation_path, word_length, password)?;
+        let result = generate_new_key_pair(
+            key_scheme,
+            mnemonic_phrase,
+            derivation_path,
+            word_length,
+            password,
+        )?;
         let new_address = result.address;
         self.add_address_encryption_data(
             new_address,
@@ -141,13 +149,14 @@ pub trait AccountKeystore {
     fn import_from_mnemonic(
         &mut self,
         phrase: &str,
+        key_scheme: BuiltinAuthValidator,
         derivation_path: Option<DerivationPath>,
         password: Option<String>,
     ) -> Result<ImportedMnemonic, anyhow::Error> {
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English)?;
         let seed = Seed::new(&mnemonic, "");
 
-        let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path)?;
+        let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path, key_scheme)?;
 
         let encryption = encrypt_key(&sk, password).expect("Encryption failed for private key");
 
@@ -167,13 +176,14 @@ pub trait AccountKeystore {
         &mut self,
         _address: &RoochAddress,
         phrase: String,
+        key_scheme: BuiltinAuthValidator,
         derivation_path: Option<DerivationPath>,
         password: Option<String>,
     ) -> Result<EncryptionData, anyhow::Error> {
         let mnemonic = Mnemonic::from_phrase(&phrase, Language::English)?;
         let seed = Seed::new(&mnemonic, "");
 
-        let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path)?;
+        let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path, key_scheme)?;
 
         let encryption_data =
             encrypt_key(&sk, password).expect("Encryption failed for private key");
@@ -11,6 +11,7 @@ use rooch_types::{
     authentication_key::AuthenticationKey,
     crypto::{PublicKey, RoochKeyPair, Signature},
     error::RoochError,
+    framework::auth_validator::BuiltinAuthValidator,
     key_struct::EncryptionData,
     transaction::{
         authenticator,
@@ -174,7 +175,13 @@ impl AccountKeystore for BaseKeyStore {
         password: Option<String>,
     ) -> Result<AuthenticationKey, anyhow::Error> {
         //TODO define derivation_path for session key
-        let result = generate_new_key_pair(None, None, None, password.clone())?;
+        let result = generate_new_key_pair(
+            BuiltinAuthValidator::Rooch,
+            None,
+            None,
+            None,
+            password.clone(),
+        )?;
         let kp: RoochKeyPair =
             retrieve_key_pair(&result.key_pair_data.private_key_encryption, password)?;
         let authentication_key = kp.public().authentication_key();
@@ -3,7 +3,10 @@
 
 use anyhow::Result;
 use coerce::actor::{message::Message, scheduler::timer::TimerTick};
+use moveos_types::h256::H256;
 use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_da::batch::DABatch;
+use rooch_types::block::{Block, BlockSubmitStatus};
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 /// Transaction Sequence Message
@@ -37,3 +40,61 @@ impl Message for ProposeBlock {
 }
 
 impl TimerTick for ProposeBlock {}
+
+/// Get Blocks By Number Message
+#[derive(Debug)]
+pub struct GetBlocksMessage {
+    pub block_numbers: Vec<u128>,
+}
+
+impl Message for GetBlocksMessage {
+    type Result = Result<Vec<Option<Block>>>;
+}
+
+/// Get Latest Block Message
+#[derive(Debug)]
+pub struct GetLatestBlockMessage {}
+
+impl Message for GetLatestBlockMessage {
+    type Result = Result<Option<Block>>;
+}
+
+/// Get the number of the block by the block hash
+#[derive(Debug)]
+pub struct GetBlockNumberByHashMessage {
+    pub block_hash: H256,
+}
+
+impl Message for GetBlockNumberByHashMessage {
+    type Result = Result<Option<u128>>;
+}
+
+/// Get the number of the block which contains the transaction
+#[derive(Debug)]
+pub struct GetBlockNumberByTxOrderMessage {
+    pub tx_order: u64,
+}
+
+impl Message for GetBlockNumberByTxOrderMessage {
+    type Result = Result<Option<u128>>;
+}
+
+/// Get Block Submit Status Message
+#[derive(Debug)]
+pub struct GetBlockSubmitStatusMessage {
+    pub block_number: u128,
+}
+
+impl Message for GetBlockSubmitStatusMessage {
+    type Result = Result<Option<BlockSubmitStatus>>;
+}
+
+/// Get the transaction batch of the block from the DA
+#[derive(Debug)]
+pub struct GetDABatchMessage {
+    pub block_number: u128,
+}
+
+impl Message for GetDABatchMessage {
+    type Result = Result<Option<DABatch>>;
+}
@@ -1,23 +1,99 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::messages::{ProposeBlock, TransactionProposeMessage, TransactionProposeResult};
+use super::messages::{
+    GetBlockNumberByHashMessage, GetBlockNumberByTxOrderMessage, GetBlockSubmitStatusMessage,
+    GetBlocksMessage, GetDABatchMessage, GetLatestBlockMessage, ProposeBlock,
+    TransactionProposeMessage, TransactionProposeResult,
+};
 use crate::scc::StateCommitmentChain;
+use crate::submitter::{BlockSubmitWorker, BlockSubmitter};
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use rooch_da::batch::DABatch;
+use rooch_da::proxy::DAProxy;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::block::{Block, BlockSubmitStatus};
 use rooch_types::crypto::RoochKeyPair;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
 pub struct ProposerActor {
-    proposer_key: RoochKeyPair,
     scc: StateCommitmentChain,
+    rooch_store: RoochStore,
+    da_proxy: DAProxy,
+    submit_worker: Arc<BlockSubmitWorker>,
+    // The running background task which submits the blocks, at most one task runs at a time
+    // to keep the blocks submitted in order.
+    submit_task: Option<JoinHandle<()>>,
 }
 
 impl ProposerActor {
-    pub fn new(proposer_key: RoochKeyPair) -> Self {
-        Self {
-            proposer_key,
-            scc: StateCommitmentChain::new(),
+    pub fn new<S: BlockSubmitter + 'static>(
+        proposer_key: RoochKeyPair,
+        rooch_store: RoochStore,
+        block_submitter: S,
+        da_proxy: DAProxy,
+    ) -> Result<Self> {
+        let scc = StateCommitmentChain::new(rooch_store.clone())?;
+        log::info!(
+            "Load latest block number {:?}, pending transactions {}",
+            scc.last_block_number(),
+            scc.buffer().len()
+        );
+        let submit_worker =
+            BlockSubmitWorker::new(proposer_key, rooch_store.clone(), block_submitter);
+        Ok(Self {
+            scc,
+            rooch_store,
+            da_proxy,
+            submit_worker: Arc::new(submit_worker),
+            submit_task: None,
+        })
+    }
+
+    /// Propose a new block from the buffer.
+    /// The transaction batch of the block is put to the DA before the block is committed to the SCC,
+    /// so every committed block can be fetched from the DA, if the DA put failed,
+    /// the transactions are kept in the buffer and proposed in the next tick.
+    async fn propose_block(&mut self) -> Result<Option<Block>> {
+        let block = match self.scc.build_block() {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let transactions = self.scc.buffer()[..block.batch_size as usize].to_vec();
+        let da_put_response = self
+            .da_proxy
+            .put_batch(DABatch::new(block.clone(), transactions))
+            .await?;
+        let block = self.scc.commit_block(block, da_put_response)?.clone();
+        self.rooch_store.save_block_submit_status(
+            block.block_number,
+            BlockSubmitStatus::Pending {
+                attempts: 0,
+                last_error: None,
+            },
+        )?;
+        Ok(Some(block))
+    }
+
+    /// Spawn a background task to submit the proposed but not submitted blocks,
+    /// skip if the previous task is still running, the new blocks will be submitted by it or the next task.
+    fn spawn_submit_task(&mut self) {
+        if let Some(submit_task) = &self.submit_task {
+            if !submit_task.is_finished() {
+                log::debug!("[SubmitBlock] the previous submit task is still running");
+                return;
+            }
         }
+        let submit_worker = self.submit_worker.clone();
+        self.submit_task = Some(tokio::spawn(async move {
+            if let Err(e) = submit_worker.submit_blocks().await {
+                log::error!("[SubmitBlock] submit block error: {:?}", e);
+            }
+        }));
     }
 }
 
@@ -30,7 +106,7 @@ impl Handler<TransactionProposeMessage> for ProposerActor {
         msg: TransactionProposeMessage,
         _ctx: &mut ActorContext,
     ) -> Result<TransactionProposeResult> {
-        self.scc.append_transaction(msg);
+        self.scc.append_transaction(msg)?;
         Ok(TransactionProposeResult {})
     }
 }
@@ -38,20 +114,103 @@ impl Handler<TransactionProposeMessage> for ProposerActor {
 #[async_trait]
 impl Handler<ProposeBlock> for ProposerActor {
     async fn handle(&mut self, _message: ProposeBlock, _ctx: &mut ActorContext) {
-        let block = self.scc.propose_block();
+        let block = self.propose_block().await;
         match block {
-            Some(block) => {
+            Ok(Some(block)) => {
                 log::info!(
                     "[ProposeBlock] block_number: {}, batch_size: {:?}",
                     block.block_number,
                     block.batch_size
                 );
             }
-            None => {
+            Ok(None) => {
                 log::debug!("[ProposeBlock] no transaction to propose block");
             }
+            Err(e) => {
+                log::error!("[ProposeBlock] propose block error: {:?}", e);
+            }
         };
-        //TODO submit to the on-chain SCC contract use the proposer key
-        let _proposer_key = &self.proposer_key;
+        // Submit the new block and retry the blocks which failed to submit in the previous ticks
+        self.spawn_submit_task();
+    }
+}
+
+#[async_trait]
+impl Handler<GetBlocksMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetBlocksMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<Block>>> {
+        let GetBlocksMessage { block_numbers } = msg;
+        self.rooch_store.get_blocks(block_numbers)
+    }
+}
+
+#[async_trait]
+impl Handler<GetLatestBlockMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        _msg: GetLatestBlockMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<Block>> {
+        // Read from the store, the blocks are synced from the leader in follower mode.
+        match self.rooch_store.get_last_block_number()? {
+            Some(block_number) => self.rooch_store.get_block(block_number),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetBlockNumberByHashMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetBlockNumberByHashMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<u128>> {
+        let GetBlockNumberByHashMessage { block_hash } = msg;
+        self.rooch_store.get_block_number_by_hash(block_hash)
+    }
+}
+
+#[async_trait]
+impl Handler<GetBlockNumberByTxOrderMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetBlockNumberByTxOrderMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<u128>> {
+        let GetBlockNumberByTxOrderMessage { tx_order } = msg;
+        self.rooch_store.get_block_number_by_tx_order(tx_order)
+    }
+}
+
+#[async_trait]
+impl Handler<GetBlockSubmitStatusMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetBlockSubmitStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<BlockSubmitStatus>> {
+        let GetBlockSubmitStatusMessage { block_number } = msg;
+        self.rooch_store.get_block_submit_status(block_number)
+    }
+}
+
+#[async_trait]
+impl Handler<GetDABatchMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetDABatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<DABatch>> {
+        let GetDABatchMessage { block_number } = msg;
+        match self.rooch_store.get_da_put_response(block_number)? {
+            Some(da_put_response) => Ok(Some(
+                self.da_proxy.get_batch(da_put_response.value_hash).await?,
+            )),
+            None => Ok(None),
+        }
     }
 }
@@ -4,3 +4,6 @@
 pub mod actor;
 pub mod proxy;
 pub mod scc;
+pub mod submitter;
+#[cfg(test)]
+mod tests;
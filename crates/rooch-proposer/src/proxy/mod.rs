@@ -2,12 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::{
-    messages::{TransactionProposeMessage, TransactionProposeResult},
+    messages::{
+        GetBlockNumberByHashMessage, GetBlockNumberByTxOrderMessage, GetBlockSubmitStatusMessage,
+        GetBlocksMessage, GetDABatchMessage, GetLatestBlockMessage, TransactionProposeMessage,
+        TransactionProposeResult,
+    },
     proposer::ProposerActor,
 };
 use anyhow::Result;
 use coerce::actor::ActorRef;
+use moveos_types::h256::H256;
 use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_da::batch::DABatch;
+use rooch_types::block::{Block, BlockSubmitStatus};
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 #[derive(Clone)]
@@ -34,4 +41,37 @@ impl ProposerProxy {
             })
             .await?
     }
+
+    pub async fn get_blocks(&self, block_numbers: Vec<u128>) -> Result<Vec<Option<Block>>> {
+        self.actor.send(GetBlocksMessage { block_numbers }).await?
+    }
+
+    pub async fn get_latest_block(&self) -> Result<Option<Block>> {
+        self.actor.send(GetLatestBlockMessage {}).await?
+    }
+
+    pub async fn get_block_number_by_hash(&self, block_hash: H256) -> Result<Option<u128>> {
+        self.actor
+            .send(GetBlockNumberByHashMessage { block_hash })
+            .await?
+    }
+
+    pub async fn get_block_number_by_tx_order(&self, tx_order: u64) -> Result<Option<u128>> {
+        self.actor
+            .send(GetBlockNumberByTxOrderMessage { tx_order })
+            .await?
+    }
+
+    pub async fn get_block_submit_status(
+        &self,
+        block_number: u128,
+    ) -> Result<Option<BlockSubmitStatus>> {
+        self.actor
+            .send(GetBlockSubmitStatusMessage { block_number })
+            .await?
+    }
+
+    pub async fn get_da_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        self.actor.send(GetDABatchMessage { block_number }).await?
+    }
 }
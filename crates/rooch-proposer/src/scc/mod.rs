@@ -2,66 +2,93 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::TransactionProposeMessage;
+use anyhow::{ensure, format_err, Result};
 use moveos_types::h256::H256;
+use rooch_da::DAPutResponse;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
 use rooch_types::block::Block;
-use std::collections::BTreeMap;
+use rooch_types::transaction::TransactionWithInfo;
 
 /// State Commitment Chain(SCC) is a chain of transaction state root
 /// This SCC is a mirror of the on-chain SCC
 pub struct StateCommitmentChain {
-    //TODO save to the storage
-    blocks: BTreeMap<u128, Block>,
-    buffer: Vec<TransactionProposeMessage>,
-}
-
-impl Default for StateCommitmentChain {
-    fn default() -> Self {
-        Self::new()
-    }
+    last_block: Option<Block>,
+    buffer: Vec<TransactionWithInfo>,
+    rooch_store: RoochStore,
 }
 
 impl StateCommitmentChain {
-    /// Create a new SCC
-    pub fn new() -> Self {
-        Self {
-            blocks: BTreeMap::new(),
-            buffer: Vec::new(),
-        }
+    /// Create a new SCC, restore the last block and the pending buffer from the store
+    pub fn new(rooch_store: RoochStore) -> Result<Self> {
+        let last_block = match rooch_store.get_last_block_number()? {
+            Some(block_number) => Some(rooch_store.get_block(block_number)?.ok_or_else(|| {
+                format_err!("The last block {} not found in store", block_number)
+            })?),
+            None => None,
+        };
+        let buffer = rooch_store.get_propose_buffer()?;
+        Ok(Self {
+            last_block,
+            buffer,
+            rooch_store,
+        })
     }
 
-    pub fn append_transaction(&mut self, tx: TransactionProposeMessage) {
+    pub fn append_transaction(&mut self, tx: TransactionProposeMessage) -> Result<()> {
+        let tx = TransactionWithInfo {
+            transaction: tx.tx,
+            sequence_info: tx.tx_sequence_info,
+            execution_info: tx.tx_execution_info,
+        };
+        self.rooch_store.save_propose_buffer_item(tx.clone())?;
         self.buffer.push(tx);
-    }
-
-    /// Append a new block to the SCC
-    fn append_block(&mut self, block: Block) {
-        self.blocks.insert(block.block_number, block);
+        Ok(())
     }
 
     /// Get the last block of the SCC
     pub fn last_block(&self) -> Option<&Block> {
-        self.blocks.values().last()
+        self.last_block.as_ref()
     }
 
     /// Get the last block number of the SCC
     pub fn last_block_number(&self) -> Option<u128> {
-        self.blocks.keys().last().copied()
+        self.last_block.as_ref().map(|block| block.block_number)
+    }
+
+    /// Get the block by block number
+    pub fn get_block(&self, block_number: u128) -> Result<Option<Block>> {
+        self.rooch_store.get_block(block_number)
     }
 
-    /// Trigger the proposer to propose a new block
-    pub fn propose_block(&mut self) -> Option<&Block> {
+    /// Get the transactions which are waiting to be proposed
+    pub fn buffer(&self) -> &[TransactionWithInfo] {
+        &self.buffer
+    }
+
+    /// Build a new block from the buffer, the block is not appended to the SCC until `commit_block` is called.
+    pub fn build_block(&self) -> Option<Block> {
         if self.buffer.is_empty() {
             return None;
         }
         // construct a new block from buffer
         let latest_transaction = self.buffer.last().expect("buffer must not empty");
-        let tx_accumulator_root = latest_transaction.tx_sequence_info.tx_accumulator_root;
+        let tx_accumulator_root = latest_transaction.sequence_info.tx_accumulator_root;
         let state_roots = self
             .buffer
             .iter()
-            .map(|tx| tx.tx_execution_info.state_root)
+            .map(|tx| tx.execution_info.state_root)
             .collect();
         let batch_size = self.buffer.len() as u64;
+        let first_tx_order = self
+            .buffer
+            .first()
+            .expect("buffer must not empty")
+            .sequence_info
+            .tx_order;
+        // The block timestamp is the sequenced timestamp of its last transaction,
+        // so the block built from the same transactions is the same whenever it is proposed.
+        let timestamp = latest_transaction.sequence_info.tx_timestamp;
         let last_block = self.last_block();
         let (block_number, prev_tx_accumulator_root) = match last_block {
             Some(block) => {
@@ -75,15 +102,48 @@ impl StateCommitmentChain {
                 (block_number, prev_tx_accumulator_root)
             }
         };
-        let new_block = Block::new(
+        Some(Block::new(
             block_number,
             batch_size,
             prev_tx_accumulator_root,
             tx_accumulator_root,
             state_roots,
+            first_tx_order,
+            timestamp,
+        ))
+    }
+
+    /// Append the block built by `build_block` to the SCC with the DA reference of its transaction batch,
+    /// and remove the proposed transactions from the buffer.
+    pub fn commit_block(&mut self, block: Block, da_put_response: DAPutResponse) -> Result<&Block> {
+        let expect_block_number = self
+            .last_block_number()
+            .map_or(0, |block_number| block_number + 1);
+        ensure!(
+            block.block_number == expect_block_number,
+            "The block number {} mismatch, expect {}",
+            block.block_number,
+            expect_block_number
         );
-        self.append_block(new_block);
-        self.buffer.clear();
-        self.last_block()
+        let batch_size = block.batch_size as usize;
+        ensure!(
+            batch_size <= self.buffer.len(),
+            "The batch size {} of block {} is greater than the buffer size {}",
+            batch_size,
+            block.block_number,
+            self.buffer.len()
+        );
+        let tx_orders = self.buffer[..batch_size]
+            .iter()
+            .map(|tx| tx.sequence_info.tx_order)
+            .collect();
+        // The in-memory state is updated only after the block is committed to the store.
+        self.rooch_store
+            .commit_block(block.clone(), tx_orders, Some(da_put_response))?;
+        self.last_block = Some(block);
+        self.buffer.drain(..batch_size);
+        Ok(self
+            .last_block()
+            .expect("the last block must exist after append"))
     }
 }
@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::BlockSubmitter;
+use anyhow::Result;
+use async_trait::async_trait;
+use rooch_types::block::SignedBlock;
+use std::path::PathBuf;
+
+/// FileBlockSubmitter saves the signed blocks as json files in a local directory,
+/// it is used for local development and testing.
+pub struct FileBlockSubmitter {
+    dir: PathBuf,
+}
+
+impl FileBlockSubmitter {
+    pub fn new(dir: PathBuf) -> Result<Self> {
+        if !dir.exists() {
+            std::fs::create_dir_all(dir.as_path())?;
+        }
+        Ok(Self { dir })
+    }
+
+    pub fn block_file(&self, block_number: u128) -> PathBuf {
+        self.dir.join(format!("block_{}.json", block_number))
+    }
+}
+
+#[async_trait]
+impl BlockSubmitter for FileBlockSubmitter {
+    async fn submit_block(&self, block: SignedBlock) -> Result<String> {
+        block.verify()?;
+        let path = self.block_file(block.block.block_number);
+        let content = serde_json::to_string_pretty(&block)?;
+        tokio::fs::write(path.as_path(), content).await?;
+        Ok(path.display().to_string())
+    }
+}
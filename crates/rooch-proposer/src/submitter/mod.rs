@@ -0,0 +1,27 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rooch_types::block::SignedBlock;
+
+pub mod file;
+pub mod worker;
+
+pub use file::FileBlockSubmitter;
+pub use worker::BlockSubmitWorker;
+
+/// BlockSubmitter submits the blocks signed by the proposer to the settlement layer.
+/// The local implementation is `FileBlockSubmitter`,
+/// the on-chain SCC contract client should implement this trait to settle the blocks on L1.
+#[async_trait]
+pub trait BlockSubmitter: Send + Sync {
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Submit the signed block, return the receipt of the submission.
+    /// The blocks are submitted in order of block number, and a block may be submitted more than once
+    /// when retrying, so the implementation should be idempotent.
+    async fn submit_block(&self, block: SignedBlock) -> Result<String>;
+}
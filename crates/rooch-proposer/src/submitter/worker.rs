@@ -0,0 +1,121 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::BlockSubmitter;
+use anyhow::{format_err, Result};
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::block::{Block, BlockSubmitStatus, SignedBlock};
+use rooch_types::crypto::RoochKeyPair;
+use std::time::Duration;
+
+/// The max retry times of submitting a block in one submission round,
+/// the block will be retried again in the next round if all retries failed.
+pub const MAX_SUBMIT_RETRY_TIMES: u64 = 3;
+pub const SUBMIT_RETRY_INTERVAL_MILLIS: u64 = 200;
+
+/// BlockSubmitWorker submits the proposed blocks in order of block number.
+/// It runs in a background task spawned by the proposer, so the retries of a slow or failed submission
+/// do not block the proposer actor from handling the other messages.
+pub struct BlockSubmitWorker {
+    proposer_key: RoochKeyPair,
+    rooch_store: RoochStore,
+    block_submitter: Box<dyn BlockSubmitter>,
+    retry_interval: Duration,
+}
+
+impl BlockSubmitWorker {
+    pub fn new<S: BlockSubmitter + 'static>(
+        proposer_key: RoochKeyPair,
+        rooch_store: RoochStore,
+        block_submitter: S,
+    ) -> Self {
+        Self {
+            proposer_key,
+            rooch_store,
+            block_submitter: Box::new(block_submitter),
+            retry_interval: Duration::from_millis(SUBMIT_RETRY_INTERVAL_MILLIS),
+        }
+    }
+
+    pub fn with_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Submit all the proposed but not submitted blocks in order of block number,
+    /// stop at the first block which failed to submit after all retries.
+    pub async fn submit_blocks(&self) -> Result<()> {
+        let last_block_number = match self.rooch_store.get_last_block_number()? {
+            Some(last_block_number) => last_block_number,
+            None => return Ok(()),
+        };
+        let next_block_number = self
+            .rooch_store
+            .get_last_submitted_block_number()?
+            .map_or(0, |block_number| block_number + 1);
+        for block_number in next_block_number..=last_block_number {
+            let block = self
+                .rooch_store
+                .get_block(block_number)?
+                .ok_or_else(|| format_err!("The block {} not found in store", block_number))?;
+            self.submit_block(block).await?;
+        }
+        Ok(())
+    }
+
+    async fn submit_block(&self, block: Block) -> Result<()> {
+        let block_number = block.block_number;
+        let mut attempts = self
+            .rooch_store
+            .get_block_submit_status(block_number)?
+            .map_or(0, |status| status.attempts());
+        let signed_block = SignedBlock::new(block, &self.proposer_key);
+        let mut last_error = None;
+        for retry in 0..MAX_SUBMIT_RETRY_TIMES {
+            if retry > 0 {
+                tokio::time::sleep(self.retry_interval * retry as u32).await;
+            }
+            match self
+                .block_submitter
+                .submit_block(signed_block.clone())
+                .await
+            {
+                Ok(receipt) => {
+                    log::info!(
+                        "[SubmitBlock] block_number: {}, submitter: {}, receipt: {}",
+                        block_number,
+                        self.block_submitter.name(),
+                        receipt
+                    );
+                    return self.rooch_store.save_block_submit_status(
+                        block_number,
+                        BlockSubmitStatus::Submitted {
+                            submitter: self.block_submitter.name().to_string(),
+                            receipt,
+                        },
+                    );
+                }
+                Err(e) => {
+                    attempts += 1;
+                    log::warn!(
+                        "[SubmitBlock] block_number: {}, attempts: {}, error: {:?}",
+                        block_number,
+                        attempts,
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        let error = last_error.expect("the last error must exist after all retries failed");
+        self.rooch_store.save_block_submit_status(
+            block_number,
+            BlockSubmitStatus::Pending {
+                attempts,
+                last_error: Some(error.to_string()),
+            },
+        )?;
+        Err(error)
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::messages::TransactionProposeMessage;
+use crate::scc::StateCommitmentChain;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+use rooch_store::RoochStore;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{AbstractTransaction, TransactionSequenceInfo, TypedTransaction};
+
+fn mock_authenticator() -> Authenticator {
+    Authenticator::new(0, vec![])
+}
+
+fn mock_propose_message(tx_order: u64, tx_timestamp: u64) -> TransactionProposeMessage {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        tx_order,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    let transaction = RoochTransaction::new(tx_data, mock_authenticator());
+    let tx_hash = transaction.tx_hash();
+    TransactionProposeMessage {
+        tx: TypedTransaction::Rooch(transaction),
+        tx_sequence_info: TransactionSequenceInfo::new(
+            tx_order,
+            mock_authenticator(),
+            H256::random(),
+            tx_timestamp,
+        ),
+        tx_execution_info: TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        ),
+    }
+}
+
+#[test]
+fn test_build_block_with_sequenced_timestamp() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let mut scc = StateCommitmentChain::new(rooch_store.clone()).unwrap();
+    assert!(scc.build_block().is_none());
+
+    for (tx_order, tx_timestamp) in [(0, 1000), (1, 1500), (2, 2000)] {
+        scc.append_transaction(mock_propose_message(tx_order, tx_timestamp))
+            .unwrap();
+    }
+
+    // The block timestamp is the sequenced timestamp of the last transaction in the block
+    let block = scc.build_block().unwrap();
+    assert_eq!(block.block_number, 0);
+    assert_eq!(block.batch_size, 3);
+    assert_eq!(block.first_tx_order, 0);
+    assert_eq!(block.timestamp, 2000);
+    assert_eq!(
+        block.tx_accumulator_root,
+        scc.buffer()
+            .last()
+            .unwrap()
+            .sequence_info
+            .tx_accumulator_root
+    );
+
+    // The block built from the same transactions is the same, even if it is rebuilt after a restart
+    let restored_scc = StateCommitmentChain::new(rooch_store).unwrap();
+    assert_eq!(restored_scc.build_block(), Some(block));
+}
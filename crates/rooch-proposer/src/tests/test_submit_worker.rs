@@ -0,0 +1,135 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::submitter::worker::MAX_SUBMIT_RETRY_TIMES;
+use crate::submitter::{BlockSubmitWorker, BlockSubmitter};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::traits::KeyPair;
+use moveos_types::h256::H256;
+use rand::{rngs::StdRng, SeedableRng};
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::block::{Block, BlockSubmitStatus, SignedBlock};
+use rooch_types::crypto::RoochKeyPair;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A submitter which fails the first `failures` submissions.
+#[derive(Clone)]
+struct MockBlockSubmitter {
+    failures: Arc<Mutex<u64>>,
+    submitted: Arc<Mutex<Vec<u128>>>,
+}
+
+impl MockBlockSubmitter {
+    fn new(failures: u64) -> Self {
+        Self {
+            failures: Arc::new(Mutex::new(failures)),
+            submitted: Arc::default(),
+        }
+    }
+
+    fn submitted(&self) -> Vec<u128> {
+        self.submitted.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl BlockSubmitter for MockBlockSubmitter {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn submit_block(&self, block: SignedBlock) -> Result<String> {
+        block.verify()?;
+        {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                bail!("mock submit failure");
+            }
+        }
+        let block_number = block.block.block_number;
+        self.submitted.lock().unwrap().push(block_number);
+        Ok(format!("receipt_{}", block_number))
+    }
+}
+
+fn proposer_key() -> RoochKeyPair {
+    let mut rng = StdRng::seed_from_u64(0);
+    RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng))
+}
+
+fn save_blocks(rooch_store: &RoochStore, count: u128) {
+    for block_number in 0..count {
+        let block = Block::new(
+            block_number,
+            1,
+            H256::random(),
+            H256::random(),
+            vec![H256::random()],
+            block_number as u64,
+            0,
+        );
+        rooch_store.save_block(block).unwrap();
+    }
+}
+
+fn submit_worker(rooch_store: &RoochStore, submitter: MockBlockSubmitter) -> BlockSubmitWorker {
+    BlockSubmitWorker::new(proposer_key(), rooch_store.clone(), submitter)
+        .with_retry_interval(Duration::from_millis(1))
+}
+
+#[tokio::test]
+async fn test_submit_blocks_in_order() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    save_blocks(&rooch_store, 3);
+    // The failures are recovered by the retries
+    let submitter = MockBlockSubmitter::new(MAX_SUBMIT_RETRY_TIMES - 1);
+    let worker = submit_worker(&rooch_store, submitter.clone());
+
+    worker.submit_blocks().await.unwrap();
+    assert_eq!(submitter.submitted(), vec![0, 1, 2]);
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(2)
+    );
+    assert_eq!(
+        rooch_store.get_block_submit_status(0).unwrap(),
+        Some(BlockSubmitStatus::Submitted {
+            submitter: "mock".to_string(),
+            receipt: "receipt_0".to_string(),
+        })
+    );
+
+    // The submitted blocks are not submitted again
+    worker.submit_blocks().await.unwrap();
+    assert_eq!(submitter.submitted(), vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_submit_blocks_retry_in_next_round() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    save_blocks(&rooch_store, 2);
+    let submitter = MockBlockSubmitter::new(MAX_SUBMIT_RETRY_TIMES + 1);
+    let worker = submit_worker(&rooch_store, submitter.clone());
+
+    // All the retries of the first block failed, the following blocks are not submitted
+    assert!(worker.submit_blocks().await.is_err());
+    assert!(submitter.submitted().is_empty());
+    assert_eq!(rooch_store.get_last_submitted_block_number().unwrap(), None);
+    let status = rooch_store.get_block_submit_status(0).unwrap().unwrap();
+    assert!(!status.is_submitted());
+    assert_eq!(status.attempts(), MAX_SUBMIT_RETRY_TIMES);
+    assert_eq!(rooch_store.get_block_submit_status(1).unwrap(), None);
+
+    // The next round continues from the failed block
+    worker.submit_blocks().await.unwrap();
+    assert_eq!(submitter.submitted(), vec![0, 1]);
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(1)
+    );
+}
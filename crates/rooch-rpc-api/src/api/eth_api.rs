@@ -3,13 +3,17 @@
 
 use crate::jsonrpc_types::{
     eth::{
-        ethereum_types::block::{Block, BlockNumber},
+        ethereum_types::{
+            block::{Block, BlockNumber},
+            filter::{Filter, FilterChanges},
+            log::Log,
+        },
         transaction::{Transaction, TransactionReceipt, TransactionRequest},
         CallRequest, EthFeeHistory,
     },
     BytesView, H160View, H256View, StrView,
 };
-use ethers::types::{H256, U256};
+use ethers::types::{H256, U256, U64};
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use rooch_open_rpc_macros::open_rpc;
@@ -17,7 +21,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::string::String;
 
+/// The transactions of a block are the hashes or the full transactions, depending on the `include_txs` flag.
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug, JsonSchema)]
+#[serde(untagged)]
 pub enum TransactionType {
     Full(Transaction),
     Hash(H256View),
@@ -47,7 +53,22 @@ pub trait EthAPI {
         &self,
         num: StrView<BlockNumber>,
         include_txs: bool,
-    ) -> RpcResult<Block<TransactionType>>;
+    ) -> RpcResult<Option<Block<TransactionType>>>;
+
+    /// Returns the number of transactions in a block by block number.
+    #[method(name = "getBlockTransactionCountByNumber")]
+    async fn get_block_transaction_count_by_number(
+        &self,
+        num: StrView<BlockNumber>,
+    ) -> RpcResult<Option<StrView<U256>>>;
+
+    /// Returns information about a transaction by block number and transaction index position.
+    #[method(name = "getTransactionByBlockNumberAndIndex")]
+    async fn transaction_by_block_number_and_index(
+        &self,
+        num: StrView<BlockNumber>,
+        index: StrView<U64>,
+    ) -> RpcResult<Option<Transaction>>;
 
     /// Returns the balance of the account of given address.
     #[method(name = "getBalance")]
@@ -115,7 +136,7 @@ pub trait EthAPI {
         &self,
         hash: H256View,
         include_txs: bool,
-    ) -> RpcResult<Block<TransactionType>>;
+    ) -> RpcResult<Option<Block<TransactionType>>>;
 
     /// Returns the code at the given contract address.
     #[method(name = "getCode")]
@@ -124,6 +145,26 @@ pub trait EthAPI {
         address: H160View,
         block_number: Option<StrView<BlockNumber>>,
     ) -> RpcResult<BytesView>;
+
+    /// Returns the logs matching the given filter.
+    #[method(name = "getLogs")]
+    async fn get_logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+
+    /// Creates a log filter, returns the filter id for polling the changes by `eth_getFilterChanges`.
+    #[method(name = "newFilter")]
+    async fn new_filter(&self, filter: Filter) -> RpcResult<StrView<U256>>;
+
+    /// Creates a filter to notify the new blocks, returns the filter id.
+    #[method(name = "newBlockFilter")]
+    async fn new_block_filter(&self) -> RpcResult<StrView<U256>>;
+
+    /// Returns the logs or block hashes since the last poll of the filter.
+    #[method(name = "getFilterChanges")]
+    async fn get_filter_changes(&self, filter_id: StrView<U256>) -> RpcResult<FilterChanges>;
+
+    /// Uninstalls the filter, returns true if the filter was found and uninstalled.
+    #[method(name = "uninstallFilter")]
+    async fn uninstall_filter(&self, filter_id: StrView<U256>) -> RpcResult<bool>;
 }
 
 #[open_rpc]
@@ -2,13 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
-use crate::jsonrpc_types::event_view::EventFilterView;
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::block_view::{BlockSubmitStatusView, BlockView};
+use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventView};
+use crate::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionProofView, TransactionWithInfoView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    BytesView, EventOptions, EventPageView, ExecuteTransactionResponseView, FunctionCallView,
-    H256View, IndexerEventPageView, IndexerTableChangeSetPageView, StateFilterView, StateOptions,
-    StateView, StatesPageView, StrView, StructTagView, TransactionWithInfoPageView,
+    BytesView, DryRunOptions, DryRunTransactionResponseView, EventOptions, EventPageView,
+    ExecuteTransactionResponseView, ExecutionTraceView, FunctionCallView, GasPriceView, H256View,
+    IndexerEventPageView, IndexerTableChangeSetPageView, IndexerTableChangeSetView,
+    PackageInfoView, StateFilterView, StateOptions, StateView, StatesPageView, StrView,
+    StructTagView, TransactionWithInfoPageView,
 };
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -36,6 +41,16 @@ pub trait RoochAPI {
         tx_bcs_hex: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Dry run the transaction in bcs hex format, the transaction can be unsigned.
+    /// The output is not applied to the state, it returns the status, gas used, events and state change set.
+    /// If the `profileGas` option is enabled, the gas profile of the Move function call tree is returned too.
+    #[method(name = "dryRunRawTransaction")]
+    async fn dry_run_raw_transaction(
+        &self,
+        tx_bcs_hex: BytesView,
+        dry_run_options: Option<DryRunOptions>,
+    ) -> RpcResult<DryRunTransactionResponseView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -64,6 +79,14 @@ pub trait RoochAPI {
         state_option: Option<StateOptions>,
     ) -> RpcResult<StatesPageView>;
 
+    /// Get the metadata of the package published at the address,
+    /// only the packages published by `moveos_std::package_registry::publish_package_entry` are recorded.
+    #[method(name = "getPackage")]
+    async fn get_package(
+        &self,
+        package_address: AccountAddressView,
+    ) -> RpcResult<Option<PackageInfoView>>;
+
     /// Get the events by event handle id
     #[method(name = "getEventsByEventHandle")]
     async fn get_events_by_event_handle(
@@ -87,6 +110,47 @@ pub trait RoochAPI {
         limit: Option<StrView<u64>>,
     ) -> RpcResult<TransactionWithInfoPageView>;
 
+    /// Get the call stack, arguments and locals leading to the failure of the transaction,
+    /// the trace is only available for the failed transactions executed by the connected node with `--trace-failed-txs` enabled.
+    #[method(name = "getTransactionTrace")]
+    async fn get_transaction_trace(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ExecutionTraceView>>;
+
+    /// Get the inclusion proof of the transaction at `tx_order` under the latest tx accumulator root
+    #[method(name = "getTransactionProof")]
+    async fn get_transaction_proof(
+        &self,
+        tx_order: StrView<u64>,
+    ) -> RpcResult<Option<TransactionProofView>>;
+
+    /// Get the block proposed by the proposer by block number
+    #[method(name = "getBlockByNumber")]
+    async fn get_block_by_number(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockView>>;
+
+    /// Get the latest block proposed by the proposer
+    #[method(name = "getLatestBlock")]
+    async fn get_latest_block(&self) -> RpcResult<Option<BlockView>>;
+
+    /// Get the submission status of the block to the settlement layer
+    #[method(name = "getBlockSubmitStatus")]
+    async fn get_block_submit_status(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockSubmitStatusView>>;
+
+    /// Get the bcs encoded transaction batch of the block from the DA
+    #[method(name = "getDABatch")]
+    async fn get_da_batch(&self, block_number: StrView<u128>) -> RpcResult<Option<BytesView>>;
+
+    /// Get the current gas price, the base fee is adjusted by the gas usage of the recent transactions
+    #[method(name = "getGasPrice")]
+    async fn get_gas_price(&self) -> RpcResult<GasPriceView>;
+
     /// get account balance by AccountAddress and CoinType
     #[method(name = "getBalance")]
     async fn get_balance(
@@ -136,4 +200,16 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableChangeSetPageView>;
+
+    /// Subscribe to the executed transactions which match the transaction filter
+    #[subscription(name = "subscribeTransactions", item = TransactionWithInfoView)]
+    fn subscribe_transactions(&self, filter: TransactionFilterView);
+
+    /// Subscribe to the events emitted by the executed transactions which match the event filter
+    #[subscription(name = "subscribeEvents", item = IndexerEventView)]
+    fn subscribe_events(&self, filter: EventFilterView);
+
+    /// Subscribe to the state change sets of the executed transactions which match the state filter
+    #[subscription(name = "subscribeStates", item = IndexerTableChangeSetView)]
+    fn subscribe_states(&self, filter: StateFilterView);
 }
@@ -0,0 +1,62 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use rooch_types::block::{Block, BlockSubmitStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BlockView {
+    pub block_number: StrView<u128>,
+    pub batch_size: StrView<u64>,
+    pub prev_tx_accumulator_root: H256View,
+    pub tx_accumulator_root: H256View,
+    pub state_roots: Vec<H256View>,
+    pub first_tx_order: StrView<u64>,
+    pub timestamp: StrView<u64>,
+}
+
+impl From<Block> for BlockView {
+    fn from(block: Block) -> Self {
+        Self {
+            block_number: StrView(block.block_number),
+            batch_size: StrView(block.batch_size),
+            prev_tx_accumulator_root: block.prev_tx_accumulator_root.into(),
+            tx_accumulator_root: block.tx_accumulator_root.into(),
+            state_roots: block.state_roots.into_iter().map(Into::into).collect(),
+            first_tx_order: StrView(block.first_tx_order),
+            timestamp: StrView(block.timestamp),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum BlockSubmitStatusView {
+    Pending {
+        attempts: StrView<u64>,
+        last_error: Option<String>,
+    },
+    Submitted {
+        submitter: String,
+        receipt: String,
+    },
+}
+
+impl From<BlockSubmitStatus> for BlockSubmitStatusView {
+    fn from(status: BlockSubmitStatus) -> Self {
+        match status {
+            BlockSubmitStatus::Pending {
+                attempts,
+                last_error,
+            } => Self::Pending {
+                attempts: StrView(attempts),
+                last_error,
+            },
+            BlockSubmitStatus::Submitted { submitter, receipt } => {
+                Self::Submitted { submitter, receipt }
+            }
+        }
+    }
+}
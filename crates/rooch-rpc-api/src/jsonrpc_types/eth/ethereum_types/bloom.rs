@@ -9,6 +9,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use ethers::utils::keccak256;
 use fixed_hash::construct_fixed_hash;
 #[cfg(feature = "codec")]
 use impl_codec::impl_fixed_hash_codec;
@@ -20,7 +21,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // 3 according to yellowpaper
-// const BLOOM_BITS: u32 = 3;
+const BLOOM_BITS: usize = 3;
 const BLOOM_SIZE: usize = 256;
 
 construct_fixed_hash! {
@@ -29,13 +30,41 @@ construct_fixed_hash! {
     pub struct Bloom(BLOOM_SIZE);
 }
 
+impl Bloom {
+    /// Add the keccak256 hash of the input to the bloom, as the `M3:2048` function of the yellowpaper.
+    pub fn accrue(&mut self, input: &[u8]) {
+        for (index, bit) in Self::bloom_bits(input) {
+            self.0[index] |= bit;
+        }
+    }
+
+    /// Returns true if the input may be contained in the bloom.
+    pub fn contains_input(&self, input: &[u8]) -> bool {
+        Self::bloom_bits(input).all(|(index, bit)| self.0[index] & bit == bit)
+    }
+
+    /// Returns true if all the bits of the other bloom are set in this bloom.
+    pub fn contains_bloom(&self, other: &Bloom) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a & b == *b)
+    }
+
+    /// Take the low 11 bits of the first 3 pairs of bytes of the hash as the bit positions.
+    fn bloom_bits(input: &[u8]) -> impl Iterator<Item = (usize, u8)> {
+        let hash = keccak256(input);
+        (0..BLOOM_BITS).map(move |i| {
+            let bit = (((hash[2 * i] as usize) << 8) | hash[2 * i + 1] as usize) % (BLOOM_SIZE * 8);
+            (BLOOM_SIZE - 1 - bit / 8, 1u8 << (bit % 8))
+        })
+    }
+}
+
 impl Serialize for Bloom {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // Serialize the byte array directly.
-        serializer.serialize_bytes(&self.0)
+        // Serialize as a hex string with `0x` prefix, same as the other ethereum hashes.
+        serializer.serialize_str(&format!("0x{}", hex::encode(self.0)))
     }
 }
 
@@ -74,14 +103,17 @@ impl<'de> Deserialize<'de> for Bloom {
     where
         D: Deserializer<'de>,
     {
-        // Deserialize as a byte slice.
-        let bytes: &[u8] = Deserialize::deserialize(deserializer)?;
-
-        // Convert the byte slice to your custom `Bloom` struct.
-        let bloom: Bloom = bytes.try_into().map_err(|_| {
-            serde::de::Error::custom("Failed to convert byte slice to Bloom struct")
-        })?;
-
-        Ok(bloom)
+        // Deserialize from a hex string with or without `0x` prefix.
+        let s = String::deserialize(deserializer)?;
+        let bytes =
+            hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(serde::de::Error::custom)?;
+        if bytes.len() != BLOOM_SIZE {
+            return Err(serde::de::Error::custom(format!(
+                "Invalid bloom length {}, expect {}",
+                bytes.len(),
+                BLOOM_SIZE
+            )));
+        }
+        Ok(Bloom::from(bytes.as_slice()))
     }
 }
@@ -0,0 +1,76 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{block::BlockNumber, log::Log};
+use crate::jsonrpc_types::{H160View, H256View, StrView};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A single value or an array of values, the ethereum filter accepts both forms for the address and topics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ValueOrArray<T> {
+    Value(T),
+    Array(Vec<T>),
+}
+
+impl<T: PartialEq> ValueOrArray<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            ValueOrArray::Value(v) => v == value,
+            ValueOrArray::Array(values) => values.contains(value),
+        }
+    }
+}
+
+/// The filter of `eth_getLogs` and `eth_newFilter`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    /// From block, inclusive, default is `latest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<StrView<BlockNumber>>,
+    /// To block, inclusive, default is `latest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<StrView<BlockNumber>>,
+    /// Only return the logs of the block with the hash, can not be used with `fromBlock` and `toBlock`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<H256View>,
+    /// The addresses of the logs, match any of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<ValueOrArray<H160View>>,
+    /// The topics of the logs, the position is significant,
+    /// `null` matches any topic, an array matches any of the topics in it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<Vec<Option<ValueOrArray<H256View>>>>,
+}
+
+impl Filter {
+    /// Whether the log matches the address and topics of the filter, the block range is not checked.
+    pub fn matches(&self, log: &Log) -> bool {
+        if let Some(address) = &self.address {
+            if !address.contains(&log.address) {
+                return false;
+            }
+        }
+        if let Some(topics) = &self.topics {
+            for (i, topic) in topics.iter().enumerate() {
+                if let Some(topic) = topic {
+                    match log.topics.get(i) {
+                        Some(log_topic) if topic.contains(log_topic) => {}
+                        _ => return false,
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+/// The result of `eth_getFilterChanges`, logs for the log filter, and block hashes for the block filter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum FilterChanges {
+    Logs(Vec<Log>),
+    Hashes(Vec<H256View>),
+}
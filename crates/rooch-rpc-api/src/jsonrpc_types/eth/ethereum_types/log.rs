@@ -3,8 +3,11 @@
 
 // Adapted from https://github.com/tomusdrw/rust-web3/blob/master/src/types/log.rs
 
+use super::bloom::Bloom;
 use crate::jsonrpc_types::{BytesView, H160View, H256View, StrView};
-use ethers::types::U256;
+use ethers::types::{H160, H256, U256, U64};
+use ethers::utils::keccak256;
+use rooch_types::indexer::event_filter::IndexerEvent;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -32,7 +35,7 @@ pub struct Log {
     /// Block Number
     #[serde(rename = "blockNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub block_number: Option<StrView<u64>>,
+    pub block_number: Option<StrView<U64>>,
 
     /// Transaction Hash
     #[serde(rename = "transactionHash")]
@@ -42,7 +45,7 @@ pub struct Log {
     /// Transaction Index
     #[serde(rename = "transactionIndex")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transaction_index: Option<StrView<u64>>,
+    pub transaction_index: Option<StrView<U64>>,
 
     /// Integer of the log index position in the block. None if it's a pending log.
     #[serde(rename = "logIndex")]
@@ -65,3 +68,59 @@ pub struct Log {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub removed: Option<bool>,
 }
+
+impl Log {
+    /// Map a Move event to an Ethereum log:
+    /// the address is the last 20 bytes of the address of the module which defines the event type,
+    /// the first topic is the keccak256 hash of the canonical event type, the second topic is the sender address,
+    /// and the data is the BCS bytes of the event.
+    /// The block fields are None if the transaction of the event is not proposed to a block yet.
+    pub fn new_from_event(
+        event: IndexerEvent,
+        block_hash: Option<H256>,
+        block_number: Option<u64>,
+        transaction_index: Option<u64>,
+        log_index: Option<u64>,
+    ) -> Self {
+        let address = H160::from_slice(&event.event_type.address.to_vec()[12..]);
+        let topics = vec![
+            Self::event_type_topic(&event.event_type.to_canonical_string()),
+            H256::from_slice(event.sender.to_vec().as_slice()),
+        ];
+        Log {
+            address: address.into(),
+            topics: topics.into_iter().map(StrView).collect(),
+            data: StrView(event.event_data),
+            block_hash: block_hash.map(Into::into),
+            block_number: block_number.map(|number| U64::from(number).into()),
+            transaction_hash: Some(event.tx_hash.into()),
+            transaction_index: transaction_index.map(|index| U64::from(index).into()),
+            log_index: log_index.map(|index| U256::from(index).into()),
+            transaction_log_index: Some(U256::from(event.indexer_event_id.event_index).into()),
+            log_type: None,
+            removed: Some(false),
+        }
+    }
+
+    /// The topic of the event type, the keccak256 hash of the canonical event type string.
+    pub fn event_type_topic(canonical_event_type: &str) -> H256 {
+        H256::from(keccak256(canonical_event_type.as_bytes()))
+    }
+
+    /// Add the address and topics of the log to the bloom.
+    pub fn accrue_bloom(&self, bloom: &mut Bloom) {
+        bloom.accrue(self.address.0.as_bytes());
+        for topic in &self.topics {
+            bloom.accrue(topic.0.as_bytes());
+        }
+    }
+
+    /// Build the bloom of the logs.
+    pub fn logs_bloom<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Bloom {
+        let mut bloom = Bloom::default();
+        for log in logs {
+            log.accrue_bloom(&mut bloom);
+        }
+        bloom
+    }
+}
@@ -4,6 +4,7 @@
 pub mod block;
 pub mod bloom;
 pub mod ens;
+pub mod filter;
 pub mod log;
 pub mod other_fields;
 pub mod withdrawal;
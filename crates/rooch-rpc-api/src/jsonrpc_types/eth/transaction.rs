@@ -3,10 +3,11 @@
 
 use super::{
     ethereum_types::{bloom::Bloom, ens::NameOrAddress, log::Log, other_fields::OtherFields},
-    AccessList,
+    AccessList, AccessListItem,
 };
 use crate::jsonrpc_types::{BytesView, H160View, H256View, StrView};
-use ethers::types::{U256, U64};
+use ethers::types::{H160, H256, U256, U64};
+use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -157,6 +158,86 @@ pub struct Transaction {
     pub other: OtherFields,
 }
 
+impl Transaction {
+    /// Convert the transaction to the Ethereum transaction view,
+    /// the block fields are None if the transaction is not proposed to a block yet.
+    pub fn new_from_typed_transaction(
+        tx: TypedTransaction,
+        block_hash: Option<H256>,
+        block_number: Option<u64>,
+        transaction_index: Option<u64>,
+    ) -> Self {
+        let hash = tx.tx_hash();
+        let block_hash = block_hash.map(Into::into);
+        let block_number = block_number.map(|number| U64::from(number).into());
+        let transaction_index = transaction_index.map(|index| U64::from(index).into());
+        match tx {
+            TypedTransaction::Ethereum(eth_tx) => {
+                let eth_tx = eth_tx.0;
+                Transaction {
+                    hash: hash.into(),
+                    nonce: eth_tx.nonce.into(),
+                    block_hash,
+                    block_number,
+                    transaction_index,
+                    from: eth_tx.from.into(),
+                    to: eth_tx.to.map(Into::into),
+                    value: eth_tx.value.into(),
+                    gas_price: eth_tx.gas_price.map(Into::into),
+                    gas: eth_tx.gas.into(),
+                    input: StrView(eth_tx.input.to_vec()),
+                    v: eth_tx.v.into(),
+                    r: eth_tx.r.into(),
+                    s: eth_tx.s.into(),
+                    transaction_type: eth_tx.transaction_type.map(Into::into),
+                    access_list: eth_tx.access_list.map(|access_list| {
+                        access_list
+                            .0
+                            .into_iter()
+                            .map(|item| {
+                                AccessListItem::new(
+                                    item.address.into(),
+                                    item.storage_keys.into_iter().map(Into::into).collect(),
+                                )
+                            })
+                            .collect()
+                    }),
+                    max_priority_fee_per_gas: eth_tx.max_priority_fee_per_gas.map(Into::into),
+                    max_fee_per_gas: eth_tx.max_fee_per_gas.map(Into::into),
+                    chain_id: eth_tx.chain_id.map(Into::into),
+                    other: OtherFields::default(),
+                }
+            }
+            // The Rooch transaction has no Ethereum signature fields,
+            // the sender is the last 20 bytes of the Rooch address, and the input is the BCS bytes of the action.
+            TypedTransaction::Rooch(rooch_tx) => Transaction {
+                hash: hash.into(),
+                nonce: U256::from(rooch_tx.sequence_number()).into(),
+                block_hash,
+                block_number,
+                transaction_index,
+                from: H160::from_slice(&rooch_tx.sender().0.as_bytes()[12..]).into(),
+                to: None,
+                value: U256::zero().into(),
+                gas_price: None,
+                gas: U256::from(rooch_tx.max_gas_amount()).into(),
+                input: StrView(
+                    bcs::to_bytes(rooch_tx.action()).expect("bcs serialize action must success"),
+                ),
+                v: U64::zero().into(),
+                r: U256::zero().into(),
+                s: U256::zero().into(),
+                transaction_type: None,
+                access_list: None,
+                max_priority_fee_per_gas: None,
+                max_fee_per_gas: None,
+                chain_id: Some(U256::from(rooch_tx.chain_id()).into()),
+                other: OtherFields::default(),
+            },
+        }
+    }
+}
+
 /// Parameters for sending a transaction
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
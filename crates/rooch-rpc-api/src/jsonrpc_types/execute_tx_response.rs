@@ -3,11 +3,15 @@
 
 use super::BytesView;
 use super::{ModuleIdView, StateChangeSetView, StrView};
-use crate::jsonrpc_types::event_view::EventView;
+use crate::jsonrpc_types::event_view::{EventView, TransactionEventView};
 use crate::jsonrpc_types::H256View;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
+use moveos_types::execution_trace::{ExecutionTrace, TraceFrame};
+use moveos_types::gas_profile::{GasProfile, GasProfileFrame};
+use moveos_types::move_std::error::MoveAbortExplain;
+use moveos_types::state::AnnotatedStateChangeSet;
 use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::transaction::TransactionOutput;
+use moveos_types::transaction::{RawTransactionOutput, TransactionOutput};
 use rooch_types::transaction::{authenticator::Authenticator, TransactionSequenceInfo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -48,6 +52,9 @@ pub enum KeptVMStatusView {
     MoveAbort {
         location: AbortLocationView,
         abort_code: StrView<u64>,
+        /// The explanation of the abort code, decoded from the error constants of the aborting module.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        explain: Option<MoveAbortExplainView>,
     },
     ExecutionFailure {
         location: AbortLocationView,
@@ -57,6 +64,47 @@ pub enum KeptVMStatusView {
     MiscellaneousError,
 }
 
+impl KeptVMStatusView {
+    /// Attach the explanation to the `MoveAbort` status, the other statuses are unchanged.
+    pub fn with_abort_explain(self, abort_explain: Option<MoveAbortExplain>) -> Self {
+        match self {
+            Self::MoveAbort {
+                location,
+                abort_code,
+                explain,
+            } => Self::MoveAbort {
+                location,
+                abort_code,
+                explain: abort_explain.map(Into::into).or(explain),
+            },
+            status => status,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveAbortExplainView {
+    pub category_code: StrView<u64>,
+    pub category_name: Option<String>,
+    pub reason_code: StrView<u64>,
+    /// The name of the error constant, eg: `ErrorInsufficientBalance`
+    pub reason_name: Option<String>,
+    /// The doc comment of the error constant
+    pub code_description: Option<String>,
+}
+
+impl From<MoveAbortExplain> for MoveAbortExplainView {
+    fn from(explain: MoveAbortExplain) -> Self {
+        Self {
+            category_code: StrView(explain.category_code),
+            category_name: explain.category_name,
+            reason_code: StrView(explain.reason_code),
+            reason_name: explain.reason_name,
+            code_description: explain.code_description,
+        }
+    }
+}
+
 impl From<KeptVMStatus> for KeptVMStatusView {
     fn from(vm_status: KeptVMStatus) -> Self {
         match vm_status {
@@ -65,6 +113,7 @@ impl From<KeptVMStatus> for KeptVMStatusView {
             KeptVMStatus::MoveAbort(location, abort_code) => Self::MoveAbort {
                 location: location.into(),
                 abort_code: StrView(abort_code),
+                explain: None,
             },
             KeptVMStatus::ExecutionFailure {
                 location,
@@ -99,6 +148,7 @@ pub struct TransactionSequenceInfoView {
     pub tx_order: StrView<u64>,
     pub tx_order_signature: AuthenticatorView,
     pub tx_accumulator_root: H256View,
+    pub tx_timestamp: StrView<u64>,
 }
 
 impl From<TransactionSequenceInfo> for TransactionSequenceInfoView {
@@ -109,6 +159,7 @@ impl From<TransactionSequenceInfo> for TransactionSequenceInfoView {
                 transaction_sequence_info.tx_order_signature,
             ),
             tx_accumulator_root: transaction_sequence_info.tx_accumulator_root.into(),
+            tx_timestamp: StrView(transaction_sequence_info.tx_timestamp),
         }
     }
 }
@@ -164,6 +215,8 @@ pub struct ExecuteTransactionResponse {
     pub sequence_info: TransactionSequenceInfo,
     pub execution_info: TransactionExecutionInfo,
     pub output: TransactionOutput,
+    /// The explanation of the abort code, only exists if the transaction is aborted.
+    pub abort_explain: Option<MoveAbortExplain>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -175,10 +228,158 @@ pub struct ExecuteTransactionResponseView {
 
 impl From<ExecuteTransactionResponse> for ExecuteTransactionResponseView {
     fn from(response: ExecuteTransactionResponse) -> Self {
+        let mut execution_info = TransactionExecutionInfoView::from(response.execution_info);
+        execution_info.status = execution_info
+            .status
+            .with_abort_explain(response.abort_explain.clone());
+        let mut output = TransactionOutputView::from(response.output);
+        output.status = output.status.with_abort_explain(response.abort_explain);
         Self {
             sequence_info: response.sequence_info.into(),
-            execution_info: response.execution_info.into(),
-            output: response.output.into(),
+            execution_info,
+            output,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunTransactionResponse {
+    pub raw_output: RawTransactionOutput,
+    /// The decoded state change set, None if the state change set can not be decoded.
+    pub annotated_state_changeset: Option<AnnotatedStateChangeSet>,
+    /// The explanation of the abort code, only exists if the transaction is aborted.
+    pub abort_explain: Option<MoveAbortExplain>,
+}
+
+/// The result of the dry run, the output is not applied to the state.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DryRunTransactionResponseView {
+    pub status: KeptVMStatusView,
+    pub gas_used: u64,
+    pub events: Vec<TransactionEventView>,
+    /// The state change set, the state values are decoded if the types can be resolved.
+    pub state_changeset: StateChangeSetView,
+    /// The gas profile, only returned when the `profileGas` option is enabled.
+    pub gas_profile: Option<GasProfileView>,
+    /// The call stack leading to the failure, only returned when the transaction fails.
+    pub execution_trace: Option<ExecutionTraceView>,
+}
+
+impl From<DryRunTransactionResponse> for DryRunTransactionResponseView {
+    fn from(response: DryRunTransactionResponse) -> Self {
+        let state_changeset = match response.annotated_state_changeset {
+            Some(annotated_state_changeset) => annotated_state_changeset.into(),
+            None => response.raw_output.state_changeset.into(),
+        };
+        Self {
+            status: KeptVMStatusView::from(response.raw_output.status)
+                .with_abort_explain(response.abort_explain),
+            gas_used: response.raw_output.gas_used,
+            events: response
+                .raw_output
+                .events
+                .into_iter()
+                .map(|event| event.into())
+                .collect(),
+            state_changeset,
+            gas_profile: response.raw_output.gas_profile.map(Into::into),
+            execution_trace: response.raw_output.execution_trace.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasProfileFrameView {
+    pub name: String,
+    /// The gas charged in this frame, exclude the children frames
+    pub self_gas: u64,
+    pub instructions: u64,
+    pub native_calls: u64,
+    pub native_gas: u64,
+    pub storage_reads: u64,
+    pub storage_writes: u64,
+    pub children: Vec<GasProfileFrameView>,
+}
+
+impl From<GasProfileFrame> for GasProfileFrameView {
+    fn from(frame: GasProfileFrame) -> Self {
+        Self {
+            name: frame.name,
+            self_gas: frame.self_gas,
+            instructions: frame.instructions,
+            native_calls: frame.native_calls,
+            native_gas: frame.native_gas,
+            storage_reads: frame.storage_reads,
+            storage_writes: frame.storage_writes,
+            children: frame.children.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<GasProfileFrameView> for GasProfileFrame {
+    fn from(frame: GasProfileFrameView) -> Self {
+        Self {
+            name: frame.name,
+            self_gas: frame.self_gas,
+            instructions: frame.instructions,
+            native_calls: frame.native_calls,
+            native_gas: frame.native_gas,
+            storage_reads: frame.storage_reads,
+            storage_writes: frame.storage_writes,
+            children: frame.children.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The gas profile of a transaction, the gas used is attributed to the Move function call tree.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasProfileView {
+    pub root: GasProfileFrameView,
+}
+
+impl From<GasProfile> for GasProfileView {
+    fn from(profile: GasProfile) -> Self {
+        Self {
+            root: profile.root.into(),
+        }
+    }
+}
+
+impl From<GasProfileView> for GasProfile {
+    fn from(profile: GasProfileView) -> Self {
+        GasProfile::new(profile.root.into())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TraceFrameView {
+    pub function: String,
+    /// The rendered arguments, the arguments of the top level functions are the BCS bytes in hex
+    pub args: Vec<String>,
+    /// The latest values stored to the locals of the frame, in the execution order
+    pub locals: Vec<String>,
+}
+
+impl From<TraceFrame> for TraceFrameView {
+    fn from(frame: TraceFrame) -> Self {
+        Self {
+            function: frame.function,
+            args: frame.args,
+            locals: frame.locals,
+        }
+    }
+}
+
+/// The call stack leading to the failure of a transaction, the outermost frame is the first.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExecutionTraceView {
+    pub frames: Vec<TraceFrameView>,
+}
+
+impl From<ExecutionTrace> for ExecutionTraceView {
+    fn from(trace: ExecutionTrace) -> Self {
+        Self {
+            frames: trace.frames.into_iter().map(Into::into).collect(),
         }
     }
 }
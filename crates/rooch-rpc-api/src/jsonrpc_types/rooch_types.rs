@@ -6,10 +6,12 @@ use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
 use crate::jsonrpc_types::transaction_view::TransactionWithInfoView;
 use crate::jsonrpc_types::{
     move_types::{MoveActionTypeView, MoveActionView},
-    BytesView, IndexerTableChangeSetView, StateView, StrView, StructTagView,
+    AccountAddressView, BytesView, IndexerTableChangeSetView, StateView, StrView, StructTagView,
 };
 use move_core_types::u256::U256;
+use moveos_types::moveos_std::package_registry::PackageInfo;
 use rooch_types::framework::coin::CoinInfo;
+use rooch_types::framework::transaction_fee::GasPrice;
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::transaction::{AbstractTransaction, TransactionType, TypedTransaction};
@@ -108,3 +110,53 @@ impl<CoinType> From<CoinInfo<CoinType>> for CoinInfoView {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasPriceView {
+    /// The base fee per gas unit, the transaction pays `gas_used * base_fee`
+    pub base_fee: StrView<u64>,
+    /// The gas used by the transactions in the current fee window
+    pub window_gas_used: StrView<u64>,
+    /// How many transactions in the current fee window
+    pub window_tx_count: StrView<u64>,
+}
+
+impl From<GasPrice> for GasPriceView {
+    fn from(gas_price: GasPrice) -> Self {
+        Self {
+            base_fee: StrView(gas_price.base_fee),
+            window_gas_used: StrView(gas_price.window_gas_used),
+            window_tx_count: StrView(gas_price.window_tx_count),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PackageInfoView {
+    pub name: String,
+    pub version: String,
+    /// The digest of the package source files, empty if the digest is unknown
+    pub source_digest: String,
+    /// The addresses of the packages this package depends on
+    pub dependencies: Vec<AccountAddressView>,
+    pub modules: Vec<String>,
+    /// The number of the upgrades since the package is first published
+    pub upgrade_number: StrView<u64>,
+}
+
+impl From<PackageInfo> for PackageInfoView {
+    fn from(package_info: PackageInfo) -> Self {
+        Self {
+            name: package_info.name.to_string(),
+            version: package_info.version.to_string(),
+            source_digest: package_info.source_digest.to_string(),
+            dependencies: package_info.dependencies.into_iter().map(StrView).collect(),
+            modules: package_info
+                .modules
+                .into_iter()
+                .map(|module| module.to_string())
+                .collect(),
+            upgrade_number: StrView(package_info.upgrade_number),
+        }
+    }
+}
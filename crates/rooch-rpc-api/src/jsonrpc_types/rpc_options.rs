@@ -39,3 +39,21 @@ impl EventOptions {
         self
     }
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DryRunOptions {
+    /// If true, the gas used is attributed to the Move function call tree and returned in the response.
+    pub profile_gas: bool,
+}
+
+impl DryRunOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn profile_gas(mut self, profile_gas: bool) -> Self {
+        self.profile_gas = profile_gas;
+        self
+    }
+}
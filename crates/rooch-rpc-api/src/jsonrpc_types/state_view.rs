@@ -6,7 +6,10 @@ use move_core_types::effects::Op;
 use moveos_types::state::TableChangeSet;
 use moveos_types::{
     moveos_std::object::ObjectID,
-    state::{AnnotatedState, State, StateChangeSet, TableChange, TableTypeInfo},
+    state::{
+        AnnotatedState, AnnotatedStateChangeSet, AnnotatedTableChange, State, StateChangeSet,
+        TableChange, TableTypeInfo,
+    },
 };
 use rooch_types::indexer::state::{IndexerStateChangeSet, IndexerTableChangeSet, StateFilter};
 use schemars::JsonSchema;
@@ -94,6 +97,24 @@ impl From<StateChangeSet> for StateChangeSetView {
     }
 }
 
+impl From<AnnotatedStateChangeSet> for StateChangeSetView {
+    fn from(state_change_set: AnnotatedStateChangeSet) -> Self {
+        Self {
+            new_tables: state_change_set
+                .new_tables
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            removed_tables: state_change_set.removed_tables,
+            changes: state_change_set
+                .changes
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum OpView<T> {
@@ -112,6 +133,16 @@ impl From<Op<State>> for OpView<StateView> {
     }
 }
 
+impl From<Op<AnnotatedState>> for OpView<StateView> {
+    fn from(op: Op<AnnotatedState>) -> Self {
+        match op {
+            Op::New(data) => Self::New(data.into()),
+            Op::Modify(data) => Self::Modify(data.into()),
+            Op::Delete => Self::Delete,
+        }
+    }
+}
+
 impl From<OpView<StateView>> for Op<State> {
     fn from(op: OpView<StateView>) -> Self {
         match op {
@@ -141,6 +172,19 @@ impl From<TableChange> for TableChangeView {
     }
 }
 
+impl From<AnnotatedTableChange> for TableChangeView {
+    fn from(table_change: AnnotatedTableChange) -> Self {
+        Self {
+            entries: table_change
+                .entries
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            size_increment: table_change.size_increment,
+        }
+    }
+}
+
 impl From<TableChangeView> for TableChange {
     fn from(table_change: TableChangeView) -> Self {
         Self {
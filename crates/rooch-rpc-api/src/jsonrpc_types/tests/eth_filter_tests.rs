@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::eth::ethereum_types::{
+    bloom::Bloom,
+    filter::{Filter, ValueOrArray},
+    log::Log,
+};
+use crate::jsonrpc_types::StrView;
+use ethers::types::{H160, H256};
+use std::str::FromStr;
+
+fn test_log() -> Log {
+    Log {
+        address: StrView(H160::from_low_u64_be(3)),
+        topics: vec![
+            StrView(Log::event_type_topic("0x3::coin::DepositEvent")),
+            StrView(H256::from_low_u64_be(42)),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_bloom_accrue() {
+    let log = test_log();
+    let bloom = Log::logs_bloom(&[log.clone()]);
+    assert!(bloom.contains_input(log.address.0.as_bytes()));
+    for topic in &log.topics {
+        assert!(bloom.contains_input(topic.0.as_bytes()));
+    }
+    assert!(bloom.contains_bloom(&Bloom::default()));
+    assert!(!Bloom::default().contains_bloom(&bloom));
+
+    let json = serde_json::to_string(&bloom).unwrap();
+    assert!(json.starts_with("\"0x"));
+    let bloom2: Bloom = serde_json::from_str(&json).unwrap();
+    assert_eq!(bloom, bloom2);
+}
+
+#[test]
+fn test_filter_matches() {
+    let log = test_log();
+    assert!(Filter::default().matches(&log));
+
+    let filter = Filter {
+        address: Some(ValueOrArray::Array(vec![
+            StrView(H160::from_low_u64_be(1)),
+            StrView(H160::from_low_u64_be(3)),
+        ])),
+        topics: Some(vec![None, Some(ValueOrArray::Value(log.topics[1]))]),
+        ..Default::default()
+    };
+    assert!(filter.matches(&log));
+
+    let filter = Filter {
+        address: Some(ValueOrArray::Value(StrView(H160::from_low_u64_be(1)))),
+        ..Default::default()
+    };
+    assert!(!filter.matches(&log));
+
+    let filter = Filter {
+        topics: Some(vec![None, None, Some(ValueOrArray::Value(log.topics[0]))]),
+        ..Default::default()
+    };
+    assert!(!filter.matches(&log));
+}
+
+#[test]
+fn test_filter_json() {
+    let json = r#"{"fromBlock":"0x1","toBlock":"latest","address":"0x0000000000000000000000000000000000000003","topics":[null,["0x000000000000000000000000000000000000000000000000000000000000002a"]]}"#;
+    let filter: Filter = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        filter.address,
+        Some(ValueOrArray::Value(StrView(
+            H160::from_str("0x0000000000000000000000000000000000000003").unwrap()
+        )))
+    );
+    assert_eq!(filter.topics.as_ref().unwrap().len(), 2);
+    assert!(filter.matches(&test_log()));
+}
@@ -2,11 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::{
-    AccountAddressView, H256View, TransactionExecutionInfoView, TransactionSequenceInfoView,
-    TransactionView,
+    AccountAddressView, H256View, StrView, TransactionExecutionInfoView,
+    TransactionSequenceInfoView, TransactionView,
 };
+use accumulator::proof::AccumulatorProof;
 use rooch_types::indexer::transaction_filter::TransactionFilter;
-use rooch_types::transaction::TransactionWithInfo;
+use rooch_types::transaction::{TransactionProof, TransactionWithInfo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -78,3 +79,56 @@ impl From<TransactionFilterView> for TransactionFilter {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccumulatorProofView {
+    /// Siblings are ordered from the bottom level to the root level.
+    pub siblings: Vec<H256View>,
+}
+
+impl From<AccumulatorProof> for AccumulatorProofView {
+    fn from(proof: AccumulatorProof) -> Self {
+        Self {
+            siblings: proof.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<AccumulatorProofView> for AccumulatorProof {
+    fn from(proof: AccumulatorProofView) -> Self {
+        AccumulatorProof::new(proof.siblings.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionProofView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    pub accumulator_order: StrView<u64>,
+    pub tx_accumulator_root: H256View,
+    pub proof: AccumulatorProofView,
+}
+
+impl From<TransactionProof> for TransactionProofView {
+    fn from(proof: TransactionProof) -> Self {
+        Self {
+            tx_order: StrView(proof.tx_order),
+            tx_hash: proof.tx_hash.into(),
+            accumulator_order: StrView(proof.accumulator_order),
+            tx_accumulator_root: proof.tx_accumulator_root.into(),
+            proof: proof.proof.into(),
+        }
+    }
+}
+
+impl From<TransactionProofView> for TransactionProof {
+    fn from(proof: TransactionProofView) -> Self {
+        TransactionProof::new(
+            proof.tx_order.0,
+            proof.tx_hash.into(),
+            proof.accumulator_order.0,
+            proof.tx_accumulator_root.into(),
+            proof.proof.into(),
+        )
+    }
+}
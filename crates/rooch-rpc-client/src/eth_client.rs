@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use ethers::types::{H160, H256, U256};
+use ethers::types::{H160, H256, U256, U64};
 use jsonrpsee::http_client::HttpClient;
 use rooch_rpc_api::api::eth_api::EthAPIClient;
 use rooch_rpc_api::jsonrpc_types::{H256View, StrView};
@@ -40,13 +40,34 @@ impl EthRpcClient {
         &self,
         num: BlockNumber,
         include_txs: bool,
-    ) -> Result<Block<TransactionType>> {
+    ) -> Result<Option<Block<TransactionType>>> {
         Ok(self
             .http
             .get_block_by_number(num.into(), include_txs)
             .await?)
     }
 
+    pub async fn get_block_transaction_count_by_number(
+        &self,
+        num: BlockNumber,
+    ) -> Result<Option<StrView<U256>>> {
+        Ok(self
+            .http
+            .get_block_transaction_count_by_number(num.into())
+            .await?)
+    }
+
+    pub async fn transaction_by_block_number_and_index(
+        &self,
+        num: BlockNumber,
+        index: U64,
+    ) -> Result<Option<Transaction>> {
+        Ok(self
+            .http
+            .transaction_by_block_number_and_index(num.into(), index.into())
+            .await?)
+    }
+
     pub async fn get_balance(
         &self,
         address: H160,
@@ -114,7 +135,7 @@ impl EthRpcClient {
         &self,
         hash: H256,
         include_txs: bool,
-    ) -> Result<Block<TransactionType>> {
+    ) -> Result<Option<Block<TransactionType>>> {
         Ok(self.http.block_by_hash(hash.into(), include_txs).await?)
     }
 }
@@ -5,6 +5,7 @@ use anyhow::Result;
 use eth_client::EthRpcClient;
 use jsonrpsee::core::client::ClientT;
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::ws_client::WsClientBuilder;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use moveos_types::access_path::AccessPath;
@@ -20,6 +21,7 @@ use std::time::Duration;
 
 pub mod client_config;
 pub mod eth_client;
+pub mod proof_verifier;
 pub mod rooch_client;
 pub mod wallet_context;
 
@@ -56,9 +58,22 @@ impl ClientBuilder {
                 .build(http)?,
         );
 
+        // The WebSocket client is only required by the subscriptions
+        let ws_client = match self.ws_url {
+            Some(ws_url) => Some(Arc::new(
+                WsClientBuilder::default()
+                    .max_request_body_size(2 << 30)
+                    .max_concurrent_requests(self.max_concurrent_requests)
+                    .request_timeout(self.request_timeout)
+                    .build(ws_url)
+                    .await?,
+            )),
+            None => None,
+        };
+
         Ok(Client {
             http: http_client.clone(),
-            rooch: RoochRpcClient::new(http_client.clone()),
+            rooch: RoochRpcClient::new(http_client.clone(), ws_client),
             eth: EthRpcClient::new(http_client),
         })
     }
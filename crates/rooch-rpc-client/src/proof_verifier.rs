@@ -0,0 +1,130 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionProofView;
+use rooch_types::transaction::{AbstractTransaction, TransactionProof, TypedTransaction};
+
+/// Verify the `tx` is sequenced at the `tx_order` under the `expected_root`, without trusting the node.
+/// The `expected_root` should be the `tx_accumulator_root` of the tx at `proof.accumulator_order`,
+/// which is obtained from a trusted source.
+pub fn verify_transaction_proof(
+    tx: &TypedTransaction,
+    tx_order: u64,
+    expected_root: H256,
+    proof: TransactionProofView,
+) -> Result<()> {
+    let proof = TransactionProof::from(proof);
+    ensure!(
+        proof.tx_order == tx_order,
+        "Tx order mismatch, expect: {}, actual in proof: {}",
+        tx_order,
+        proof.tx_order
+    );
+    let tx_hash = tx.tx_hash();
+    ensure!(
+        proof.tx_hash == tx_hash,
+        "Tx hash mismatch, expect: {:?}, actual in proof: {:?}",
+        tx_hash,
+        proof.tx_hash
+    );
+    ensure!(
+        proof.tx_accumulator_root == expected_root,
+        "Tx accumulator root mismatch, expect: {:?}, actual in proof: {:?}",
+        expected_root,
+        proof.tx_accumulator_root
+    );
+    proof.verify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_transaction_proof;
+    use accumulator::tree_store::mock::MockAccumulatorStore;
+    use accumulator::{Accumulator, MerkleAccumulator};
+    use moveos_types::h256::H256;
+    use moveos_types::transaction::MoveAction;
+    use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionProofView;
+    use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+    use rooch_types::transaction::authenticator::Authenticator;
+    use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+    use rooch_types::transaction::{AbstractTransaction, TransactionProof, TypedTransaction};
+    use std::sync::Arc;
+
+    fn mock_tx(sequence_number: u64) -> TypedTransaction {
+        let tx_data = RoochTransactionData::new_for_test(
+            RoochAddress::random(),
+            sequence_number,
+            MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+        );
+        TypedTransaction::Rooch(RoochTransaction::new(
+            tx_data,
+            Authenticator::new(0, vec![]),
+        ))
+    }
+
+    /// Sequence the txs in an accumulator, return the txs, the root and the accumulator.
+    fn sequence_txs(count: u64) -> (Vec<TypedTransaction>, H256, MerkleAccumulator) {
+        let accumulator = MerkleAccumulator::new_empty(Arc::new(MockAccumulatorStore::new()));
+        let txs = (0..count).map(mock_tx).collect::<Vec<_>>();
+        let tx_hashes = txs.iter().map(|tx| tx.tx_hash()).collect::<Vec<_>>();
+        let root = accumulator.append(&tx_hashes).unwrap();
+        (txs, root, accumulator)
+    }
+
+    fn proof_view(
+        accumulator: &MerkleAccumulator,
+        tx_order: u64,
+        tx_hash: H256,
+        root: H256,
+    ) -> TransactionProofView {
+        let proof = accumulator.get_proof(tx_order).unwrap().unwrap();
+        TransactionProof::new(tx_order, tx_hash, accumulator.num_leaves() - 1, root, proof).into()
+    }
+
+    #[test]
+    fn test_verify_transaction_proof() {
+        let (txs, root, accumulator) = sequence_txs(10);
+        for (tx_order, tx) in txs.iter().enumerate() {
+            let tx_order = tx_order as u64;
+            let proof = proof_view(&accumulator, tx_order, tx.tx_hash(), root);
+            verify_transaction_proof(tx, tx_order, root, proof).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_verify_transaction_proof_with_wrong_tx_order() {
+        let (txs, root, accumulator) = sequence_txs(10);
+        let tx = &txs[3];
+        let proof = proof_view(&accumulator, 3, tx.tx_hash(), root);
+        // The tx is not sequenced at the expected tx order
+        assert!(verify_transaction_proof(tx, 4, root, proof.clone()).is_err());
+
+        // The proof claims another tx order
+        let mut wrong_proof = proof.clone();
+        wrong_proof.tx_order.0 = 4;
+        assert!(verify_transaction_proof(tx, 4, root, wrong_proof).is_err());
+
+        // The tx order aliases to the proved leaf index above the proof depth
+        let alias_order = 3 + (1 << proof.proof.siblings.len());
+        let mut wrong_proof = proof;
+        wrong_proof.tx_order.0 = alias_order;
+        wrong_proof.accumulator_order.0 = u64::MAX;
+        assert!(verify_transaction_proof(tx, alias_order, root, wrong_proof).is_err());
+    }
+
+    #[test]
+    fn test_verify_transaction_proof_with_wrong_tx_or_root() {
+        let (txs, root, accumulator) = sequence_txs(10);
+        let proof = proof_view(&accumulator, 3, txs[3].tx_hash(), root);
+        // The proof is for another tx
+        assert!(verify_transaction_proof(&txs[4], 3, root, proof.clone()).is_err());
+        // The root is not the expected root
+        assert!(verify_transaction_proof(&txs[3], 3, H256::random(), proof).is_err());
+
+        // The proof of another tx is relabeled with the tx hash
+        let wrong_proof = proof_view(&accumulator, 4, txs[3].tx_hash(), root);
+        assert!(verify_transaction_proof(&txs[3], 4, root, wrong_proof).is_err());
+    }
+}
@@ -2,37 +2,53 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use jsonrpsee::core::client::Subscription;
 use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::ws_client::WsClient;
 use moveos_types::h256::H256;
 use moveos_types::{
     access_path::AccessPath,
     state::{MoveStructType, State},
     transaction::FunctionCall,
 };
+use rooch_da::batch::DABatch;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::{
-    account_view::BalanceInfoView, transaction_view::TransactionWithInfoView,
+    account_view::BalanceInfoView,
+    block_view::{BlockSubmitStatusView, BlockView},
+    event_view::{EventFilterView, IndexerEventView},
+    transaction_view::{TransactionFilterView, TransactionProofView, TransactionWithInfoView},
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    EventOptions, EventPageView, StateOptions, StatesPageView, StructTagView,
+    EventOptions, EventPageView, IndexerTableChangeSetView, StateFilterView, StateOptions,
+    StatesPageView, StructTagView,
 };
 use rooch_rpc_api::jsonrpc_types::{BytesView, TransactionWithInfoPageView};
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, StateView};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunOptions, DryRunTransactionResponseView, ExecuteTransactionResponseView, StateView,
+};
 use rooch_types::{account::Account, address::RoochAddress, transaction::rooch::RoochTransaction};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct RoochRpcClient {
     http: Arc<HttpClient>,
+    ws: Option<Arc<WsClient>>,
 }
 
 // TODO: call args are uniformly defined in jsonrpc types?
 // example execute_view_function get_events_by_event_handle
 
 impl RoochRpcClient {
-    pub fn new(http: Arc<HttpClient>) -> Self {
-        Self { http }
+    pub fn new(http: Arc<HttpClient>, ws: Option<Arc<WsClient>>) -> Self {
+        Self { http, ws }
+    }
+
+    fn ws(&self) -> Result<&WsClient> {
+        self.ws.as_deref().ok_or_else(|| {
+            anyhow::anyhow!("The subscription requires a WebSocket client, please set the ws url")
+        })
     }
 
     pub async fn get_chain_id(&self) -> Result<u64> {
@@ -47,6 +63,18 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    pub async fn dry_run_tx(
+        &self,
+        tx: RoochTransaction,
+        dry_run_options: Option<DryRunOptions>,
+    ) -> Result<DryRunTransactionResponseView> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        self.http
+            .dry_run_raw_transaction(tx_payload.into(), dry_run_options)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -95,6 +123,39 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+    ) -> Result<Option<TransactionProofView>> {
+        Ok(self.http.get_transaction_proof(tx_order.into()).await?)
+    }
+
+    pub async fn get_block_by_number(&self, block_number: u128) -> Result<Option<BlockView>> {
+        Ok(self.http.get_block_by_number(block_number.into()).await?)
+    }
+
+    pub async fn get_latest_block(&self) -> Result<Option<BlockView>> {
+        Ok(self.http.get_latest_block().await?)
+    }
+
+    pub async fn get_block_submit_status(
+        &self,
+        block_number: u128,
+    ) -> Result<Option<BlockSubmitStatusView>> {
+        Ok(self
+            .http
+            .get_block_submit_status(block_number.into())
+            .await?)
+    }
+
+    pub async fn get_da_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        self.http
+            .get_da_batch(block_number.into())
+            .await?
+            .map(|bytes| DABatch::decode(&bytes.0))
+            .transpose()
+    }
+
     pub async fn get_sequence_number(&self, sender: RoochAddress) -> Result<u64> {
         Ok(self
             .get_states(AccessPath::resource(sender.into(), Account::struct_tag()))
@@ -176,4 +237,25 @@ impl RoochRpcClient {
             .get_balances(account_addr, cursor, limit.map(Into::into))
             .await?)
     }
+
+    pub async fn subscribe_transactions(
+        &self,
+        filter: TransactionFilterView,
+    ) -> Result<Subscription<TransactionWithInfoView>> {
+        Ok(self.ws()?.subscribe_transactions(filter).await?)
+    }
+
+    pub async fn subscribe_events(
+        &self,
+        filter: EventFilterView,
+    ) -> Result<Subscription<IndexerEventView>> {
+        Ok(self.ws()?.subscribe_events(filter).await?)
+    }
+
+    pub async fn subscribe_states(
+        &self,
+        filter: StateFilterView,
+    ) -> Result<Subscription<IndexerTableChangeSetView>> {
+        Ok(self.ws()?.subscribe_states(filter).await?)
+    }
 }
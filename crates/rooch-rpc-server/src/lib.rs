@@ -6,6 +6,7 @@ use crate::server::rooch_server::RoochServer;
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_logger::RpcLogger;
 use crate::service::rpc_service::RpcService;
+use crate::service::subscription_service::SubscriptionService;
 use anyhow::{Error, Result};
 use coerce::actor::scheduler::timer::Timer;
 use coerce::actor::{system::ActorSystem, IntoActor};
@@ -20,9 +21,14 @@ use raw_store::StoreInstance;
 use rooch_config::indexer_config::IndexerConfig;
 use rooch_config::server_config::ServerConfig;
 use rooch_config::store_config::StoreConfig;
-use rooch_config::{BaseConfig, RoochOpt, ServerOpt};
+use rooch_config::{BaseConfig, RoochOpt, ServerMode, ServerOpt};
+use rooch_da::actor::da::DAActor;
+use rooch_da::proxy::DAProxy;
+use rooch_da::server::LocalDAServer;
 use rooch_executor::actor::executor::ExecutorActor;
 use rooch_executor::proxy::ExecutorProxy;
+use rooch_follower::actor::follower::FollowerActor;
+use rooch_follower::actor::messages::FollowTick;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::indexer_reader::IndexerReader;
 use rooch_indexer::proxy::IndexerProxy;
@@ -31,6 +37,7 @@ use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair};
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
 use rooch_proposer::proxy::ProposerProxy;
+use rooch_proposer::submitter::FileBlockSubmitter;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
 use rooch_rpc_api::api::RoochRpcModule;
@@ -40,10 +47,12 @@ use rooch_store::RoochStore;
 use rooch_types::address::RoochAddress;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::{GenesisError, RoochError};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use serde_json::json;
 use std::env;
 use std::fmt::Debug;
 use std::net::SocketAddr;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tower_http::cors::{AllowOrigin, CorsLayer};
@@ -53,9 +62,17 @@ use tracing::info;
 pub mod server;
 pub mod service;
 
+#[cfg(test)]
+mod tests;
+
 /// This exit code means is that the server failed to start and required human intervention.
 static R_EXIT_CODE_NEED_HELP: i32 = 120;
 
+/// The directory under the data dir to save the signed blocks submitted by the proposer.
+const SUBMITTED_BLOCKS_DIR: &str = "submitted_blocks";
+/// The directory under the data dir of the local DA server.
+const DA_DIR: &str = "da";
+
 pub struct ServerHandle {
     handle: jsonrpsee::server::ServerHandle,
     timers: Vec<Timer>,
@@ -169,6 +186,13 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         ServerConfig::new_with_port(port)
     });
     let chain_id_opt = opt.chain_id.clone().unwrap_or_default();
+    let server_mode = opt.mode();
+    let follower_config = match server_mode {
+        ServerMode::Sequencer => None,
+        ServerMode::Follower => Some(opt.follower_config().ok_or(anyhow::anyhow!(
+            "The leader-url and leader-sequencer-address are required in follower mode"
+        ))?),
+    };
 
     let addr: SocketAddr = format!("{}:{}", config.host, config.port).parse()?;
     let actor_system = ActorSystem::global_system();
@@ -178,6 +202,10 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let mut store_config = StoreConfig::default();
     store_config.merge_with_opt_with_init(opt, Arc::new(base_config.clone()), true)?;
     let (moveos_store, rooch_store) = init_storage(&store_config)?;
+    //TODO submit the blocks to the on-chain SCC contract when the L1 submitter is ready
+    let block_submitter =
+        FileBlockSubmitter::new(base_config.data_dir().join(SUBMITTED_BLOCKS_DIR))?;
+    let da_dir = base_config.data_dir().join(DA_DIR);
 
     //Init indexer store
     let mut indexer_config = IndexerConfig::default();
@@ -191,6 +219,7 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         moveos_store.clone(),
         rooch_store.clone(),
     )?
+    .with_trace_failed_txs(opt.trace_failed_txs())
     .into_actor(Some("Executor"), &actor_system)
     .await?;
     let executor_proxy = ExecutorProxy::new(executor.into());
@@ -200,9 +229,11 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         || server_opt.proposer_keypair.is_none()
         || server_opt.relayer_keypair.is_none()
     {
-        // only for integration test, generate test key pairs
-        if chain_id_opt.is_test_or_dev_or_local() {
-            let result = generate_new_key_pair(None, None, None, None)?;
+        // only for integration test, generate test key pairs.
+        // The follower does not sign transactions, the local actors use the generated key pairs.
+        if chain_id_opt.is_test_or_dev_or_local() || server_mode == ServerMode::Follower {
+            let result =
+                generate_new_key_pair(BuiltinAuthValidator::Rooch, None, None, None, None)?;
             let kp: RoochKeyPair =
                 retrieve_key_pair(&result.key_pair_data.private_key_encryption, None)?;
             server_opt.sequencer_keypair = Some(kp.copy());
@@ -219,7 +250,9 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let sequencer_keypair = server_opt.sequencer_keypair.unwrap();
     let sequencer_account: RoochAddress = (&sequencer_keypair.public()).into();
     info!("RPC Server sequencer address: {:?}", sequencer_account);
-    let sequencer = SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?
+    // The transaction batches are put to the DA by the sequencer, the DA only accepts the data signed by it.
+    let da_signer = sequencer_keypair.copy();
+    let sequencer = SequencerActor::new(sequencer_keypair, rooch_store.clone(), is_genesis)?
         .into_actor(Some("Sequencer"), &actor_system)
         .await?;
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
@@ -228,19 +261,32 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let proposer_keypair = server_opt.proposer_keypair.unwrap();
     let proposer_account: RoochAddress = (&proposer_keypair.public()).into();
     info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(proposer_keypair)
-        .into_actor(Some("Proposer"), &actor_system)
+    let da_server = LocalDAServer::new(da_dir, sequencer_account)?;
+    let da = DAActor::new(da_signer, da_server)
+        .into_actor(Some("DA"), &actor_system)
         .await?;
+    let da_proxy = DAProxy::new(da.into());
+    let proposer = ProposerActor::new(
+        proposer_keypair,
+        rooch_store.clone(),
+        block_submitter,
+        da_proxy,
+    )?
+    .into_actor(Some("Proposer"), &actor_system)
+    .await?;
     let proposer_proxy = ProposerProxy::new(proposer.clone().into());
     //TODO load from config
     let block_propose_duration_in_seconds: u64 = 5;
     let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
+    // The follower syncs the blocks from the leader, so it does not propose blocks.
+    if server_mode == ServerMode::Sequencer {
+        let proposer_timer = Timer::start(
+            proposer,
+            Duration::from_secs(block_propose_duration_in_seconds),
+            ProposeBlock {},
+        );
+        timers.push(proposer_timer);
+    }
 
     // Init indexer
     let indexer_executor = IndexerActor::new(indexer_store, indexer_reader, moveos_store)?
@@ -254,13 +300,17 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
+        server_mode,
+        SubscriptionService::new(),
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
     let ethereum_relayer_config = opt.ethereum_relayer_config();
     let bitcoin_relayer_config = opt.bitcoin_relayer_config();
 
-    if ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some() {
+    if server_mode == ServerMode::Sequencer
+        && (ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some())
+    {
         let relayer_keypair = server_opt.relayer_keypair.unwrap();
         let relayer_account: RoochAddress = (&relayer_keypair.public()).into();
         info!("RPC Server relayer address: {:?}", relayer_account);
@@ -283,6 +333,30 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         timers.push(relayer_timer);
     }
 
+    if let Some(follower_config) = follower_config {
+        let sequencer = RoochAddress::from_str(follower_config.leader_sequencer_address.as_str())?;
+        info!(
+            "RPC Server follows the leader {} with sequencer address: {:?}",
+            follower_config.leader_url, sequencer
+        );
+        let follower = FollowerActor::new_for_leader(
+            follower_config.leader_url.as_str(),
+            sequencer,
+            rpc_service.clone(),
+            rooch_store,
+        )
+        .await?
+        .into_actor(Some("Follower"), &actor_system)
+        .await?;
+        let follow_tick_in_seconds: u64 = 1;
+        let follower_timer = Timer::start(
+            follower,
+            Duration::from_secs(follow_tick_in_seconds),
+            FollowTick {},
+        );
+        timers.push(follower_timer);
+    }
+
     let acl = match env::var("ACCESS_CONTROL_ALLOW_ORIGIN") {
         Ok(value) => {
             let allow_hosts = value
@@ -329,7 +403,10 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let methods_names = rpc_module_builder.module.method_names().collect::<Vec<_>>();
     let handle = server.start(rpc_module_builder.module)?;
 
-    info!("JSON-RPC HTTP Server start listening {:?}", addr);
+    info!(
+        "JSON-RPC HTTP and WebSocket Server start listening {:?}",
+        addr
+    );
     info!("Available JSON-RPC methods : {:?}", methods_names);
 
     Ok(ServerHandle {
@@ -355,7 +432,7 @@ fn _build_rpc_api<M: Send + Sync + 'static>(mut rpc_module: RpcModule<M>) -> Rpc
     rpc_module
 }
 
-fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
+pub fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
     let (rooch_db_path, moveos_db_path) = (
         store_config.get_rooch_store_dir(),
         store_config.get_moveos_store_dir(),
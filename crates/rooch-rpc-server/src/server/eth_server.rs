@@ -1,19 +1,20 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::service::{aggregate_service::AggregateService, rpc_service::RpcService};
+use crate::service::{
+    aggregate_service::AggregateService, eth_filter_service::EthFilterService,
+    rpc_service::RpcService,
+};
 use ethers::types::{H160, U256, U64};
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpcError, RpcResult},
     RpcModule,
 };
+use move_core_types::{account_address::AccountAddress, vm_status::KeptVMStatus};
 use moveos_types::{
     access_path::AccessPath, gas_config::GasConfig, h256::H256, state::MoveStructType,
 };
-use rooch_rpc_api::{
-    api::eth_api::EthNetAPIServer,
-    jsonrpc_types::{eth::ethereum_types::bloom::Bloom, H160View},
-};
+use rooch_rpc_api::{api::eth_api::EthNetAPIServer, jsonrpc_types::H160View};
 use rooch_rpc_api::{
     api::{
         eth_api::{EthAPIServer, TransactionType},
@@ -23,10 +24,11 @@ use rooch_rpc_api::{
         eth::{
             ethereum_types::{
                 block::{Block, BlockNumber},
+                filter::{Filter, FilterChanges},
+                log::Log,
                 other_fields::OtherFields,
-                withdrawal::Withdrawal,
             },
-            AccessList, CallRequest, EthFeeHistory, Transaction, TransactionReceipt,
+            CallRequest, EthFeeHistory, Transaction, TransactionReceipt,
         },
         BytesView, H256View, StrView,
     },
@@ -34,19 +36,29 @@ use rooch_rpc_api::{
 use rooch_types::{
     account::Account,
     address::{EthereumAddress, MultiChainAddress},
+    block::Block as RoochBlock,
     framework::gas_coin::GasCoin,
     transaction::{AbstractTransaction, TypedTransaction},
 };
-use rooch_types::{chain_id::ChainID, transaction::ethereum::EthereumTransaction};
-use std::iter;
+use rooch_types::{
+    chain_id::ChainID,
+    transaction::ethereum::{EthereumTransaction, EthereumTransactionBuilder},
+};
 use std::str::FromStr;
-use std::time::SystemTime;
 use tracing::info;
 
+/// The max block count of the `eth_feeHistory` query
+const MAX_FEE_HISTORY_BLOCK_COUNT: u64 = 100;
+
+/// The keccak256 hash of the RLP encoding of the empty uncle list
+const EMPTY_UNCLES_HASH: &str =
+    "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347";
+
 pub struct EthServer {
     chain_id: ChainID,
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    filter_service: EthFilterService,
 }
 
 impl EthServer {
@@ -55,156 +67,271 @@ impl EthServer {
         rpc_service: RpcService,
         aggregate_service: AggregateService,
     ) -> Self {
+        let filter_service = EthFilterService::new(rpc_service.clone());
         Self {
             chain_id,
             rpc_service,
             aggregate_service,
+            filter_service,
         }
     }
-}
 
-#[async_trait]
-impl EthAPIServer for EthServer {
-    async fn chain_id(&self) -> RpcResult<String> {
-        Ok(format!("0x{:x}", self.chain_id.id()))
+    async fn get_sequence_number(&self, account_address: AccountAddress) -> RpcResult<u64> {
+        Ok(self
+            .rpc_service
+            .get_states(AccessPath::resource(account_address, Account::struct_tag()))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state_view| state_view.cast::<Account>())
+            .transpose()?
+            .map_or(0, |account| account.sequence_number))
     }
 
-    async fn get_block_number(&self) -> RpcResult<String> {
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("Time went backwards");
+    async fn get_block(&self, num: BlockNumber) -> RpcResult<Option<RoochBlock>> {
+        match self.filter_service.resolve_block_number(num).await? {
+            Some(block_number) => Ok(self
+                .rpc_service
+                .get_blocks(vec![block_number as u128])
+                .await?
+                .pop()
+                .flatten()),
+            None => Ok(None),
+        }
+    }
 
-        let block_number = now.as_secs();
-        Ok(format!("0x{:x}", block_number))
+    async fn get_block_tx_hashes(&self, block: &RoochBlock) -> RpcResult<Vec<H256>> {
+        Ok(self
+            .rpc_service
+            .get_tx_sequence_info_mapping_by_order(block.tx_orders().collect())
+            .await?
+            .into_iter()
+            .flatten()
+            .map(|mapping| mapping.tx_hash)
+            .collect())
     }
 
-    async fn get_block_by_number(
+    async fn get_txs_gas_used(&self, tx_hashes: Vec<H256>) -> RpcResult<u64> {
+        Ok(self
+            .rpc_service
+            .get_transaction_execution_infos_by_hash(tx_hashes)
+            .await?
+            .into_iter()
+            .flatten()
+            .map(|info| info.gas_used)
+            .sum())
+    }
+
+    async fn get_block_txs(&self, tx_hashes: Vec<H256>) -> RpcResult<Vec<TypedTransaction>> {
+        Ok(self
+            .rpc_service
+            .get_transactions_by_hash(tx_hashes)
+            .await?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    /// Rooch does not limit the gas of a block, the block gas limit is the sum of the max gas amount of its transactions,
+    /// so the gas used of a block never exceeds its gas limit.
+    fn block_gas_limit(txs: &[TypedTransaction]) -> U256 {
+        txs.iter()
+            .map(|tx| U256::from(tx.max_gas_amount()))
+            .fold(U256::zero(), |gas_limit, max_gas_amount| {
+                gas_limit + max_gas_amount
+            })
+    }
+
+    /// Get the current base fee per gas unit.
+    async fn get_current_base_fee(&self) -> RpcResult<U256> {
+        self.rpc_service
+            .get_gas_prices(vec![None])
+            .await?
+            .pop()
+            .flatten()
+            .map(|gas_price| U256::from(gas_price.base_fee))
+            .ok_or_else(|| JsonRpcError::Custom(String::from("The gas price is not initialized")))
+    }
+
+    /// Get the base fee per gas unit of the blocks, the base fee of a block is the gas price at the last state of its parent block.
+    /// The genesis state is not in any block, so the first block uses the gas price after its first transaction.
+    async fn get_blocks_base_fee(&self, blocks: &[RoochBlock]) -> RpcResult<Vec<U256>> {
+        let parent_blocks = self
+            .rpc_service
+            .get_blocks(
+                blocks
+                    .iter()
+                    .map(|block| block.block_number.saturating_sub(1))
+                    .collect(),
+            )
+            .await?;
+        let state_roots = blocks
+            .iter()
+            .zip(parent_blocks)
+            .map(|(block, parent_block)| {
+                let parent_state_root = if block.block_number == 0 {
+                    None
+                } else {
+                    parent_block.and_then(|parent_block| parent_block.state_roots.last().copied())
+                };
+                parent_state_root.or_else(|| block.state_roots.first().copied())
+            })
+            .collect();
+        Ok(self
+            .rpc_service
+            .get_gas_prices(state_roots)
+            .await?
+            .into_iter()
+            .map(|gas_price| {
+                gas_price.map_or(U256::zero(), |gas_price| U256::from(gas_price.base_fee))
+            })
+            .collect())
+    }
+
+    /// Build the Ethereum block from the proposer block.
+    async fn build_block(
         &self,
-        num: StrView<BlockNumber>,
+        block: RoochBlock,
         include_txs: bool,
     ) -> RpcResult<Block<TransactionType>> {
-        let block_number = num
-            .0
-            .as_number()
-            .ok_or_else(|| JsonRpcError::Custom("block number should be a number".to_string()))?;
-        let parent_hash =
-            H256::from_str("0xe5ece23ec875db0657f964cbc74fa34439eef3ab3dc8664e7f4ae8b5c5c963e1")
-                .unwrap();
-        let gas_limit = StrView(U256::from_str("0x1c9c380").unwrap());
-        let gas_used = StrView(U256::from_str("0xf4954d").unwrap());
-
-        let txs = if include_txs {
-            vec![TransactionType::Full(Transaction {
-                hash: H256View::from(
-                    H256::from_str(
-                        "0x96c133e6ee7966ee28e6a3b4abd38d1feb15bfcb9e3a36257bd4818ad679c26e",
-                    )
-                    .unwrap(),
-                ),
-                nonce: StrView(U256::zero()),
-                block_hash: Some(parent_hash.into()),
-                block_number: Some(block_number.into()),
-                transaction_index: Some(U64::from(0u8).into()),
-                from: StrView(
-                    H160::from_str("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").unwrap(),
-                ),
-                to: Some(StrView(
-                    H160::from_str("0x832daF8DDe81fA5186EF2D04b3099251c508D5A1").unwrap(),
-                )),
-                value: StrView(U256::from(1_000_000u64)),
-                gas_price: Some(StrView(U256::from(20_000_000_000u64))),
-                gas: StrView(U256::from(21_000u32)),
-                input: BytesView::from(vec![]),
-                r: StrView(U256::zero()),
-                s: StrView(U256::zero()),
-                v: StrView(U64::from(0u8)),
-                transaction_type: Default::default(),
-                access_list: Some(AccessList::default()),
-                max_priority_fee_per_gas: Default::default(),
-                max_fee_per_gas: Default::default(),
-                chain_id: Some(StrView(U256::from(10001u32))),
-                other: OtherFields::default(),
-            })]
+        let block_hash = block.block_hash();
+        let block_number = block.block_number as u64;
+        let tx_hashes = self.get_block_tx_hashes(&block).await?;
+        let gas_used = self.get_txs_gas_used(tx_hashes.clone()).await?;
+        let base_fee = self
+            .get_blocks_base_fee(std::slice::from_ref(&block))
+            .await?
+            .pop()
+            .unwrap_or_default();
+        let logs = self
+            .filter_service
+            .get_blocks_logs(&Filter::default(), &[block.clone()])
+            .await?;
+        let txs = self.get_block_txs(tx_hashes.clone()).await?;
+        let gas_limit = Self::block_gas_limit(&txs);
+        let transactions = if include_txs {
+            txs.into_iter()
+                .enumerate()
+                .map(|(index, tx)| {
+                    TransactionType::Full(Transaction::new_from_typed_transaction(
+                        tx,
+                        Some(block_hash),
+                        Some(block_number),
+                        Some(index as u64),
+                    ))
+                })
+                .collect()
         } else {
-            vec![TransactionType::Hash(H256View::from(
-                H256::from_str(
-                    "0x96c133e6ee7966ee28e6a3b4abd38d1feb15bfcb9e3a36257bd4818ad679c26e",
-                )
-                .unwrap(),
-            ))]
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| TransactionType::Hash(tx_hash.into()))
+                .collect()
         };
 
-        let block = Block {
-            hash: Some(H256View::from(
-                H256::from_str(
-                    "0xa4161cc321054df6e370776f19a958950ce4237fca4aff57605efdcdd3b802f4",
-                )
-                .unwrap(),
-            )),
-            parent_hash: parent_hash.into(),
-            uncles_hash: H256View::from(
-                H256::from_str(
-                    "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
-                )
-                .unwrap(),
-            ),
-            author: Some(StrView(
-                H160::from_str("0xbaf6dc2e647aeb6f510f9e318856a1bcd66c5e19").unwrap(),
-            )),
-            state_root: H256View::from(
-                H256::from_str(
-                    "0xde1cdf9816313c105a75eaaedab04815b1b7aa5650bf91b69749d71a36497243",
-                )
-                .unwrap(),
-            ),
-            transactions_root: H256View::from(
-                H256::from_str(
-                    "0xdc8c2a8825fbbe669360d351e34f3ad09d320db83539c98e92bb18ea5fa93773",
-                )
-                .unwrap(),
-            ),
-            receipts_root: H256View::from(
-                H256::from_str(
-                    "0x31814320e99d27d63448b25b122870e70427d8261bbaa3674e96dd686bcb507a",
-                )
-                .unwrap(),
-            ),
-            number: Some(block_number.into()),
-            gas_used,
-            gas_limit,
-            extra_data: BytesView::from_str(
-                "0x4d616465206f6e20746865206d6f6f6e20627920426c6f636b6e6174697665",
-            )
-            .unwrap(),
-            logs_bloom: None,
-            timestamp: StrView(U256::from_str("0x64731653").unwrap()),
-            difficulty: StrView(U256::zero()),
-            total_difficulty: Some(StrView(U256::from_str("0xc70d815d562d3cfa955").unwrap())),
+        Ok(Block {
+            hash: Some(block_hash.into()),
+            parent_hash: block.parent_hash().into(),
+            uncles_hash: H256::from_str(EMPTY_UNCLES_HASH)
+                .expect("valid hash")
+                .into(),
+            author: Some(H160::zero().into()),
+            state_root: block.state_roots.last().copied().unwrap_or_default().into(),
+            transactions_root: block.tx_accumulator_root.into(),
+            receipts_root: H256::zero().into(),
+            number: Some(U64::from(block_number).into()),
+            gas_used: U256::from(gas_used).into(),
+            gas_limit: gas_limit.into(),
+            extra_data: BytesView::from(vec![]),
+            logs_bloom: Some(Log::logs_bloom(&logs)),
+            // The Ethereum block timestamp is in seconds
+            timestamp: U256::from(block.timestamp / 1000).into(),
+            difficulty: U256::zero().into(),
+            total_difficulty: Some(U256::zero().into()),
             seal_fields: vec![],
             uncles: vec![],
-            transactions: txs,
+            transactions,
             size: None,
             mix_hash: None,
             nonce: None,
-            base_fee_per_gas: Some(StrView(U256::from_str("0x52e0ce91c").unwrap())),
-            withdrawals_root: Some(H256View::from(
-                H256::from_str(
-                    "0xdc8c2a8825fbbe669360d351e34f3ad09d320db83539c98e92bb18ea5fa93773",
-                )
-                .unwrap(),
-            )),
-            withdrawals: Some(vec![Withdrawal {
-                address: StrView(
-                    H160::from_str("0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f").unwrap(),
-                ),
-                amount: StrView(U256::from_str("0xc7a3fa").unwrap()),
-                index: StrView(U64::from_str("0x4e81dc").unwrap()),
-                validator_index: StrView(U64::from_str("0x5be41").unwrap()),
-            }]),
+            base_fee_per_gas: Some(base_fee.into()),
+            withdrawals_root: None,
+            withdrawals: None,
             other: OtherFields::default(),
-        };
+        })
+    }
+}
+
+#[async_trait]
+impl EthAPIServer for EthServer {
+    async fn chain_id(&self) -> RpcResult<String> {
+        Ok(format!("0x{:x}", self.chain_id.id()))
+    }
 
-        Ok(block)
+    async fn get_block_number(&self) -> RpcResult<String> {
+        let block_number = self
+            .filter_service
+            .latest_block_number()
+            .await?
+            .unwrap_or_default();
+        Ok(format!("0x{:x}", block_number))
+    }
+
+    async fn get_block_by_number(
+        &self,
+        num: StrView<BlockNumber>,
+        include_txs: bool,
+    ) -> RpcResult<Option<Block<TransactionType>>> {
+        match self.get_block(num.0).await? {
+            Some(block) => Ok(Some(self.build_block(block, include_txs).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_block_transaction_count_by_number(
+        &self,
+        num: StrView<BlockNumber>,
+    ) -> RpcResult<Option<StrView<U256>>> {
+        Ok(self
+            .get_block(num.0)
+            .await?
+            .map(|block| U256::from(block.batch_size).into()))
+    }
+
+    async fn transaction_by_block_number_and_index(
+        &self,
+        num: StrView<BlockNumber>,
+        index: StrView<U64>,
+    ) -> RpcResult<Option<Transaction>> {
+        let block = match self.get_block(num.0).await? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let index = index.0.as_u64();
+        if index >= block.batch_size {
+            return Ok(None);
+        }
+        let tx_hash = match self
+            .rpc_service
+            .get_tx_sequence_info_mapping_by_order(vec![block.first_tx_order + index])
+            .await?
+            .pop()
+            .flatten()
+        {
+            Some(mapping) => mapping.tx_hash,
+            None => return Ok(None),
+        };
+        Ok(self
+            .rpc_service
+            .get_transaction_by_hash(tx_hash)
+            .await?
+            .map(|tx| {
+                Transaction::new_from_typed_transaction(
+                    tx,
+                    Some(block.block_hash()),
+                    Some(block.block_number as u64),
+                    Some(index),
+                )
+            }))
     }
 
     async fn get_balance(
@@ -241,25 +368,56 @@ impl EthAPIServer for EthServer {
         request: CallRequest,
         _num: Option<StrView<BlockNumber>>,
     ) -> RpcResult<StrView<U256>> {
-        let gas = match request.from {
-            Some(from) => {
-                let account_address = self
-                    .rpc_service
-                    .resolve_address(MultiChainAddress::from(EthereumAddress(from.into())))
-                    .await?;
-                let account_exists = self.rpc_service.exists_account(account_address).await?;
-                if account_exists {
-                    //TODO call dry run to estimate gas
-                    StrView(U256::from(GasConfig::DEFAULT_MAX_GAS_AMOUNT))
-                } else {
-                    //The contract will automatically call faucet to deposit gas coin when the account does not exist.
-                    //So, we return 0 gas to avoid MetaMask blocking the transaction submission.
-                    //TODO when we implement the contract pay gas, we should return the real gas amount that user should pay.
-                    StrView(U256::zero())
+        let gas =
+            match request.from {
+                Some(from) => {
+                    let account_address = self
+                        .rpc_service
+                        .resolve_address(MultiChainAddress::from(EthereumAddress(from.into())))
+                        .await?;
+                    let account_exists = self.rpc_service.exists_account(account_address).await?;
+                    if account_exists {
+                        let nonce = match request.nonce {
+                            Some(nonce) => nonce.0,
+                            None => U256::from(self.get_sequence_number(account_address).await?),
+                        };
+                        let tx =
+                            EthereumTransactionBuilder::new()
+                                .from(from.into())
+                                .to(request.to.map(Into::into))
+                                .value(request.value.map(|value| value.0).unwrap_or_default())
+                                .input(request.data.map(|data| data.0.into()).unwrap_or_default())
+                                .nonce(nonce)
+                                .gas(request.gas.map(|gas| gas.0).unwrap_or_else(|| {
+                                    U256::from(GasConfig::DEFAULT_MAX_GAS_AMOUNT)
+                                }))
+                                .chain_id(Some(U256::from(self.chain_id.id())))
+                                .build();
+                        let max_gas_amount = tx.max_gas_amount();
+                        let output = self
+                            .rpc_service
+                            .dry_run_tx(TypedTransaction::Ethereum(tx), false)
+                            .await?
+                            .raw_output;
+                        if output.status != KeptVMStatus::Executed {
+                            return Err(JsonRpcError::Custom(format!(
+                                "The transaction dry run failed: {:?}",
+                                output.status
+                            )));
+                        }
+                        StrView(U256::from(GasConfig::estimate_max_gas_amount(
+                            output.gas_used,
+                            max_gas_amount,
+                        )))
+                    } else {
+                        //The contract will automatically call faucet to deposit gas coin when the account does not exist.
+                        //So, we return 0 gas to avoid MetaMask blocking the transaction submission.
+                        //TODO when we implement the contract pay gas, we should return the real gas amount that user should pay.
+                        StrView(U256::zero())
+                    }
                 }
-            }
-            None => StrView(U256::from(GasConfig::DEFAULT_MAX_GAS_AMOUNT)),
-        };
+                None => StrView(U256::from(GasConfig::DEFAULT_MAX_GAS_AMOUNT)),
+            };
         Ok(gas)
     }
 
@@ -269,54 +427,83 @@ impl EthAPIServer for EthServer {
         newest_block: StrView<BlockNumber>,
         reward_percentiles: Option<Vec<f64>>,
     ) -> RpcResult<EthFeeHistory> {
-        let block_count: usize = if block_count.0 > U256::from(100u8) {
-            100
-        } else {
-            block_count.0.as_usize()
-        };
-        let base_fee_per_gas: Vec<U256> = iter::repeat_with(U256::zero).take(block_count).collect();
-
-        let gas_used_ratio: Vec<f64> = iter::repeat_with(|| 0.1).take(block_count).collect();
-
-        let reward = match reward_percentiles {
-            Some(percentiles) => {
-                let rewards: Vec<Vec<U256>> = (0..block_count)
-                    .map(|_| percentiles.iter().map(|_| U256::from(1u8)).collect())
-                    .collect();
-                Some(rewards)
-            }
-            None => None,
-        };
-
-        match newest_block.0.as_number() {
-            Some(newest_block_num) => {
-                let oldest_block_num = newest_block_num.as_u64() - block_count as u64;
-                let base_fee_per_gas_view: Vec<StrView<U256>> =
-                    base_fee_per_gas.iter().map(|u256| StrView(*u256)).collect();
-                let reward_view: Option<Vec<Vec<StrView<U256>>>> = reward.map(|rewards| {
-                    rewards
-                        .iter()
-                        .map(|inner| inner.iter().map(|u256| StrView(*u256)).collect())
-                        .collect()
-                });
-                Ok(EthFeeHistory {
-                    oldest_block: BlockNumber::Number(oldest_block_num.into()).into(),
-                    base_fee_per_gas: base_fee_per_gas_view,
-                    gas_used_ratio,
-                    reward: reward_view,
-                })
-            }
+        let newest_block_number = match self
+            .filter_service
+            .resolve_block_number(newest_block.0)
+            .await?
+        {
+            Some(newest_block_number) => newest_block_number,
             None => {
                 return Err(JsonRpcError::Custom(String::from(
-                    "newest_block not a number",
+                    "The newest block does not exist",
                 )));
             }
+        };
+        let block_count = block_count
+            .0
+            .min(U256::from(MAX_FEE_HISTORY_BLOCK_COUNT))
+            .as_u64()
+            .min(newest_block_number + 1);
+        let oldest_block_number = newest_block_number + 1 - block_count;
+        let blocks: Vec<RoochBlock> = self
+            .rpc_service
+            .get_blocks(
+                (oldest_block_number..=newest_block_number)
+                    .map(|block_number| block_number as u128)
+                    .collect(),
+            )
+            .await?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut base_fee_per_gas = self.get_blocks_base_fee(&blocks).await?;
+        // The base fee of the next block is the current gas price after the newest block
+        let next_base_fee = match blocks
+            .last()
+            .and_then(|block| block.state_roots.last().copied())
+        {
+            Some(state_root) => self
+                .rpc_service
+                .get_gas_prices(vec![Some(state_root)])
+                .await?
+                .pop()
+                .flatten()
+                .map_or(U256::zero(), |gas_price| U256::from(gas_price.base_fee)),
+            None => self.get_current_base_fee().await?,
+        };
+        base_fee_per_gas.push(next_base_fee);
+
+        let mut gas_used_ratio = Vec::with_capacity(blocks.len());
+        for block in &blocks {
+            let tx_hashes = self.get_block_tx_hashes(block).await?;
+            let gas_used = self.get_txs_gas_used(tx_hashes.clone()).await?;
+            let gas_limit = Self::block_gas_limit(&self.get_block_txs(tx_hashes).await?);
+            gas_used_ratio.push(if gas_limit.is_zero() {
+                0f64
+            } else {
+                gas_used as f64 / gas_limit.as_u128() as f64
+            });
         }
+
+        // Rooch does not have the priority fee, so the rewards are always zero
+        let reward = reward_percentiles.map(|percentiles| {
+            blocks
+                .iter()
+                .map(|_| percentiles.iter().map(|_| StrView(U256::zero())).collect())
+                .collect()
+        });
+
+        Ok(EthFeeHistory {
+            oldest_block: BlockNumber::Number(oldest_block_number.into()).into(),
+            base_fee_per_gas: base_fee_per_gas.into_iter().map(StrView).collect(),
+            gas_used_ratio,
+            reward,
+        })
     }
 
     async fn gas_price(&self) -> RpcResult<StrView<U256>> {
-        //TODO read the get_gas_factor from contract.
-        Ok(StrView(U256::from(1u8)))
+        Ok(StrView(self.get_current_base_fee().await?))
     }
 
     async fn transaction_count(
@@ -334,17 +521,7 @@ impl EthAPIServer for EthServer {
             address, account_address
         );
 
-        let seq_number = self
-            .rpc_service
-            .get_states(AccessPath::resource(account_address, Account::struct_tag()))
-            .await?
-            .pop()
-            .flatten()
-            .map(|state_view| state_view.cast::<Account>())
-            .transpose()?
-            .map_or(StrView(U256::zero()), |account| {
-                StrView(<u64 as Into<U256>>::into(account.sequence_number))
-            });
+        let seq_number = StrView(U256::from(self.get_sequence_number(account_address).await?));
 
         info!("transaction_count seq_number: {:?}", seq_number);
 
@@ -377,192 +554,114 @@ impl EthAPIServer for EthServer {
     }
 
     async fn transaction_receipt(&self, hash: H256View) -> RpcResult<Option<TransactionReceipt>> {
-        let result = self
+        let tx_hash: H256 = hash.into();
+        let info = match self
             .rpc_service
-            .get_transaction_execution_infos_by_hash(vec![hash.into()])
+            .get_transaction_execution_infos_by_hash(vec![tx_hash])
             .await?
-            .into_iter()
-            .last()
-            .and_then(|trans| {
-                trans.map(|info| TransactionReceipt {
-                    transaction_hash: info.tx_hash.into(),
-                    block_hash: Some(info.state_root.into()),
-                    block_number: Some(StrView(U64::from(10u16))),
-                    gas_used: Some(StrView(<u64 as Into<U256>>::into(info.gas_used))),
-                    status: Some(StrView(U64::from(info.status.is_success() as u8))),
-                    cumulative_gas_used: StrView(<u64 as Into<U256>>::into(info.gas_used)),
-                    contract_address: None,
-                    logs: Vec::new(),
-                    logs_bloom: Bloom::default(),
-                    transaction_index: StrView(U64::from(0u8)),
-                    from: StrView(H160::default()),
-                    to: Some(StrView(H160::default())),
-                    root: Some(H256View::from(H256::default())),
-                    transaction_type: None,
-                    effective_gas_price: None,
-                    other: OtherFields::default(),
-                })
-            });
-
-        Ok(result)
+            .pop()
+            .flatten()
+        {
+            Some(info) => info,
+            None => return Ok(None),
+        };
+        let (tx, mapping) = match (
+            self.rpc_service.get_transaction_by_hash(tx_hash).await?,
+            self.rpc_service
+                .get_tx_sequence_info_mapping_by_hash(vec![tx_hash])
+                .await?
+                .pop()
+                .flatten(),
+        ) {
+            (Some(tx), Some(mapping)) => (tx, mapping),
+            _ => return Ok(None),
+        };
+        let block = self.filter_service.get_tx_block(mapping.tx_order).await?;
+        let transaction_index = block
+            .as_ref()
+            .map_or(0, |block| mapping.tx_order - block.first_tx_order);
+        let logs = self
+            .filter_service
+            .get_tx_logs(tx_hash, block.clone())
+            .await?;
+        let logs_bloom = Log::logs_bloom(&logs);
+        let eth_tx = Transaction::new_from_typed_transaction(tx, None, None, None);
+
+        Ok(Some(TransactionReceipt {
+            transaction_hash: info.tx_hash.into(),
+            block_hash: block.as_ref().map(|block| block.block_hash().into()),
+            block_number: block
+                .as_ref()
+                .map(|block| U64::from(block.block_number as u64).into()),
+            gas_used: Some(StrView(<u64 as Into<U256>>::into(info.gas_used))),
+            status: Some(StrView(U64::from(info.status.is_success() as u8))),
+            cumulative_gas_used: StrView(<u64 as Into<U256>>::into(info.gas_used)),
+            contract_address: None,
+            logs,
+            logs_bloom,
+            transaction_index: StrView(U64::from(transaction_index)),
+            from: eth_tx.from,
+            to: eth_tx.to,
+            root: Some(info.state_root.into()),
+            transaction_type: eth_tx.transaction_type,
+            effective_gas_price: None,
+            other: OtherFields::default(),
+        }))
     }
 
     async fn transaction_by_hash(&self, hash: H256View) -> RpcResult<Option<Transaction>> {
-        let resp = self
+        let tx_hash: H256 = hash.into();
+        let tx = match self.rpc_service.get_transaction_by_hash(tx_hash).await? {
+            Some(tx) => tx,
+            None => return Ok(None),
+        };
+        let block = match self
             .rpc_service
-            .get_transaction_by_hash(hash.into())
+            .get_tx_sequence_info_mapping_by_hash(vec![tx_hash])
             .await?
-            .unwrap();
-
-        // Create a new Transaction instance and populate its fields
-        let transaction = Transaction {
-            hash: resp.tx_hash().into(),
-            nonce: StrView(U256::from(4391989u64)),
-            block_hash: Some(H256View::from(H256::from_str("0xc2794a16acacd9f7670379ffd12b6968ff98e2a602f57d7d1f880220aa5a4973").unwrap())),
-            block_number: Some(StrView(8453214.into())),
-            transaction_index: Some(StrView(U64::from(0u8))),
-            from: EthereumAddress::try_from(resp.sender())?.0.into(),
-            to: Some(StrView(H160::from_str("0x4200000000000000000000000000000000000015").unwrap())),
-            value: StrView(U256::zero()),
-            gas_price: Some(StrView(U256::zero())),
-            gas: StrView(U256::from(1000000u64)),
-            input: BytesView::from(
-                hex::decode("015d8eb90000000000000000000000000000000000000000000000000000000000878c1c00000000000000000000000000000000000000000000000000000000644662bc0000000000000000000000000000000000000000000000000000001ee24fba17b7e19cc10812911dfa8a438e0a81a9933f843aa5b528899b8d9e221b649ae0df00000000000000000000000000000000000000000000000000000000000000060000000000000000000000007431310e026b69bfc676c0013e12a1a11411eec9000000000000000000000000000000000000000000000000000000000000083400000000000000000000000000000000000000000000000000000000000f4240").unwrap()
-            ),
-            r: StrView(U256::zero()),
-            s: StrView(U256::zero()),
-            v: StrView(U64::from(0u8)),
-            transaction_type: Some(StrView(U64::from(126u16))),
-            access_list: None,
-            max_priority_fee_per_gas: None,
-            max_fee_per_gas: None,
-            chain_id: None,
-            other: Default::default(),
+            .pop()
+            .flatten()
+        {
+            Some(mapping) => self
+                .filter_service
+                .get_tx_block(mapping.tx_order)
+                .await?
+                .map(|block| (mapping.tx_order - block.first_tx_order, block)),
+            None => None,
         };
-
-        Ok(Some(transaction))
+        Ok(Some(match block {
+            Some((index, block)) => Transaction::new_from_typed_transaction(
+                tx,
+                Some(block.block_hash()),
+                Some(block.block_number as u64),
+                Some(index),
+            ),
+            None => Transaction::new_from_typed_transaction(tx, None, None, None),
+        }))
     }
 
     async fn block_by_hash(
         &self,
         hash: H256View,
         include_txs: bool,
-    ) -> RpcResult<Block<TransactionType>> {
-        let block_number = StrView(U64::from(10u8));
-        let parent_hash = H256View::from(
-            H256::from_str("0xe5ece23ec875db0657f964cbc74fa34439eef3ab3dc8664e7f4ae8b5c5c963e1")
-                .unwrap(),
-        );
-        let gas_limit = StrView(U256::from_str("0x1c9c380").unwrap());
-        let gas_used = StrView(U256::from_str("0xf4954d").unwrap());
-
-        let txs = if include_txs {
-            vec![TransactionType::Full(Transaction {
-                hash,
-                nonce: StrView(U256::zero()),
-                block_hash: Some(parent_hash),
-                block_number: Some(block_number),
-                transaction_index: Some(StrView(U64::from(0u8))),
-                from: StrView(
-                    H160::from_str("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").unwrap(),
-                ),
-                to: Some(StrView(
-                    H160::from_str("0x832daF8DDe81fA5186EF2D04b3099251c508D5A1").unwrap(),
-                )),
-                value: StrView(U256::from(1_000_000u64)),
-                gas_price: Some(StrView(U256::from(20_000_000_000u64))),
-                gas: StrView(U256::from(21_000u32)),
-                input: BytesView::from(vec![]),
-                r: StrView(U256::zero()),
-                s: StrView(U256::zero()),
-                v: StrView(U64::from(1u8)),
-                transaction_type: Default::default(),
-                access_list: Some(AccessList::default()),
-                max_priority_fee_per_gas: Default::default(),
-                max_fee_per_gas: Default::default(),
-                chain_id: Some(StrView(U256::from(10001u32))),
-                other: OtherFields::default(),
-            })]
-        } else {
-            vec![TransactionType::Hash(H256View::from(
-                H256::from_str(
-                    "0x96c133e6ee7966ee28e6a3b4abd38d1feb15bfcb9e3a36257bd4818ad679c26e",
-                )
-                .unwrap(),
-            ))]
-        };
-
-        let block = Block {
-            hash: Some(H256View::from(
-                H256::from_str(
-                    "0xa4161cc321054df6e370776f19a958950ce4237fca4aff57605efdcdd3b802f4",
-                )
-                .unwrap(),
-            )),
-            parent_hash,
-            uncles_hash: H256View::from(
-                H256::from_str(
-                    "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
-                )
-                .unwrap(),
-            ),
-            author: Some(StrView(
-                H160::from_str("0xbaf6dc2e647aeb6f510f9e318856a1bcd66c5e19").unwrap(),
-            )),
-            state_root: H256View::from(
-                H256::from_str(
-                    "0xde1cdf9816313c105a75eaaedab04815b1b7aa5650bf91b69749d71a36497243",
-                )
-                .unwrap(),
-            ),
-            transactions_root: H256View::from(
-                H256::from_str(
-                    "0xdc8c2a8825fbbe669360d351e34f3ad09d320db83539c98e92bb18ea5fa93773",
-                )
-                .unwrap(),
-            ),
-            receipts_root: H256View::from(
-                H256::from_str(
-                    "0x31814320e99d27d63448b25b122870e70427d8261bbaa3674e96dd686bcb507a",
-                )
-                .unwrap(),
-            ),
-            number: Some(block_number),
-            gas_used,
-            gas_limit,
-            extra_data: BytesView::from_str(
-                "0x4d616465206f6e20746865206d6f6f6e20627920426c6f636b6e6174697665",
-            )
-            .unwrap(),
-            logs_bloom: None,
-            timestamp: StrView(U256::from_str("0x64731653").unwrap()),
-            difficulty: StrView(U256::zero()),
-            total_difficulty: Some(StrView(U256::from_str("0xc70d815d562d3cfa955").unwrap())),
-            seal_fields: vec![],
-            uncles: vec![],
-            transactions: txs,
-            size: None,
-            mix_hash: None,
-            nonce: None,
-            base_fee_per_gas: Some(StrView(U256::from_str("0x52e0ce91c").unwrap())),
-            withdrawals_root: Some(H256View::from(
-                H256::from_str(
-                    "0xdc8c2a8825fbbe669360d351e34f3ad09d320db83539c98e92bb18ea5fa93773",
-                )
-                .unwrap(),
-            )),
-            withdrawals: Some(vec![Withdrawal {
-                address: StrView(
-                    H160::from_str("0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f").unwrap(),
-                ),
-                amount: StrView(U256::from_str("0xc7a3fa").unwrap()),
-                index: StrView(U64::from_str("0x4e81dc").unwrap()),
-                validator_index: StrView(U64::from_str("0x5be41").unwrap()),
-            }]),
-            other: OtherFields::default(),
+    ) -> RpcResult<Option<Block<TransactionType>>> {
+        let block = match self
+            .rpc_service
+            .get_block_number_by_hash(hash.into())
+            .await?
+        {
+            Some(block_number) => self
+                .rpc_service
+                .get_blocks(vec![block_number])
+                .await?
+                .pop()
+                .flatten(),
+            None => None,
         };
-
-        Ok(block)
+        match block {
+            Some(block) => Ok(Some(self.build_block(block, include_txs).await?)),
+            None => Ok(None),
+        }
     }
 
     async fn get_code(
@@ -573,6 +672,26 @@ impl EthAPIServer for EthServer {
         let code = BytesView::from_str("0x").unwrap();
         Ok(code)
     }
+
+    async fn get_logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+        Ok(self.filter_service.get_logs(filter).await?)
+    }
+
+    async fn new_filter(&self, filter: Filter) -> RpcResult<StrView<U256>> {
+        Ok(self.filter_service.new_filter(filter).await?)
+    }
+
+    async fn new_block_filter(&self) -> RpcResult<StrView<U256>> {
+        Ok(self.filter_service.new_block_filter().await?)
+    }
+
+    async fn get_filter_changes(&self, filter_id: StrView<U256>) -> RpcResult<FilterChanges> {
+        Ok(self.filter_service.get_filter_changes(filter_id.0).await?)
+    }
+
+    async fn uninstall_filter(&self, filter_id: StrView<U256>) -> RpcResult<bool> {
+        Ok(self.filter_service.uninstall_filter(filter_id.0).await)
+    }
 }
 
 impl RoochRpcModule for EthServer {
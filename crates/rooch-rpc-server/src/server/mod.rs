@@ -3,3 +3,27 @@
 
 pub mod eth_server;
 pub mod rooch_server;
+
+use futures::Stream;
+use jsonrpsee::{types::error::SubscriptionClosed, SubscriptionSink};
+use serde::Serialize;
+
+/// Pipe the items of the stream to the subscription sink in a new task, until the stream ends or the peer unsubscribes.
+pub fn spawn_subscription<S, T>(mut sink: SubscriptionSink, stream: S)
+where
+    S: Stream<Item = T> + Unpin + Send + 'static,
+    T: Serialize,
+{
+    tokio::spawn(async move {
+        match sink.pipe_from_stream(stream).await {
+            SubscriptionClosed::Success => {
+                sink.close(SubscriptionClosed::Success);
+            }
+            SubscriptionClosed::RemotePeerAborted => (),
+            SubscriptionClosed::Failed(err) => {
+                log::warn!("Subscription closed with error: {:?}", err);
+                sink.close(err);
+            }
+        };
+    });
+}
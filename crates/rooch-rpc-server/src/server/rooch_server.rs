@@ -1,24 +1,30 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::server::spawn_subscription;
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_service::RpcService;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use jsonrpsee::types::SubscriptionResult;
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpcError, RpcResult},
-    RpcModule,
+    RpcModule, SubscriptionSink,
 };
 use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::block_view::{BlockSubmitStatusView, BlockView};
 use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, EventView, IndexerEventView};
-use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionProofView};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, IndexerEventPageView, IndexerTableChangeSetPageView,
     IndexerTableChangeSetView, StateFilterView, StateOptions,
 };
 use rooch_rpc_api::jsonrpc_types::{transaction_view::TransactionWithInfoView, EventOptions};
 use rooch_rpc_api::jsonrpc_types::{
-    AccessPathView, AccountAddressView, BalanceInfoPageView, EventPageView,
-    ExecuteTransactionResponseView, FunctionCallView, H256View, StateView, StatesPageView, StrView,
-    StructTagView, TransactionWithInfoPageView,
+    AccessPathView, AccountAddressView, BalanceInfoPageView, DryRunOptions,
+    DryRunTransactionResponseView, EventPageView, ExecuteTransactionResponseView,
+    ExecutionTraceView, FunctionCallView, GasPriceView, H256View, PackageInfoView, StateView,
+    StatesPageView, StrView, StructTagView, TransactionWithInfoPageView,
 };
 use rooch_rpc_api::{api::rooch_api::RoochAPIServer, api::DEFAULT_RESULT_LIMIT};
 use rooch_rpc_api::{
@@ -29,8 +35,9 @@ use rooch_rpc_api::{
     api::{MAX_RESULT_LIMIT, MAX_RESULT_LIMIT_USIZE},
     jsonrpc_types::BytesView,
 };
-use rooch_types::indexer::event_filter::IndexerEventID;
-use rooch_types::indexer::state::IndexerStateID;
+use rooch_types::indexer::event_filter::{IndexerEvent, IndexerEventID};
+use rooch_types::indexer::state::{IndexerStateID, IndexerTableChangeSet};
+use rooch_types::indexer::transaction_filter::IndexerTransaction;
 use rooch_types::transaction::rooch::RoochTransaction;
 use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
 use std::cmp::min;
@@ -81,6 +88,20 @@ impl RoochAPIServer for RoochServer {
             .into())
     }
 
+    async fn dry_run_raw_transaction(
+        &self,
+        payload: BytesView,
+        dry_run_options: Option<DryRunOptions>,
+    ) -> RpcResult<DryRunTransactionResponseView> {
+        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        let profile_gas = dry_run_options.unwrap_or_default().profile_gas;
+        Ok(self
+            .rpc_service
+            .dry_run_tx(TypedTransaction::Rooch(tx), profile_gas)
+            .await?
+            .into())
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
@@ -160,6 +181,17 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_package(
+        &self,
+        package_address: AccountAddressView,
+    ) -> RpcResult<Option<PackageInfoView>> {
+        Ok(self
+            .rpc_service
+            .get_package(package_address.into())
+            .await?
+            .map(PackageInfoView::from))
+    }
+
     async fn get_events_by_event_handle(
         &self,
         event_handle_type: StructTagView,
@@ -226,6 +258,17 @@ impl RoochAPIServer for RoochServer {
         Ok(data)
     }
 
+    async fn get_transaction_trace(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ExecutionTraceView>> {
+        Ok(self
+            .rpc_service
+            .get_transaction_execution_trace(tx_hash.into())
+            .await?
+            .map(ExecutionTraceView::from))
+    }
+
     async fn get_transactions_by_order(
         &self,
         cursor: Option<StrView<u64>>,
@@ -288,6 +331,72 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_transaction_proof(
+        &self,
+        tx_order: StrView<u64>,
+    ) -> RpcResult<Option<TransactionProofView>> {
+        Ok(self
+            .rpc_service
+            .get_transaction_proof(tx_order.0)
+            .await?
+            .map(TransactionProofView::from))
+    }
+
+    async fn get_block_by_number(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockView>> {
+        Ok(self
+            .rpc_service
+            .get_blocks(vec![block_number.0])
+            .await?
+            .pop()
+            .flatten()
+            .map(BlockView::from))
+    }
+
+    async fn get_latest_block(&self) -> RpcResult<Option<BlockView>> {
+        Ok(self
+            .rpc_service
+            .get_latest_block()
+            .await?
+            .map(BlockView::from))
+    }
+
+    async fn get_block_submit_status(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockSubmitStatusView>> {
+        Ok(self
+            .rpc_service
+            .get_block_submit_status(block_number.0)
+            .await?
+            .map(BlockSubmitStatusView::from))
+    }
+
+    async fn get_da_batch(&self, block_number: StrView<u128>) -> RpcResult<Option<BytesView>> {
+        Ok(self
+            .rpc_service
+            .get_da_batch(block_number.0)
+            .await?
+            .map(|batch| batch.encode())
+            .transpose()?
+            .map(StrView))
+    }
+
+    async fn get_gas_price(&self) -> RpcResult<GasPriceView> {
+        let gas_price = self
+            .rpc_service
+            .get_gas_prices(vec![None])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                JsonRpcError::Custom(String::from("The gas price is not initialized"))
+            })?;
+        Ok(gas_price.into())
+    }
+
     async fn get_balance(
         &self,
         account_addr: AccountAddressView,
@@ -450,6 +559,33 @@ impl RoochAPIServer for RoochServer {
             has_next_page,
         })
     }
+
+    fn subscribe_transactions(
+        &self,
+        sink: SubscriptionSink,
+        filter: TransactionFilterView,
+    ) -> SubscriptionResult {
+        pipe_transactions(sink, self.rpc_service.subscribe_transactions(filter.into()));
+        Ok(())
+    }
+
+    fn subscribe_events(
+        &self,
+        sink: SubscriptionSink,
+        filter: EventFilterView,
+    ) -> SubscriptionResult {
+        pipe_events(sink, self.rpc_service.subscribe_events(filter.into()));
+        Ok(())
+    }
+
+    fn subscribe_states(
+        &self,
+        sink: SubscriptionSink,
+        filter: StateFilterView,
+    ) -> SubscriptionResult {
+        pipe_states(sink, self.rpc_service.subscribe_states(filter.into()));
+        Ok(())
+    }
 }
 
 impl RoochRpcModule for RoochServer {
@@ -457,3 +593,27 @@ impl RoochRpcModule for RoochServer {
         self.into_rpc()
     }
 }
+
+/// Pipe the subscribed transactions to the sink as `TransactionWithInfoView`.
+pub(crate) fn pipe_transactions(
+    sink: SubscriptionSink,
+    stream: BoxStream<'static, IndexerTransaction>,
+) {
+    spawn_subscription(
+        sink,
+        stream.map(|tx| TransactionWithInfoView::from(tx.transaction)),
+    );
+}
+
+/// Pipe the subscribed events to the sink as `IndexerEventView`.
+pub(crate) fn pipe_events(sink: SubscriptionSink, stream: BoxStream<'static, IndexerEvent>) {
+    spawn_subscription(sink, stream.map(IndexerEventView::from));
+}
+
+/// Pipe the subscribed table change sets to the sink as `IndexerTableChangeSetView`.
+pub(crate) fn pipe_states(
+    sink: SubscriptionSink,
+    stream: BoxStream<'static, IndexerTableChangeSet>,
+) {
+    spawn_subscription(sink, stream.map(IndexerTableChangeSetView::from));
+}
@@ -0,0 +1,344 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::{bail, ensure, Result};
+use ethers::types::U256;
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::eth::ethereum_types::{
+    block::BlockNumber,
+    filter::{Filter, FilterChanges},
+    log::Log,
+};
+use rooch_rpc_api::jsonrpc_types::StrView;
+use rooch_types::block::Block;
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEventID};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// The filters which are not polled in the timeout will be uninstalled, same as geth.
+const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// The max number of logs returned by one `eth_getLogs` or `eth_getFilterChanges`.
+const MAX_LOGS_LIMIT: usize = 10000;
+/// The max number of blocks queried by one `eth_getLogs` or `eth_getFilterChanges`.
+const MAX_BLOCK_RANGE: u64 = 1000;
+/// The page size of querying the events from the indexer.
+const EVENT_QUERY_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Clone)]
+enum FilterKind {
+    Logs(Box<Filter>),
+    Blocks,
+}
+
+#[derive(Debug, Clone)]
+struct InstalledFilter {
+    kind: FilterKind,
+    /// The next block to poll.
+    next_block: u64,
+    last_poll: Instant,
+}
+
+/// EthFilterService serves the ethereum logs of the proposer blocks, and keeps the installed filters in memory.
+#[derive(Clone)]
+pub struct EthFilterService {
+    rpc_service: RpcService,
+    filters: Arc<RwLock<HashMap<U256, InstalledFilter>>>,
+    next_filter_id: Arc<AtomicU64>,
+}
+
+impl EthFilterService {
+    pub fn new(rpc_service: RpcService) -> Self {
+        Self {
+            rpc_service,
+            filters: Arc::new(RwLock::new(HashMap::new())),
+            next_filter_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// The number of the latest proposed block, None if no block is proposed yet.
+    pub async fn latest_block_number(&self) -> Result<Option<u64>> {
+        Ok(self
+            .rpc_service
+            .get_latest_block()
+            .await?
+            .map(|block| block.block_number as u64))
+    }
+
+    /// Resolve the tag or number to the block number, the tags resolve to the latest block.
+    pub async fn resolve_block_number(&self, block_number: BlockNumber) -> Result<Option<u64>> {
+        Ok(match block_number {
+            BlockNumber::Number(number) => Some(number.as_u64()),
+            BlockNumber::Earliest => Some(0),
+            BlockNumber::Latest
+            | BlockNumber::Finalized
+            | BlockNumber::Safe
+            | BlockNumber::Pending => self.latest_block_number().await?,
+        })
+    }
+
+    /// Get the blocks in [from_block, to_block], stop at the first block which does not exist.
+    pub async fn get_blocks_in_range(&self, from_block: u64, to_block: u64) -> Result<Vec<Block>> {
+        if from_block > to_block {
+            return Ok(vec![]);
+        }
+        ensure!(
+            to_block - from_block < MAX_BLOCK_RANGE,
+            "The block range should not exceed {} blocks",
+            MAX_BLOCK_RANGE
+        );
+        let block_numbers = (from_block..=to_block).map(|n| n as u128).collect();
+        Ok(self
+            .rpc_service
+            .get_blocks(block_numbers)
+            .await?
+            .into_iter()
+            .map_while(|block| block)
+            .collect())
+    }
+
+    /// The logs emitted in the blocks, which match the filter.
+    /// The blocks should be continuous and in ascending order.
+    pub async fn get_blocks_logs(&self, filter: &Filter, blocks: &[Block]) -> Result<Vec<Log>> {
+        let (first_block, last_block) = match (blocks.first(), blocks.last()) {
+            (Some(first_block), Some(last_block)) => (first_block, last_block),
+            _ => return Ok(vec![]),
+        };
+        let event_filter = EventFilter::TxOrderRange {
+            from_order: first_block.first_tx_order,
+            to_order: last_block.tx_orders().end,
+        };
+        let mut logs = vec![];
+        let mut cursor: Option<IndexerEventID> = None;
+        // The log index is the position of the log in the block, count all the events before filtering.
+        let mut log_index_in_block = (first_block.block_number, 0u64);
+        loop {
+            let events = self
+                .rpc_service
+                .query_events(event_filter.clone(), cursor, EVENT_QUERY_PAGE_SIZE, false)
+                .await?;
+            let page_size = events.len();
+            cursor = events.last().map(|event| event.indexer_event_id);
+            for event in events {
+                let tx_order = event.indexer_event_id.tx_order;
+                let block_index = blocks
+                    .partition_point(|block| block.first_tx_order <= tx_order)
+                    .saturating_sub(1);
+                let block = &blocks[block_index];
+                if log_index_in_block.0 != block.block_number {
+                    log_index_in_block = (block.block_number, 0);
+                }
+                let log_index = log_index_in_block.1;
+                log_index_in_block.1 += 1;
+                let log = Log::new_from_event(
+                    event,
+                    Some(block.block_hash()),
+                    Some(block.block_number as u64),
+                    Some(tx_order - block.first_tx_order),
+                    Some(log_index),
+                );
+                if filter.matches(&log) {
+                    logs.push(log);
+                }
+            }
+            ensure!(
+                logs.len() <= MAX_LOGS_LIMIT,
+                "Query returned more than {} results, please narrow the block range",
+                MAX_LOGS_LIMIT
+            );
+            if page_size < EVENT_QUERY_PAGE_SIZE {
+                break;
+            }
+        }
+        Ok(logs)
+    }
+
+    /// The logs emitted in the blocks in [from_block, to_block], which match the filter.
+    pub async fn get_logs_in_range(
+        &self,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>> {
+        let blocks = self.get_blocks_in_range(from_block, to_block).await?;
+        self.get_blocks_logs(filter, &blocks).await
+    }
+
+    /// The block which contains the transaction, None if the transaction is not proposed yet.
+    pub async fn get_tx_block(&self, tx_order: u64) -> Result<Option<Block>> {
+        match self
+            .rpc_service
+            .get_block_number_by_tx_order(tx_order)
+            .await?
+        {
+            Some(block_number) => Ok(self
+                .rpc_service
+                .get_blocks(vec![block_number])
+                .await?
+                .pop()
+                .flatten()),
+            None => Ok(None),
+        }
+    }
+
+    /// The logs emitted by the transaction in the block, the block fields are None if the transaction is not proposed yet.
+    pub async fn get_tx_logs(&self, tx_hash: H256, block: Option<Block>) -> Result<Vec<Log>> {
+        match block {
+            Some(block) => Ok(self
+                .get_blocks_logs(&Filter::default(), &[block])
+                .await?
+                .into_iter()
+                .filter(|log| log.transaction_hash == Some(tx_hash.into()))
+                .collect()),
+            None => Ok(self
+                .rpc_service
+                .query_events(
+                    EventFilter::TxHash(tx_hash),
+                    None,
+                    EVENT_QUERY_PAGE_SIZE,
+                    false,
+                )
+                .await?
+                .into_iter()
+                .map(|event| Log::new_from_event(event, None, None, None, None))
+                .collect()),
+        }
+    }
+
+    pub async fn get_logs(&self, filter: Filter) -> Result<Vec<Log>> {
+        let (from_block, to_block) = match filter.block_hash {
+            Some(block_hash) => {
+                ensure!(
+                    filter.from_block.is_none() && filter.to_block.is_none(),
+                    "The blockHash can not be used with fromBlock or toBlock"
+                );
+                match self
+                    .rpc_service
+                    .get_block_number_by_hash(block_hash.into())
+                    .await?
+                {
+                    Some(block_number) => (block_number as u64, block_number as u64),
+                    None => bail!("Block {} not found", block_hash),
+                }
+            }
+            None => {
+                let from_block = self
+                    .resolve_block_number(filter.from_block.map(|b| b.0).unwrap_or_default())
+                    .await?;
+                let to_block = self
+                    .resolve_block_number(filter.to_block.map(|b| b.0).unwrap_or_default())
+                    .await?;
+                match (from_block, to_block) {
+                    (Some(from_block), Some(to_block)) => (from_block, to_block),
+                    _ => return Ok(vec![]),
+                }
+            }
+        };
+        self.get_logs_in_range(&filter, from_block, to_block).await
+    }
+
+    async fn install(&self, kind: FilterKind, next_block: u64) -> StrView<U256> {
+        let filter_id = U256::from(self.next_filter_id.fetch_add(1, Ordering::SeqCst));
+        let mut filters = self.filters.write().await;
+        // Lazily uninstall the timeout filters when a new filter is installed.
+        filters.retain(|_, filter| filter.last_poll.elapsed() < FILTER_TIMEOUT);
+        filters.insert(
+            filter_id,
+            InstalledFilter {
+                kind,
+                next_block,
+                last_poll: Instant::now(),
+            },
+        );
+        StrView(filter_id)
+    }
+
+    async fn next_block_number(&self) -> Result<u64> {
+        Ok(self
+            .latest_block_number()
+            .await?
+            .map_or(0, |block_number| block_number + 1))
+    }
+
+    pub async fn new_filter(&self, filter: Filter) -> Result<StrView<U256>> {
+        ensure!(
+            filter.block_hash.is_none(),
+            "The blockHash is not supported by eth_newFilter"
+        );
+        let next_block = match filter.from_block {
+            // The tags start the filter from the next block
+            Some(StrView(from_block @ (BlockNumber::Number(_) | BlockNumber::Earliest))) => self
+                .resolve_block_number(from_block)
+                .await?
+                .unwrap_or_default(),
+            _ => self.next_block_number().await?,
+        };
+        Ok(self
+            .install(FilterKind::Logs(Box::new(filter)), next_block)
+            .await)
+    }
+
+    pub async fn new_block_filter(&self) -> Result<StrView<U256>> {
+        let next_block = self.next_block_number().await?;
+        Ok(self.install(FilterKind::Blocks, next_block).await)
+    }
+
+    pub async fn get_filter_changes(&self, filter_id: U256) -> Result<FilterChanges> {
+        let filter = match self.filters.read().await.get(&filter_id) {
+            Some(filter) => filter.clone(),
+            None => bail!("Filter {} not found", filter_id),
+        };
+        let latest_block = match self.latest_block_number().await? {
+            Some(latest_block) if latest_block >= filter.next_block => latest_block,
+            // No new block since the last poll
+            _ => {
+                return Ok(match filter.kind {
+                    FilterKind::Logs(_) => FilterChanges::Logs(vec![]),
+                    FilterKind::Blocks => FilterChanges::Hashes(vec![]),
+                })
+            }
+        };
+        // Poll at most `MAX_BLOCK_RANGE` blocks at once, the rest are returned in the next polls.
+        let to_block = latest_block.min(filter.next_block + MAX_BLOCK_RANGE - 1);
+        let blocks = self
+            .get_blocks_in_range(filter.next_block, to_block)
+            .await?;
+        let next_block = blocks
+            .last()
+            .map_or(filter.next_block, |block| block.block_number as u64 + 1);
+        let changes = match &filter.kind {
+            FilterKind::Logs(log_filter) => {
+                let to_block = match log_filter.to_block {
+                    Some(to_block) => self.resolve_block_number(to_block.0).await?,
+                    None => None,
+                };
+                let blocks = match to_block {
+                    Some(to_block) => blocks
+                        .into_iter()
+                        .take_while(|block| block.block_number as u64 <= to_block)
+                        .collect(),
+                    None => blocks,
+                };
+                FilterChanges::Logs(self.get_blocks_logs(log_filter, &blocks).await?)
+            }
+            FilterKind::Blocks => FilterChanges::Hashes(
+                blocks
+                    .iter()
+                    .map(|block| block.block_hash().into())
+                    .collect(),
+            ),
+        };
+        if let Some(installed) = self.filters.write().await.get_mut(&filter_id) {
+            installed.next_block = next_block;
+            installed.last_poll = Instant::now();
+        }
+        Ok(changes)
+    }
+
+    pub async fn uninstall_filter(&self, filter_id: U256) -> bool {
+        self.filters.write().await.remove(&filter_id).is_some()
+    }
+}
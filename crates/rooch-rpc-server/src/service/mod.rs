@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod aggregate_service;
+pub mod eth_filter_service;
 pub mod rpc_logger;
 pub mod rpc_service;
+pub mod subscription_service;
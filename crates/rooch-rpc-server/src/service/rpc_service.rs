@@ -1,29 +1,47 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use crate::service::subscription_service::SubscriptionService;
+use anyhow::{ensure, Result};
+use futures::stream::BoxStream;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::access_path::AccessPath;
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
+use moveos_types::move_std::error::MoveAbortExplain;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
+use moveos_types::moveos_std::package_registry::{PackageInfo, PackageRegistry};
 use moveos_types::state::{AnnotatedState, MoveStructType, State};
-use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use moveos_types::transaction::{
+    FunctionCall, TransactionExecutionInfo, TransactionOutput, VerifiedMoveOSTransaction,
+};
+use rooch_config::ServerMode;
+use rooch_da::batch::DABatch;
 use rooch_executor::proxy::ExecutorProxy;
+use rooch_follower::TxReplayer;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::TxSubmiter;
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponse, ExecuteTransactionResponseView};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponse, ExecuteTransactionResponse, ExecuteTransactionResponseView,
+};
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::account::Account;
 use rooch_types::address::{MultiChainAddress, RoochAddress};
+use rooch_types::block::{Block, BlockSubmitStatus};
+use rooch_types::framework::transaction_fee::GasPrice;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::state::{IndexerStateID, IndexerTableChangeSet, StateFilter};
+use rooch_types::indexer::transaction_filter::IndexerTransaction;
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::sequencer::SequencerOrder;
 use rooch_types::transaction::rooch::RoochTransaction;
-use rooch_types::transaction::{TransactionSequenceInfo, TransactionSequenceInfoMapping};
+use rooch_types::transaction::{
+    TransactionProof, TransactionSequenceInfo, TransactionSequenceInfoMapping,
+};
 use rooch_types::transaction::{TransactionWithInfo, TypedTransaction};
 
 /// RpcService is the implementation of the RPC service.
@@ -36,6 +54,8 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) proposer: ProposerProxy,
     pub(crate) indexer: IndexerProxy,
+    pub(crate) server_mode: ServerMode,
+    pub(crate) subscription: SubscriptionService,
 }
 
 impl RpcService {
@@ -45,6 +65,8 @@ impl RpcService {
         sequencer: SequencerProxy,
         proposer: ProposerProxy,
         indexer: IndexerProxy,
+        server_mode: ServerMode,
+        subscription: SubscriptionService,
     ) -> Self {
         Self {
             chain_id,
@@ -52,6 +74,8 @@ impl RpcService {
             sequencer,
             proposer,
             indexer,
+            server_mode,
+            subscription,
         }
     }
 }
@@ -68,6 +92,10 @@ impl RpcService {
     }
 
     pub async fn execute_tx(&self, tx: TypedTransaction) -> Result<ExecuteTransactionResponse> {
+        ensure!(
+            self.server_mode != ServerMode::Follower,
+            "The follower does not accept transactions, please send the transaction to the leader"
+        );
         // First, validate the transactin
         let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
         let sequence_info = self.sequencer.sequence_transaction(tx.clone()).await?;
@@ -78,6 +106,93 @@ impl RpcService {
             .await?;
 
         // Last save indexer
+        self.index_tx(tx, moveos_tx, &sequence_info, &execution_info, &output)
+            .await;
+
+        let abort_explain = self.explain_move_abort(&output.status).await;
+        Ok(ExecuteTransactionResponse {
+            sequence_info,
+            execution_info,
+            output,
+            abort_explain,
+        })
+    }
+
+    /// Execute the transaction without applying the output, the signature is not verified.
+    pub async fn dry_run_tx(
+        &self,
+        tx: TypedTransaction,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResponse> {
+        let result = self.executor.dry_run_transaction(tx, profile_gas).await?;
+        let abort_explain = self.explain_move_abort(&result.raw_output.status).await;
+        Ok(DryRunTransactionResponse {
+            raw_output: result.raw_output,
+            annotated_state_changeset: result.annotated_state_changeset,
+            abort_explain,
+        })
+    }
+
+    /// Replay the transaction sequenced by the leader, it is used by the follower.
+    pub async fn replay_tx(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<ExecuteTransactionResponse> {
+        let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
+        let sequence_info = self
+            .sequencer
+            .sync_transaction(tx.clone(), sequence_info)
+            .await?;
+        let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
+        self.index_tx(tx, moveos_tx, &sequence_info, &execution_info, &output)
+            .await;
+
+        let abort_explain = self.explain_move_abort(&output.status).await;
+        Ok(ExecuteTransactionResponse {
+            sequence_info,
+            execution_info,
+            output,
+            abort_explain,
+        })
+    }
+
+    /// Explain the abort code if the transaction is aborted.
+    /// The explanation is best effort, the errors are logged and ignored.
+    async fn explain_move_abort(&self, status: &KeptVMStatus) -> Option<MoveAbortExplain> {
+        match status {
+            KeptVMStatus::MoveAbort(location, abort_code) => {
+                match self
+                    .executor
+                    .explain_move_abort(location.clone(), *abort_code)
+                    .await
+                {
+                    Ok(explain) => Some(explain),
+                    Err(error) => {
+                        log::warn!("Explain move abort error: {}", error);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    async fn index_tx(
+        &self,
+        tx: TypedTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+        sequence_info: &TransactionSequenceInfo,
+        execution_info: &TransactionExecutionInfo,
+        output: &TransactionOutput,
+    ) {
+        self.subscription.process_tx(
+            &tx,
+            moveos_tx.ctx.sender,
+            sequence_info,
+            execution_info,
+            output,
+        );
         let result = self
             .indexer
             .indexer_states(sequence_info.tx_order, output.state_changeset.clone())
@@ -107,12 +222,6 @@ impl RpcService {
             Ok(_) => {}
             Err(error) => log::error!("Indexer events error: {}", error),
         };
-
-        Ok(ExecuteTransactionResponse {
-            sequence_info,
-            execution_info,
-            output,
-        })
     }
 
     pub async fn execute_view_function(
@@ -138,6 +247,30 @@ impl RpcService {
         Ok(resp.pop().flatten().is_some())
     }
 
+    /// Get the package metadata recorded in the `PackageRegistry` by the package address
+    pub async fn get_package(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<PackageInfo>> {
+        let registry = self
+            .get_states(AccessPath::object(PackageRegistry::object_id()))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| state.as_object::<PackageRegistry>())
+            .transpose()?;
+        let registry = match registry {
+            Some(registry) => registry.value,
+            None => return Ok(None),
+        };
+        self.get_states(registry.package_info_access_path(package_address))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| state.cast::<PackageInfo>())
+            .transpose()
+    }
+
     pub async fn get_annotated_states(
         &self,
         access_path: AccessPath,
@@ -256,11 +389,71 @@ impl RpcService {
         Ok(resp)
     }
 
+    /// Get the call stack captured when the transaction failed, it is only available for the failed transactions
+    /// executed or dry run by this node.
+    pub async fn get_transaction_execution_trace(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<ExecutionTrace>> {
+        let resp = self
+            .executor
+            .get_transaction_execution_trace(tx_hash)
+            .await?;
+        Ok(resp)
+    }
+
+    /// Get the gas price at the state roots, the latest state is used if the state root is None
+    pub async fn get_gas_prices(
+        &self,
+        state_roots: Vec<Option<H256>>,
+    ) -> Result<Vec<Option<GasPrice>>> {
+        let resp = self.executor.get_gas_prices(state_roots).await?;
+        Ok(resp)
+    }
+
     pub async fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>> {
         let resp = self.sequencer.get_sequencer_order().await?;
         Ok(resp)
     }
 
+    pub async fn get_transaction_proof(&self, tx_order: u64) -> Result<Option<TransactionProof>> {
+        let resp = self.sequencer.get_transaction_proof(tx_order).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_blocks(&self, block_numbers: Vec<u128>) -> Result<Vec<Option<Block>>> {
+        let resp = self.proposer.get_blocks(block_numbers).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_latest_block(&self) -> Result<Option<Block>> {
+        let resp = self.proposer.get_latest_block().await?;
+        Ok(resp)
+    }
+
+    pub async fn get_block_number_by_hash(&self, block_hash: H256) -> Result<Option<u128>> {
+        let resp = self.proposer.get_block_number_by_hash(block_hash).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_block_number_by_tx_order(&self, tx_order: u64) -> Result<Option<u128>> {
+        let resp = self.proposer.get_block_number_by_tx_order(tx_order).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_block_submit_status(
+        &self,
+        block_number: u128,
+    ) -> Result<Option<BlockSubmitStatus>> {
+        let resp = self.proposer.get_block_submit_status(block_number).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_da_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        let resp = self.proposer.get_da_batch(block_number).await?;
+        Ok(resp)
+    }
+
     pub async fn query_transactions(
         &self,
         filter: TransactionFilter,
@@ -307,6 +500,24 @@ impl RpcService {
             .await?;
         Ok(resp)
     }
+
+    pub fn subscribe_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> BoxStream<'static, IndexerTransaction> {
+        self.subscription.subscribe_transactions(filter)
+    }
+
+    pub fn subscribe_events(&self, filter: EventFilter) -> BoxStream<'static, IndexerEvent> {
+        self.subscription.subscribe_events(filter)
+    }
+
+    pub fn subscribe_states(
+        &self,
+        filter: StateFilter,
+    ) -> BoxStream<'static, IndexerTableChangeSet> {
+        self.subscription.subscribe_states(filter)
+    }
 }
 
 //TODO we need to make the RpcService to an Actor, and implement TxSubmiter for it's actor proxy.
@@ -330,3 +541,14 @@ impl TxSubmiter for RpcService {
         Ok(self.execute_tx(TypedTransaction::Rooch(tx)).await?.into())
     }
 }
+
+#[async_trait::async_trait]
+impl TxReplayer for RpcService {
+    async fn replay_tx(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<TransactionExecutionInfo> {
+        Ok(self.replay_tx(tx, sequence_info).await?.execution_info)
+    }
+}
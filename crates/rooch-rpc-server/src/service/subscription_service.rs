@@ -0,0 +1,143 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::state::SplitStateChangeSet;
+use moveos_types::transaction::{TransactionExecutionInfo, TransactionOutput};
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::state::{IndexerTableChangeSet, StateFilter};
+use rooch_types::indexer::transaction_filter::{IndexerTransaction, TransactionFilter};
+use rooch_types::indexer::Filter;
+use rooch_types::transaction::{TransactionSequenceInfo, TransactionWithInfo, TypedTransaction};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// The capacity of the subscription channels, a subscriber lagging behind more than it will miss the oldest items.
+pub(crate) const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 1024;
+
+/// SubscriptionService publishes the executed transactions, events and state change sets to the subscribers.
+/// It is fed by the RpcService after the transaction is executed, in the same pipeline as the indexer.
+#[derive(Clone)]
+pub struct SubscriptionService {
+    transaction_sender: broadcast::Sender<IndexerTransaction>,
+    event_sender: broadcast::Sender<IndexerEvent>,
+    state_sender: broadcast::Sender<IndexerTableChangeSet>,
+}
+
+impl SubscriptionService {
+    pub fn new() -> Self {
+        let (transaction_sender, _) = broadcast::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+        let (event_sender, _) = broadcast::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+        let (state_sender, _) = broadcast::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+        Self {
+            transaction_sender,
+            event_sender,
+            state_sender,
+        }
+    }
+
+    /// Publish the executed transaction, the send result is ignored because there may be no subscriber.
+    pub fn process_tx(
+        &self,
+        tx: &TypedTransaction,
+        sender: AccountAddress,
+        sequence_info: &TransactionSequenceInfo,
+        execution_info: &TransactionExecutionInfo,
+        output: &TransactionOutput,
+    ) {
+        let tx_order = sequence_info.tx_order;
+        let created_at = sequence_info.tx_timestamp;
+
+        if self.transaction_sender.receiver_count() > 0 {
+            let _ = self.transaction_sender.send(IndexerTransaction {
+                transaction: TransactionWithInfo {
+                    transaction: tx.clone(),
+                    sequence_info: sequence_info.clone(),
+                    execution_info: execution_info.clone(),
+                },
+                sender,
+                created_at,
+            });
+        }
+
+        if self.event_sender.receiver_count() > 0 {
+            for event in output.events.iter() {
+                let _ = self.event_sender.send(IndexerEvent {
+                    indexer_event_id: IndexerEventID::new(tx_order, event.event_index),
+                    event_id: event.event_id,
+                    event_type: event.event_type.clone(),
+                    event_data: event.event_data.clone(),
+                    tx_hash: execution_info.tx_hash,
+                    sender,
+                    created_at,
+                });
+            }
+        }
+
+        if self.state_sender.receiver_count() > 0 {
+            let split_state_change_set = SplitStateChangeSet::from(output.state_changeset.clone());
+            for (index, (table_handle, table_change_set)) in split_state_change_set
+                .table_change_sets
+                .into_iter()
+                .enumerate()
+            {
+                let _ = self.state_sender.send(IndexerTableChangeSet {
+                    tx_order,
+                    table_handle_index: index as u64,
+                    table_handle,
+                    table_change_set,
+                    created_at,
+                });
+            }
+        }
+    }
+
+    pub fn subscribe_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> BoxStream<'static, IndexerTransaction> {
+        subscribe(&self.transaction_sender, filter)
+    }
+
+    pub fn subscribe_events(&self, filter: EventFilter) -> BoxStream<'static, IndexerEvent> {
+        subscribe(&self.event_sender, filter)
+    }
+
+    pub fn subscribe_states(
+        &self,
+        filter: StateFilter,
+    ) -> BoxStream<'static, IndexerTableChangeSet> {
+        subscribe(&self.state_sender, filter)
+    }
+}
+
+impl Default for SubscriptionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn subscribe<T, F>(sender: &broadcast::Sender<T>, filter: F) -> BoxStream<'static, T>
+where
+    T: Clone + Send + 'static,
+    F: Filter<T> + Send + 'static,
+{
+    let receiver = sender.subscribe();
+    stream::unfold((receiver, filter), |(mut receiver, filter)| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(item) => {
+                    if filter.matches(&item) {
+                        return Some((item, (receiver, filter)));
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    log::warn!("Subscriber lagged behind, {} items skipped", skipped);
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+    .boxed()
+}
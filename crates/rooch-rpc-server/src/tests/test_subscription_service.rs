@@ -0,0 +1,428 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::server::rooch_server::{pipe_events, pipe_states, pipe_transactions};
+use crate::service::subscription_service::{SubscriptionService, SUBSCRIPTION_CHANNEL_CAPACITY};
+use futures::StreamExt;
+use jsonrpsee::core::server::rpc_module::Subscription;
+use jsonrpsee::{rpc_params, RpcModule};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::ChangeSet;
+use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::{Event, EventID};
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::StateChangeSet;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, TransactionOutput};
+use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, IndexerEventView};
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionWithInfoView,
+};
+use rooch_rpc_api::jsonrpc_types::{IndexerTableChangeSetView, StateFilterView};
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::state::{IndexerTableChangeSet, StateFilter};
+use rooch_types::indexer::transaction_filter::TransactionFilter;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{
+    AbstractTransaction, TransactionSequenceInfo, TransactionWithInfo, TypedTransaction,
+};
+use serde_json::Value;
+use std::time::Duration;
+
+/// An executed transaction to publish to the subscription service.
+struct MockTx {
+    tx: TypedTransaction,
+    sender: AccountAddress,
+    sequence_info: TransactionSequenceInfo,
+    execution_info: TransactionExecutionInfo,
+    output: TransactionOutput,
+}
+
+impl MockTx {
+    fn new(
+        tx_order: u64,
+        sender: AccountAddress,
+        event_types: Vec<StructTag>,
+        table_handles: Vec<ObjectID>,
+    ) -> Self {
+        let tx_data = RoochTransactionData::new_for_test(
+            sender.into(),
+            0,
+            MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+        );
+        let tx = TypedTransaction::Rooch(RoochTransaction::new(
+            tx_data,
+            Authenticator::new(0, vec![]),
+        ));
+        let tx_hash = tx.tx_hash();
+        let events = event_types
+            .into_iter()
+            .enumerate()
+            .map(|(index, event_type)| {
+                Event::new(
+                    EventID::new(ObjectID::new(H256::random().into()), 0),
+                    event_type,
+                    vec![index as u8],
+                    index as u64,
+                )
+            })
+            .collect();
+        let mut state_changeset = StateChangeSet::default();
+        state_changeset.removed_tables.extend(table_handles);
+        Self {
+            tx,
+            sender,
+            sequence_info: TransactionSequenceInfo::new(
+                tx_order,
+                Authenticator::new(0, vec![]),
+                H256::random(),
+                1000 + tx_order,
+            ),
+            execution_info: TransactionExecutionInfo::new(
+                tx_hash,
+                H256::random(),
+                H256::random(),
+                0,
+                KeptVMStatus::Executed,
+            ),
+            output: TransactionOutput {
+                status: KeptVMStatus::Executed,
+                changeset: ChangeSet::new(),
+                state_changeset,
+                events,
+                gas_used: 0,
+                execution_trace: None,
+            },
+        }
+    }
+
+    fn publish(&self, service: &SubscriptionService) {
+        service.process_tx(
+            &self.tx,
+            self.sender,
+            &self.sequence_info,
+            &self.execution_info,
+            &self.output,
+        );
+    }
+
+    fn transaction_with_info(&self) -> TransactionWithInfo {
+        TransactionWithInfo {
+            transaction: self.tx.clone(),
+            sequence_info: self.sequence_info.clone(),
+            execution_info: self.execution_info.clone(),
+        }
+    }
+}
+
+fn event_type(name: &str) -> StructTag {
+    format!("0x3::test::{}", name).parse().unwrap()
+}
+
+fn random_table_handle() -> ObjectID {
+    ObjectID::new(H256::random().into())
+}
+
+/// Wait for the next item of the subscription, fail if no item is published in time.
+async fn next_item<T, S>(stream: &mut S) -> T
+where
+    S: futures::Stream<Item = T> + Unpin,
+{
+    tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Wait for the subscription item timeout")
+        .expect("The subscription is closed")
+}
+
+/// Check no item is published to the subscription.
+async fn assert_no_item<T, S>(stream: &mut S)
+where
+    S: futures::Stream<Item = T> + Unpin,
+{
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), stream.next())
+            .await
+            .is_err(),
+        "Unexpected subscription item"
+    );
+}
+
+#[tokio::test]
+async fn test_process_tx_without_subscriber() {
+    let service = SubscriptionService::new();
+    let tx = MockTx::new(
+        0,
+        AccountAddress::random(),
+        vec![event_type("A")],
+        vec![random_table_handle()],
+    );
+    // Publishing without subscriber is a no-op
+    tx.publish(&service);
+
+    // The subscriber only receives the transactions published after it subscribes
+    let mut transactions = service.subscribe_transactions(TransactionFilter::TxOrderRange {
+        from_order: 0,
+        to_order: u64::MAX,
+    });
+    assert_no_item(&mut transactions).await;
+    let tx = MockTx::new(1, AccountAddress::random(), vec![], vec![]);
+    tx.publish(&service);
+    assert_eq!(
+        next_item(&mut transactions).await.transaction,
+        tx.transaction_with_info()
+    );
+}
+
+#[tokio::test]
+async fn test_subscribe_transactions() {
+    let service = SubscriptionService::new();
+    let sender = AccountAddress::random();
+    let other_sender = AccountAddress::random();
+    let all_filter = || TransactionFilter::TxOrderRange {
+        from_order: 0,
+        to_order: u64::MAX,
+    };
+    // Every subscriber receives the published transactions
+    let mut first = service.subscribe_transactions(all_filter());
+    let mut second = service.subscribe_transactions(all_filter());
+    let mut by_sender = service.subscribe_transactions(TransactionFilter::Sender(sender));
+
+    let other_tx = MockTx::new(0, other_sender, vec![], vec![]);
+    let tx = MockTx::new(1, sender, vec![], vec![]);
+    other_tx.publish(&service);
+    tx.publish(&service);
+
+    for subscriber in [&mut first, &mut second] {
+        let item = next_item(subscriber).await;
+        assert_eq!(item.transaction, other_tx.transaction_with_info());
+        assert_eq!(item.sender, other_sender);
+        assert_eq!(item.created_at, other_tx.sequence_info.tx_timestamp);
+        let item = next_item(subscriber).await;
+        assert_eq!(item.transaction, tx.transaction_with_info());
+        assert_eq!(item.sender, sender);
+    }
+
+    // The filtered subscriber only receives the transactions of the sender
+    let item = next_item(&mut by_sender).await;
+    assert_eq!(item.transaction, tx.transaction_with_info());
+    assert_no_item(&mut by_sender).await;
+}
+
+#[tokio::test]
+async fn test_subscribe_events() {
+    let service = SubscriptionService::new();
+    let sender = AccountAddress::random();
+    let mut all = service.subscribe_events(EventFilter::Sender(sender));
+    let mut by_type = service.subscribe_events(EventFilter::EventType(event_type("B")));
+
+    let tx = MockTx::new(
+        3,
+        sender,
+        vec![event_type("A"), event_type("B"), event_type("A")],
+        vec![],
+    );
+    tx.publish(&service);
+
+    // Each event of the transaction is published with its index
+    let events: Vec<IndexerEvent> = vec![
+        next_item(&mut all).await,
+        next_item(&mut all).await,
+        next_item(&mut all).await,
+    ];
+    for (index, (event, expected)) in events.iter().zip(tx.output.events.iter()).enumerate() {
+        assert_eq!(event.indexer_event_id, IndexerEventID::new(3, index as u64));
+        assert_eq!(event.event_id, expected.event_id);
+        assert_eq!(event.event_type, expected.event_type);
+        assert_eq!(event.event_data, expected.event_data);
+        assert_eq!(event.tx_hash, tx.execution_info.tx_hash);
+        assert_eq!(event.sender, sender);
+        assert_eq!(event.created_at, tx.sequence_info.tx_timestamp);
+    }
+    assert_no_item(&mut all).await;
+
+    let event = next_item(&mut by_type).await;
+    assert_eq!(event.indexer_event_id, IndexerEventID::new(3, 1));
+    assert_eq!(event.event_type, event_type("B"));
+    assert_no_item(&mut by_type).await;
+}
+
+#[tokio::test]
+async fn test_subscribe_states() {
+    let service = SubscriptionService::new();
+    let table_handle = random_table_handle();
+    let other_table_handle = random_table_handle();
+    let mut by_table = service.subscribe_states(StateFilter::TableHandle(table_handle));
+    let mut by_other_table = service.subscribe_states(StateFilter::TableHandle(other_table_handle));
+
+    let tx = MockTx::new(5, AccountAddress::random(), vec![], vec![table_handle]);
+    tx.publish(&service);
+
+    let item = next_item(&mut by_table).await;
+    assert_eq!(item.tx_order, 5);
+    assert_eq!(item.table_handle, table_handle);
+    assert!(item.table_change_set.removed_tables.contains(&table_handle));
+    assert_eq!(item.created_at, tx.sequence_info.tx_timestamp);
+    assert_no_item(&mut by_table).await;
+    assert_no_item(&mut by_other_table).await;
+}
+
+#[tokio::test]
+async fn test_subscribe_lagged() {
+    let service = SubscriptionService::new();
+    let mut lagged = service.subscribe_transactions(TransactionFilter::TxOrderRange {
+        from_order: 0,
+        to_order: u64::MAX,
+    });
+    let skipped = 10;
+    let txs: Vec<_> = (0..(SUBSCRIPTION_CHANNEL_CAPACITY as u64 + skipped))
+        .map(|tx_order| MockTx::new(tx_order, AccountAddress::random(), vec![], vec![]))
+        .collect();
+    for tx in &txs {
+        tx.publish(&service);
+    }
+
+    // The lagged subscriber misses the oldest transactions, and keeps receiving the rest
+    for tx in &txs[skipped as usize..] {
+        let item = next_item(&mut lagged).await;
+        assert_eq!(item.transaction, tx.transaction_with_info());
+    }
+    assert_no_item(&mut lagged).await;
+
+    let tx = MockTx::new(txs.len() as u64, AccountAddress::random(), vec![], vec![]);
+    tx.publish(&service);
+    assert_eq!(
+        next_item(&mut lagged).await.transaction,
+        tx.transaction_with_info()
+    );
+}
+
+/// The rpc module with the same subscription methods as `RoochServer`, to pipe the subscriptions through a sink.
+fn subscription_module(service: SubscriptionService) -> RpcModule<SubscriptionService> {
+    let mut module = RpcModule::new(service);
+    module
+        .register_subscription(
+            "rooch_subscribeTransactions",
+            "rooch_subscribeTransactions",
+            "rooch_unsubscribeTransactions",
+            |params, sink, service| {
+                let filter: TransactionFilterView = params.one().unwrap();
+                pipe_transactions(sink, service.subscribe_transactions(filter.into()));
+                Ok(())
+            },
+        )
+        .unwrap();
+    module
+        .register_subscription(
+            "rooch_subscribeEvents",
+            "rooch_subscribeEvents",
+            "rooch_unsubscribeEvents",
+            |params, sink, service| {
+                let filter: EventFilterView = params.one().unwrap();
+                pipe_events(sink, service.subscribe_events(filter.into()));
+                Ok(())
+            },
+        )
+        .unwrap();
+    module
+        .register_subscription(
+            "rooch_subscribeStates",
+            "rooch_subscribeStates",
+            "rooch_unsubscribeStates",
+            |params, sink, service| {
+                let filter: StateFilterView = params.one().unwrap();
+                pipe_states(sink, service.subscribe_states(filter.into()));
+                Ok(())
+            },
+        )
+        .unwrap();
+    module
+}
+
+async fn next_notification(subscription: &mut Subscription) -> Value {
+    let (value, _id) = tokio::time::timeout(Duration::from_secs(5), subscription.next::<Value>())
+        .await
+        .expect("Wait for the subscription notification timeout")
+        .expect("The subscription is closed")
+        .unwrap();
+    value
+}
+
+#[tokio::test]
+async fn test_subscription_handlers() {
+    let service = SubscriptionService::new();
+    let module = subscription_module(service.clone());
+    let sender = AccountAddress::random();
+    let table_handle = random_table_handle();
+
+    let mut transactions = module
+        .subscribe(
+            "rooch_subscribeTransactions",
+            rpc_params![TransactionFilterView::Sender(sender.into())],
+        )
+        .await
+        .unwrap();
+    let mut events = module
+        .subscribe(
+            "rooch_subscribeEvents",
+            rpc_params![EventFilterView::EventType(event_type("B").into())],
+        )
+        .await
+        .unwrap();
+    let mut states = module
+        .subscribe(
+            "rooch_subscribeStates",
+            rpc_params![StateFilterView::TableHandle(table_handle)],
+        )
+        .await
+        .unwrap();
+
+    MockTx::new(
+        0,
+        AccountAddress::random(),
+        vec![event_type("A")],
+        vec![random_table_handle()],
+    )
+    .publish(&service);
+    let tx = MockTx::new(
+        1,
+        sender,
+        vec![event_type("A"), event_type("B")],
+        vec![table_handle],
+    );
+    tx.publish(&service);
+
+    // Each subscription receives the views of the matched items only
+    assert_eq!(
+        next_notification(&mut transactions).await,
+        serde_json::to_value(TransactionWithInfoView::from(tx.transaction_with_info())).unwrap()
+    );
+    let event = tx.output.events[1].clone();
+    assert_eq!(
+        next_notification(&mut events).await,
+        serde_json::to_value(IndexerEventView::from(IndexerEvent {
+            indexer_event_id: IndexerEventID::new(1, 1),
+            event_id: event.event_id,
+            event_type: event.event_type,
+            event_data: event.event_data,
+            tx_hash: tx.execution_info.tx_hash,
+            sender,
+            created_at: tx.sequence_info.tx_timestamp,
+        }))
+        .unwrap()
+    );
+    let mut table_change_set = moveos_types::state::TableChangeSet::default();
+    table_change_set.removed_tables.insert(table_handle);
+    assert_eq!(
+        next_notification(&mut states).await,
+        serde_json::to_value(IndexerTableChangeSetView::from(IndexerTableChangeSet {
+            tx_order: 1,
+            table_handle_index: 0,
+            table_handle,
+            table_change_set,
+            created_at: tx.sequence_info.tx_timestamp,
+        }))
+        .unwrap()
+    );
+}
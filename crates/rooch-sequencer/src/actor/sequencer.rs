@@ -2,29 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage, TransactionSequenceMessage,
+    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionProofMessage,
+    GetTransactionsByHashMessage, GetTxSequenceInfoMappingByHashMessage,
+    GetTxSequenceInfoMappingByOrderMessage, GetTxSequenceInfosMessage, TransactionSequenceMessage,
+    TransactionSyncMessage,
 };
-use anyhow::Result;
+use accumulator::{Accumulator, MerkleAccumulator};
+use anyhow::{bail, ensure, format_err, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use moveos_types::h256::{self, H256};
-use rooch_store::meta_store::MetaStore;
+use moveos_types::h256::H256;
+use rooch_store::accumulator_store::TxAccumulatorStore;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::sequencer::SequencerOrder;
+use rooch_types::transaction::authenticator::Authenticator;
 use rooch_types::transaction::{
-    TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
+    TransactionProof, TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
 };
-use rooch_types::{
-    crypto::{RoochKeyPair, Signature},
-    transaction::AbstractTransaction,
-};
-use tracing::info;
+use rooch_types::{crypto::RoochKeyPair, transaction::AbstractTransaction};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// The max number of tx hashes loaded at once when rebuilding the tx accumulator.
+const REBUILD_ACCUMULATOR_BATCH_SIZE: u64 = 1000;
 
 pub struct SequencerActor {
     last_order: u64,
+    tx_accumulator: MerkleAccumulator,
     sequencer_key: RoochKeyPair,
     rooch_store: RoochStore,
 }
@@ -41,24 +46,70 @@ impl SequencerActor {
             .map(|order| order.last_order);
         let last_order = last_order_opt.unwrap_or(0u64);
         info!("Load latest sequencer order {:?}", last_order);
+        let tx_accumulator = Self::load_tx_accumulator(&rooch_store, last_order_opt)?;
+        info!(
+            "Load latest tx accumulator root {:?}, num_leaves {}",
+            tx_accumulator.root_hash(),
+            tx_accumulator.num_leaves()
+        );
         Ok(Self {
             last_order,
+            tx_accumulator,
             sequencer_key,
             rooch_store,
         })
     }
-}
 
-impl Actor for SequencerActor {}
+    /// Load the tx accumulator from the accumulator info saved at `last_order`.
+    /// If the info is missing, the store was created before the accumulator was persisted,
+    /// so rebuild the accumulator from the sequenced tx hashes.
+    fn load_tx_accumulator(
+        rooch_store: &RoochStore,
+        last_order_opt: Option<u64>,
+    ) -> Result<MerkleAccumulator> {
+        let node_store = rooch_store.get_tx_accumulator_node_store();
+        let last_order = match last_order_opt {
+            Some(last_order) => last_order,
+            None => return Ok(MerkleAccumulator::new_empty(node_store)),
+        };
+        if let Some(info) = rooch_store.get_tx_accumulator_info(last_order)? {
+            ensure!(
+                info.num_leaves == last_order + 1,
+                "Tx accumulator num_leaves {} mismatch with last sequencer order {}",
+                info.num_leaves,
+                last_order
+            );
+            return Ok(MerkleAccumulator::new_with_info(info, node_store));
+        }
 
-#[async_trait]
-impl Handler<TransactionSequenceMessage> for SequencerActor {
-    async fn handle(
-        &mut self,
-        msg: TransactionSequenceMessage,
-        _ctx: &mut ActorContext,
-    ) -> Result<TransactionSequenceInfo> {
-        let tx = msg.tx;
+        warn!(
+            "Tx accumulator info not found at order {}, rebuild it from sequenced transactions",
+            last_order
+        );
+        let tx_accumulator = MerkleAccumulator::new_empty(node_store);
+        let mut start = 0u64;
+        while start <= last_order {
+            let end = std::cmp::min(start + REBUILD_ACCUMULATOR_BATCH_SIZE, last_order + 1);
+            let tx_hashes = rooch_store
+                .get_transaction_store()
+                .get_tx_sequence_info_mapping_by_order((start..end).collect())?
+                .into_iter()
+                .zip(start..end)
+                .map(|(mapping, tx_order)| {
+                    mapping
+                        .map(|mapping| mapping.tx_hash)
+                        .ok_or_else(|| format_err!("Tx hash not found at order {}", tx_order))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            tx_accumulator.append(&tx_hashes)?;
+            start = end;
+        }
+        tx_accumulator.flush()?;
+        rooch_store.save_tx_accumulator_info(last_order, tx_accumulator.get_info())?;
+        Ok(tx_accumulator)
+    }
+
+    fn next_tx_order(&self) -> Result<u64> {
         let tx_order = if self.last_order == 0 {
             let last_order_opt = self
                 .rooch_store
@@ -72,31 +123,113 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         } else {
             self.last_order + 1
         };
+        Ok(tx_order)
+    }
+
+    /// Append the transaction to the tx accumulator at `tx_order`, and save the transaction with the sequence info
+    /// and the accumulator atomically.
+    /// If `expected_tx_accumulator_root` is provided, the new accumulator root must be equal to it.
+    fn append_transaction(
+        &mut self,
+        tx: TypedTransaction,
+        tx_order: u64,
+        tx_order_signature: Authenticator,
+        tx_timestamp: u64,
+        expected_tx_accumulator_root: Option<H256>,
+    ) -> Result<TransactionSequenceInfo> {
         let hash = tx.tx_hash();
-        let mut witness_data = hash.as_ref().to_vec();
-        witness_data.extend(tx_order.to_le_bytes().iter());
-        let witness_hash = h256::sha3_256_of(&witness_data);
-        let tx_order_signature = Signature::new_hashed(&witness_hash.0, &self.sequencer_key).into();
+        // The leaf index of the tx in the accumulator is always the tx order.
+        ensure!(
+            self.tx_accumulator.num_leaves() == tx_order,
+            "Tx accumulator num_leaves {} mismatch with tx order {}",
+            self.tx_accumulator.num_leaves(),
+            tx_order
+        );
+        let tx_accumulator_root = self.tx_accumulator.append(&[hash])?;
+        if let Some(expected_root) = expected_tx_accumulator_root {
+            if tx_accumulator_root != expected_root {
+                // Drop the unsaved leaf, the accumulator is reloaded from the saved info.
+                self.tx_accumulator =
+                    Self::load_tx_accumulator(&self.rooch_store, tx_order.checked_sub(1))?;
+                bail!(
+                    "The tx accumulator root {:?} at tx order {} mismatch with the expected root {:?}",
+                    tx_accumulator_root,
+                    tx_order,
+                    expected_root
+                );
+            }
+        }
+        let tx_accumulator_info = self.tx_accumulator.get_info();
+        let tx_accumulator_nodes = self.tx_accumulator.pop_unsaved_nodes().unwrap_or_default();
+        let tx_sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            tx_order_signature,
+            tx_accumulator_root,
+            tx_timestamp,
+        );
+        // The accumulator nodes are saved with the transaction in one batch instead of being flushed alone.
+        if let Err(e) = self.rooch_store.save_sequenced_tx(
+            tx,
+            tx_sequence_info.clone(),
+            tx_accumulator_nodes,
+            tx_accumulator_info,
+        ) {
+            self.tx_accumulator =
+                Self::load_tx_accumulator(&self.rooch_store, tx_order.checked_sub(1))?;
+            return Err(e);
+        }
         self.last_order = tx_order;
+        Ok(tx_sequence_info)
+    }
+}
 
-        self.rooch_store.save_transaction(tx)?;
-        self.rooch_store
-            .save_tx_sequence_info_mapping(tx_order, hash)?;
-        self.rooch_store
-            .save_tx_sequence_info_reverse_mapping(hash, tx_order)?;
+impl Actor for SequencerActor {}
 
-        self.rooch_store
-            .save_sequencer_order(SequencerOrder::new(self.last_order))?;
+#[async_trait]
+impl Handler<TransactionSequenceMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: TransactionSequenceMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TransactionSequenceInfo> {
+        let tx = msg.tx;
+        let tx_order = self.next_tx_order()?;
+        let tx_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64;
+        let tx_order_signature = TransactionSequenceInfo::sign_tx_order(
+            tx.tx_hash(),
+            tx_order,
+            tx_timestamp,
+            &self.sequencer_key,
+        );
+        self.append_transaction(tx, tx_order, tx_order_signature, tx_timestamp, None)
+    }
+}
 
-        let tx_accumulator_root = H256::random();
-        let tx_sequence_info = TransactionSequenceInfo {
+#[async_trait]
+impl Handler<TransactionSyncMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: TransactionSyncMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TransactionSequenceInfo> {
+        let TransactionSyncMessage { tx, sequence_info } = msg;
+        let tx_order = self.next_tx_order()?;
+        ensure!(
+            sequence_info.tx_order == tx_order,
+            "The synced tx order {} mismatch with the expected tx order {}",
+            sequence_info.tx_order,
+            tx_order
+        );
+        self.append_transaction(
+            tx,
             tx_order,
-            tx_order_signature,
-            tx_accumulator_root,
-        };
-        self.rooch_store
-            .save_tx_sequence_info(tx_sequence_info.clone())?;
-        Ok(tx_sequence_info)
+            sequence_info.tx_order_signature,
+            sequence_info.tx_timestamp,
+            Some(sequence_info.tx_accumulator_root),
+        )
     }
 }
 
@@ -175,3 +308,33 @@ impl Handler<GetSequencerOrderMessage> for SequencerActor {
         self.rooch_store.get_meta_store().get_sequencer_order()
     }
 }
+
+#[async_trait]
+impl Handler<GetTransactionProofMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: GetTransactionProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<TransactionProof>> {
+        let GetTransactionProofMessage { tx_order } = msg;
+        let num_leaves = self.tx_accumulator.num_leaves();
+        if tx_order >= num_leaves {
+            return Ok(None);
+        }
+        let tx_hash = match self.tx_accumulator.get_leaf(tx_order)? {
+            Some(tx_hash) => tx_hash,
+            None => return Ok(None),
+        };
+        let proof = match self.tx_accumulator.get_proof(tx_order)? {
+            Some(proof) => proof,
+            None => return Ok(None),
+        };
+        Ok(Some(TransactionProof::new(
+            tx_order,
+            tx_hash,
+            num_leaves - 1,
+            self.tx_accumulator.root_hash(),
+            proof,
+        )))
+    }
+}
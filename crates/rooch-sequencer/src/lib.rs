@@ -4,3 +4,5 @@
 pub mod actor;
 pub mod messages;
 pub mod proxy;
+#[cfg(test)]
+mod tests;
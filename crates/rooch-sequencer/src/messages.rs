@@ -5,7 +5,7 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use moveos_types::h256::H256;
 use rooch_types::sequencer::SequencerOrder;
-use rooch_types::transaction::TransactionSequenceInfoMapping;
+use rooch_types::transaction::{TransactionProof, TransactionSequenceInfoMapping};
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +19,17 @@ impl Message for TransactionSequenceMessage {
     type Result = Result<TransactionSequenceInfo>;
 }
 
+/// Transaction Sync Message, append the transaction sequenced by the leader sequencer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionSyncMessage {
+    pub tx: TypedTransaction,
+    pub sequence_info: TransactionSequenceInfo,
+}
+
+impl Message for TransactionSyncMessage {
+    type Result = Result<TransactionSequenceInfo>;
+}
+
 /// Get Transaction By Hash Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTransactionByHashMessage {
@@ -72,3 +83,13 @@ pub struct GetSequencerOrderMessage {}
 impl Message for GetSequencerOrderMessage {
     type Result = Result<Option<SequencerOrder>>;
 }
+
+/// Get the inclusion proof of the tx at `tx_order` under the latest tx accumulator root
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTransactionProofMessage {
+    pub tx_order: u64,
+}
+
+impl Message for GetTransactionProofMessage {
+    type Result = Result<Option<TransactionProof>>;
+}
@@ -2,16 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage,
+    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionProofMessage,
+    GetTransactionsByHashMessage, GetTxSequenceInfoMappingByHashMessage,
+    GetTxSequenceInfoMappingByOrderMessage, GetTxSequenceInfosMessage, TransactionSyncMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
 use coerce::actor::ActorRef;
 use moveos_types::h256::H256;
 use rooch_types::sequencer::SequencerOrder;
-use rooch_types::transaction::TransactionSequenceInfo;
+use rooch_types::transaction::{TransactionProof, TransactionSequenceInfo};
 use rooch_types::transaction::{TransactionSequenceInfoMapping, TypedTransaction};
 
 #[derive(Clone)]
@@ -31,6 +31,17 @@ impl SequencerProxy {
         self.actor.send(TransactionSequenceMessage { tx }).await?
     }
 
+    /// Sync the transaction sequenced by the leader sequencer, it is used by the follower.
+    pub async fn sync_transaction(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<TransactionSequenceInfo> {
+        self.actor
+            .send(TransactionSyncMessage { tx, sequence_info })
+            .await?
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
         self.actor
             .send(GetTransactionByHashMessage { hash })
@@ -76,4 +87,10 @@ impl SequencerProxy {
     pub async fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>> {
         self.actor.send(GetSequencerOrderMessage {}).await?
     }
+
+    pub async fn get_transaction_proof(&self, tx_order: u64) -> Result<Option<TransactionProof>> {
+        self.actor
+            .send(GetTransactionProofMessage { tx_order })
+            .await?
+    }
 }
@@ -0,0 +1,98 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::sequencer::SequencerActor;
+use crate::proxy::SequencerProxy;
+use accumulator::tree_store::mock::MockAccumulatorStore;
+use accumulator::{Accumulator, MerkleAccumulator};
+use coerce::actor::{system::ActorSystem, IntoActor};
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::traits::KeyPair;
+use moveos_types::transaction::MoveAction;
+use rand::{rngs::StdRng, SeedableRng};
+use rooch_store::accumulator_store::TxAccumulatorStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::RoochStore;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
+use std::sync::Arc;
+
+fn mock_tx(sequence_number: u64) -> TypedTransaction {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        sequence_number,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    TypedTransaction::Rooch(RoochTransaction::new(
+        tx_data,
+        Authenticator {
+            auth_validator_id: 0,
+            payload: vec![],
+        },
+    ))
+}
+
+async fn start_sequencer(
+    rooch_store: &RoochStore,
+    actor_system: &ActorSystem,
+    name: &str,
+) -> SequencerProxy {
+    let mut rng = StdRng::seed_from_u64(0);
+    let sequencer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+    let sequencer = SequencerActor::new(sequencer_key, rooch_store.clone(), false)
+        .unwrap()
+        .into_actor(Some(name), actor_system)
+        .await
+        .unwrap();
+    SequencerProxy::new(sequencer.into())
+}
+
+#[tokio::test]
+async fn test_sequencer_reload_tx_accumulator() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let actor_system = ActorSystem::new();
+    // The expected accumulator is built in memory
+    let expected_accumulator = MerkleAccumulator::new_empty(Arc::new(MockAccumulatorStore::new()));
+
+    let sequencer = start_sequencer(&rooch_store, &actor_system, "Sequencer").await;
+    for tx_order in 0..3 {
+        let tx = mock_tx(tx_order);
+        let expected_root = expected_accumulator.append(&[tx.tx_hash()]).unwrap();
+        let sequence_info = sequencer.sequence_transaction(tx).await.unwrap();
+        assert_eq!(sequence_info.tx_order, tx_order);
+        assert_eq!(sequence_info.tx_accumulator_root, expected_root);
+    }
+    assert_eq!(
+        rooch_store.get_tx_accumulator_info(2).unwrap(),
+        Some(expected_accumulator.get_info())
+    );
+
+    // The restarted sequencer continues from the persisted accumulator
+    let sequencer = start_sequencer(&rooch_store, &actor_system, "RestartedSequencer").await;
+    let tx = mock_tx(3);
+    let expected_root = expected_accumulator.append(&[tx.tx_hash()]).unwrap();
+    let sequence_info = sequencer.sequence_transaction(tx).await.unwrap();
+    assert_eq!(sequence_info.tx_order, 3);
+    assert_eq!(sequence_info.tx_accumulator_root, expected_root);
+    assert_eq!(
+        rooch_store
+            .get_sequencer_order()
+            .unwrap()
+            .unwrap()
+            .last_order,
+        3
+    );
+
+    for tx_order in 0..4 {
+        let proof = sequencer
+            .get_transaction_proof(tx_order)
+            .await
+            .unwrap()
+            .expect("the proof of the sequenced tx should exist");
+        assert_eq!(proof.tx_accumulator_root, expected_root);
+        proof.verify().unwrap();
+    }
+}
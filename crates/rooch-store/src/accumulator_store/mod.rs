@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TX_ACCUMULATOR_INFO_PREFIX_NAME, TX_ACCUMULATOR_NODE_PREFIX_NAME};
+use accumulator::accumulator_info::AccumulatorInfo;
+use accumulator::{AccumulatorNode, AccumulatorTreeStore};
+use anyhow::Result;
+use moveos_store::accumulator_store::AccumulatorStore;
+use moveos_types::h256::H256;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use std::sync::Arc;
+
+derive_store!(
+    TxAccumulatorNodeStore,
+    H256,
+    AccumulatorNode,
+    TX_ACCUMULATOR_NODE_PREFIX_NAME
+);
+
+derive_store!(
+    TxAccumulatorInfoStore,
+    u64,
+    AccumulatorInfo,
+    TX_ACCUMULATOR_INFO_PREFIX_NAME
+);
+
+pub trait TxAccumulatorStore {
+    /// The node store of the transaction accumulator, used to build the `MerkleAccumulator`.
+    fn get_tx_accumulator_node_store(&self) -> Arc<dyn AccumulatorTreeStore>;
+
+    /// Save the transaction accumulator info after the tx with `tx_order` is appended.
+    fn save_tx_accumulator_info(&self, tx_order: u64, info: AccumulatorInfo) -> Result<()>;
+
+    fn get_tx_accumulator_info(&self, tx_order: u64) -> Result<Option<AccumulatorInfo>>;
+}
+
+#[derive(Clone)]
+pub struct TxAccumulatorDBStore {
+    node_store: Arc<AccumulatorStore<TxAccumulatorNodeStore>>,
+    info_store: TxAccumulatorInfoStore,
+}
+
+impl TxAccumulatorDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        TxAccumulatorDBStore {
+            node_store: Arc::new(AccumulatorStore::new(TxAccumulatorNodeStore::new(
+                instance.clone(),
+            ))),
+            info_store: TxAccumulatorInfoStore::new(instance),
+        }
+    }
+
+    pub fn get_tx_accumulator_node_store(&self) -> Arc<dyn AccumulatorTreeStore> {
+        self.node_store.clone()
+    }
+
+    pub fn save_tx_accumulator_info(&self, tx_order: u64, info: AccumulatorInfo) -> Result<()> {
+        self.info_store.kv_put(tx_order, info)
+    }
+
+    pub fn get_tx_accumulator_info(&self, tx_order: u64) -> Result<Option<AccumulatorInfo>> {
+        self.info_store.kv_get(tx_order)
+    }
+}
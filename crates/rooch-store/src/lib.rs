@@ -1,24 +1,39 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::meta_store::{MetaDBStore, MetaStore};
+use crate::accumulator_store::{TxAccumulatorDBStore, TxAccumulatorStore};
+use crate::meta_store::{MetaDBStore, MetaStore, CURRENT_STORE_VERSION, SEQUENCER_ORDER_KEY};
+use crate::proposer_store::{ProposerDBStore, ProposerStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
+use accumulator::accumulator_info::AccumulatorInfo;
+use accumulator::{AccumulatorNode, AccumulatorTreeStore};
 use anyhow::Result;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_config::temp_dir;
 use moveos_types::h256::H256;
 use once_cell::sync::Lazy;
+use raw_store::rocks::batch::WriteBatchCF;
 use raw_store::rocks::RocksDB;
-use raw_store::{ColumnFamilyName, StoreInstance};
+use raw_store::traits::DBStore;
+use raw_store::{CodecWriteBatch, ColumnFamilyName, StoreInstance};
+use rooch_da::DAPutResponse;
+use rooch_types::block::{Block, BlockSubmitStatus};
 use rooch_types::sequencer::SequencerOrder;
 use rooch_types::transaction::{
-    TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
+    AbstractTransaction, TransactionSequenceInfo, TransactionSequenceInfoMapping,
+    TransactionWithInfo, TypedTransaction,
 };
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
 
+pub mod accumulator_store;
 pub mod meta_store;
+pub mod proposer_store;
 pub mod transaction_store;
 
+#[cfg(test)]
+mod tests;
+
 // pub const DEFAULT_PREFIX_NAME: ColumnFamilyName = "default";
 pub const TYPED_TRANSACTION_PREFIX_NAME: ColumnFamilyName = "typed_transaction";
 pub const TX_SEQUENCE_INFO_PREFIX_NAME: ColumnFamilyName = "tx_sequence_info";
@@ -27,6 +42,18 @@ pub const TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME: ColumnFamilyName =
     "tx_sequence_info_reverse_mapping";
 
 pub const META_SEQUENCER_ORDER_PREFIX_NAME: ColumnFamilyName = "meta_sequencer_order";
+pub const META_STORE_VERSION_PREFIX_NAME: ColumnFamilyName = "meta_store_version";
+
+pub const TX_ACCUMULATOR_NODE_PREFIX_NAME: ColumnFamilyName = "tx_accumulator_node";
+pub const TX_ACCUMULATOR_INFO_PREFIX_NAME: ColumnFamilyName = "tx_accumulator_info";
+
+pub const PROPOSER_BLOCK_PREFIX_NAME: ColumnFamilyName = "proposer_block";
+pub const PROPOSER_LAST_BLOCK_PREFIX_NAME: ColumnFamilyName = "proposer_last_block";
+pub const PROPOSER_BUFFER_PREFIX_NAME: ColumnFamilyName = "proposer_buffer";
+pub const PROPOSER_BLOCK_SUBMIT_STATUS_PREFIX_NAME: ColumnFamilyName =
+    "proposer_block_submit_status";
+pub const PROPOSER_DA_PUT_RESPONSE_PREFIX_NAME: ColumnFamilyName = "proposer_da_put_response";
+pub const PROPOSER_BLOCK_HASH_INDEX_PREFIX_NAME: ColumnFamilyName = "proposer_block_hash_index";
 
 ///db store use prefix_name vec to init
 /// Please note that adding a prefix needs to be added in vec simultaneously, remember！！
@@ -37,6 +64,15 @@ static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TX_SEQUENCE_INFO_MAPPING_PREFIX_NAME,
         META_SEQUENCER_ORDER_PREFIX_NAME,
         TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME,
+        TX_ACCUMULATOR_NODE_PREFIX_NAME,
+        TX_ACCUMULATOR_INFO_PREFIX_NAME,
+        PROPOSER_BLOCK_PREFIX_NAME,
+        PROPOSER_LAST_BLOCK_PREFIX_NAME,
+        PROPOSER_BUFFER_PREFIX_NAME,
+        PROPOSER_BLOCK_SUBMIT_STATUS_PREFIX_NAME,
+        PROPOSER_DA_PUT_RESPONSE_PREFIX_NAME,
+        PROPOSER_BLOCK_HASH_INDEX_PREFIX_NAME,
+        META_STORE_VERSION_PREFIX_NAME,
     ]
 });
 
@@ -51,24 +87,43 @@ impl StoreMeta {
 
 #[derive(Clone)]
 pub struct RoochStore {
+    store_instance: StoreInstance,
     pub transaction_store: TransactionDBStore,
     pub meta_store: MetaDBStore,
+    pub tx_accumulator_store: TxAccumulatorDBStore,
+    pub proposer_store: ProposerDBStore,
 }
 
 impl RoochStore {
     pub fn new(instance: StoreInstance) -> Result<Self> {
         let store = Self {
+            store_instance: instance.clone(),
             transaction_store: TransactionDBStore::new(instance.clone()),
-            meta_store: MetaDBStore::new(instance),
+            meta_store: MetaDBStore::new(instance.clone()),
+            tx_accumulator_store: TxAccumulatorDBStore::new(instance.clone()),
+            proposer_store: ProposerDBStore::new(instance),
         };
+        store.migrate()?;
         Ok(store)
     }
 
+    /// Migrate the values saved in a legacy layout to the current layout, the store version is saved after
+    /// the migration, so the migration only runs once.
+    fn migrate(&self) -> Result<()> {
+        let store_version = self.meta_store.get_store_version()?.unwrap_or(0);
+        if store_version < CURRENT_STORE_VERSION {
+            self.transaction_store.migrate_legacy_tx_sequence_infos()?;
+            self.proposer_store.migrate_legacy_propose_buffer()?;
+            self.meta_store.save_store_version(CURRENT_STORE_VERSION)?;
+        }
+        Ok(())
+    }
+
     //TODO implement a memory mock store
     pub fn mock_rooch_store() -> Result<Self> {
         Self::new(StoreInstance::new_db_instance(RocksDB::new(
             temp_dir().path(),
-            moveos_store::StoreMeta::get_column_family_names().to_vec(),
+            StoreMeta::get_column_family_names().to_vec(),
             RocksdbConfig::default(),
             None,
         )?))
@@ -81,6 +136,67 @@ impl RoochStore {
     pub fn get_meta_store(&self) -> &MetaDBStore {
         &self.meta_store
     }
+
+    pub fn get_tx_accumulator_store(&self) -> &TxAccumulatorDBStore {
+        &self.tx_accumulator_store
+    }
+
+    pub fn get_proposer_store(&self) -> &ProposerDBStore {
+        &self.proposer_store
+    }
+
+    /// Save the sequenced transaction, its sequence info and mappings, the new tx accumulator nodes and info,
+    /// and the sequencer order in one batch, so the accumulator is always consistent with the sequenced
+    /// transactions after a crash.
+    pub fn save_sequenced_tx(
+        &self,
+        tx: TypedTransaction,
+        tx_sequence_info: TransactionSequenceInfo,
+        tx_accumulator_nodes: Vec<AccumulatorNode>,
+        tx_accumulator_info: AccumulatorInfo,
+    ) -> Result<()> {
+        let tx_hash = tx.tx_hash();
+        let tx_order = tx_sequence_info.tx_order;
+        let cf_batches = vec![
+            WriteBatchCF::new_with_codec(
+                TYPED_TRANSACTION_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(tx_hash, tx)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                TX_SEQUENCE_INFO_MAPPING_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(tx_order, tx_hash)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(tx_hash, tx_order)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                TX_SEQUENCE_INFO_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(tx_order, tx_sequence_info)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                TX_ACCUMULATOR_NODE_PREFIX_NAME,
+                CodecWriteBatch::new_puts(
+                    tx_accumulator_nodes
+                        .into_iter()
+                        .map(|node| (node.hash(), node))
+                        .collect(),
+                ),
+            )?,
+            WriteBatchCF::new_with_codec(
+                TX_ACCUMULATOR_INFO_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(tx_order, tx_accumulator_info)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                META_SEQUENCER_ORDER_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(
+                    SEQUENCER_ORDER_KEY.to_string(),
+                    SequencerOrder::new(tx_order),
+                )]),
+            )?,
+        ];
+        self.store_instance.write_cf_batch(cf_batches, true)
+    }
 }
 
 impl Display for RoochStore {
@@ -158,4 +274,97 @@ impl MetaStore for RoochStore {
     fn save_sequencer_order(&self, sequencer_order: SequencerOrder) -> Result<()> {
         self.get_meta_store().save_sequencer_order(sequencer_order)
     }
+
+    fn get_store_version(&self) -> Result<Option<u64>> {
+        self.get_meta_store().get_store_version()
+    }
+
+    fn save_store_version(&self, version: u64) -> Result<()> {
+        self.get_meta_store().save_store_version(version)
+    }
+}
+
+impl TxAccumulatorStore for RoochStore {
+    fn get_tx_accumulator_node_store(&self) -> Arc<dyn AccumulatorTreeStore> {
+        self.get_tx_accumulator_store()
+            .get_tx_accumulator_node_store()
+    }
+
+    fn save_tx_accumulator_info(&self, tx_order: u64, info: AccumulatorInfo) -> Result<()> {
+        self.get_tx_accumulator_store()
+            .save_tx_accumulator_info(tx_order, info)
+    }
+
+    fn get_tx_accumulator_info(&self, tx_order: u64) -> Result<Option<AccumulatorInfo>> {
+        self.get_tx_accumulator_store()
+            .get_tx_accumulator_info(tx_order)
+    }
+}
+
+impl ProposerStore for RoochStore {
+    fn save_block(&self, block: Block) -> Result<()> {
+        self.get_proposer_store().save_block(block)
+    }
+
+    fn get_block(&self, block_number: u128) -> Result<Option<Block>> {
+        self.get_proposer_store().get_block(block_number)
+    }
+
+    fn get_blocks(&self, block_numbers: Vec<u128>) -> Result<Vec<Option<Block>>> {
+        self.get_proposer_store().get_blocks(block_numbers)
+    }
+
+    fn get_last_block_number(&self) -> Result<Option<u128>> {
+        self.get_proposer_store().get_last_block_number()
+    }
+
+    fn get_block_number_by_hash(&self, block_hash: H256) -> Result<Option<u128>> {
+        self.get_proposer_store()
+            .get_block_number_by_hash(block_hash)
+    }
+
+    fn get_block_number_by_tx_order(&self, tx_order: u64) -> Result<Option<u128>> {
+        self.get_proposer_store()
+            .get_block_number_by_tx_order(tx_order)
+    }
+
+    fn save_propose_buffer_item(&self, tx: TransactionWithInfo) -> Result<()> {
+        self.get_proposer_store().save_propose_buffer_item(tx)
+    }
+
+    fn get_propose_buffer(&self) -> Result<Vec<TransactionWithInfo>> {
+        self.get_proposer_store().get_propose_buffer()
+    }
+
+    fn commit_block(
+        &self,
+        block: Block,
+        proposed_tx_orders: Vec<u64>,
+        da_put_response: Option<DAPutResponse>,
+    ) -> Result<()> {
+        self.get_proposer_store()
+            .commit_block(block, proposed_tx_orders, da_put_response)
+    }
+
+    fn save_block_submit_status(
+        &self,
+        block_number: u128,
+        status: BlockSubmitStatus,
+    ) -> Result<()> {
+        self.get_proposer_store()
+            .save_block_submit_status(block_number, status)
+    }
+
+    fn get_block_submit_status(&self, block_number: u128) -> Result<Option<BlockSubmitStatus>> {
+        self.get_proposer_store()
+            .get_block_submit_status(block_number)
+    }
+
+    fn get_last_submitted_block_number(&self) -> Result<Option<u128>> {
+        self.get_proposer_store().get_last_submitted_block_number()
+    }
+
+    fn get_da_put_response(&self, block_number: u128) -> Result<Option<DAPutResponse>> {
+        self.get_proposer_store().get_da_put_response(block_number)
+    }
 }
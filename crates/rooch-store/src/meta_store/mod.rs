@@ -1,13 +1,19 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::META_SEQUENCER_ORDER_PREFIX_NAME;
+use crate::{META_SEQUENCER_ORDER_PREFIX_NAME, META_STORE_VERSION_PREFIX_NAME};
 use anyhow::Result;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
 use rooch_types::sequencer::SequencerOrder;
 use std::string::ToString;
 
 pub const SEQUENCER_ORDER_KEY: &str = "sequencer_order";
+pub const STORE_VERSION_KEY: &str = "store_version";
+
+/// The version of the layout of the stored values, the store is migrated when it is opened with a lower version.
+/// Version 1: the tx timestamp is added to the `TransactionSequenceInfo`.
+pub const CURRENT_STORE_VERSION: u64 = 1;
+
 derive_store!(
     SequencerOrderStore,
     String,
@@ -15,21 +21,35 @@ derive_store!(
     META_SEQUENCER_ORDER_PREFIX_NAME
 );
 
+derive_store!(
+    StoreVersionStore,
+    String,
+    u64,
+    META_STORE_VERSION_PREFIX_NAME
+);
+
 pub trait MetaStore {
     fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>>;
 
     fn save_sequencer_order(&self, sequencer_order: SequencerOrder) -> Result<()>;
+
+    /// Get the layout version of the store, None if the store is created before the version is recorded.
+    fn get_store_version(&self) -> Result<Option<u64>>;
+
+    fn save_store_version(&self, version: u64) -> Result<()>;
 }
 
 #[derive(Clone)]
 pub struct MetaDBStore {
     sequencer_order_store: SequencerOrderStore,
+    store_version_store: StoreVersionStore,
 }
 
 impl MetaDBStore {
     pub fn new(instance: StoreInstance) -> Self {
         MetaDBStore {
-            sequencer_order_store: SequencerOrderStore::new(instance),
+            sequencer_order_store: SequencerOrderStore::new(instance.clone()),
+            store_version_store: StoreVersionStore::new(instance),
         }
     }
 
@@ -42,4 +62,14 @@ impl MetaDBStore {
         self.sequencer_order_store
             .put_sync(SEQUENCER_ORDER_KEY.to_string(), sequencer_order)
     }
+
+    pub fn get_store_version(&self) -> Result<Option<u64>> {
+        self.store_version_store
+            .kv_get(STORE_VERSION_KEY.to_string())
+    }
+
+    pub fn save_store_version(&self, version: u64) -> Result<()> {
+        self.store_version_store
+            .put_sync(STORE_VERSION_KEY.to_string(), version)
+    }
 }
@@ -0,0 +1,287 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    PROPOSER_BLOCK_HASH_INDEX_PREFIX_NAME, PROPOSER_BLOCK_PREFIX_NAME,
+    PROPOSER_BLOCK_SUBMIT_STATUS_PREFIX_NAME, PROPOSER_BUFFER_PREFIX_NAME,
+    PROPOSER_DA_PUT_RESPONSE_PREFIX_NAME, PROPOSER_LAST_BLOCK_PREFIX_NAME,
+};
+use anyhow::Result;
+use moveos_types::h256::H256;
+use raw_store::rocks::batch::WriteBatchCF;
+use raw_store::traits::DBStore;
+use raw_store::{derive_store, CodecKVStore, CodecWriteBatch, StoreInstance};
+use rooch_da::DAPutResponse;
+use rooch_types::block::{Block, BlockSubmitStatus};
+use rooch_types::transaction::TransactionWithInfo;
+use std::string::ToString;
+
+pub const PROPOSER_LAST_BLOCK_NUMBER_KEY: &str = "last_block_number";
+pub const PROPOSER_LAST_SUBMITTED_BLOCK_NUMBER_KEY: &str = "last_submitted_block_number";
+
+derive_store!(BlockStore, u128, Block, PROPOSER_BLOCK_PREFIX_NAME);
+
+derive_store!(
+    BlockHashIndexStore,
+    H256,
+    u128,
+    PROPOSER_BLOCK_HASH_INDEX_PREFIX_NAME
+);
+
+derive_store!(
+    LastBlockNumberStore,
+    String,
+    u128,
+    PROPOSER_LAST_BLOCK_PREFIX_NAME
+);
+
+derive_store!(
+    ProposeBufferStore,
+    u64,
+    TransactionWithInfo,
+    PROPOSER_BUFFER_PREFIX_NAME
+);
+
+derive_store!(
+    BlockSubmitStatusStore,
+    u128,
+    BlockSubmitStatus,
+    PROPOSER_BLOCK_SUBMIT_STATUS_PREFIX_NAME
+);
+
+derive_store!(
+    DAPutResponseStore,
+    u128,
+    DAPutResponse,
+    PROPOSER_DA_PUT_RESPONSE_PREFIX_NAME
+);
+
+pub trait ProposerStore {
+    /// Save the block and update the last block number.
+    fn save_block(&self, block: Block) -> Result<()>;
+
+    fn get_block(&self, block_number: u128) -> Result<Option<Block>>;
+
+    fn get_blocks(&self, block_numbers: Vec<u128>) -> Result<Vec<Option<Block>>>;
+
+    fn get_last_block_number(&self) -> Result<Option<u128>>;
+
+    fn get_block_number_by_hash(&self, block_hash: H256) -> Result<Option<u128>>;
+
+    /// Get the number of the block which contains the transaction, None if the transaction is not proposed yet.
+    fn get_block_number_by_tx_order(&self, tx_order: u64) -> Result<Option<u128>>;
+
+    /// Save the transaction which is waiting to be proposed to a block.
+    fn save_propose_buffer_item(&self, tx: TransactionWithInfo) -> Result<()>;
+
+    /// Get all the transactions in the propose buffer, order by tx order.
+    fn get_propose_buffer(&self) -> Result<Vec<TransactionWithInfo>>;
+
+    /// Save the block, the DA reference of its transaction batch and remove the proposed transactions
+    /// from the propose buffer atomically.
+    fn commit_block(
+        &self,
+        block: Block,
+        proposed_tx_orders: Vec<u64>,
+        da_put_response: Option<DAPutResponse>,
+    ) -> Result<()>;
+
+    /// Save the submit status of the block, and update the last submitted block number if the block is submitted
+    /// and its number is greater than the last submitted block number.
+    fn save_block_submit_status(&self, block_number: u128, status: BlockSubmitStatus)
+        -> Result<()>;
+
+    fn get_block_submit_status(&self, block_number: u128) -> Result<Option<BlockSubmitStatus>>;
+
+    fn get_last_submitted_block_number(&self) -> Result<Option<u128>>;
+
+    fn get_da_put_response(&self, block_number: u128) -> Result<Option<DAPutResponse>>;
+}
+
+#[derive(Clone)]
+pub struct ProposerDBStore {
+    store_instance: StoreInstance,
+    block_store: BlockStore,
+    block_hash_index_store: BlockHashIndexStore,
+    last_block_number_store: LastBlockNumberStore,
+    propose_buffer_store: ProposeBufferStore,
+    block_submit_status_store: BlockSubmitStatusStore,
+    da_put_response_store: DAPutResponseStore,
+}
+
+impl ProposerDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        ProposerDBStore {
+            store_instance: instance.clone(),
+            block_store: BlockStore::new(instance.clone()),
+            block_hash_index_store: BlockHashIndexStore::new(instance.clone()),
+            last_block_number_store: LastBlockNumberStore::new(instance.clone()),
+            propose_buffer_store: ProposeBufferStore::new(instance.clone()),
+            block_submit_status_store: BlockSubmitStatusStore::new(instance.clone()),
+            da_put_response_store: DAPutResponseStore::new(instance),
+        }
+    }
+
+    pub fn save_block(&self, block: Block) -> Result<()> {
+        self.commit_block(block, vec![], None)
+    }
+
+    /// The block, the block hash index, the last block number, the DA put response and the removal of the
+    /// proposed transactions are written in one batch, so the propose buffer and the DA reference are always
+    /// consistent with the last block after a crash.
+    pub fn commit_block(
+        &self,
+        block: Block,
+        proposed_tx_orders: Vec<u64>,
+        da_put_response: Option<DAPutResponse>,
+    ) -> Result<()> {
+        let block_number = block.block_number;
+        let mut cf_batches = vec![
+            WriteBatchCF::new_with_codec(
+                PROPOSER_BLOCK_HASH_INDEX_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(block.block_hash(), block_number)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                PROPOSER_BLOCK_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(block_number, block)]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                PROPOSER_LAST_BLOCK_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(
+                    PROPOSER_LAST_BLOCK_NUMBER_KEY.to_string(),
+                    block_number,
+                )]),
+            )?,
+            WriteBatchCF::new_with_codec(
+                PROPOSER_BUFFER_PREFIX_NAME,
+                CodecWriteBatch::<u64, TransactionWithInfo>::new_deletes(proposed_tx_orders),
+            )?,
+        ];
+        if let Some(da_put_response) = da_put_response {
+            cf_batches.push(WriteBatchCF::new_with_codec(
+                PROPOSER_DA_PUT_RESPONSE_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(block_number, da_put_response)]),
+            )?);
+        }
+        self.store_instance.write_cf_batch(cf_batches, true)
+    }
+
+    pub fn get_block(&self, block_number: u128) -> Result<Option<Block>> {
+        self.block_store.kv_get(block_number)
+    }
+
+    pub fn get_blocks(&self, block_numbers: Vec<u128>) -> Result<Vec<Option<Block>>> {
+        self.block_store.multiple_get(block_numbers)
+    }
+
+    pub fn get_last_block_number(&self) -> Result<Option<u128>> {
+        self.last_block_number_store
+            .kv_get(PROPOSER_LAST_BLOCK_NUMBER_KEY.to_string())
+    }
+
+    pub fn get_block_number_by_hash(&self, block_hash: H256) -> Result<Option<u128>> {
+        self.block_hash_index_store.kv_get(block_hash)
+    }
+
+    pub fn get_block_number_by_tx_order(&self, tx_order: u64) -> Result<Option<u128>> {
+        let last_block_number = match self.get_last_block_number()? {
+            Some(block_number) => block_number,
+            None => return Ok(None),
+        };
+        // The blocks are ordered by the tx orders, so binary search the block which contains the tx order.
+        let (mut low, mut high) = (0u128, last_block_number);
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let block = match self.get_block(mid)? {
+                Some(block) => block,
+                None => return Ok(None),
+            };
+            let tx_orders = block.tx_orders();
+            if tx_orders.contains(&tx_order) {
+                return Ok(Some(mid));
+            } else if tx_order < tx_orders.start {
+                if mid == 0 {
+                    break;
+                }
+                high = mid - 1;
+            } else {
+                low = mid + 1;
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn save_propose_buffer_item(&self, tx: TransactionWithInfo) -> Result<()> {
+        self.propose_buffer_store
+            .kv_put(tx.sequence_info.tx_order, tx)
+    }
+
+    /// Rewrite the propose buffer items saved in the legacy layout without the tx timestamp in the current layout,
+    /// return the count of the migrated items.
+    pub fn migrate_legacy_propose_buffer(&self) -> Result<usize> {
+        let mut migrated = vec![];
+        for tx_order in self.propose_buffer_store.keys()? {
+            if let Some(bytes) = self.propose_buffer_store.get_raw(tx_order)? {
+                if bcs::from_bytes::<TransactionWithInfo>(&bytes).is_err() {
+                    migrated.push((tx_order, TransactionWithInfo::decode(&bytes)?));
+                }
+            }
+        }
+        let count = migrated.len();
+        if count > 0 {
+            self.propose_buffer_store.put_all(migrated)?;
+        }
+        Ok(count)
+    }
+
+    pub fn get_propose_buffer(&self) -> Result<Vec<TransactionWithInfo>> {
+        let mut iter = self.propose_buffer_store.iter()?;
+        iter.seek_to_first();
+        let mut buffer = iter
+            .map(|item| item.map(|(_tx_order, tx)| tx))
+            .collect::<Result<Vec<_>>>()?;
+        // The key is bcs serialized, so the iterator order is not the tx order.
+        buffer.sort_by_key(|tx| tx.sequence_info.tx_order);
+        Ok(buffer)
+    }
+
+    /// The last submitted block number only moves forward, a block submitted again when retrying
+    /// does not roll it back. The status and the last submitted block number are written in one batch.
+    pub fn save_block_submit_status(
+        &self,
+        block_number: u128,
+        status: BlockSubmitStatus,
+    ) -> Result<()> {
+        let update_last_submitted = status.is_submitted()
+            && self
+                .get_last_submitted_block_number()?
+                .map_or(true, |last_submitted| block_number > last_submitted);
+        let mut cf_batches = vec![WriteBatchCF::new_with_codec(
+            PROPOSER_BLOCK_SUBMIT_STATUS_PREFIX_NAME,
+            CodecWriteBatch::new_puts(vec![(block_number, status)]),
+        )?];
+        if update_last_submitted {
+            cf_batches.push(WriteBatchCF::new_with_codec(
+                PROPOSER_LAST_BLOCK_PREFIX_NAME,
+                CodecWriteBatch::new_puts(vec![(
+                    PROPOSER_LAST_SUBMITTED_BLOCK_NUMBER_KEY.to_string(),
+                    block_number,
+                )]),
+            )?);
+        }
+        self.store_instance.write_cf_batch(cf_batches, true)
+    }
+
+    pub fn get_block_submit_status(&self, block_number: u128) -> Result<Option<BlockSubmitStatus>> {
+        self.block_submit_status_store.kv_get(block_number)
+    }
+
+    pub fn get_last_submitted_block_number(&self) -> Result<Option<u128>> {
+        self.last_block_number_store
+            .kv_get(PROPOSER_LAST_SUBMITTED_BLOCK_NUMBER_KEY.to_string())
+    }
+
+    pub fn get_da_put_response(&self, block_number: u128) -> Result<Option<DAPutResponse>> {
+        self.da_put_response_store.kv_get(block_number)
+    }
+}
@@ -0,0 +1,6 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+mod test_proposer_store;
+mod test_store_migration;
+mod test_transaction_store;
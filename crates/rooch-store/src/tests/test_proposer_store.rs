@@ -0,0 +1,174 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::proposer_store::ProposerStore;
+use crate::RoochStore;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_da::{DAPutResponse, DA_PUT_RESPONSE_VERSION};
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::block::{Block, BlockSubmitStatus};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{
+    AbstractTransaction, TransactionSequenceInfo, TransactionWithInfo, TypedTransaction,
+};
+
+fn mock_authenticator() -> Authenticator {
+    Authenticator {
+        auth_validator_id: 0,
+        payload: vec![],
+    }
+}
+
+fn mock_tx_with_info(tx_order: u64) -> TransactionWithInfo {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        tx_order,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    let transaction = RoochTransaction::new(tx_data, mock_authenticator());
+    let tx_hash = transaction.tx_hash();
+    TransactionWithInfo {
+        transaction: TypedTransaction::Rooch(transaction),
+        sequence_info: TransactionSequenceInfo::new(
+            tx_order,
+            mock_authenticator(),
+            H256::random(),
+            0,
+        ),
+        execution_info: TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        ),
+    }
+}
+
+fn mock_block(block_number: u128, first_tx_order: u64, batch_size: u64) -> Block {
+    Block::new(
+        block_number,
+        batch_size,
+        H256::random(),
+        H256::random(),
+        vec![H256::random(); batch_size as usize],
+        first_tx_order,
+        0,
+    )
+}
+
+#[test]
+fn test_commit_block() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    for tx_order in 0..3 {
+        rooch_store
+            .save_propose_buffer_item(mock_tx_with_info(tx_order))
+            .unwrap();
+    }
+
+    let block = mock_block(0, 0, 2);
+    let da_put_response = DAPutResponse {
+        keyset_hash: vec![],
+        value_hash: H256::random().as_bytes().to_vec(),
+        signers_mask: 0,
+        signature: vec![],
+        version: DA_PUT_RESPONSE_VERSION,
+    };
+    rooch_store
+        .commit_block(block.clone(), vec![0, 1], Some(da_put_response.clone()))
+        .unwrap();
+
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), Some(0));
+    assert_eq!(rooch_store.get_block(0).unwrap(), Some(block.clone()));
+    assert_eq!(
+        rooch_store
+            .get_block_number_by_hash(block.block_hash())
+            .unwrap(),
+        Some(0)
+    );
+    assert_eq!(
+        rooch_store.get_block_number_by_tx_order(1).unwrap(),
+        Some(0)
+    );
+    assert_eq!(
+        rooch_store.get_da_put_response(0).unwrap(),
+        Some(da_put_response)
+    );
+    // Only the proposed transactions are removed from the propose buffer
+    let buffer = rooch_store.get_propose_buffer().unwrap();
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer[0].sequence_info.tx_order, 2);
+}
+
+#[test]
+fn test_save_block_keeps_propose_buffer() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    rooch_store
+        .save_propose_buffer_item(mock_tx_with_info(0))
+        .unwrap();
+
+    rooch_store.save_block(mock_block(0, 0, 1)).unwrap();
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), Some(0));
+    assert_eq!(rooch_store.get_propose_buffer().unwrap().len(), 1);
+    assert_eq!(rooch_store.get_da_put_response(0).unwrap(), None);
+}
+
+#[test]
+fn test_last_submitted_block_number_is_monotonic() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let submitted = || BlockSubmitStatus::Submitted {
+        submitter: "test".to_string(),
+        receipt: "receipt".to_string(),
+    };
+    assert_eq!(rooch_store.get_last_submitted_block_number().unwrap(), None);
+
+    rooch_store
+        .save_block_submit_status(1, submitted())
+        .unwrap();
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(1)
+    );
+
+    // A block submitted again when retrying does not roll back the last submitted block number
+    rooch_store
+        .save_block_submit_status(0, submitted())
+        .unwrap();
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        rooch_store.get_block_submit_status(0).unwrap(),
+        Some(submitted())
+    );
+
+    // The pending status does not change the last submitted block number
+    let pending = BlockSubmitStatus::Pending {
+        attempts: 1,
+        last_error: Some("error".to_string()),
+    };
+    rooch_store
+        .save_block_submit_status(2, pending.clone())
+        .unwrap();
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        rooch_store.get_block_submit_status(2).unwrap(),
+        Some(pending)
+    );
+
+    rooch_store
+        .save_block_submit_status(2, submitted())
+        .unwrap();
+    assert_eq!(
+        rooch_store.get_last_submitted_block_number().unwrap(),
+        Some(2)
+    );
+}
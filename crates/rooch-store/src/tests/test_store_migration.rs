@@ -0,0 +1,130 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::meta_store::{MetaStore, CURRENT_STORE_VERSION};
+use crate::proposer_store::ProposerStore;
+use crate::transaction_store::TransactionStore;
+use crate::{RoochStore, StoreMeta, PROPOSER_BUFFER_PREFIX_NAME, TX_SEQUENCE_INFO_PREFIX_NAME};
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_config::store_config::RocksdbConfig;
+use moveos_config::{temp_dir, DataDirPath};
+use moveos_types::h256::H256;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+use raw_store::rocks::RocksDB;
+use raw_store::traits::DBStore;
+use raw_store::StoreInstance;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{
+    AbstractTransaction, LegacyTransactionSequenceInfo, LegacyTransactionWithInfo,
+    TransactionSequenceInfo, TransactionWithInfo, TypedTransaction, LEGACY_TX_TIMESTAMP,
+};
+
+fn store_instance(dir: &DataDirPath) -> StoreInstance {
+    StoreInstance::new_db_instance(
+        RocksDB::new(
+            dir.path(),
+            StoreMeta::get_column_family_names().to_vec(),
+            RocksdbConfig::default(),
+            None,
+        )
+        .unwrap(),
+    )
+}
+
+fn mock_legacy_tx_with_info(tx_order: u64) -> LegacyTransactionWithInfo {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        tx_order,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    let transaction = RoochTransaction::new(tx_data, Authenticator::new(0, vec![]));
+    let tx_hash = transaction.tx_hash();
+    LegacyTransactionWithInfo {
+        transaction: TypedTransaction::Rooch(transaction),
+        sequence_info: LegacyTransactionSequenceInfo {
+            tx_order,
+            tx_order_signature: Authenticator::new(0, vec![]),
+            tx_accumulator_root: H256::random(),
+        },
+        execution_info: TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        ),
+    }
+}
+
+#[test]
+fn test_migrate_legacy_layout() {
+    let dir = temp_dir();
+    let instance = store_instance(&dir);
+
+    // Write the sequence infos and the propose buffer in the layout before the tx timestamp is added
+    let legacy_txs: Vec<_> = (0..3).map(mock_legacy_tx_with_info).collect();
+    for tx in &legacy_txs {
+        let tx_order = tx.sequence_info.tx_order;
+        instance
+            .put(
+                TX_SEQUENCE_INFO_PREFIX_NAME,
+                bcs::to_bytes(&tx_order).unwrap(),
+                bcs::to_bytes(&tx.sequence_info).unwrap(),
+            )
+            .unwrap();
+        instance
+            .put(
+                PROPOSER_BUFFER_PREFIX_NAME,
+                bcs::to_bytes(&tx_order).unwrap(),
+                bcs::to_bytes(tx).unwrap(),
+            )
+            .unwrap();
+    }
+    // The sequence info saved in the current layout is kept as is
+    let sequence_info =
+        TransactionSequenceInfo::new(3, Authenticator::new(0, vec![]), H256::random(), 1000);
+    instance
+        .put(
+            TX_SEQUENCE_INFO_PREFIX_NAME,
+            bcs::to_bytes(&3u64).unwrap(),
+            bcs::to_bytes(&sequence_info).unwrap(),
+        )
+        .unwrap();
+
+    let rooch_store = RoochStore::new(instance).unwrap();
+    assert_eq!(
+        rooch_store.get_store_version().unwrap(),
+        Some(CURRENT_STORE_VERSION)
+    );
+
+    let sequence_infos = rooch_store.get_tx_sequence_infos_by_order(None, 4).unwrap();
+    let expected: Vec<_> = legacy_txs
+        .iter()
+        .map(|tx| Some(TransactionSequenceInfo::from(tx.sequence_info.clone())))
+        .chain(std::iter::once(Some(sequence_info)))
+        .collect();
+    assert_eq!(sequence_infos, expected);
+    assert!(sequence_infos[..3]
+        .iter()
+        .all(|info| info.as_ref().unwrap().tx_timestamp == LEGACY_TX_TIMESTAMP));
+
+    let propose_buffer = rooch_store.get_propose_buffer().unwrap();
+    let expected: Vec<_> = legacy_txs
+        .into_iter()
+        .map(TransactionWithInfo::from)
+        .collect();
+    assert_eq!(propose_buffer, expected);
+}
+
+#[test]
+fn test_migrate_new_store() {
+    let dir = temp_dir();
+    let rooch_store = RoochStore::new(store_instance(&dir)).unwrap();
+    assert_eq!(
+        rooch_store.get_store_version().unwrap(),
+        Some(CURRENT_STORE_VERSION)
+    );
+    assert!(rooch_store.get_propose_buffer().unwrap().is_empty());
+}
@@ -0,0 +1,111 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::accumulator_store::TxAccumulatorStore;
+use crate::meta_store::MetaStore;
+use crate::transaction_store::TransactionStore;
+use crate::RoochStore;
+use accumulator::{Accumulator, MerkleAccumulator};
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{AbstractTransaction, TransactionSequenceInfo, TypedTransaction};
+
+fn mock_authenticator() -> Authenticator {
+    Authenticator {
+        auth_validator_id: 0,
+        payload: vec![],
+    }
+}
+
+fn mock_tx(sequence_number: u64) -> TypedTransaction {
+    let tx_data = RoochTransactionData::new_for_test(
+        RoochAddress::random(),
+        sequence_number,
+        MoveAction::new_function_call("0x3::empty::empty".parse().unwrap(), vec![], vec![]),
+    );
+    TypedTransaction::Rooch(RoochTransaction::new(tx_data, mock_authenticator()))
+}
+
+#[test]
+fn test_save_sequenced_tx() {
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let tx_accumulator = MerkleAccumulator::new_empty(rooch_store.get_tx_accumulator_node_store());
+    let mut tx_hashes = vec![];
+    for tx_order in 0..3 {
+        let tx = mock_tx(tx_order);
+        let tx_hash = tx.tx_hash();
+        let tx_accumulator_root = tx_accumulator.append(&[tx_hash]).unwrap();
+        let tx_accumulator_info = tx_accumulator.get_info();
+        let tx_accumulator_nodes = tx_accumulator.pop_unsaved_nodes().unwrap();
+        let tx_sequence_info =
+            TransactionSequenceInfo::new(tx_order, mock_authenticator(), tx_accumulator_root, 0);
+        rooch_store
+            .save_sequenced_tx(
+                tx.clone(),
+                tx_sequence_info.clone(),
+                tx_accumulator_nodes,
+                tx_accumulator_info.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            rooch_store.get_transaction_by_hash(tx_hash).unwrap(),
+            Some(tx)
+        );
+        let mapping = rooch_store
+            .get_tx_sequence_info_mapping_by_order(vec![tx_order])
+            .unwrap()
+            .pop()
+            .unwrap()
+            .unwrap();
+        assert_eq!(mapping.tx_hash, tx_hash);
+        let mapping = rooch_store
+            .multi_get_tx_sequence_info_mapping_by_hash(vec![tx_hash])
+            .unwrap()
+            .pop()
+            .unwrap()
+            .unwrap();
+        assert_eq!(mapping.tx_order, tx_order);
+        assert_eq!(
+            rooch_store
+                .get_transaction_store()
+                .get_tx_sequence_infos(vec![tx_order])
+                .unwrap(),
+            vec![Some(tx_sequence_info)]
+        );
+        assert_eq!(
+            rooch_store.get_tx_accumulator_info(tx_order).unwrap(),
+            Some(tx_accumulator_info)
+        );
+        assert_eq!(
+            rooch_store
+                .get_sequencer_order()
+                .unwrap()
+                .unwrap()
+                .last_order,
+            tx_order
+        );
+        tx_hashes.push(tx_hash);
+    }
+
+    // The accumulator is reloaded from the saved nodes and info
+    let info = rooch_store.get_tx_accumulator_info(2).unwrap().unwrap();
+    let root_hash = info.accumulator_root;
+    let reloaded =
+        MerkleAccumulator::new_with_info(info, rooch_store.get_tx_accumulator_node_store());
+    for (tx_order, tx_hash) in tx_hashes.into_iter().enumerate() {
+        let tx_order = tx_order as u64;
+        assert_eq!(reloaded.get_leaf(tx_order).unwrap(), Some(tx_hash));
+        let proof = reloaded.get_proof(tx_order).unwrap().unwrap();
+        proof.verify(root_hash, tx_hash, tx_order).unwrap();
+    }
+    // The reloaded accumulator continues from the same state
+    let leaf = H256::random();
+    assert_eq!(
+        reloaded.append(&[leaf]).unwrap(),
+        tx_accumulator.append(&[leaf]).unwrap()
+    );
+}
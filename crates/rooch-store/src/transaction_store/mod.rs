@@ -189,4 +189,22 @@ impl TransactionDBStore {
             })
             .collect()
     }
+
+    /// Rewrite the sequence infos saved in the legacy layout without the tx timestamp in the current layout,
+    /// return the count of the migrated sequence infos.
+    pub fn migrate_legacy_tx_sequence_infos(&self) -> Result<usize> {
+        let mut migrated = vec![];
+        for tx_order in self.tx_sequence_info_store.keys()? {
+            if let Some(bytes) = self.tx_sequence_info_store.get_raw(tx_order)? {
+                if bcs::from_bytes::<TransactionSequenceInfo>(&bytes).is_err() {
+                    migrated.push((tx_order, TransactionSequenceInfo::decode(&bytes)?));
+                }
+            }
+        }
+        let count = migrated.len();
+        if count > 0 {
+            self.tx_sequence_info_store.put_all(migrated)?;
+        }
+        Ok(count)
+    }
 }
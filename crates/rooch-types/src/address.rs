@@ -3,12 +3,14 @@
 
 use crate::{
     addresses::ROOCH_FRAMEWORK_ADDRESS,
+    crypto::DefaultHash,
     multichain_id::{MultiChainID, RoochMultiChainID},
 };
 use anyhow::Result;
 use bech32::{FromBase32, ToBase32};
 use bitcoin::{address::Address, secp256k1::Secp256k1, Network, PrivateKey, Script};
 use ethers::types::H160;
+use fastcrypto::hash::HashFunction;
 use fastcrypto::secp256k1::recoverable::Secp256k1RecoverablePublicKey;
 use move_core_types::{
     account_address::AccountAddress,
@@ -396,6 +398,37 @@ impl RoochSupportedAddress for BitcoinAddress {
     }
 }
 
+impl BitcoinAddress {
+    const P2PKH_ADDR_DECIMAL_PREFIX_MAIN: u8 = 0x00;
+    const P2SH_ADDR_DECIMAL_PREFIX_MAIN: u8 = 0x05;
+
+    /// The raw bytes of the address in the same format as the `bitcoin_address::BTCAddress` in Move,
+    /// `prefix || pubkey_hash` for P2PKH, `prefix || script_hash` for P2SH and the witness program for segwit and taproot.
+    pub fn to_raw_bytes(&self) -> Vec<u8> {
+        let script = self.0.script_pubkey();
+        let bytes = script.as_bytes();
+        if script.is_p2pkh() {
+            let mut raw = vec![Self::P2PKH_ADDR_DECIMAL_PREFIX_MAIN];
+            raw.extend_from_slice(&bytes[3..23]);
+            raw
+        } else if script.is_p2sh() {
+            let mut raw = vec![Self::P2SH_ADDR_DECIMAL_PREFIX_MAIN];
+            raw.extend_from_slice(&bytes[2..22]);
+            raw
+        } else {
+            bytes[2..].to_vec()
+        }
+    }
+
+    /// The Rooch address generated by `address_mapping` for the Bitcoin address which is not bound,
+    /// it is the sender address of the transactions signed by the Bitcoin validator.
+    pub fn to_rooch_address(&self) -> RoochAddress {
+        let mut hasher = DefaultHash::default();
+        hasher.update(self.to_raw_bytes());
+        RoochAddress(H256(hasher.finalize().digest))
+    }
+}
+
 impl From<BitcoinAddress> for MultiChainAddress {
     fn from(address: BitcoinAddress) -> Self {
         Self::new(
@@ -434,6 +467,57 @@ impl RoochSupportedAddress for NostrAddress {
     }
 }
 
+impl NostrAddress {
+    const NPUB_HRP: &'static str = "npub";
+
+    /// The Rooch address generated by `address_mapping` for the Nostr public key which is not bound,
+    /// it is the sender address of the transactions signed by the Nostr validator.
+    pub fn to_rooch_address(&self) -> RoochAddress {
+        let mut hasher = DefaultHash::default();
+        hasher.update(self.0.serialize());
+        RoochAddress(H256(hasher.finalize().digest))
+    }
+
+    /// The NIP-19 bech32 encoding of the public key
+    pub fn to_npub(&self) -> String {
+        bech32::encode(
+            Self::NPUB_HRP,
+            self.0.serialize().to_base32(),
+            bech32::Variant::Bech32,
+        )
+        .expect("bech32 encode should success")
+    }
+
+    pub fn from_npub(npub: &str) -> Result<Self> {
+        let (hrp, data, variant) = bech32::decode(npub)?;
+        if hrp != Self::NPUB_HRP || variant != bech32::Variant::Bech32 {
+            return Err(anyhow::anyhow!("invalid npub: {}", npub));
+        }
+        let public_key = Vec::<u8>::from_base32(&data)?;
+        Ok(Self(XOnlyPublicKey::from_slice(&public_key)?))
+    }
+}
+
+impl fmt::Display for NostrAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_npub())
+    }
+}
+
+/// Parse the Nostr address from the npub or the hex encoded x-only public key
+impl FromStr for NostrAddress {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(Self::NPUB_HRP) {
+            Self::from_npub(s)
+        } else {
+            let public_key = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+            Ok(Self(XOnlyPublicKey::from_slice(&public_key)?))
+        }
+    }
+}
+
 impl From<NostrAddress> for MultiChainAddress {
     fn from(address: NostrAddress) -> Self {
         Self::new(RoochMultiChainID::Nostr, address.0.serialize().to_vec())
@@ -486,6 +570,17 @@ mod test {
         test_rooch_supported_address_roundtrip::<NostrAddress>();
     }
 
+    #[test]
+    fn test_nostr_address() {
+        // The npub test vector from NIP-19
+        let npub = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+        let public_key = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+        let address = NostrAddress::from_str(npub).unwrap();
+        assert_eq!(address, NostrAddress::from_str(public_key).unwrap());
+        assert_eq!(address.to_string(), npub);
+        assert_eq!(hex::encode(address.0.serialize()), public_key);
+    }
+
     fn test_rooch_address_roundtrip(rooch_address: RoochAddress) {
         let rooch_str = rooch_address.to_string();
         //ensure the rooch to string is hex with 0x prefix
@@ -545,4 +640,30 @@ mod test {
             "tb1qjlxl7n7na4hcsh25554hn4azzsg89t3ljdldnj"
         )
     }
+
+    #[test]
+    pub fn test_bitcoin_address_raw_bytes() {
+        // The same cases as the `bitcoin_address` tests in Move
+        let cases = [
+            (
+                "76a914010966776006953d5567439e5e39f86a0d273bee88ac",
+                "00010966776006953d5567439e5e39f86a0d273bee",
+            ),
+            (
+                "a91474d691da1574e6b3c192ecfb52cc8984ee7b6c4887",
+                "0574d691da1574e6b3c192ecfb52cc8984ee7b6c48",
+            ),
+            (
+                "001497cdff4fd3ed6f885d54a52b79d7a2141072ae3f",
+                "97cdff4fd3ed6f885d54a52b79d7a2141072ae3f",
+            ),
+        ];
+        for (script, raw) in cases {
+            let bytes = hex::decode(script).unwrap();
+            let address =
+                Address::from_script(Script::from_bytes(bytes.as_slice()), Network::Bitcoin)
+                    .unwrap();
+            assert_eq!(hex::encode(BitcoinAddress(address).to_raw_bytes()), raw);
+        }
+    }
 }
@@ -1,8 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::address::RoochAddress;
+use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
+use anyhow::Result;
 use moveos_types::h256::H256;
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 /// The block in Rooch is constructed by the proposer, representing a batch of transactions
 /// How many transactions in the block is determined by the proposer
@@ -20,6 +24,10 @@ pub struct Block {
     /// The all transaction's state root
     //TODO should we keep all the state root in the block?
     pub state_roots: Vec<H256>,
+    /// The tx order of the first transaction in the block
+    pub first_tx_order: u64,
+    /// The timestamp in milliseconds when the last transaction of the block is sequenced
+    pub timestamp: u64,
 }
 
 impl Block {
@@ -29,6 +37,8 @@ impl Block {
         prev_tx_accumulator_root: H256,
         tx_accumulator_root: H256,
         state_roots: Vec<H256>,
+        first_tx_order: u64,
+        timestamp: u64,
     ) -> Self {
         Self {
             block_number,
@@ -36,6 +46,81 @@ impl Block {
             prev_tx_accumulator_root,
             tx_accumulator_root,
             state_roots,
+            first_tx_order,
+            timestamp,
+        }
+    }
+
+    /// The block is identified by the tx accumulator root after its last transaction,
+    /// so the hash of the parent block is the `prev_tx_accumulator_root`.
+    pub fn block_hash(&self) -> H256 {
+        self.tx_accumulator_root
+    }
+
+    pub fn parent_hash(&self) -> H256 {
+        self.prev_tx_accumulator_root
+    }
+
+    /// The tx orders of the transactions in the block
+    pub fn tx_orders(&self) -> Range<u64> {
+        self.first_tx_order..self.first_tx_order + self.batch_size
+    }
+}
+
+/// The block signed by the proposer, it is the payload submitted to the settlement layer
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SignedBlock {
+    pub block: Block,
+    /// The address of the proposer who signed the block
+    pub proposer: RoochAddress,
+    /// The signature of the proposer on the bcs bytes of the block
+    pub signature: Signature,
+}
+
+impl SignedBlock {
+    pub fn new(block: Block, proposer_key: &RoochKeyPair) -> Self {
+        let signature = Signature::new_secure(&block, proposer_key);
+        Self {
+            block,
+            proposer: proposer_key.public().address(),
+            signature,
+        }
+    }
+
+    /// Verify the signature of the block is signed by the proposer
+    pub fn verify(&self) -> Result<()> {
+        self.signature
+            .verify_secure(&self.block, self.proposer)
+            .map_err(Into::into)
+    }
+}
+
+/// The submission status of a block to the settlement layer
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BlockSubmitStatus {
+    /// The block is waiting to be submitted, `attempts` is the count of failed submissions
+    Pending {
+        attempts: u64,
+        last_error: Option<String>,
+    },
+    /// The block is submitted to the settlement layer
+    Submitted {
+        /// The name of the submitter
+        submitter: String,
+        /// The receipt returned by the submitter, such as the L1 tx hash or the file path
+        receipt: String,
+    },
+}
+
+impl BlockSubmitStatus {
+    pub fn is_submitted(&self) -> bool {
+        matches!(self, BlockSubmitStatus::Submitted { .. })
+    }
+
+    pub fn attempts(&self) -> u64 {
+        match self {
+            BlockSubmitStatus::Pending { attempts, .. } => *attempts,
+            BlockSubmitStatus::Submitted { .. } => 0,
         }
     }
 }
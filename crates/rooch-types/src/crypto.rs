@@ -24,6 +24,14 @@ use fastcrypto::{
         Ed25519SignatureAsBytes,
     },
     encoding::{Base64, Encoding},
+    secp256k1::{
+        Secp256k1KeyPair, Secp256k1PublicKey, Secp256k1PublicKeyAsBytes, Secp256k1Signature,
+        Secp256k1SignatureAsBytes,
+    },
+    secp256r1::{
+        Secp256r1KeyPair, Secp256r1PublicKey, Secp256r1PublicKeyAsBytes, Secp256r1Signature,
+        Secp256r1SignatureAsBytes,
+    },
 };
 use moveos_types::{h256::H256, serde::Readable};
 use schemars::JsonSchema;
@@ -38,12 +46,16 @@ pub type DefaultHash = Blake2b256;
 #[derive(Debug, From, PartialEq, Eq)]
 pub enum RoochKeyPair {
     Ed25519(Ed25519KeyPair),
+    Secp256k1(Secp256k1KeyPair),
+    Secp256r1(Secp256r1KeyPair),
 }
 
 impl RoochKeyPair {
     pub fn public(&self) -> PublicKey {
         match self {
             RoochKeyPair::Ed25519(kp) => PublicKey::Ed25519(kp.public().into()),
+            RoochKeyPair::Secp256k1(kp) => PublicKey::Secp256k1(kp.public().into()),
+            RoochKeyPair::Secp256r1(kp) => PublicKey::Secp256r1(kp.public().into()),
         }
     }
 
@@ -51,9 +63,30 @@ impl RoochKeyPair {
         self.public().authentication_key()
     }
 
+    /// Construct a RoochKeyPair of the given key scheme from the private key bytes.
+    pub fn try_from_bytes(
+        key_scheme: BuiltinAuthValidator,
+        private_key_bytes: &[u8],
+    ) -> Result<Self, eyre::Report> {
+        match key_scheme {
+            BuiltinAuthValidator::Rooch => Ok(RoochKeyPair::Ed25519(Ed25519KeyPair::from_bytes(
+                private_key_bytes,
+            )?)),
+            BuiltinAuthValidator::Secp256k1 => Ok(RoochKeyPair::Secp256k1(
+                Secp256k1KeyPair::from_bytes(private_key_bytes)?,
+            )),
+            BuiltinAuthValidator::Secp256r1 => Ok(RoochKeyPair::Secp256r1(
+                Secp256r1KeyPair::from_bytes(private_key_bytes)?,
+            )),
+            _ => Err(eyre!("Unsupported key scheme: {}", key_scheme)),
+        }
+    }
+
     pub fn copy(&self) -> Self {
         match self {
             RoochKeyPair::Ed25519(kp) => RoochKeyPair::Ed25519(kp.copy()),
+            RoochKeyPair::Secp256k1(kp) => RoochKeyPair::Secp256k1(kp.copy()),
+            RoochKeyPair::Secp256r1(kp) => RoochKeyPair::Secp256r1(kp.copy()),
         }
     }
 }
@@ -62,6 +95,8 @@ impl Signer<Signature> for RoochKeyPair {
     fn sign(&self, msg: &[u8]) -> Signature {
         match self {
             RoochKeyPair::Ed25519(kp) => kp.sign(msg),
+            RoochKeyPair::Secp256k1(kp) => kp.sign(msg),
+            RoochKeyPair::Secp256r1(kp) => kp.sign(msg),
         }
     }
 }
@@ -85,6 +120,12 @@ impl EncodeDecodeBase64 for RoochKeyPair {
             RoochKeyPair::Ed25519(kp) => {
                 bytes.extend_from_slice(kp.as_bytes());
             }
+            RoochKeyPair::Secp256k1(kp) => {
+                bytes.extend_from_slice(kp.as_bytes());
+            }
+            RoochKeyPair::Secp256r1(kp) => {
+                bytes.extend_from_slice(kp.as_bytes());
+            }
         }
         Base64::encode(&bytes[..])
     }
@@ -92,15 +133,10 @@ impl EncodeDecodeBase64 for RoochKeyPair {
     /// Decode a RoochKeyPair from `flag || privkey` in Base64. The public key is computed directly from the private key bytes.
     fn decode_base64(value: &str) -> Result<Self, eyre::Report> {
         let bytes = Base64::decode(value).map_err(|e| eyre!("{}", e.to_string()))?;
-        match BuiltinAuthValidator::from_flag_byte(
+        let flag = BuiltinAuthValidator::from_flag_byte(
             *bytes.first().ok_or_else(|| eyre!("Invalid length"))?,
-        ) {
-            // Process Rooch key pair by default
-            Ok(_x) => Ok(RoochKeyPair::Ed25519(Ed25519KeyPair::from_bytes(
-                bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
-            )?)),
-            _ => Err(eyre!("Invalid bytes")),
-        }
+        )?;
+        RoochKeyPair::try_from_bytes(flag, bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?)
     }
 }
 
@@ -129,12 +165,16 @@ impl<'de> Deserialize<'de> for RoochKeyPair {
 #[derive(Debug, Clone, PartialEq, Eq, JsonSchema)]
 pub enum PublicKey {
     Ed25519(Ed25519PublicKeyAsBytes),
+    Secp256k1(Secp256k1PublicKeyAsBytes),
+    Secp256r1(Secp256r1PublicKeyAsBytes),
 }
 
 impl AsRef<[u8]> for PublicKey {
     fn as_ref(&self) -> &[u8] {
         match self {
             PublicKey::Ed25519(pk) => &pk.0,
+            PublicKey::Secp256k1(pk) => &pk.0,
+            PublicKey::Secp256r1(pk) => &pk.0,
         }
     }
 }
@@ -151,14 +191,12 @@ impl EncodeDecodeBase64 for PublicKey {
         let bytes = Base64::decode(value).map_err(|e| eyre!("{}", e.to_string()))?;
         match bytes.first() {
             Some(x) => {
-                if x == &BuiltinAuthValidator::Rooch.flag() {
-                    let pk: Ed25519PublicKey = Ed25519PublicKey::from_bytes(
-                        bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
-                    )?;
-                    Ok(PublicKey::Ed25519((&pk).into()))
-                } else {
-                    Err(eyre!("Invalid flag byte"))
-                }
+                let auth_validator = BuiltinAuthValidator::from_flag_byte(*x)
+                    .map_err(|_| eyre!("Invalid flag byte"))?;
+                PublicKey::try_from_bytes(
+                    auth_validator,
+                    bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
+                )
             }
             _ => Err(eyre!("Invalid bytes")),
         }
@@ -191,20 +229,32 @@ impl PublicKey {
     pub fn flag(&self) -> u8 {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME.flag(),
+            PublicKey::Secp256k1(_) => Secp256k1RoochSignature::SCHEME.flag(),
+            PublicKey::Secp256r1(_) => Secp256r1RoochSignature::SCHEME.flag(),
         }
     }
     pub fn try_from_bytes(
-        _auth_validator: BuiltinAuthValidator,
+        auth_validator: BuiltinAuthValidator,
         key_bytes: &[u8],
     ) -> Result<PublicKey, eyre::Report> {
-        // Process Rooch public key by default
-        Ok(PublicKey::Ed25519(
-            (&Ed25519PublicKey::from_bytes(key_bytes)?).into(),
-        ))
+        match auth_validator {
+            BuiltinAuthValidator::Rooch => Ok(PublicKey::Ed25519(
+                (&Ed25519PublicKey::from_bytes(key_bytes)?).into(),
+            )),
+            BuiltinAuthValidator::Secp256k1 => Ok(PublicKey::Secp256k1(
+                (&Secp256k1PublicKey::from_bytes(key_bytes)?).into(),
+            )),
+            BuiltinAuthValidator::Secp256r1 => Ok(PublicKey::Secp256r1(
+                (&Secp256r1PublicKey::from_bytes(key_bytes)?).into(),
+            )),
+            _ => Err(eyre!("Unsupported key scheme: {}", auth_validator)),
+        }
     }
     pub fn auth_validator(&self) -> BuiltinAuthValidator {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME,
+            PublicKey::Secp256k1(_) => Secp256k1RoochSignature::SCHEME,
+            PublicKey::Secp256r1(_) => Secp256r1RoochSignature::SCHEME,
         }
     }
 
@@ -225,6 +275,14 @@ impl RoochPublicKey for Ed25519PublicKey {
     const SIGNATURE_SCHEME: BuiltinAuthValidator = BuiltinAuthValidator::Rooch;
 }
 
+impl RoochPublicKey for Secp256k1PublicKey {
+    const SIGNATURE_SCHEME: BuiltinAuthValidator = BuiltinAuthValidator::Secp256k1;
+}
+
+impl RoochPublicKey for Secp256r1PublicKey {
+    const SIGNATURE_SCHEME: BuiltinAuthValidator = BuiltinAuthValidator::Secp256r1;
+}
+
 impl<T: RoochPublicKey> From<&T> for RoochAddress {
     fn from(pk: &T) -> Self {
         let mut hasher = DefaultHash::default();
@@ -316,6 +374,8 @@ pub trait RoochSignatureInner: Sized + ToFromBytes + PartialEq + Eq + Hash {
 #[derive(Clone, JsonSchema, Debug, PartialEq, Eq, Hash)]
 pub enum Signature {
     Ed25519RoochSignature,
+    Secp256k1RoochSignature,
+    Secp256r1RoochSignature,
 }
 
 impl Serialize for Signature {
@@ -372,25 +432,28 @@ impl Signature {
     /// This is useful for the MultiSig to combine partial signature into a MultiSig public key.
     pub fn to_compressed(&self) -> Result<CompressedSignature, RoochError> {
         let bytes = self.signature_bytes();
-        // Process Rooch signature by default
-        Ok(CompressedSignature::Ed25519(
-            (&Ed25519Signature::from_bytes(bytes).map_err(|_| RoochError::InvalidSignature {
-                error: "Cannot parse sig".to_owned(),
-            })?)
-                .into(),
-        ))
+        let map_err = |_| RoochError::InvalidSignature {
+            error: "Cannot parse sig".to_owned(),
+        };
+        match self {
+            Signature::Ed25519RoochSignature(_) => Ok(CompressedSignature::Ed25519(
+                (&Ed25519Signature::from_bytes(bytes).map_err(map_err)?).into(),
+            )),
+            Signature::Secp256k1RoochSignature(_) => Ok(CompressedSignature::Secp256k1(
+                (&Secp256k1Signature::from_bytes(bytes).map_err(map_err)?).into(),
+            )),
+            Signature::Secp256r1RoochSignature(_) => Ok(CompressedSignature::Secp256r1(
+                (&Secp256r1Signature::from_bytes(bytes).map_err(map_err)?).into(),
+            )),
+        }
     }
 
     /// Parse [struct PublicKey] from trait RoochSignature `flag || sig || pk`.
     /// This is useful for the MultiSig to construct the bitmap in [struct MultiPublicKey].
     pub fn to_public_key(&self) -> Result<PublicKey, RoochError> {
         let bytes = self.public_key_bytes();
-        // Process Rooch signature by default
-        Ok(PublicKey::Ed25519(
-            (&Ed25519PublicKey::from_bytes(bytes)
-                .map_err(|_| RoochError::KeyConversionError("Cannot parse pk".to_owned()))?)
-                .into(),
-        ))
+        PublicKey::try_from_bytes(self.auth_validator(), bytes)
+            .map_err(|_| RoochError::KeyConversionError("Cannot parse pk".to_owned()))
     }
 }
 
@@ -398,6 +461,8 @@ impl AsRef<[u8]> for Signature {
     fn as_ref(&self) -> &[u8] {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_ref(),
+            Signature::Secp256k1RoochSignature(sig) => sig.as_ref(),
+            Signature::Secp256r1RoochSignature(sig) => sig.as_ref(),
         }
     }
 }
@@ -405,6 +470,8 @@ impl AsMut<[u8]> for Signature {
     fn as_mut(&mut self) -> &mut [u8] {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_mut(),
+            Signature::Secp256k1RoochSignature(sig) => sig.as_mut(),
+            Signature::Secp256r1RoochSignature(sig) => sig.as_mut(),
         }
     }
 }
@@ -415,6 +482,10 @@ impl ToFromBytes for Signature {
             Some(x) => {
                 if x == &Ed25519RoochSignature::SCHEME.flag() {
                     Ok(<Ed25519RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
+                } else if x == &Secp256k1RoochSignature::SCHEME.flag() {
+                    Ok(<Secp256k1RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
+                } else if x == &Secp256r1RoochSignature::SCHEME.flag() {
+                    Ok(<Secp256r1RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
                 } else {
                     Err(FastCryptoError::InvalidInput)
                 }
@@ -428,12 +499,16 @@ impl ToFromBytes for Signature {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum CompressedSignature {
     Ed25519(Ed25519SignatureAsBytes),
+    Secp256k1(Secp256k1SignatureAsBytes),
+    Secp256r1(Secp256r1SignatureAsBytes),
 }
 
 impl AsRef<[u8]> for CompressedSignature {
     fn as_ref(&self) -> &[u8] {
         match self {
             CompressedSignature::Ed25519(sig) => &sig.0,
+            CompressedSignature::Secp256k1(sig) => &sig.0,
+            CompressedSignature::Secp256r1(sig) => &sig.0,
         }
     }
 }
@@ -447,6 +522,9 @@ pub trait RoochSignature: Sized + ToFromBytes {
     fn verify_secure<T>(&self, value: &T, author: RoochAddress) -> RoochResult<()>
     where
         T: Serialize;
+
+    /// Verify the signature created by `Signature::new_hashed`
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> RoochResult<()>;
 }
 
 impl<S: RoochSignatureInner + Sized> RoochSignature for S {
@@ -480,6 +558,14 @@ impl<S: RoochSignatureInner + Sized> RoochSignature for S {
                 error: format!("Fail to verify user sig {}", e),
             })
     }
+
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> Result<(), RoochError> {
+        let (sig, pk) = &self.get_verification_inputs(author)?;
+        pk.verify(hashed_msg, sig)
+            .map_err(|e| RoochError::InvalidSignature {
+                error: format!("Fail to verify hashed sig {}", e),
+            })
+    }
 }
 
 //
@@ -526,14 +612,90 @@ impl RoochSignatureInner for Ed25519RoochSignature {
     const LENGTH: usize = Ed25519PublicKey::LENGTH + Ed25519Signature::LENGTH + 1;
 }
 
+//
+// Secp256k1 Rooch Signature port
+//
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, AsRef, AsMut)]
+#[as_ref(forward)]
+#[as_mut(forward)]
+pub struct Secp256k1RoochSignature(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    [u8; Secp256k1PublicKey::LENGTH + Secp256k1Signature::LENGTH + 1],
+);
+
+impl ToFromBytes for Secp256k1RoochSignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
+        if bytes.len() != Self::LENGTH {
+            return Err(FastCryptoError::InputLengthWrong(Self::LENGTH));
+        }
+        let mut sig_bytes = [0; Self::LENGTH];
+        sig_bytes.copy_from_slice(bytes);
+        Ok(Self(sig_bytes))
+    }
+}
+
+impl Signer<Signature> for Secp256k1KeyPair {
+    fn sign(&self, msg: &[u8]) -> Signature {
+        Secp256k1RoochSignature::new(self, msg).into()
+    }
+}
+
+impl RoochSignatureInner for Secp256k1RoochSignature {
+    type Sig = Secp256k1Signature;
+    type PubKey = Secp256k1PublicKey;
+    type KeyPair = Secp256k1KeyPair;
+    const LENGTH: usize = Secp256k1PublicKey::LENGTH + Secp256k1Signature::LENGTH + 1;
+}
+
+//
+// Secp256r1 Rooch Signature port
+//
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, AsRef, AsMut)]
+#[as_ref(forward)]
+#[as_mut(forward)]
+pub struct Secp256r1RoochSignature(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    [u8; Secp256r1PublicKey::LENGTH + Secp256r1Signature::LENGTH + 1],
+);
+
+impl ToFromBytes for Secp256r1RoochSignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
+        if bytes.len() != Self::LENGTH {
+            return Err(FastCryptoError::InputLengthWrong(Self::LENGTH));
+        }
+        let mut sig_bytes = [0; Self::LENGTH];
+        sig_bytes.copy_from_slice(bytes);
+        Ok(Self(sig_bytes))
+    }
+}
+
+impl Signer<Signature> for Secp256r1KeyPair {
+    fn sign(&self, msg: &[u8]) -> Signature {
+        Secp256r1RoochSignature::new(self, msg).into()
+    }
+}
+
+impl RoochSignatureInner for Secp256r1RoochSignature {
+    type Sig = Secp256r1Signature;
+    type PubKey = Secp256r1PublicKey;
+    type KeyPair = Secp256r1KeyPair;
+    const LENGTH: usize = Secp256r1PublicKey::LENGTH + Secp256r1Signature::LENGTH + 1;
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{RoochKeyPair, RoochSignature, Signature};
     use crate::address::RoochAddress;
+    use crate::framework::auth_validator::BuiltinAuthValidator;
     use ethers::utils::keccak256;
     use fastcrypto::{
         ed25519::{Ed25519KeyPair, Ed25519PrivateKey},
         secp256k1::recoverable::{Secp256k1RecoverableKeyPair, Secp256k1RecoverablePrivateKey},
-        traits::{KeyPair, ToFromBytes},
+        traits::{EncodeDecodeBase64, KeyPair, ToFromBytes},
     };
 
     // this test ensure the Rooch public key to address keep the same as the old version
@@ -563,4 +725,51 @@ mod tests {
         let expected_address = "0x1a642f0e3c3af545e7acbd38b07251b3990914f1";
         assert_eq!(address_str, expected_address);
     }
+
+    // this test ensures the secp256k1 and secp256r1 public key to address keep the same as the move validators
+    #[test]
+    fn test_secp_public_key_to_address() {
+        let k1 = RoochKeyPair::try_from_bytes(BuiltinAuthValidator::Secp256k1, &[1u8; 32]).unwrap();
+        assert_eq!(
+            hex::encode(k1.public().as_ref()),
+            "031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f"
+        );
+        assert_eq!(
+            k1.public().address().to_string(),
+            "0x62e36e040aaf71eff54b1c3f7089a71610c5e98d0be5d397324eecc57baba13b"
+        );
+
+        let r1 = RoochKeyPair::try_from_bytes(BuiltinAuthValidator::Secp256r1, &[1u8; 32]).unwrap();
+        assert_eq!(
+            hex::encode(r1.public().as_ref()),
+            "026ff03b949241ce1dadd43519e6960e0a85b41a69a05c328103aa2bce1594ca16"
+        );
+        assert_eq!(
+            r1.public().address().to_string(),
+            "0xca8a45a687f568eb732995fa7c3b8d5a61b59bf44c67a1c0fe348425fc19b96e"
+        );
+    }
+
+    #[test]
+    fn test_secp_key_pair_sign_and_verify() {
+        for scheme in [
+            BuiltinAuthValidator::Secp256k1,
+            BuiltinAuthValidator::Secp256r1,
+        ] {
+            let kp = RoochKeyPair::try_from_bytes(scheme, &[7u8; 32]).unwrap();
+            let decoded = RoochKeyPair::decode_base64(&kp.encode_base64()).unwrap();
+            assert_eq!(kp, decoded);
+
+            let msg = [0u8; 32];
+            let signature = Signature::new_hashed(&msg, &kp);
+            assert_eq!(signature.auth_validator(), scheme);
+            assert_eq!(signature.to_public_key().unwrap(), kp.public());
+            signature
+                .verify_hashed(&msg, kp.public().address())
+                .unwrap();
+            assert!(signature
+                .verify_hashed(&[1u8; 32], kp.public().address())
+                .is_err());
+        }
+    }
 }
@@ -3,7 +3,9 @@
 
 use super::ethereum_validator::EthereumValidatorModule;
 use super::native_validator::NativeValidatorModule;
+use super::secp256_validator::{Secp256k1ValidatorModule, Secp256r1ValidatorModule};
 use super::transaction_validator::TransactionValidator;
+use super::webauthn_validator::WebauthnValidatorModule;
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::error::RoochError;
 use anyhow::{ensure, Result};
@@ -26,7 +28,7 @@ use moveos_types::{
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-/// The Authenticator auth validator which has builtin Rooch and Ethereum
+/// The Authenticator auth validator which has builtin Rooch, Ethereum, Multisig, Bitcoin, Nostr, Secp256k1, Secp256r1 and Webauthn
 #[derive(
     Copy,
     Clone,
@@ -45,16 +47,34 @@ use strum_macros::{Display, EnumString};
 pub enum BuiltinAuthValidator {
     Rooch,
     Ethereum,
+    Multisig,
+    Bitcoin,
+    Nostr,
+    Secp256k1,
+    Secp256r1,
+    Webauthn,
 }
 
 impl BuiltinAuthValidator {
     const ROOCH_FLAG: u8 = 0x00;
     const ETHEREUM_FLAG: u8 = 0x01;
+    const MULTISIG_FLAG: u8 = 0x02;
+    const BITCOIN_FLAG: u8 = 0x03;
+    const NOSTR_FLAG: u8 = 0x04;
+    const SECP256K1_FLAG: u8 = 0x05;
+    const SECP256R1_FLAG: u8 = 0x06;
+    const WEBAUTHN_FLAG: u8 = 0x07;
 
     pub fn flag(&self) -> u8 {
         match self {
             BuiltinAuthValidator::Rooch => Self::ROOCH_FLAG,
             BuiltinAuthValidator::Ethereum => Self::ETHEREUM_FLAG,
+            BuiltinAuthValidator::Multisig => Self::MULTISIG_FLAG,
+            BuiltinAuthValidator::Bitcoin => Self::BITCOIN_FLAG,
+            BuiltinAuthValidator::Nostr => Self::NOSTR_FLAG,
+            BuiltinAuthValidator::Secp256k1 => Self::SECP256K1_FLAG,
+            BuiltinAuthValidator::Secp256r1 => Self::SECP256R1_FLAG,
+            BuiltinAuthValidator::Webauthn => Self::WEBAUTHN_FLAG,
         }
     }
 
@@ -68,6 +88,13 @@ impl BuiltinAuthValidator {
     pub fn from_flag_byte(byte_int: u8) -> Result<BuiltinAuthValidator, RoochError> {
         match byte_int {
             Self::ROOCH_FLAG => Ok(BuiltinAuthValidator::Rooch),
+            Self::ETHEREUM_FLAG => Ok(BuiltinAuthValidator::Ethereum),
+            Self::MULTISIG_FLAG => Ok(BuiltinAuthValidator::Multisig),
+            Self::BITCOIN_FLAG => Ok(BuiltinAuthValidator::Bitcoin),
+            Self::NOSTR_FLAG => Ok(BuiltinAuthValidator::Nostr),
+            Self::SECP256K1_FLAG => Ok(BuiltinAuthValidator::Secp256k1),
+            Self::SECP256R1_FLAG => Ok(BuiltinAuthValidator::Secp256r1),
+            Self::WEBAUTHN_FLAG => Ok(BuiltinAuthValidator::Webauthn),
             _ => Err(RoochError::KeyConversionError(
                 "Invalid key auth validator".to_owned(),
             )),
@@ -85,6 +112,31 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Ethereum => {
                 EthereumValidatorModule::rotate_authentication_key_action(public_key)
             }
+            BuiltinAuthValidator::Multisig => {
+                return Err(RoochError::RotateAuthenticationKeyError(
+                    "The multisig account has no authentication key, update the key set instead"
+                        .to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Bitcoin => {
+                return Err(RoochError::RotateAuthenticationKeyError(
+                    "The Bitcoin account is authenticated by the Bitcoin address".to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Nostr => {
+                return Err(RoochError::RotateAuthenticationKeyError(
+                    "The Nostr account is authenticated by the Nostr public key".to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Secp256k1 => {
+                Secp256k1ValidatorModule::rotate_authentication_key_action(public_key)
+            }
+            BuiltinAuthValidator::Secp256r1 => {
+                Secp256r1ValidatorModule::rotate_authentication_key_action(public_key)
+            }
+            BuiltinAuthValidator::Webauthn => {
+                WebauthnValidatorModule::rotate_authentication_key_action(public_key)
+            }
         };
         Ok(action)
     }
@@ -97,6 +149,31 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Ethereum => {
                 EthereumValidatorModule::remove_authentication_key_action()
             }
+            BuiltinAuthValidator::Multisig => {
+                return Err(RoochError::RemoveAuthenticationKeyError(
+                    "The multisig account has no authentication key, update the key set instead"
+                        .to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Bitcoin => {
+                return Err(RoochError::RemoveAuthenticationKeyError(
+                    "The Bitcoin account is authenticated by the Bitcoin address".to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Nostr => {
+                return Err(RoochError::RemoveAuthenticationKeyError(
+                    "The Nostr account is authenticated by the Nostr public key".to_owned(),
+                ))
+            }
+            BuiltinAuthValidator::Secp256k1 => {
+                Secp256k1ValidatorModule::remove_authentication_key_action()
+            }
+            BuiltinAuthValidator::Secp256r1 => {
+                Secp256r1ValidatorModule::remove_authentication_key_action()
+            }
+            BuiltinAuthValidator::Webauthn => {
+                WebauthnValidatorModule::remove_authentication_key_action()
+            }
         };
         Ok(action)
     }
@@ -13,6 +13,7 @@ pub mod address_mapping;
 pub mod auth_validator;
 pub mod bitcoin_light_client;
 pub mod bitcoin_types;
+pub mod bitcoin_validator;
 pub mod brc20;
 pub mod coin;
 pub mod coin_store;
@@ -22,12 +23,17 @@ pub mod ethereum_light_client;
 pub mod ethereum_validator;
 pub mod gas_coin;
 pub mod genesis;
+pub mod multisig_validator;
 pub mod native_validator;
+pub mod nostr_validator;
 pub mod ord;
+pub mod secp256_validator;
 pub mod session_key;
 pub mod timestamp;
+pub mod transaction_fee;
 pub mod transaction_validator;
 pub mod transfer;
+pub mod webauthn_validator;
 
 /// MoveOS system pre_execute functions registry.
 /// The registry is used to filter out system pre_execute functions.
@@ -0,0 +1,247 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::crypto::DefaultHash;
+use fastcrypto::hash::HashFunction;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    state::{MoveStructState, MoveStructType},
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("multisig_validator");
+
+pub struct MultisigValidator {}
+
+impl MultisigValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Multisig.flag().into()
+    }
+}
+
+impl MoveStructType for MultisigValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MultisigValidator");
+}
+
+/// The key schemes supported by the multisig validator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum MultisigKeyScheme {
+    Ed25519 = 0,
+    Secp256k1 = 1,
+    Secp256r1 = 2,
+}
+
+impl MultisigKeyScheme {
+    pub fn flag(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_flag(flag: u8) -> anyhow::Result<Self> {
+        match flag {
+            0 => Ok(MultisigKeyScheme::Ed25519),
+            1 => Ok(MultisigKeyScheme::Secp256k1),
+            2 => Ok(MultisigKeyScheme::Secp256r1),
+            _ => anyhow::bail!("Invalid multisig key scheme: {}", flag),
+        }
+    }
+}
+
+impl fmt::Display for MultisigKeyScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultisigKeyScheme::Ed25519 => write!(f, "ed25519"),
+            MultisigKeyScheme::Secp256k1 => write!(f, "secp256k1"),
+            MultisigKeyScheme::Secp256r1 => write!(f, "secp256r1"),
+        }
+    }
+}
+
+impl FromStr for MultisigKeyScheme {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ed25519" => Ok(MultisigKeyScheme::Ed25519),
+            "secp256k1" => Ok(MultisigKeyScheme::Secp256k1),
+            "secp256r1" => Ok(MultisigKeyScheme::Secp256r1),
+            _ => anyhow::bail!("Invalid multisig key scheme: {}", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigPublicKey {
+    pub scheme: u8,
+    pub public_key: Vec<u8>,
+}
+
+impl MultisigPublicKey {
+    pub fn new(scheme: MultisigKeyScheme, public_key: Vec<u8>) -> Self {
+        Self {
+            scheme: scheme.flag(),
+            public_key,
+        }
+    }
+}
+
+/// The string format is `<scheme>:<hex public key>`, for example `ed25519:0x1234...`
+impl FromStr for MultisigPublicKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, public_key) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid multisig public key: {}", s))?;
+        let public_key = public_key.strip_prefix("0x").unwrap_or(public_key);
+        Ok(Self::new(scheme.parse()?, hex::decode(public_key)?))
+    }
+}
+
+impl fmt::Display for MultisigPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match MultisigKeyScheme::from_flag(self.scheme) {
+            Ok(scheme) => write!(f, "{}:0x{}", scheme, hex::encode(&self.public_key)),
+            Err(_) => write!(f, "{}:0x{}", self.scheme, hex::encode(&self.public_key)),
+        }
+    }
+}
+
+impl MoveStructType for MultisigPublicKey {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MultisigPublicKey");
+}
+
+impl MoveStructState for MultisigPublicKey {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U8,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+        ])
+    }
+}
+
+/// The key set of a multisig account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigAccount {
+    pub threshold: u64,
+    pub public_keys: Vec<MultisigPublicKey>,
+}
+
+impl MoveStructType for MultisigAccount {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MultisigAccount");
+}
+
+impl MoveStructState for MultisigAccount {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::Struct(MultisigPublicKey::struct_layout()),
+            )),
+        ])
+    }
+}
+
+/// Derive the multisig account address from the initial key set and threshold,
+/// it is the same as `multisig_validator::multisig_address` in Move.
+pub fn multisig_address(public_keys: &[MultisigPublicKey], threshold: u64) -> AccountAddress {
+    let mut hasher = DefaultHash::default();
+    hasher.update([MultisigValidator::auth_validator_id() as u8]);
+    hasher.update(bcs::to_bytes(&threshold).expect("Serialize u64 should success"));
+    for key in public_keys {
+        hasher.update([key.scheme]);
+        hasher.update(&key.public_key);
+    }
+    AccountAddress::new(hasher.finalize().digest)
+}
+
+/// Rust bindings for RoochFramework multisig_validator module
+pub struct MultisigValidatorModule;
+
+impl MultisigValidatorModule {
+    const CREATE_MULTISIG_ACCOUNT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("create_multisig_account_entry");
+    const ADD_PUBLIC_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("add_public_key_entry");
+    const REMOVE_PUBLIC_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_public_key_entry");
+    const UPDATE_THRESHOLD_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_threshold_entry");
+
+    pub fn create_multisig_account_action(
+        public_keys: Vec<MultisigPublicKey>,
+        threshold: u64,
+    ) -> MoveAction {
+        let (schemes, public_keys): (Vec<u8>, Vec<Vec<u8>>) = public_keys
+            .into_iter()
+            .map(|key| (key.scheme, key.public_key))
+            .unzip();
+        Self::create_move_action(
+            Self::CREATE_MULTISIG_ACCOUNT_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(schemes),
+                MoveValue::Vector(public_keys.into_iter().map(MoveValue::vector_u8).collect()),
+                MoveValue::U64(threshold),
+            ],
+        )
+    }
+
+    pub fn add_public_key_action(public_key: MultisigPublicKey) -> MoveAction {
+        Self::create_move_action(
+            Self::ADD_PUBLIC_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U8(public_key.scheme),
+                MoveValue::vector_u8(public_key.public_key),
+            ],
+        )
+    }
+
+    pub fn remove_public_key_action(public_key: MultisigPublicKey) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_PUBLIC_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U8(public_key.scheme),
+                MoveValue::vector_u8(public_key.public_key),
+            ],
+        )
+    }
+
+    pub fn update_threshold_action(threshold: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_THRESHOLD_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(threshold)],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for MultisigValidatorModule {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(_caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+}
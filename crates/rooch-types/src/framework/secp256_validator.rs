@@ -0,0 +1,115 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::MoveStructType,
+    transaction::{FunctionCall, MoveAction},
+};
+use std::marker::PhantomData;
+
+/// The secp256k1 and secp256r1 validators share the same Move interface,
+/// the validator struct decides the module of the binding.
+pub trait Secp256Validator: MoveStructType {
+    const AUTH_VALIDATOR: BuiltinAuthValidator;
+
+    fn auth_validator_id() -> u64 {
+        Self::AUTH_VALIDATOR.flag().into()
+    }
+}
+
+pub struct Secp256k1Validator {}
+
+impl MoveStructType for Secp256k1Validator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("secp256k1_validator");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Secp256k1Validator");
+}
+
+impl Secp256Validator for Secp256k1Validator {
+    const AUTH_VALIDATOR: BuiltinAuthValidator = BuiltinAuthValidator::Secp256k1;
+}
+
+pub struct Secp256r1Validator {}
+
+impl MoveStructType for Secp256r1Validator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("secp256r1_validator");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Secp256r1Validator");
+}
+
+impl Secp256Validator for Secp256r1Validator {
+    const AUTH_VALIDATOR: BuiltinAuthValidator = BuiltinAuthValidator::Secp256r1;
+}
+
+/// Rust bindings for RoochFramework secp256k1_validator module
+pub type Secp256k1ValidatorModule<'a> = Secp256ValidatorModule<'a, Secp256k1Validator>;
+/// Rust bindings for RoochFramework secp256r1_validator module
+pub type Secp256r1ValidatorModule<'a> = Secp256ValidatorModule<'a, Secp256r1Validator>;
+
+/// Rust bindings for the RoochFramework secp256 validator modules
+pub struct Secp256ValidatorModule<'a, V> {
+    caller: &'a dyn MoveFunctionCaller,
+    validator: PhantomData<fn() -> V>,
+}
+
+impl<'a, V: Secp256Validator> Secp256ValidatorModule<'a, V> {
+    const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    const ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rotate_authentication_key_entry");
+    const REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_authentication_key_entry");
+
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<()> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        self.caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|values| {
+                debug_assert!(values.is_empty(), "should not have return values");
+            })?;
+        Ok(())
+    }
+
+    pub fn rotate_authentication_key_action(public_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(public_key)],
+        )
+    }
+
+    pub fn remove_authentication_key_action() -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+}
+
+impl<'a, V: Secp256Validator> ModuleBinding<'a> for Secp256ValidatorModule<'a, V> {
+    const MODULE_NAME: &'static IdentStr = V::MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self {
+            caller,
+            validator: PhantomData,
+        }
+    }
+}
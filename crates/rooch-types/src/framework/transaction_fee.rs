@@ -0,0 +1,50 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use moveos_types::{
+    moveos_std::object::{self, ObjectID},
+    state::{MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("transaction_fee");
+
+/// `GasPrice` is represented `rooch_framework::transaction_fee::GasPrice` in Move.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GasPrice {
+    /// The base fee per gas unit
+    pub base_fee: u64,
+    /// The gas used by the transactions in the current fee window
+    pub window_gas_used: u64,
+    /// How many transactions in the current fee window
+    pub window_tx_count: u64,
+}
+
+impl GasPrice {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+impl MoveStructType for GasPrice {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPrice");
+}
+
+impl MoveStructState for GasPrice {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+        ])
+    }
+}
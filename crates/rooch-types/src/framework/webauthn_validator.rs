@@ -0,0 +1,97 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::address::RoochAddress;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::crypto::DefaultHash;
+use anyhow::Result;
+use fastcrypto::hash::HashFunction;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    h256::H256,
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::MoveStructType,
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub struct WebauthnValidator {}
+
+impl WebauthnValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+
+    /// The address of the passkey account, it is the hash of the compressed secp256r1 public key of the credential
+    pub fn public_key_to_address(public_key: &[u8]) -> RoochAddress {
+        let mut hasher = DefaultHash::default();
+        hasher.update([BuiltinAuthValidator::Webauthn.flag()]);
+        hasher.update(public_key);
+        RoochAddress(H256(hasher.finalize().digest))
+    }
+}
+
+impl MoveStructType for WebauthnValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = WebauthnValidatorModule::MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnValidator");
+}
+
+/// Rust bindings for RoochFramework webauthn_validator module
+pub struct WebauthnValidatorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> WebauthnValidatorModule<'a> {
+    const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    const ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rotate_authentication_key_entry");
+    const REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_authentication_key_entry");
+
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<()> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        self.caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|values| {
+                debug_assert!(values.is_empty(), "should not have return values");
+            })?;
+        Ok(())
+    }
+
+    pub fn rotate_authentication_key_action(public_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(public_key)],
+        )
+    }
+
+    pub fn remove_authentication_key_action() -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for WebauthnValidatorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("webauthn_validator");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::indexer::Filter;
-use anyhow::Result;
 use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::state::{StateChangeSet, TableChangeSet};
 use schemars::JsonSchema;
@@ -58,16 +57,37 @@ pub enum StateFilter {
     TableHandle(ObjectID),
 }
 
-impl StateFilter {
-    fn try_matches(&self, item: &IndexerTableChangeSet) -> Result<bool> {
-        Ok(match self {
+impl Filter<IndexerTableChangeSet> for StateFilter {
+    fn matches(&self, item: &IndexerTableChangeSet) -> bool {
+        match self {
             StateFilter::TableHandle(table_handle) => table_handle == &item.table_handle,
-        })
+        }
     }
 }
 
-impl Filter<IndexerTableChangeSet> for StateFilter {
-    fn matches(&self, item: &IndexerTableChangeSet) -> bool {
-        self.try_matches(item).unwrap_or_default()
+#[cfg(test)]
+mod tests {
+    use super::{IndexerTableChangeSet, StateFilter};
+    use crate::indexer::Filter;
+    use moveos_types::h256::H256;
+    use moveos_types::moveos_std::object::ObjectID;
+    use moveos_types::state::TableChangeSet;
+
+    fn random_object_id() -> ObjectID {
+        ObjectID::new(H256::random().into())
+    }
+
+    #[test]
+    fn test_state_filter() {
+        let table_handle = random_object_id();
+        let item = IndexerTableChangeSet {
+            tx_order: 0,
+            table_handle_index: 0,
+            table_handle,
+            table_change_set: TableChangeSet::default(),
+            created_at: 0,
+        };
+        assert!(StateFilter::TableHandle(table_handle).matches(&item));
+        assert!(!StateFilter::TableHandle(random_object_id()).matches(&item));
     }
 }
@@ -1,10 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::indexer::Filter;
+use crate::transaction::{AbstractTransaction, TransactionWithInfo};
 use move_core_types::account_address::AccountAddress;
 use moveos_types::h256::H256;
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Debug)]
+pub struct IndexerTransaction {
+    pub transaction: TransactionWithInfo,
+    /// the account address of sender who send the transaction
+    pub sender: AccountAddress,
+
+    /// the transaction created timestamp on chain
+    pub created_at: u64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionFilter {
@@ -29,3 +41,102 @@ pub enum TransactionFilter {
         to_order: u64,
     },
 }
+
+impl Filter<IndexerTransaction> for TransactionFilter {
+    fn matches(&self, item: &IndexerTransaction) -> bool {
+        match self {
+            TransactionFilter::Sender(sender) => sender == &item.sender,
+            TransactionFilter::OriginalAddress(address) => {
+                address == &item.transaction.transaction.original_address_str()
+            }
+            TransactionFilter::TxHashes(tx_hashes) => {
+                tx_hashes.contains(&item.transaction.execution_info.tx_hash)
+            }
+            TransactionFilter::TimeRange {
+                start_time,
+                end_time,
+            } => *start_time <= item.created_at && *end_time > item.created_at,
+            TransactionFilter::TxOrderRange {
+                from_order,
+                to_order,
+            } => {
+                *from_order <= item.transaction.sequence_info.tx_order
+                    && *to_order > item.transaction.sequence_info.tx_order
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexerTransaction, TransactionFilter};
+    use crate::indexer::Filter;
+    use crate::transaction::authenticator::Authenticator;
+    use crate::transaction::rooch::RoochTransaction;
+    use crate::transaction::{
+        AbstractTransaction, TransactionSequenceInfo, TransactionWithInfo, TypedTransaction,
+    };
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::h256::H256;
+    use moveos_types::transaction::TransactionExecutionInfo;
+
+    fn mock_indexer_transaction(tx_order: u64, tx_timestamp: u64) -> IndexerTransaction {
+        let tx = RoochTransaction::mock();
+        let sender = tx.sender().into();
+        let tx_hash = tx.tx_hash();
+        IndexerTransaction {
+            transaction: TransactionWithInfo {
+                transaction: TypedTransaction::Rooch(tx),
+                sequence_info: TransactionSequenceInfo::new(
+                    tx_order,
+                    Authenticator::new(0, vec![]),
+                    H256::random(),
+                    tx_timestamp,
+                ),
+                execution_info: TransactionExecutionInfo::new(
+                    tx_hash,
+                    H256::random(),
+                    H256::random(),
+                    0,
+                    KeptVMStatus::Executed,
+                ),
+            },
+            sender,
+            created_at: tx_timestamp,
+        }
+    }
+
+    #[test]
+    fn test_transaction_filter() {
+        let item = mock_indexer_transaction(10, 1000);
+
+        assert!(TransactionFilter::Sender(item.sender).matches(&item));
+        assert!(!TransactionFilter::Sender(AccountAddress::random()).matches(&item));
+
+        let original_address = item.transaction.transaction.original_address_str();
+        assert!(TransactionFilter::OriginalAddress(original_address).matches(&item));
+        assert!(!TransactionFilter::OriginalAddress("0x1".to_string()).matches(&item));
+
+        let tx_hash = item.transaction.execution_info.tx_hash;
+        assert!(TransactionFilter::TxHashes(vec![H256::random(), tx_hash]).matches(&item));
+        assert!(!TransactionFilter::TxHashes(vec![H256::random()]).matches(&item));
+
+        let time_range = |start_time, end_time| TransactionFilter::TimeRange {
+            start_time,
+            end_time,
+        };
+        assert!(time_range(1000, 1001).matches(&item));
+        assert!(time_range(0, 2000).matches(&item));
+        assert!(!time_range(0, 1000).matches(&item));
+        assert!(!time_range(1001, 2000).matches(&item));
+
+        let order_range = |from_order, to_order| TransactionFilter::TxOrderRange {
+            from_order,
+            to_order,
+        };
+        assert!(order_range(10, 11).matches(&item));
+        assert!(!order_range(0, 10).matches(&item));
+        assert!(!order_range(11, 20).matches(&item));
+    }
+}
@@ -10,8 +10,11 @@
 use anyhow::Result;
 #[cfg(any(test, feature = "fuzzing"))]
 use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::encoding::{Base64, Encoding};
+use fastcrypto::hash::{HashFunction, Sha256};
 #[cfg(any(test, feature = "fuzzing"))]
 use fastcrypto::traits::KeyPair;
+use moveos_types::h256::H256;
 #[cfg(any(test, feature = "fuzzing"))]
 use proptest::{collection::vec, prelude::*};
 #[cfg(any(test, feature = "fuzzing"))]
@@ -19,7 +22,10 @@ use rand::{rngs::StdRng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
-use crate::{crypto::Signature, framework::auth_validator::BuiltinAuthValidator};
+use crate::{
+    crypto::{RoochSignature, Signature},
+    framework::{auth_validator::BuiltinAuthValidator, multisig_validator::MultisigKeyScheme},
+};
 
 /// A `Authenticator` is an an abstraction of a account authenticator.
 /// It is a part of `AccountAbstraction`
@@ -36,7 +42,8 @@ pub struct RoochAuthenticator {
 
 impl BuiltinAuthenticator for RoochAuthenticator {
     fn auth_validator_id(&self) -> u64 {
-        BuiltinAuthValidator::Rooch.flag().into()
+        // The key scheme of the signature decides the validator, Ed25519 for the native validator
+        self.signature.auth_validator().flag().into()
     }
     fn payload(&self) -> Vec<u8> {
         self.signature.as_ref().to_vec()
@@ -65,6 +72,206 @@ prop_compose! {
     }
 }
 
+/// A partial signature of a multisig transaction, signed by one key of the multisig key set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigSignature {
+    pub scheme: u8,
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl From<&Signature> for MultisigSignature {
+    fn from(signature: &Signature) -> Self {
+        let scheme = match signature {
+            Signature::Ed25519RoochSignature(_) => MultisigKeyScheme::Ed25519,
+            Signature::Secp256k1RoochSignature(_) => MultisigKeyScheme::Secp256k1,
+            Signature::Secp256r1RoochSignature(_) => MultisigKeyScheme::Secp256r1,
+        };
+        MultisigSignature {
+            scheme: scheme.flag(),
+            public_key: signature.public_key_bytes().to_vec(),
+            signature: signature.signature_bytes().to_vec(),
+        }
+    }
+}
+
+impl FromStr for MultisigSignature {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(s)?;
+        bcs::from_bytes(bytes.as_slice()).map_err(Into::into)
+    }
+}
+
+impl fmt::Display for MultisigSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "0x{}",
+            hex::encode(bcs::to_bytes(self).expect("Serialize MultisigSignature should success"))
+        )
+    }
+}
+
+/// The authenticator of the multisig validator, the payload is the bcs bytes of the signatures.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultisigAuthenticator {
+    pub signatures: Vec<MultisigSignature>,
+}
+
+impl BuiltinAuthenticator for MultisigAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Multisig.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize MultisigAuthenticator should success")
+    }
+}
+
+/// The authenticator of the Bitcoin validator, the Bitcoin wallet signs the message returned by
+/// `BitcoinAuthenticator::sign_message`, the payload is the bcs bytes of the struct.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitcoinAuthenticator {
+    pub sign_type: u8,
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+impl BitcoinAuthenticator {
+    pub const SIGN_TYPE_BIP137: u8 = 0;
+    pub const SIGN_TYPE_BIP322_SIMPLE: u8 = 1;
+
+    /// The message to sign by the Bitcoin wallet, it is the lowercase hex string of the transaction hash.
+    pub fn sign_message(tx_hash: &H256) -> String {
+        hex::encode(tx_hash.as_bytes())
+    }
+
+    /// The BIP-137 signature `header || r || s` and the compressed public key,
+    /// for the legacy(P2PKH) and segwit(P2SH-P2WPKH, P2WPKH) addresses.
+    pub fn bip137(signature: Vec<u8>, public_key: Vec<u8>) -> Self {
+        Self {
+            sign_type: Self::SIGN_TYPE_BIP137,
+            signature,
+            public_key,
+        }
+    }
+
+    /// The signature of the BIP-322 simple signature and the public key,
+    /// the ecdsa `r || s` signature and the compressed public key for the native segwit(P2WPKH) addresses,
+    /// or the schnorr signature and the x-only output key for the taproot(P2TR) addresses.
+    pub fn bip322_simple(signature: Vec<u8>, public_key: Vec<u8>) -> Self {
+        Self {
+            sign_type: Self::SIGN_TYPE_BIP322_SIMPLE,
+            signature,
+            public_key,
+        }
+    }
+}
+
+impl BuiltinAuthenticator for BitcoinAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Bitcoin.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize BitcoinAuthenticator should success")
+    }
+}
+
+/// The authenticator of the Nostr validator, the Nostr key signs the 32 bytes transaction hash as the message of BIP-340,
+/// the same way as the Nostr event id is signed, the payload is the bcs bytes of the struct.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NostrAuthenticator {
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+impl NostrAuthenticator {
+    /// The 64 bytes schnorr signature and the 32 bytes x-only public key
+    pub fn new(signature: Vec<u8>, public_key: Vec<u8>) -> Self {
+        Self {
+            signature,
+            public_key,
+        }
+    }
+}
+
+impl BuiltinAuthenticator for NostrAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Nostr.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize NostrAuthenticator should success")
+    }
+}
+
+/// The authenticator of the WebAuthn validator, it is the assertion of a passkey(secp256r1 credential)
+/// whose challenge is the transaction hash, the payload is the bcs bytes of the struct.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+}
+
+impl WebauthnAuthenticator {
+    pub const CLIENT_DATA_TYPE: &'static str = "webauthn.get";
+
+    /// The challenge of the assertion, it is the base64url(without padding) encoded transaction hash.
+    pub fn challenge(tx_hash: &H256) -> String {
+        Base64::encode(tx_hash.as_bytes())
+            .trim_end_matches('=')
+            .replace('+', "-")
+            .replace('/', "_")
+    }
+
+    /// The client data JSON of the assertion for the transaction hash, as the browser collects it.
+    pub fn client_data_json(tx_hash: &H256, origin: &str) -> Vec<u8> {
+        serde_json::json!({
+            "type": Self::CLIENT_DATA_TYPE,
+            "challenge": Self::challenge(tx_hash),
+            "origin": origin,
+            "crossOrigin": false,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    /// The message signed by the credential with ES256, it is `authenticator_data || sha256(client_data_json)`.
+    pub fn sign_message(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+        let mut message = authenticator_data.to_vec();
+        message.extend_from_slice(&Sha256::digest(client_data_json).digest);
+        message
+    }
+
+    /// The 64 bytes secp256r1 signature in form (r, s), the 33 bytes compressed public key,
+    /// and the authenticator data and client data JSON of the assertion
+    pub fn new(
+        signature: Vec<u8>,
+        public_key: Vec<u8>,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Self {
+        Self {
+            signature,
+            public_key,
+            authenticator_data,
+            client_data_json,
+        }
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize WebauthnAuthenticator should success")
+    }
+}
+
 impl<T> From<T> for Authenticator
 where
     T: BuiltinAuthenticator,
@@ -97,11 +304,31 @@ impl Authenticator {
         self.auth_validator_id
     }
 
-    /// Create a single-signature rooch authenticator
+    /// Create a single-signature rooch authenticator, the validator is chosen by the key scheme of the signature
     pub fn rooch(signature: Signature) -> Self {
         RoochAuthenticator { signature }.into()
     }
 
+    /// Create a multisig authenticator from the partial signatures
+    pub fn multisig(signatures: Vec<MultisigSignature>) -> Self {
+        MultisigAuthenticator { signatures }.into()
+    }
+
+    /// Create a Bitcoin authenticator
+    pub fn bitcoin(authenticator: BitcoinAuthenticator) -> Self {
+        authenticator.into()
+    }
+
+    /// Create a Nostr authenticator
+    pub fn nostr(authenticator: NostrAuthenticator) -> Self {
+        authenticator.into()
+    }
+
+    /// Create a WebAuthn authenticator
+    pub fn webauthn(authenticator: WebauthnAuthenticator) -> Self {
+        authenticator.into()
+    }
+
     /// Create a custom authenticator
     pub fn new(auth_validator_id: u64, payload: Vec<u8>) -> Self {
         Self {
@@ -134,7 +361,30 @@ impl fmt::Display for Authenticator {
 
 #[cfg(test)]
 mod tests {
+    use super::WebauthnAuthenticator;
+    use moveos_types::h256::H256;
     use proptest::prelude::*;
+    use std::str::FromStr;
+
+    // this test ensures the client data JSON keep the same as the one in the move webauthn tests
+    #[test]
+    fn test_webauthn_client_data_json() {
+        let tx_hash =
+            H256::from_str("0x5f3d2b8c1e7a9f40b6d2c8e1a3f5b7d9e0c2a4f6b8d0e2c4a6f8b0d2e4c6a8f0")
+                .unwrap();
+        assert_eq!(
+            WebauthnAuthenticator::challenge(&tx_hash),
+            "Xz0rjB56n0C20sjho_W32eDCpPa40OLEpviw0uTGqPA"
+        );
+        assert_eq!(
+            String::from_utf8(WebauthnAuthenticator::client_data_json(
+                &tx_hash,
+                "https://rooch.network"
+            ))
+            .unwrap(),
+            r#"{"type":"webauthn.get","challenge":"Xz0rjB56n0C20sjho_W32eDCpPa40OLEpviw0uTGqPA","origin":"https://rooch.network","crossOrigin":false}"#
+        );
+    }
 
     proptest! {
         #[test]
@@ -13,16 +13,17 @@ use crate::{
 };
 use anyhow::{bail, Result};
 use ethers::{
+    signers::{LocalWallet, Signer},
     types::{
-        transaction::eip2930::AccessList, Bytes, OtherFields, Signature, Transaction, H160, U256,
-        U64,
+        transaction::{eip2718::TypedTransaction, eip2930::AccessList},
+        Bytes, OtherFields, Signature, Transaction, H160, U256, U64,
     },
     utils::rlp::{Decodable, Rlp},
 };
 use move_core_types::account_address::AccountAddress;
 use moveos_types::{
     gas_config::GasConfig,
-    h256::{self, H256},
+    h256::H256,
     moveos_std::tx_context::TxContext,
     state::MoveStructType,
     transaction::{MoveAction, MoveOSTransaction},
@@ -243,24 +244,28 @@ impl EthereumTransaction {
             .build()
     }
 
-    pub fn new_for_test(sender: RoochAddress, nonce: U256, action: Bytes) -> Self {
-        let sender_and_action = (sender, action.clone());
-        let tx_hash = h256::sha3_256_of(bcs::to_bytes(&sender_and_action).unwrap().as_slice());
-        let transaction = Transaction {
-            hash: tx_hash,
+    /// The max gas amount of the transaction, it is the `gas` of the Ethereum transaction.
+    pub fn max_gas_amount(&self) -> u64 {
+        self.0.gas.as_u64()
+    }
+
+    /// Build a legacy transaction signed by the given wallet, the `from` address is the wallet address.
+    pub fn new_for_test(wallet: &LocalWallet, nonce: U256, action: Bytes) -> Self {
+        let mut transaction = Transaction {
+            hash: H256::zero(),
             nonce,
             block_hash: None,
             block_number: None,
             transaction_index: None,
-            from: H160::from_slice(&sender.0 .0[..20]), // scrape first 20 bytes as ethereum address
+            from: wallet.address(),
             to: None,
             value: U256::one(),
             gas_price: None,
             gas: GasConfig::DEFAULT_MAX_GAS_AMOUNT.into(),
             input: action,
-            v: U64::one(),
-            r: U256::one(),
-            s: U256::one(),
+            v: U64::zero(),
+            r: U256::zero(),
+            s: U256::zero(),
             transaction_type: None,
             access_list: None,
             max_priority_fee_per_gas: None,
@@ -268,6 +273,13 @@ impl EthereumTransaction {
             chain_id: Some(U256::from(RoochChainID::LOCAL.chain_id().id())), // build ethereum chain id from rooch chain id as parsed from MetaMask rooch chain id
             other: OtherFields::default(),
         };
+        let signature = wallet
+            .sign_transaction_sync(&TypedTransaction::from(&transaction))
+            .expect("Sign the Ethereum transaction should succeed");
+        transaction.v = U64::from(signature.v);
+        transaction.r = signature.r;
+        transaction.s = signature.s;
+        transaction.hash = transaction.hash();
 
         Self(transaction)
     }
@@ -309,9 +321,19 @@ impl EthereumTransaction {
         Ok(Signature { r, s, v })
     }
 
+    /// The message signed by the sender, the RLP encoding of the transaction without signature.
+    pub fn signing_message(&self) -> Vec<u8> {
+        TypedTransaction::from(&self.0).rlp().to_vec()
+    }
+
+    /// The hash signed by the sender, the keccak256 hash of the signing message.
+    pub fn signing_hash(&self) -> H256 {
+        TypedTransaction::from(&self.0).sighash()
+    }
+
     pub fn into_address(&self) -> Result<EthereumAddress, RoochError> {
-        // Prepare the signed message (RLP encoding of the transaction)
-        let message = self.tx_hash().to_fixed_bytes();
+        // The signed hash (keccak256 of the signing message)
+        let message = self.signing_hash().to_fixed_bytes();
         // Get the signature
         let ethereum_signature = self.into_signature()?;
         // Recover the h160 address using default recover method
@@ -341,6 +363,7 @@ impl AbstractTransaction for EthereumTransaction {
         self.0.rlp().to_vec()
     }
 
+    /// The Ethereum transaction hash, the keccak256 hash of the signed RLP encoding.
     fn tx_hash(&self) -> H256 {
         self.0.hash()
     }
@@ -351,7 +374,7 @@ impl AbstractTransaction for EthereumTransaction {
     ) -> Result<MoveOSTransaction> {
         let action = self.decode_calldata_to_action()?;
         let sequence_number = self.0.nonce.as_u64();
-        let gas = self.0.gas.as_u64();
+        let gas = self.max_gas_amount();
         let tx_ctx = TxContext::new(resolved_sender, sequence_number, gas, self.tx_hash());
         Ok(MoveOSTransaction::new(tx_ctx, action))
     }
@@ -366,10 +389,22 @@ impl AbstractTransaction for EthereumTransaction {
 
     fn authenticator_info(&self) -> Result<AuthenticatorInfo> {
         let chain_id = self.0.chain_id.ok_or(RoochError::InvalidChainID)?.as_u64();
-        let authenticator = Authenticator::new(
-            BuiltinAuthValidator::Ethereum.flag().into(),
-            self.into_signature()?.to_vec(),
-        );
+        // The payload is `signature || signer's Ethereum address || signing hash || signing message`,
+        // the validator recovers the signer from the signature and checks it against the address,
+        // and binds the payload to the tx hash by re-encoding the signed transaction.
+        let mut payload = self.into_signature()?.to_vec();
+        // Normalize the recovery id, the EIP-155 `v` does not fit in one byte.
+        let v = self.0.v.as_u64();
+        payload[64] = match v {
+            27 | 28 => v - 27,
+            v if v >= 35 => (v - 35) % 2,
+            v => v,
+        } as u8;
+        payload.extend_from_slice(self.0.from.as_bytes());
+        payload.extend_from_slice(self.signing_hash().as_bytes());
+        payload.extend(self.signing_message());
+        let authenticator =
+            Authenticator::new(BuiltinAuthValidator::Ethereum.flag().into(), payload);
         Ok(AuthenticatorInfo::new(chain_id, authenticator))
     }
 
@@ -377,3 +412,43 @@ impl AbstractTransaction for EthereumTransaction {
         MultiChainID::from(ETHER)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::utils::keccak256;
+
+    #[test]
+    fn test_tx_hash_and_signing_hash() {
+        let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+        let tx = EthereumTransaction::new_for_test(&wallet, U256::zero(), Bytes::default());
+
+        assert_eq!(tx.tx_hash(), H256::from(keccak256(tx.encode())));
+        assert_eq!(
+            tx.signing_hash(),
+            H256::from(keccak256(tx.signing_message()))
+        );
+        assert_ne!(tx.tx_hash(), tx.signing_hash());
+        assert_eq!(
+            tx.into_address().unwrap(),
+            EthereumAddress(wallet.address())
+        );
+
+        let decoded = EthereumTransaction::decode(&tx.encode()).unwrap();
+        assert_eq!(decoded.tx_hash(), tx.tx_hash());
+        assert_eq!(decoded.0.from, wallet.address());
+    }
+
+    #[test]
+    fn test_tx_hash_of_different_signers() {
+        let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+        let other_wallet = LocalWallet::from_bytes(&[2u8; 32]).unwrap();
+        let tx = EthereumTransaction::new_for_test(&wallet, U256::zero(), Bytes::default());
+        let other_tx =
+            EthereumTransaction::new_for_test(&other_wallet, U256::zero(), Bytes::default());
+
+        // The same unsigned transaction signed by different senders
+        assert_eq!(tx.signing_hash(), other_tx.signing_hash());
+        assert_ne!(tx.tx_hash(), other_tx.tx_hash());
+    }
+}
@@ -2,12 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use self::{authenticator::Authenticator, ethereum::EthereumTransaction, rooch::RoochTransaction};
-use crate::address::MultiChainAddress;
+use crate::address::{MultiChainAddress, RoochAddress};
+use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
+use crate::framework::auth_validator::BuiltinAuthValidator;
 use crate::multichain_id::{MultiChainID, ETHER, ROOCH};
-use anyhow::{format_err, Result};
+use accumulator::proof::AccumulatorProof;
+use anyhow::{ensure, format_err, Result};
+use fastcrypto::traits::ToFromBytes;
 use move_core_types::account_address::AccountAddress;
 use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::{h256::H256, transaction::MoveOSTransaction};
+use moveos_types::{
+    h256::{self, H256},
+    transaction::MoveOSTransaction,
+};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -128,6 +136,16 @@ impl TryFrom<RawTransaction> for TypedTransaction {
     }
 }
 
+impl TypedTransaction {
+    /// The max gas amount the sender is willing to pay for the transaction.
+    pub fn max_gas_amount(&self) -> u64 {
+        match self {
+            TypedTransaction::Rooch(tx) => tx.max_gas_amount(),
+            TypedTransaction::Ethereum(tx) => tx.max_gas_amount(),
+        }
+    }
+}
+
 impl AbstractTransaction for TypedTransaction {
     fn transaction_type(&self) -> TransactionType {
         match self {
@@ -202,10 +220,12 @@ impl AbstractTransaction for TypedTransaction {
 pub struct TransactionSequenceInfo {
     /// The tx order
     pub tx_order: u64,
-    /// The tx order signature, it is the signature of the sequencer to commit the tx order.
+    /// The tx order signature, it is the signature of the sequencer to commit the tx order and the tx timestamp.
     pub tx_order_signature: Authenticator,
     /// The tx accumulator root after the tx is append to the accumulator.
     pub tx_accumulator_root: H256,
+    /// The timestamp in milliseconds when the tx is sequenced.
+    pub tx_timestamp: u64,
 }
 
 impl TransactionSequenceInfo {
@@ -213,23 +233,139 @@ impl TransactionSequenceInfo {
         tx_order: u64,
         tx_order_signature: Authenticator,
         tx_accumulator_root: H256,
+        tx_timestamp: u64,
     ) -> TransactionSequenceInfo {
         TransactionSequenceInfo {
             tx_order,
             tx_order_signature,
             tx_accumulator_root,
+            tx_timestamp,
         }
     }
+
+    /// The hash of the witness data signed by the sequencer, it commits the tx hash to the tx order and the tx timestamp.
+    pub fn tx_order_witness_hash(tx_hash: H256, tx_order: u64, tx_timestamp: u64) -> H256 {
+        let mut witness_data = tx_hash.as_ref().to_vec();
+        witness_data.extend(tx_order.to_le_bytes().iter());
+        witness_data.extend(tx_timestamp.to_le_bytes().iter());
+        h256::sha3_256_of(&witness_data)
+    }
+
+    /// The hash of the witness data of the legacy sequence info, which does not commit to the tx timestamp.
+    fn legacy_tx_order_witness_hash(tx_hash: H256, tx_order: u64) -> H256 {
+        let mut witness_data = tx_hash.as_ref().to_vec();
+        witness_data.extend(tx_order.to_le_bytes().iter());
+        h256::sha3_256_of(&witness_data)
+    }
+
+    /// Sign the tx order and the tx timestamp of the transaction with the sequencer key.
+    pub fn sign_tx_order(
+        tx_hash: H256,
+        tx_order: u64,
+        tx_timestamp: u64,
+        sequencer_key: &RoochKeyPair,
+    ) -> Authenticator {
+        let witness_hash = Self::tx_order_witness_hash(tx_hash, tx_order, tx_timestamp);
+        Signature::new_hashed(&witness_hash.0, sequencer_key).into()
+    }
+
+    /// Verify the tx order signature is signed by the `sequencer` for the transaction with `tx_hash`.
+    /// The sequence info migrated from the legacy layout has no tx timestamp, it is verified with the legacy witness.
+    pub fn verify_tx_order_signature(&self, tx_hash: H256, sequencer: RoochAddress) -> Result<()> {
+        ensure!(
+            self.tx_order_signature.auth_validator_id
+                == u64::from(BuiltinAuthValidator::Rooch.flag()),
+            "Unsupported tx order signature auth validator id {}",
+            self.tx_order_signature.auth_validator_id
+        );
+        let signature = Signature::from_bytes(&self.tx_order_signature.payload)
+            .map_err(|e| format_err!("Invalid tx order signature: {:?}", e))?;
+        let witness_hash = if self.tx_timestamp == LEGACY_TX_TIMESTAMP {
+            Self::legacy_tx_order_witness_hash(tx_hash, self.tx_order)
+        } else {
+            Self::tx_order_witness_hash(tx_hash, self.tx_order, self.tx_timestamp)
+        };
+        signature
+            .verify_hashed(&witness_hash.0, sequencer)
+            .map_err(Into::into)
+    }
+
+    /// Decode the sequence info, the data saved in the legacy layout without the tx timestamp is also accepted.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        decode_with_legacy::<Self, LegacyTransactionSequenceInfo>(bytes)
+    }
+}
+
+/// The tx timestamp of the sequence info migrated from the legacy layout, the legacy sequence info has no timestamp.
+pub const LEGACY_TX_TIMESTAMP: u64 = 0;
+
+/// The layout of `TransactionSequenceInfo` before the tx timestamp is added, it is only used to decode the legacy data.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LegacyTransactionSequenceInfo {
+    pub tx_order: u64,
+    pub tx_order_signature: Authenticator,
+    pub tx_accumulator_root: H256,
+}
+
+impl From<LegacyTransactionSequenceInfo> for TransactionSequenceInfo {
+    fn from(info: LegacyTransactionSequenceInfo) -> Self {
+        TransactionSequenceInfo::new(
+            info.tx_order,
+            info.tx_order_signature,
+            info.tx_accumulator_root,
+            LEGACY_TX_TIMESTAMP,
+        )
+    }
+}
+
+/// Decode the data as `T`, fallback to decode it as the legacy layout `L` and convert it to `T`.
+/// The legacy layout is shorter than the current layout, so the data of one layout can not be decoded as the other.
+pub fn decode_with_legacy<T, L>(bytes: &[u8]) -> Result<T>
+where
+    T: DeserializeOwned,
+    L: DeserializeOwned + Into<T>,
+{
+    match bcs::from_bytes::<T>(bytes) {
+        Ok(value) => Ok(value),
+        Err(e) => bcs::from_bytes::<L>(bytes)
+            .map(Into::into)
+            .map_err(|_| e.into()),
+    }
 }
 
 /// Transaction with sequence info and execution info.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TransactionWithInfo {
     pub transaction: TypedTransaction,
     pub sequence_info: TransactionSequenceInfo,
     pub execution_info: TransactionExecutionInfo,
 }
 
+impl TransactionWithInfo {
+    /// Decode the transaction with info, the data saved in the legacy layout without the tx timestamp is also accepted.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        decode_with_legacy::<Self, LegacyTransactionWithInfo>(bytes)
+    }
+}
+
+/// The layout of `TransactionWithInfo` before the tx timestamp is added, it is only used to decode the legacy data.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LegacyTransactionWithInfo {
+    pub transaction: TypedTransaction,
+    pub sequence_info: LegacyTransactionSequenceInfo,
+    pub execution_info: TransactionExecutionInfo,
+}
+
+impl From<LegacyTransactionWithInfo> for TransactionWithInfo {
+    fn from(tx: LegacyTransactionWithInfo) -> Self {
+        TransactionWithInfo {
+            transaction: tx.transaction,
+            sequence_info: tx.sequence_info.into(),
+            execution_info: tx.execution_info,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TransactionSequenceInfoMapping {
     /// The tx order
@@ -244,6 +380,60 @@ impl TransactionSequenceInfoMapping {
     }
 }
 
+/// `TransactionProof` proves that the tx with `tx_hash` is sequenced at `tx_order`,
+/// the tx hash is the leaf at index `tx_order` of the tx accumulator with `tx_accumulator_root`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionProof {
+    /// The tx order, also the leaf index of the tx in the accumulator
+    pub tx_order: u64,
+    /// The tx hash
+    pub tx_hash: H256,
+    /// The last tx order of the accumulator when the proof is generated
+    pub accumulator_order: u64,
+    /// The tx accumulator root after the tx with `accumulator_order` is append to the accumulator.
+    pub tx_accumulator_root: H256,
+    pub proof: AccumulatorProof,
+}
+
+impl TransactionProof {
+    pub fn new(
+        tx_order: u64,
+        tx_hash: H256,
+        accumulator_order: u64,
+        tx_accumulator_root: H256,
+        proof: AccumulatorProof,
+    ) -> TransactionProof {
+        TransactionProof {
+            tx_order,
+            tx_hash,
+            accumulator_order,
+            tx_accumulator_root,
+            proof,
+        }
+    }
+
+    /// Verify the tx hash is the leaf at `tx_order` under the `tx_accumulator_root`.
+    pub fn verify(&self) -> Result<()> {
+        ensure!(
+            self.tx_order <= self.accumulator_order,
+            "The tx order {} is greater than the accumulator order {}",
+            self.tx_order,
+            self.accumulator_order
+        );
+        // The bits of the leaf index above the proof depth are not used when computing the root,
+        // so the index must fit in the proof, otherwise the proof also verifies for other tx orders.
+        let depth = self.proof.siblings().len() as u32;
+        ensure!(
+            self.tx_order.checked_shr(depth).unwrap_or(0) == 0,
+            "The tx order {} is out of the accumulator proof depth {}",
+            self.tx_order,
+            depth
+        );
+        self.proof
+            .verify(self.tx_accumulator_root, self.tx_hash, self.tx_order)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::rooch::RoochTransaction;
@@ -262,4 +452,150 @@ mod tests {
         let tx = RoochTransaction::mock();
         test_serialize_deserialize_roundtrip(tx)
     }
+
+    #[test]
+    fn test_max_gas_amount() {
+        use super::ethereum::EthereumTransaction;
+        use super::TypedTransaction;
+        use ethers::signers::LocalWallet;
+        use ethers::types::{Bytes, U256};
+        use moveos_types::gas_config::GasConfig;
+
+        let tx = RoochTransaction::mock();
+        let max_gas_amount = tx.max_gas_amount();
+        assert_eq!(TypedTransaction::Rooch(tx).max_gas_amount(), max_gas_amount);
+
+        let wallet = LocalWallet::from_bytes(&[1u8; 32]).unwrap();
+        let tx = EthereumTransaction::new_for_test(&wallet, U256::zero(), Bytes::default());
+        assert_eq!(
+            TypedTransaction::Ethereum(tx).max_gas_amount(),
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT
+        );
+    }
+
+    #[test]
+    fn test_transaction_proof() {
+        use super::TransactionProof;
+        use accumulator::tree_store::mock::MockAccumulatorStore;
+        use accumulator::{Accumulator, MerkleAccumulator};
+        use moveos_types::h256::H256;
+        use std::sync::Arc;
+
+        let accumulator = MerkleAccumulator::new_empty(Arc::new(MockAccumulatorStore::new()));
+        let tx_hashes = (0..10).map(|_| H256::random()).collect::<Vec<_>>();
+        let root = accumulator.append(&tx_hashes).unwrap();
+        for (tx_order, tx_hash) in tx_hashes.iter().enumerate() {
+            let proof = accumulator.get_proof(tx_order as u64).unwrap().unwrap();
+            let tx_proof = TransactionProof::new(tx_order as u64, *tx_hash, 9, root, proof);
+            tx_proof.verify().unwrap();
+
+            let mut wrong_proof = tx_proof.clone();
+            wrong_proof.tx_order = (tx_order as u64 + 1) % 10;
+            assert!(wrong_proof.verify().is_err());
+
+            // The tx order aliases to the same leaf index if the bits above the proof depth are ignored
+            let mut wrong_proof = tx_proof.clone();
+            wrong_proof.tx_order = tx_order as u64 + (1 << tx_proof.proof.siblings().len());
+            wrong_proof.accumulator_order = u64::MAX;
+            assert!(wrong_proof.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_tx_order_signature() {
+        use super::{TransactionSequenceInfo, LEGACY_TX_TIMESTAMP};
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+        use crate::crypto::RoochKeyPair;
+        use fastcrypto::ed25519::Ed25519KeyPair;
+        use fastcrypto::traits::KeyPair;
+        use moveos_types::h256::H256;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let sequencer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+        let sequencer: RoochAddress = (&sequencer_key.public()).into();
+        let tx_hash = H256::random();
+        let tx_order = 10;
+        let tx_timestamp = 1000;
+        let sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            TransactionSequenceInfo::sign_tx_order(tx_hash, tx_order, tx_timestamp, &sequencer_key),
+            H256::random(),
+            tx_timestamp,
+        );
+        sequence_info
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .unwrap();
+        assert!(sequence_info
+            .verify_tx_order_signature(H256::random(), sequencer)
+            .is_err());
+        assert!(sequence_info
+            .verify_tx_order_signature(tx_hash, RoochAddress::random())
+            .is_err());
+
+        let mut wrong_order = sequence_info.clone();
+        wrong_order.tx_order += 1;
+        assert!(wrong_order
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .is_err());
+
+        // The tx timestamp is committed by the signature
+        let mut wrong_timestamp = sequence_info.clone();
+        wrong_timestamp.tx_timestamp += 1;
+        assert!(wrong_timestamp
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .is_err());
+        let mut wrong_timestamp = sequence_info;
+        wrong_timestamp.tx_timestamp = LEGACY_TX_TIMESTAMP;
+        assert!(wrong_timestamp
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .is_err());
+    }
+
+    #[test]
+    fn test_decode_legacy_sequence_info() {
+        use super::*;
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+        use crate::crypto::RoochKeyPair;
+        use fastcrypto::ed25519::Ed25519KeyPair;
+        use fastcrypto::traits::KeyPair;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let sequencer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+        let sequencer: RoochAddress = (&sequencer_key.public()).into();
+        let tx_hash = H256::random();
+        let tx_order = 10;
+        // The legacy sequence info is signed without the tx timestamp
+        let witness_hash = TransactionSequenceInfo::legacy_tx_order_witness_hash(tx_hash, tx_order);
+        let legacy_info = LegacyTransactionSequenceInfo {
+            tx_order,
+            tx_order_signature: Signature::new_hashed(&witness_hash.0, &sequencer_key).into(),
+            tx_accumulator_root: H256::random(),
+        };
+        let sequence_info =
+            TransactionSequenceInfo::decode(&bcs::to_bytes(&legacy_info).unwrap()).unwrap();
+        assert_eq!(sequence_info.tx_order, tx_order);
+        assert_eq!(sequence_info.tx_timestamp, LEGACY_TX_TIMESTAMP);
+        assert_eq!(
+            sequence_info.tx_accumulator_root,
+            legacy_info.tx_accumulator_root
+        );
+        sequence_info
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .unwrap();
+
+        // The sequence info of the current layout is decoded as is
+        let sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            TransactionSequenceInfo::sign_tx_order(tx_hash, tx_order, 1000, &sequencer_key),
+            H256::random(),
+            1000,
+        );
+        assert_eq!(
+            TransactionSequenceInfo::decode(&bcs::to_bytes(&sequence_info).unwrap()).unwrap(),
+            sequence_info
+        );
+        assert!(TransactionSequenceInfo::decode(&[0u8; 8]).is_err());
+    }
 }
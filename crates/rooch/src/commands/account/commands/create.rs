@@ -7,6 +7,7 @@ use move_core_types::account_address::AccountAddress;
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use rpassword::prompt_password;
 
 /// Create a new account off-chain.
@@ -17,6 +18,10 @@ use rpassword::prompt_password;
 /// any coins will have to transferred afterwards.
 #[derive(Debug, Parser)]
 pub struct CreateCommand {
+    /// The key scheme of the new key pair: rooch(Ed25519), secp256k1 or secp256r1
+    #[clap(long, arg_enum, default_value = "rooch")]
+    scheme: BuiltinAuthValidator,
+
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
 }
@@ -24,10 +29,21 @@ pub struct CreateCommand {
 impl CreateCommand {
     pub async fn execute(self) -> RoochResult<String> {
         let mut context = self.context_options.build()?;
+        if !matches!(
+            self.scheme,
+            BuiltinAuthValidator::Rooch
+                | BuiltinAuthValidator::Secp256k1
+                | BuiltinAuthValidator::Secp256r1
+        ) {
+            return Err(RoochError::CommandArgumentError(format!(
+                "Unsupported key scheme: {}",
+                self.scheme
+            )));
+        }
         let result = if context.keystore.get_if_password_is_empty() {
             context
                 .keystore
-                .generate_and_add_new_key(None, None, None, None)?
+                .generate_and_add_new_key(self.scheme, None, None, None, None)?
         } else {
             let password =
                 prompt_password("Enter the password to create a new key pair:").unwrap_or_default();
@@ -40,15 +56,19 @@ impl CreateCommand {
                 ));
             }
 
-            context
-                .keystore
-                .generate_and_add_new_key(None, None, None, Some(password))?
+            context.keystore.generate_and_add_new_key(
+                self.scheme,
+                None,
+                None,
+                None,
+                Some(password),
+            )?
         };
 
         let address = AccountAddress::from(result.address).to_hex_literal();
         println!(
-            "Generated new keypair for address with key pair type [{}]",
-            result.address
+            "Generated new keypair for address [{}] with key pair type [{}]",
+            result.address, self.scheme
         );
         println!(
             "Secret Recovery Phrase : [{}]",
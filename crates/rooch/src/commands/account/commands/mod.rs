@@ -5,4 +5,5 @@ pub mod balance;
 pub mod create;
 pub mod list;
 pub mod nullify;
+pub mod rotate_key;
 pub mod switch;
@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::auth_validator::BuiltinAuthValidator,
+};
+use rpassword::prompt_password;
+use std::str::FromStr;
+
+/// Rotate or remove the authentication key of an account for the given auth validator
+#[derive(Debug, Parser)]
+pub struct RotateKeyCommand {
+    /// Rooch address of the account, default is the active address
+    #[clap(short = 'a', long = "address")]
+    address: Option<String>,
+
+    /// The auth validator of the authentication key
+    #[clap(long, arg_enum, default_value = "rooch")]
+    auth_validator: BuiltinAuthValidator,
+
+    /// The hex encoded new public key, Ed25519 for rooch, compressed Secp256k1 for ethereum and secp256k1,
+    /// compressed Secp256r1 for secp256r1 and webauthn
+    #[clap(long, required_unless_present = "remove", conflicts_with = "remove")]
+    public_key: Option<String>,
+
+    /// Remove the authentication key instead of rotating it
+    #[clap(long)]
+    remove: bool,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RotateKeyCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+
+        let sender = match self.address {
+            Some(address) => RoochAddress::from_str(address.as_str()).map_err(|e| {
+                RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
+            })?,
+            None => context.client_config.active_address.ok_or_else(|| {
+                RoochError::CommandArgumentError("No active address, use --address".to_owned())
+            })?,
+        };
+
+        let action = if self.remove {
+            self.auth_validator
+                .create_remove_authentication_key_action()?
+        } else {
+            let public_key = self.public_key.unwrap_or_default();
+            let public_key = hex::decode(public_key.strip_prefix("0x").unwrap_or(&public_key))
+                .map_err(|e| {
+                    RoochError::CommandArgumentError(format!("Invalid public key: {}", e))
+                })?;
+            self.auth_validator
+                .create_rotate_authentication_key_action(public_key)?
+        };
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password =
+                prompt_password("Enter the password to rotate the key:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        context.assert_execute_success(result)
+    }
+}
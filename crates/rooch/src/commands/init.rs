@@ -19,6 +19,7 @@ use rooch_key::keystore::Keystore;
 use rooch_rpc_client::client_config::{ClientConfig, Env};
 use rooch_types::error::RoochError;
 use rooch_types::error::RoochResult;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use rpassword::prompt_password;
 use std::fs;
 
@@ -156,6 +157,7 @@ impl CommandAction<()> for Init {
                 };
 
                 let result = keystore.generate_and_add_new_key(
+                    BuiltinAuthValidator::Rooch,
                     self.mnemonic_phrase,
                     None,
                     None,
@@ -7,6 +7,7 @@ pub mod env;
 pub mod event;
 pub mod init;
 pub mod move_cli;
+pub mod multisig;
 pub mod object;
 pub mod resource;
 pub mod rpc;
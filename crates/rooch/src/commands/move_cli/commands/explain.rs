@@ -3,22 +3,25 @@
 
 use bcs_ext;
 use clap::*;
+use move_binary_format::CompiledModule;
 use move_core_types::errmap::{ErrorDescription, ErrorMapping};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::vm_status::AbortLocation;
-use moveos_types::addresses::MOVEOS_STD_ADDRESS;
-use moveos_types::addresses::MOVE_STD_ADDRESS;
-use rooch_genesis::{
-    move_std_error_descriptions, moveos_std_error_descriptions, rooch_framework_error_descriptions,
-};
-use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use moveos_types::access_path::AccessPath;
+use moveos_types::move_std::error::MoveAbortExplain;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::State;
+use moveos_verifier::metadata::get_metadata_from_compiled_module;
+use rooch_genesis::framework_error_descriptions;
+use rooch_rpc_client::Client;
 use rooch_types::function_arg::ParsedModuleId;
-use serde::{Deserialize, Serialize};
 
 use crate::cli_types::WalletContextOptions;
 
 ///Explain Move abort codes. Errors are defined as
 ///a global category + module-specific reason for the error.
+///The framework errors are explained by the builtin error maps,
+///the errors of the other modules are explained by the error constants in the on chain module metadata.
 #[derive(Parser)]
 #[clap(name = "explain")]
 pub struct Explain {
@@ -39,25 +42,20 @@ impl Explain {
         let address_mapping = context.address_mapping();
         let module_id = self.location.into_module_id(&address_mapping)?;
 
-        let error_description_bytes = {
-            match *module_id.address() {
-                MOVE_STD_ADDRESS => Some(move_std_error_descriptions()),
-                MOVEOS_STD_ADDRESS => Some(moveos_std_error_descriptions()),
-                ROOCH_FRAMEWORK_ADDRESS => Some(rooch_framework_error_descriptions()),
-                _ => None,
-            }
-        };
+        let error_description_bytes = framework_error_descriptions(module_id.address());
 
-        match error_description_bytes {
+        let explain_result = match error_description_bytes {
             Some(bytes) => {
-                let explain_result =
-                    explain_move_abort(AbortLocation::Module(module_id), self.abort_code, bytes);
-                println!("{}", explain_result)
+                explain_move_abort(AbortLocation::Module(module_id), self.abort_code, bytes)
             }
             None => {
-                return Err(anyhow::Error::msg("Error map data not found."));
+                let client = context.get_client().await?;
+                let description =
+                    get_onchain_explanation(&client, &module_id, self.abort_code).await?;
+                MoveAbortExplain::new(self.abort_code, description)
             }
-        }
+        };
+        println!("{}", explain_result);
 
         Ok(())
     }
@@ -75,38 +73,26 @@ pub fn get_explanation(
     error_descriptions.get_explanation(module_id, abort_code)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq)]
-pub struct MoveAbortExplain {
-    pub category_code: u64,
-    pub category_name: Option<String>,
-    pub reason_code: u64,
-    pub reason_name: Option<String>,
-    pub code_description: Option<String>,
-}
-
-impl std::fmt::Display for MoveAbortExplain {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Category Code: {}", self.category_code)?;
-        writeln!(
-            f,
-            "Category Name: {}",
-            self.category_name.clone().unwrap_or("Unknown".to_string())
-        )?;
-        writeln!(f, "Reason Code: {}", self.reason_code)?;
-        writeln!(
-            f,
-            "Reason Name: {}",
-            self.reason_name.clone().unwrap_or("Unknown".to_string())
-        )?;
-        writeln!(
-            f,
-            "Code Description: {}",
-            self.code_description
-                .clone()
-                .unwrap_or("Unknown".to_string())
-        )?;
-        Ok(())
-    }
+/// Read the error constants from the metadata of the module published on chain.
+async fn get_onchain_explanation(
+    client: &Client,
+    module_id: &ModuleId,
+    abort_code: u64,
+) -> anyhow::Result<Option<ErrorDescription>> {
+    let state_view = client
+        .rooch
+        .get_states(AccessPath::module(
+            *module_id.address(),
+            module_id.name().to_owned(),
+        ))
+        .await?
+        .pop()
+        .flatten()
+        .ok_or_else(|| anyhow::anyhow!("Module {} not found on chain.", module_id))?;
+    let module = State::from(state_view).cast::<MoveModule>()?;
+    let compiled_module = CompiledModule::deserialize(&module.byte_codes)?;
+    Ok(get_metadata_from_compiled_module(&compiled_module)
+        .and_then(|metadata| metadata.get_error_description(abort_code).cloned()))
 }
 
 pub fn explain_move_abort(
@@ -114,26 +100,11 @@ pub fn explain_move_abort(
     abort_code: u64,
     data: &[u8],
 ) -> MoveAbortExplain {
-    let (category, reason_code) = moveos_types::move_std::error::explain(abort_code);
+    let (_, reason_code) = moveos_types::move_std::error::explain(abort_code);
 
     let err_description = match abort_location {
         AbortLocation::Module(module_id) => get_explanation(&module_id, reason_code, data),
         AbortLocation::Script => None,
     };
-    match err_description {
-        Some(description) => MoveAbortExplain {
-            category_code: category,
-            category_name: moveos_types::move_std::error::explain_category(category),
-            reason_code,
-            reason_name: Some(description.code_name),
-            code_description: Some(description.code_description),
-        },
-        None => MoveAbortExplain {
-            category_code: category,
-            category_name: moveos_types::move_std::error::explain_category(category),
-            reason_code,
-            reason_name: None,
-            code_description: None,
-        },
-    }
+    MoveAbortExplain::new(abort_code, err_description)
 }
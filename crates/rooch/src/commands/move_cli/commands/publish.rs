@@ -8,17 +8,29 @@ use move_binary_format::file_format::CompiledModule;
 use move_bytecode_utils::dependency_graph::DependencyGraph;
 use move_bytecode_utils::Modules;
 use move_cli::Move;
-use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+};
+use move_package::source_package::manifest_parser::{
+    parse_move_manifest_from_file, parse_source_manifest,
+};
 use moveos_verifier::verifier;
 use rooch_key::key_derive::verify_password;
 use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_rpc_client::Client;
 use rooch_types::transaction::rooch::RoochTransaction;
 use rpassword::prompt_password;
 
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
 use moveos::vm::dependency_order::sort_by_dependency_order;
 use moveos_types::{
-    addresses::MOVEOS_STD_ADDRESS, move_types::FunctionId, transaction::MoveAction,
+    access_path::AccessPath,
+    addresses::MOVEOS_STD_ADDRESS,
+    move_types::FunctionId,
+    moveos_std::move_module::{check_module_upgrade, MoveModule, UpgradePolicy},
+    moveos_std::package_registry::package_dependencies,
+    state::{MoveStructType, State},
+    transaction::MoveAction,
 };
 use moveos_verifier::build::run_verifier;
 use rooch_key::keystore::account_keystore::AccountKeystore;
@@ -48,12 +60,80 @@ pub struct Publish {
 
     /// Whether publish modules by `MoveAction::ModuleBundle`?
     /// If not set, publish moduels through Move entry function
-    /// `moveos_std::context::publish_modules_entry`
+    /// `moveos_std::package_registry::publish_package_entry`, which also records the package in the `PackageRegistry`.
+    /// The packages published by `MoveAction::ModuleBundle` are recorded without the name, version and source digest.
     #[clap(long, parse(from_flag))]
     pub by_move_action: bool,
+
+    /// Upgrade the published modules.
+    /// The modules are checked against the published ones with the upgrade policy of the package address,
+    /// and the incompatibilities are reported before submitting the transaction.
+    #[clap(long, parse(from_flag))]
+    pub upgrade: bool,
 }
 
 impl Publish {
+    /// Check the modules against the published ones with the upgrade policy of the package address.
+    async fn check_upgrade(
+        client: &Client,
+        pkg_address: AccountAddress,
+        modules: &[CompiledModule],
+    ) -> RoochResult<()> {
+        let policy = client
+            .rooch
+            .get_states(AccessPath::resource(
+                pkg_address,
+                UpgradePolicy::struct_tag(),
+            ))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state_view| State::from(state_view).cast::<UpgradePolicy>())
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut upgraded = false;
+        let mut incompatibilities = vec![];
+        for module in modules {
+            let module_id = module.self_id();
+            let old_module = client
+                .rooch
+                .get_states(AccessPath::module(pkg_address, module_id.name().to_owned()))
+                .await?
+                .pop()
+                .flatten()
+                .map(|state_view| State::from(state_view).cast::<MoveModule>())
+                .transpose()?;
+            if let Some(old_module) = old_module {
+                upgraded = true;
+                let old_module = CompiledModule::deserialize(&old_module.byte_codes)
+                    .map_err(|e| RoochError::ModuleUpgradeError(e.to_string()))?;
+                incompatibilities.extend(
+                    check_module_upgrade(&old_module, module, policy)
+                        .into_iter()
+                        .map(|incompatibility| {
+                            format!("{}: {}", module_id.short_str_lossless(), incompatibility)
+                        }),
+                );
+            }
+        }
+
+        if !upgraded {
+            return Err(RoochError::ModuleUpgradeError(format!(
+                "no module of the package is published at {}",
+                pkg_address.to_hex_literal()
+            )));
+        }
+        if !incompatibilities.is_empty() {
+            return Err(RoochError::ModuleUpgradeError(format!(
+                "the modules are incompatible with the {} upgrade policy:\n{}",
+                policy,
+                incompatibilities.join("\n")
+            )));
+        }
+        Ok(())
+    }
+
     pub fn order_modules(modules: Modules) -> anyhow::Result<Vec<CompiledModule>> {
         //TODO ensure all module at same address.
         //include all module and dependency modules
@@ -85,6 +165,10 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
 
         // Compile the package and run the verifier
         let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
+        let manifest = parse_move_manifest_from_file(&package_path)
+            .and_then(parse_source_manifest)
+            .map_err(|e| RoochError::MoveCompilationError(e.to_string()))?;
+        let (major, minor, patch) = manifest.package.version;
         run_verifier(package_path, config_cloned, &mut package)?;
 
         // Get the modules from the package
@@ -105,7 +189,7 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
         let sorted_modules = sort_by_dependency_order(modules.iter_modules())?;
         let resolver = context.get_client().await?;
         // Serialize and collect module binaries into bundles
-        for module in sorted_modules {
+        for module in &sorted_modules {
             let module_address = module.self_id().address().to_owned();
             if module_address != pkg_address {
                 return Err(RoochError::MoveCompilationError(format!(
@@ -114,7 +198,7 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
                     pkg_address.clone(),
                 )));
             };
-            verifier::verify_module(&module, &resolver)?;
+            verifier::verify_module(module, &resolver)?;
             let mut binary: Vec<u8> = vec![];
             module.serialize(&mut binary)?;
             bundles.push(binary);
@@ -128,23 +212,44 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
             ));
         }
 
+        if self.upgrade {
+            Self::check_upgrade(&resolver, pkg_address, &sorted_modules).await?;
+        }
+
         // Create a sender RoochAddress
         let sender: RoochAddress = pkg_address.into();
         eprintln!("Publish modules to address: {:?}", sender);
 
         // Prepare and execute the transaction based on the action type
         let tx_result = if !self.by_move_action {
-            let args = bcs::to_bytes(&bundles).unwrap();
+            // The addresses of the packages the root modules immediately depend on, the same as the ones
+            // recorded for the packages published by `MoveAction::ModuleBundle`
+            let dependencies = package_dependencies(pkg_address, &sorted_modules);
+            let args = vec![
+                bcs::to_bytes(package.compiled_package_info.package_name.as_str()).unwrap(),
+                bcs::to_bytes(&format!("{}.{}.{}", major, minor, patch)).unwrap(),
+                bcs::to_bytes(
+                    &package
+                        .compiled_package_info
+                        .source_digest
+                        .as_ref()
+                        .map(|digest| digest.to_string())
+                        .unwrap_or_default(),
+                )
+                .unwrap(),
+                bcs::to_bytes(&dependencies).unwrap(),
+                bcs::to_bytes(&bundles).unwrap(),
+            ];
             let action = MoveAction::new_function_call(
                 FunctionId::new(
                     ModuleId::new(
                         MOVEOS_STD_ADDRESS,
-                        Identifier::new("context".to_owned()).unwrap(),
+                        Identifier::new("package_registry".to_owned()).unwrap(),
                     ),
-                    Identifier::new("publish_modules_entry".to_owned()).unwrap(),
+                    Identifier::new("publish_package_entry".to_owned()).unwrap(),
                 ),
                 vec![],
-                vec![args],
+                args,
             );
 
             // Handle transaction with or without authenticator
@@ -6,18 +6,24 @@ use anyhow::Result;
 use async_trait::async_trait;
 use clap::Parser;
 use move_command_line_common::types::ParsedStructType;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
+use moveos_types::gas_profile::GasProfile;
 use moveos_types::transaction::MoveAction;
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunOptions, DryRunTransactionResponseView, ExecuteTransactionResponseView,
+};
 use rooch_types::{
     address::RoochAddress,
     error::{RoochError, RoochResult},
+    framework::auth_validator::BuiltinAuthValidator,
     function_arg::ParsedFunctionId,
-    transaction::rooch::RoochTransaction,
+    transaction::{authenticator::Authenticator, rooch::RoochTransaction},
 };
 use rpassword::prompt_password;
+use std::path::PathBuf;
 
 /// Run a Move function
 #[derive(Parser)]
@@ -59,30 +65,101 @@ pub struct RunFunction {
 
     #[clap(flatten)]
     tx_options: TransactionOptions,
+
+    /// Dry run the function without signing, the state is not changed.
+    /// Output the status, gas used, events and state change set of the execution.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Profile the gas usage of the function via dry run, it implies `--dry-run`.
+    /// The gas profile is written to the file in the folded stack format,
+    /// which can be rendered to a flamegraph by `inferno-flamegraph` or `flamegraph.pl`.
+    #[clap(long, value_name = "FILE")]
+    pub profile_gas: Option<PathBuf>,
 }
 
-#[async_trait]
-impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
-    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
-        let context = self.context.build()?;
-        let address_mapping = context.address_mapping();
-        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
-        let function_id = self.function.into_function_id(&address_mapping)?;
-        let args = self
-            .args
+impl RunFunction {
+    pub(crate) fn build_action(
+        function: ParsedFunctionId,
+        type_args: Vec<ParsedStructType>,
+        args: Vec<FunctionArg>,
+        address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+    ) -> Result<MoveAction> {
+        let function_id = function.into_function_id(address_mapping)?;
+        let args = args
             .into_iter()
-            .map(|arg| arg.into_bytes(&address_mapping))
+            .map(|arg| arg.into_bytes(address_mapping))
             .collect::<Result<Vec<_>>>()?;
-        let type_args = self
-            .type_args
+        let type_args = type_args
             .into_iter()
             .map(|tag| {
                 Ok(TypeTag::Struct(Box::new(
-                    tag.into_struct_tag(&address_mapping)?,
+                    tag.into_struct_tag(address_mapping)?,
                 )))
             })
             .collect::<Result<Vec<_>>>()?;
-        let action = MoveAction::new_function_call(function_id, type_args, args);
+        Ok(MoveAction::new_function_call(function_id, type_args, args))
+    }
+
+    async fn dry_run(self, profile_gas: bool) -> RoochResult<DryRunTransactionResponseView> {
+        let context = self.context.build()?;
+        let address_mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let action =
+            Self::build_action(self.function, self.type_args, self.args, &address_mapping)?;
+        let tx_data = context.build_tx_data(sender, action).await?;
+        // The signature is not verified in the dry run, so the payload of the authenticator can be empty.
+        let authenticator = match self.tx_options.authenticator {
+            Some(authenticator) => authenticator.into(),
+            None => Authenticator::new(BuiltinAuthValidator::Rooch.flag().into(), vec![]),
+        };
+        let tx = RoochTransaction::new(tx_data, authenticator);
+        let client = context.get_client().await?;
+        client
+            .rooch
+            .dry_run_tx(tx, Some(DryRunOptions::new().profile_gas(profile_gas)))
+            .await
+            .map_err(|e| RoochError::TransactionError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
+    async fn execute_serialized(self) -> RoochResult<String> {
+        if let Some(profile_file) = self.profile_gas.clone() {
+            let result = self.dry_run(true).await?;
+            let gas_profile: GasProfile = result
+                .gas_profile
+                .clone()
+                .ok_or_else(|| {
+                    RoochError::TransactionError("The gas profile is not returned".to_owned())
+                })?
+                .into();
+            let mut folded_stacks = gas_profile.to_folded_stacks().join("\n");
+            folded_stacks.push('\n');
+            std::fs::write(&profile_file, folded_stacks).map_err(|e| {
+                RoochError::IOError(format!(
+                    "Write gas profile to {} failed: {}",
+                    profile_file.display(),
+                    e
+                ))
+            })?;
+            return Ok(serde_json::to_string_pretty(&result).unwrap());
+        }
+        if self.dry_run {
+            let result = self.dry_run(false).await?;
+            return Ok(serde_json::to_string_pretty(&result).unwrap());
+        }
+        let result = self.execute().await?;
+        Ok(serde_json::to_string_pretty(&result).unwrap())
+    }
+
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context.build()?;
+        let address_mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let action =
+            Self::build_action(self.function, self.type_args, self.args, &address_mapping)?;
         match (self.tx_options.authenticator, self.tx_options.session_key) {
             (Some(authenticator), _) => {
                 let tx_data = context.build_tx_data(sender, action).await?;
@@ -0,0 +1,269 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::file_format::CompiledModule;
+use move_cli::Move;
+use move_command_line_common::address::ParsedAddress;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::{
+    access_path::{AccessPath, Path},
+    moveos_std::move_module::MoveModule,
+    state::State,
+};
+use moveos_verifier::build::run_verifier;
+use rooch_rpc_client::Client;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::stderr;
+
+use super::publish::Publish;
+use crate::cli_types::{CommandAction, WalletContextOptions};
+
+/// Rebuild the local package and compare the bytecode with the modules published at `address`.
+/// The modules published at `address` but missing in the local package are reported as mismatches too.
+#[derive(Parser)]
+pub struct Verify {
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, parse(try_from_str = crate::utils::parse_map), default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    /// The address the package is published at
+    #[clap(long, parse(try_from_str = ParsedAddress::parse))]
+    pub address: ParsedAddress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleVerifyStatus {
+    /// The local bytecode is the same as the on chain bytecode
+    Matched,
+    /// The local bytecode is different from the on chain bytecode
+    Mismatched,
+    /// The module is not published at the address
+    NotPublished,
+    /// The module is published at the address but not in the local package
+    NotInPackage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleVerifyResult {
+    pub module: String,
+    pub status: ModuleVerifyStatus,
+    pub local_size: usize,
+    pub onchain_size: Option<usize>,
+    /// The offset of the first different byte
+    pub first_diff_offset: Option<usize>,
+    /// How many bytes are different, the extra bytes of the longer bytecode are counted too
+    pub diff_bytes: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyResult {
+    pub address: String,
+    pub verified: bool,
+    pub modules: Vec<ModuleVerifyResult>,
+}
+
+impl ModuleVerifyResult {
+    fn new(module: String, local: &[u8], onchain: Option<&[u8]>) -> Self {
+        match onchain {
+            Some(onchain) => {
+                let first_diff_offset = local
+                    .iter()
+                    .zip(onchain.iter())
+                    .position(|(l, o)| l != o)
+                    .or_else(|| {
+                        (local.len() != onchain.len()).then_some(local.len().min(onchain.len()))
+                    });
+                let diff_bytes = local
+                    .iter()
+                    .zip(onchain.iter())
+                    .filter(|(l, o)| l != o)
+                    .count()
+                    + local.len().abs_diff(onchain.len());
+                let status = if first_diff_offset.is_none() {
+                    ModuleVerifyStatus::Matched
+                } else {
+                    ModuleVerifyStatus::Mismatched
+                };
+                Self {
+                    module,
+                    status,
+                    local_size: local.len(),
+                    onchain_size: Some(onchain.len()),
+                    first_diff_offset,
+                    diff_bytes,
+                }
+            }
+            None => Self {
+                module,
+                status: ModuleVerifyStatus::NotPublished,
+                local_size: local.len(),
+                onchain_size: None,
+                first_diff_offset: None,
+                diff_bytes: local.len(),
+            },
+        }
+    }
+
+    fn not_in_package(module: String, onchain: &[u8]) -> Self {
+        Self {
+            module,
+            status: ModuleVerifyStatus::NotInPackage,
+            local_size: 0,
+            onchain_size: Some(onchain.len()),
+            first_diff_offset: None,
+            diff_bytes: onchain.len(),
+        }
+    }
+}
+
+impl Verify {
+    /// List the bytecode of all the modules published at the address, keyed by the module name.
+    async fn list_onchain_modules(
+        client: &Client,
+        address: AccountAddress,
+    ) -> RoochResult<BTreeMap<String, Vec<u8>>> {
+        let access_path = AccessPath(Path::Module {
+            account: address,
+            module_names: None,
+        });
+        let mut modules = BTreeMap::new();
+        let mut cursor = None;
+        loop {
+            let page = client
+                .rooch
+                .list_states(access_path.clone().into(), cursor, None)
+                .await?;
+            for state_view in page.data {
+                let module = State::from(state_view).cast::<MoveModule>()?;
+                let module_name = CompiledModule::deserialize(&module.byte_codes)
+                    .map_err(|e| RoochError::UnexpectedError(e.to_string()))?
+                    .self_id()
+                    .name()
+                    .to_string();
+                modules.insert(module_name, module.byte_codes);
+            }
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        Ok(modules)
+    }
+}
+
+#[async_trait]
+impl CommandAction<VerifyResult> for Verify {
+    async fn execute(self) -> RoochResult<VerifyResult> {
+        let context = self.context_options.build()?;
+        let address = context.resolve_address(self.address)?;
+
+        let package_path = self
+            .move_args
+            .package_path
+            .unwrap_or_else(|| std::env::current_dir().unwrap());
+        let mut config = self.move_args.build_config.clone();
+        config.additional_named_addresses =
+            context.parse_and_resolve_addresses(self.named_addresses)?;
+        let config_cloned = config.clone();
+
+        // Build the package in the same way as `rooch move build`, the runtime metadata is part of the bytecode
+        let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
+        run_verifier(package_path, config_cloned, &mut package)?;
+
+        let sorted_modules = Publish::order_modules(package.root_modules_map())?;
+        let client = context.get_client().await?;
+        let mut onchain_modules = Self::list_onchain_modules(&client, address).await?;
+        let mut modules = vec![];
+        for module in &sorted_modules {
+            let module_id = module.self_id();
+            if *module_id.address() != address {
+                return Err(RoochError::CommandArgumentError(format!(
+                    "module {} is not at the address {}, please check the --named-addresses",
+                    module_id.short_str_lossless(),
+                    address.to_hex_literal()
+                )));
+            }
+            let mut local = vec![];
+            module.serialize(&mut local)?;
+            let onchain = onchain_modules.remove(module_id.name().as_str());
+            modules.push(ModuleVerifyResult::new(
+                module_id.name().to_string(),
+                &local,
+                onchain.as_deref(),
+            ));
+        }
+        // The modules published at the address but missing in the local package
+        modules.extend(
+            onchain_modules
+                .into_iter()
+                .map(|(module, onchain)| ModuleVerifyResult::not_in_package(module, &onchain)),
+        );
+
+        Ok(VerifyResult {
+            address: address.to_hex_literal(),
+            verified: modules
+                .iter()
+                .all(|module| module.status == ModuleVerifyStatus::Matched),
+            modules,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModuleVerifyResult, ModuleVerifyStatus};
+
+    #[test]
+    fn test_module_verify_result() {
+        let result = ModuleVerifyResult::new("m".to_owned(), &[1, 2, 3], Some(&[1, 2, 3][..]));
+        assert_eq!(result.status, ModuleVerifyStatus::Matched);
+        assert_eq!(result.onchain_size, Some(3));
+        assert_eq!(result.first_diff_offset, None);
+        assert_eq!(result.diff_bytes, 0);
+
+        let result =
+            ModuleVerifyResult::new("m".to_owned(), &[1, 2, 3, 4], Some(&[1, 0, 3, 0][..]));
+        assert_eq!(result.status, ModuleVerifyStatus::Mismatched);
+        assert_eq!(result.first_diff_offset, Some(1));
+        assert_eq!(result.diff_bytes, 2);
+
+        // The extra bytes of the longer bytecode are counted, the diff starts at the end of the shorter one
+        let result =
+            ModuleVerifyResult::new("m".to_owned(), &[1, 2, 3, 4, 5], Some(&[1, 2, 3][..]));
+        assert_eq!(result.status, ModuleVerifyStatus::Mismatched);
+        assert_eq!(result.local_size, 5);
+        assert_eq!(result.onchain_size, Some(3));
+        assert_eq!(result.first_diff_offset, Some(3));
+        assert_eq!(result.diff_bytes, 2);
+        let result = ModuleVerifyResult::new("m".to_owned(), &[1, 0], Some(&[1, 2, 3][..]));
+        assert_eq!(result.first_diff_offset, Some(1));
+        assert_eq!(result.diff_bytes, 2);
+
+        let result = ModuleVerifyResult::new("m".to_owned(), &[1, 2, 3], None);
+        assert_eq!(result.status, ModuleVerifyStatus::NotPublished);
+        assert_eq!(result.onchain_size, None);
+        assert_eq!(result.first_diff_offset, None);
+        assert_eq!(result.diff_bytes, 3);
+
+        let result = ModuleVerifyResult::not_in_package("m".to_owned(), &[1, 2]);
+        assert_eq!(result.status, ModuleVerifyStatus::NotInPackage);
+        assert_eq!(result.local_size, 0);
+        assert_eq!(result.onchain_size, Some(2));
+        assert_eq!(result.diff_bytes, 2);
+    }
+}
@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use clap::{Parser, Subcommand};
 use commands::{
     build::Build, integration_test::IntegrationTest, new::New, publish::Publish,
-    run_function::RunFunction, run_view_function::RunViewFunction, unit_test::Test,
+    run_function::RunFunction, run_view_function::RunViewFunction, unit_test::Test, verify::Verify,
 };
 use move_cli::{
     base::{
@@ -46,6 +46,7 @@ pub enum MoveCommand {
     View(RunViewFunction),
     IntegrationTest(IntegrationTest),
     Explain(Explain),
+    Verify(Verify),
 }
 
 #[async_trait]
@@ -125,6 +126,7 @@ impl CommandAction<String> for MoveCli {
                 .await
                 .map(|_| "Success".to_owned())
                 .map_err(RoochError::from),
+            MoveCommand::Verify(c) => c.execute_serialized().await,
         }
     }
 }
@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, FunctionArg, WalletContextOptions};
+use crate::commands::move_cli::commands::run_function::RunFunction;
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use rooch_types::{address::RoochAddress, error::RoochResult, function_arg::ParsedFunctionId};
+
+/// Build a transaction of the multisig account, output the hex encoded transaction data.
+/// The transaction data is shared with the key holders to sign via `rooch multisig sign`.
+#[derive(Parser)]
+pub struct BuildTxCommand {
+    /// The multisig account address
+    #[clap(long, parse(try_from_str = ParsedAddress::parse))]
+    pub multisig_address: ParsedAddress,
+
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
+    #[clap(long)]
+    pub function: ParsedFunctionId,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `0x1::M::T1 0x1::M::T2 rooch_framework::empty::Empty`
+    #[clap(
+        long = "type-args",
+        takes_value(true),
+        multiple_values(true),
+        multiple_occurrences(true),
+        parse(try_from_str = ParsedStructType::parse)
+    )]
+    pub type_args: Vec<ParsedStructType>,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Example: `address:0x1 bool:true u8:0 u256:1234 'vector<u32>:a,b,c,d'`
+    #[clap(
+        long = "args",
+        takes_value(true),
+        multiple_values(true),
+        multiple_occurrences(true)
+    )]
+    pub args: Vec<FunctionArg>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for BuildTxCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let address_mapping = context.address_mapping();
+        let multisig_addr: RoochAddress = context.resolve_address(self.multisig_address)?.into();
+        let action =
+            RunFunction::build_action(self.function, self.type_args, self.args, &address_mapping)?;
+        let tx_data = context.build_tx_data(multisig_addr, action).await?;
+        Ok(format!("0x{}", hex::encode(tx_data.encode())))
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::multisig_validator::{multisig_address, MultisigPublicKey, MultisigValidatorModule},
+};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+
+/// Create a multisig account on-chain with the key set and the threshold.
+/// The multisig account address is derived from the key set and the threshold.
+#[derive(Debug, Parser)]
+pub struct CreateCommand {
+    /// The public keys of the multisig account, format: `<scheme>:<hex public key>`, scheme is ed25519 or secp256k1.
+    /// Example: `ed25519:0x1234... secp256k1:0x5678...`
+    #[clap(long, multiple_values(true), required(true))]
+    pub public_keys: Vec<MultisigPublicKey>,
+
+    /// How many signatures are required to send a transaction from the multisig account
+    #[clap(long)]
+    pub threshold: u64,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOutput {
+    pub multisig_address: String,
+    pub threshold: u64,
+    pub public_keys: Vec<String>,
+}
+
+#[async_trait]
+impl CommandAction<CreateOutput> for CreateCommand {
+    async fn execute(self) -> RoochResult<CreateOutput> {
+        let context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+
+        let multisig_addr: RoochAddress =
+            multisig_address(&self.public_keys, self.threshold).into();
+        let action = MultisigValidatorModule::create_multisig_account_action(
+            self.public_keys.clone(),
+            self.threshold,
+        );
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password =
+                prompt_password("Enter the password to sign the transaction:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        context.assert_execute_success(result)?;
+
+        Ok(CreateOutput {
+            multisig_address: multisig_addr.to_string(),
+            threshold: self.threshold,
+            public_keys: self.public_keys.iter().map(ToString::to_string).collect(),
+        })
+    }
+}
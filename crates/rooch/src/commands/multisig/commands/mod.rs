@@ -0,0 +1,7 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod build_tx;
+pub mod create;
+pub mod sign;
+pub mod submit;
@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    transaction::authenticator::MultisigSignature,
+};
+use rpassword::prompt_password;
+
+use super::submit::decode_tx_data;
+
+/// Sign the multisig transaction data built by `rooch multisig build-tx` with a key of the key set,
+/// output the hex encoded partial signature.
+#[derive(Debug, Parser)]
+pub struct SignCommand {
+    /// The hex encoded transaction data
+    #[clap(long)]
+    pub tx_data: String,
+
+    /// The address of the key in the keystore to sign the transaction
+    #[clap(long, parse(try_from_str = ParsedAddress::parse), default_value = "default")]
+    pub signer: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for SignCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let signer: RoochAddress = context.resolve_address(self.signer)?.into();
+        let tx_data = decode_tx_data(&self.tx_data)?;
+        let tx_hash = tx_data.hash();
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to sign the transaction:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+        let signature = context
+            .keystore
+            .sign_hashed(&signer, tx_hash.as_bytes(), password)
+            .map_err(|e| RoochError::SignMessageError(e.to_string()))?;
+        Ok(MultisigSignature::from(&signature).to_string())
+    }
+}
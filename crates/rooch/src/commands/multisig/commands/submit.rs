@@ -0,0 +1,47 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    error::{RoochError, RoochResult},
+    transaction::{
+        authenticator::{Authenticator, MultisigSignature},
+        rooch::{RoochTransaction, RoochTransactionData},
+    },
+};
+
+/// Combine the partial signatures into the multisig authenticator and submit the transaction.
+#[derive(Debug, Parser)]
+pub struct SubmitCommand {
+    /// The hex encoded transaction data built by `rooch multisig build-tx`
+    #[clap(long)]
+    pub tx_data: String,
+
+    /// The hex encoded partial signatures output by `rooch multisig sign`
+    #[clap(long, multiple_values(true), required(true))]
+    pub signatures: Vec<MultisigSignature>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+pub(crate) fn decode_tx_data(tx_data: &str) -> RoochResult<RoochTransactionData> {
+    let tx_data = tx_data.strip_prefix("0x").unwrap_or(tx_data);
+    let bytes = hex::decode(tx_data)
+        .map_err(|e| RoochError::CommandArgumentError(format!("Invalid tx data: {}", e)))?;
+    bcs::from_bytes(&bytes)
+        .map_err(|e| RoochError::CommandArgumentError(format!("Invalid tx data: {}", e)))
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for SubmitCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let tx_data = decode_tx_data(&self.tx_data)?;
+        let tx = RoochTransaction::new(tx_data, Authenticator::multisig(self.signatures));
+        context.execute(tx).await
+    }
+}
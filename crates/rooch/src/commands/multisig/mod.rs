@@ -0,0 +1,40 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::{
+    build_tx::BuildTxCommand, create::CreateCommand, sign::SignCommand, submit::SubmitCommand,
+};
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Multisig account Commands
+#[derive(Parser)]
+pub struct Multisig {
+    #[clap(subcommand)]
+    cmd: MultisigCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Multisig {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            MultisigCommand::Create(create) => create.execute_serialized().await,
+            MultisigCommand::BuildTx(build_tx) => build_tx.execute_serialized().await,
+            MultisigCommand::Sign(sign) => sign.execute_serialized().await,
+            MultisigCommand::Submit(submit) => submit.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "multisig")]
+pub enum MultisigCommand {
+    Create(CreateCommand),
+    BuildTx(BuildTxCommand),
+    Sign(SignCommand),
+    Submit(SubmitCommand),
+}
@@ -4,7 +4,7 @@
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
-use rooch_config::{RoochOpt, ServerOpt};
+use rooch_config::{RoochOpt, ServerMode, ServerOpt};
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_rpc_server::Service;
@@ -33,58 +33,62 @@ impl CommandAction<()> for StartCommand {
     async fn execute(mut self) -> RoochResult<()> {
         let mut context = self.context_options.build()?;
 
-        //Parse key pair from Rooch opt
-        let sequencer_account = if self.opt.sequencer_account.is_none() {
-            let active_address_opt = context.client_config.active_address;
-            if active_address_opt.is_none() {
-                return Err(RoochError::ActiveAddressDoesNotExistError);
-            }
-            active_address_opt.unwrap()
-        } else {
-            RoochAddress::from_str(self.opt.sequencer_account.clone().unwrap().as_str()).map_err(
-                |e| {
-                    RoochError::CommandArgumentError(format!(
-                        "Invalid sequencer account address: {}",
-                        e
-                    ))
-                },
-            )?
-        };
-        let proposer_account = if self.opt.proposer_account.is_none() {
-            let active_address_opt = context.client_config.active_address;
-            if active_address_opt.is_none() {
-                return Err(RoochError::ActiveAddressDoesNotExistError);
-            }
-            active_address_opt.unwrap()
-        } else {
-            RoochAddress::from_str(self.opt.proposer_account.clone().unwrap().as_str()).map_err(
-                |e| {
-                    RoochError::CommandArgumentError(format!(
-                        "Invalid proposer account address: {}",
-                        e
-                    ))
-                },
-            )?
-        };
-        let relayer_account = if self.opt.relayer_account.is_none() {
-            let active_address_opt = context.client_config.active_address;
-            if active_address_opt.is_none() {
-                return Err(RoochError::ActiveAddressDoesNotExistError);
-            }
-            active_address_opt.unwrap()
-        } else {
-            RoochAddress::from_str(self.opt.relayer_account.clone().unwrap().as_str()).map_err(
-                |e| {
-                    RoochError::CommandArgumentError(format!(
-                        "Invalid relayer account address: {}",
-                        e
-                    ))
-                },
-            )?
-        };
+        let mut server_opt = ServerOpt::new();
+        // The follower replays the transactions sequenced by the leader and does not sign anything,
+        // so the sequencer, proposer and relayer key pairs are not loaded in follower mode.
+        if self.opt.mode() != ServerMode::Follower {
+            //Parse key pair from Rooch opt
+            let sequencer_account = if self.opt.sequencer_account.is_none() {
+                let active_address_opt = context.client_config.active_address;
+                if active_address_opt.is_none() {
+                    return Err(RoochError::ActiveAddressDoesNotExistError);
+                }
+                active_address_opt.unwrap()
+            } else {
+                RoochAddress::from_str(self.opt.sequencer_account.clone().unwrap().as_str())
+                    .map_err(|e| {
+                        RoochError::CommandArgumentError(format!(
+                            "Invalid sequencer account address: {}",
+                            e
+                        ))
+                    })?
+            };
+            let proposer_account = if self.opt.proposer_account.is_none() {
+                let active_address_opt = context.client_config.active_address;
+                if active_address_opt.is_none() {
+                    return Err(RoochError::ActiveAddressDoesNotExistError);
+                }
+                active_address_opt.unwrap()
+            } else {
+                RoochAddress::from_str(self.opt.proposer_account.clone().unwrap().as_str())
+                    .map_err(|e| {
+                        RoochError::CommandArgumentError(format!(
+                            "Invalid proposer account address: {}",
+                            e
+                        ))
+                    })?
+            };
+            let relayer_account = if self.opt.relayer_account.is_none() {
+                let active_address_opt = context.client_config.active_address;
+                if active_address_opt.is_none() {
+                    return Err(RoochError::ActiveAddressDoesNotExistError);
+                }
+                active_address_opt.unwrap()
+            } else {
+                RoochAddress::from_str(self.opt.relayer_account.clone().unwrap().as_str()).map_err(
+                    |e| {
+                        RoochError::CommandArgumentError(format!(
+                            "Invalid relayer account address: {}",
+                            e
+                        ))
+                    },
+                )?
+            };
 
-        let (sequencer_keypair, proposer_keypair, relayer_keypair) =
-            if context.keystore.get_if_password_is_empty() {
+            let (sequencer_keypair, proposer_keypair, relayer_keypair) = if context
+                .keystore
+                .get_if_password_is_empty()
+            {
                 let sequencer_keypair = context
                     .keystore
                     .get_key_pair_with_password(&sequencer_account, None)
@@ -129,11 +133,11 @@ impl CommandAction<()> for StartCommand {
 
                 (sequencer_keypair, proposer_keypair, relayer_keypair)
             };
-        // Construct sequencer, proposer and relayer keypair
-        let mut server_opt = ServerOpt::new();
-        server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
-        server_opt.proposer_keypair = Some(proposer_keypair.copy());
-        server_opt.relayer_keypair = Some(relayer_keypair.copy());
+            // Construct sequencer, proposer and relayer keypair
+            server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
+            server_opt.proposer_keypair = Some(proposer_keypair.copy());
+            server_opt.relayer_keypair = Some(relayer_keypair.copy());
+        }
 
         let active_env = context.client_config.get_active_env()?;
         server_opt.active_env = Some(active_env.clone().alias);
@@ -0,0 +1,141 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_store::config_store::ConfigStore;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_config::store_config::StoreConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::replay::{replay_txs, TxReplayResult};
+use rooch_rpc_server::init_storage;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Replay the stored transactions in the range of tx order, and compare the replay result with the stored execution info.
+/// The server should be stopped before replaying, because the stores are opened directly.
+#[derive(Debug, Parser)]
+pub struct ReplayCommand {
+    /// The tx order to start replaying from, inclusive.
+    #[clap(long)]
+    pub from_order: u64,
+
+    /// The tx order to stop replaying at, inclusive.
+    #[clap(long)]
+    pub to_order: u64,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayReport {
+    pub from_order: u64,
+    pub to_order: u64,
+    pub mismatched: u64,
+    pub results: Vec<TxReplayResult>,
+}
+
+impl ReplayCommand {
+    fn get_tx_hash(rooch_store: &RoochStore, tx_order: u64) -> anyhow::Result<H256> {
+        rooch_store
+            .get_tx_sequence_info_mapping_by_order(vec![tx_order])?
+            .pop()
+            .flatten()
+            .map(|mapping| mapping.tx_hash)
+            .ok_or_else(|| anyhow::anyhow!("The tx with order {} does not exist", tx_order))
+    }
+
+    fn get_execution_info(
+        moveos_store: &MoveOSStore,
+        tx_hash: H256,
+    ) -> anyhow::Result<TransactionExecutionInfo> {
+        moveos_store
+            .get_tx_execution_info(tx_hash)?
+            .ok_or_else(|| anyhow::anyhow!("The execution info of tx {:?} does not exist", tx_hash))
+    }
+
+    /// The state root before executing the tx at `tx_order`.
+    fn get_pre_state_root(
+        moveos_store: &MoveOSStore,
+        rooch_store: &RoochStore,
+        tx_order: u64,
+    ) -> anyhow::Result<H256> {
+        if tx_order == 0 {
+            let genesis = moveos_store
+                .get_genesis()?
+                .ok_or_else(|| anyhow::anyhow!("The genesis info does not exist"))?;
+            Ok(genesis.state_root_hash)
+        } else {
+            let tx_hash = Self::get_tx_hash(rooch_store, tx_order - 1)?;
+            Ok(Self::get_execution_info(moveos_store, tx_hash)?.state_root)
+        }
+    }
+
+    fn replay(self) -> anyhow::Result<ReplayReport> {
+        anyhow::ensure!(
+            self.from_order <= self.to_order,
+            "The from order {} should not be greater than the to order {}",
+            self.from_order,
+            self.to_order
+        );
+        let genesis_ctx = self.opt.chain_id.clone().unwrap_or_default().genesis_ctx();
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut store_config = StoreConfig::default();
+        store_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let (moveos_store, rooch_store) = init_storage(&store_config)?;
+
+        // Replay into a scratch checkpoint of the MoveOSDB, so the state nodes written by the replay
+        // do not go to the live DB. The checkpoint is removed with the temp dir after replaying.
+        // The temp dir is created beside the MoveOSDB, so the checkpoint hard links the DB files instead of copying them.
+        let scratch_dir = moveos_config::temp_dir_in(
+            store_config
+                .get_moveos_store_dir()
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(std::env::temp_dir),
+        );
+        let scratch_moveosdb = moveos_store
+            .moveosdb
+            .checkpoint(&scratch_dir.path().join("moveos_store"))?;
+        let new_executor = |state_root: H256| {
+            let store = MoveOSStore::new_with_root(scratch_moveosdb.clone(), Some(state_root))?;
+            ExecutorActor::new(genesis_ctx.clone(), store, rooch_store.clone())
+        };
+        let pre_state_root =
+            Self::get_pre_state_root(&moveos_store, &rooch_store, self.from_order)?;
+        let txs = (self.from_order..=self.to_order).map(|tx_order| {
+            let tx_hash = Self::get_tx_hash(&rooch_store, tx_order)?;
+            let tx = rooch_store
+                .get_transaction_by_hash(tx_hash)?
+                .ok_or_else(|| anyhow::anyhow!("The tx {:?} does not exist", tx_hash))?;
+            let expected = Self::get_execution_info(&moveos_store, tx_hash)?;
+            Ok((tx_order, tx, expected))
+        });
+        let results = replay_txs(pre_state_root, txs, new_executor)?;
+        let mismatched = results.iter().filter(|result| !result.matched).count() as u64;
+
+        Ok(ReplayReport {
+            from_order: self.from_order,
+            to_order: self.to_order,
+            mismatched,
+            results,
+        })
+    }
+}
+
+#[async_trait]
+impl CommandAction<ReplayReport> for ReplayCommand {
+    async fn execute(self) -> RoochResult<ReplayReport> {
+        self.replay().map_err(RoochError::from)
+    }
+}
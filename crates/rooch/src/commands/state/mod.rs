@@ -0,0 +1,70 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use commands::replay::ReplayCommand;
+use moveos_types::access_path::AccessPath;
+use rooch_rpc_api::jsonrpc_types::StateView;
+use rooch_types::error::{RoochError, RoochResult};
+
+pub mod commands;
+
+/// Get states by accessPath, or replay the stored transactions
+#[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct StateCommand {
+    #[clap(subcommand)]
+    cmd: Option<StateSubCommand>,
+
+    //TODO access path should support named address?
+    /// /object/$object_id1[,$object_id2]
+    /// /resource/$account_address/$resource_type1[,$resource_type2]
+    /// /module/$account_address/$module_name1[,$module_name2]
+    /// /table/$table_handle/$key1[,$key2]
+    #[clap(long = "access-path", short = 'a', required = true)]
+    pub access_path: Option<AccessPath>,
+
+    /// RPC client options.
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+impl StateCommand {
+    async fn get_states(
+        access_path: AccessPath,
+        context_options: WalletContextOptions,
+    ) -> RoochResult<Vec<Option<StateView>>> {
+        let client = context_options.build()?.get_client().await?;
+
+        let resp = client
+            .rooch
+            .get_decoded_states(access_path)
+            .await
+            .map_err(RoochError::from)?;
+        Ok(resp)
+    }
+}
+
+#[async_trait]
+impl CommandAction<String> for StateCommand {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            Some(StateSubCommand::Replay(replay)) => replay.execute_serialized().await,
+            None => {
+                let access_path = self.access_path.ok_or_else(|| {
+                    RoochError::CommandArgumentError("The access path is required".to_owned())
+                })?;
+                let states = Self::get_states(access_path, self.context_options).await?;
+                Ok(serde_json::to_string_pretty(&states).expect("Failed to serialize states"))
+            }
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "state")]
+pub enum StateSubCommand {
+    Replay(ReplayCommand),
+}
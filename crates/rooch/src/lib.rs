@@ -4,9 +4,9 @@
 use crate::commands::event::EventCommand;
 use cli_types::CommandAction;
 use commands::{
-    abi::ABI, account::Account, env::Env, init::Init, move_cli::MoveCli, object::ObjectCommand,
-    resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
-    state::StateCommand, transaction::Transaction,
+    abi::ABI, account::Account, env::Env, init::Init, move_cli::MoveCli, multisig::Multisig,
+    object::ObjectCommand, resource::ResourceCommand, rpc::Rpc, server::Server,
+    session_key::SessionKey, state::StateCommand, transaction::Transaction,
 };
 use rooch_types::error::RoochResult;
 
@@ -35,6 +35,7 @@ pub enum Command {
     ABI(ABI),
     Env(Env),
     SessionKey(SessionKey),
+    Multisig(Multisig),
     Rpc(Rpc),
 }
 
@@ -44,7 +45,7 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Move(move_cli) => move_cli.execute().await,
         Command::Server(server) => server.execute().await,
         Command::Init(init) => init.execute_serialized().await,
-        Command::State(state) => state.execute_serialized().await,
+        Command::State(state) => state.execute().await,
         Command::Object(object) => object.execute_serialized().await,
         Command::Resource(resource) => resource.execute_serialized().await,
         Command::Transaction(transation) => transation.execute().await,
@@ -52,6 +53,7 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::ABI(abi) => abi.execute().await,
         Command::Env(env) => env.execute().await,
         Command::SessionKey(session_key) => session_key.execute().await,
+        Command::Multisig(multisig) => multisig.execute().await,
         Command::Rpc(rpc) => rpc.execute().await,
     }
 }
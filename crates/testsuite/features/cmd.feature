@@ -142,6 +142,11 @@ Feature: Rooch CLI integration tests
       Given a server for kv_store
       Then cmd: "move publish -p ../../examples/kv_store  --named-addresses rooch_examples=default"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "rpc request --method rooch_getPackage --params '["{{$.address_mapping.default}}"]'"
+      Then assert: "'{{$.rpc[-1].name}}' == 'kv_store'"
+      Then assert: "'{{$.rpc[-1].version}}' == '0.0.1'"
+      Then cmd: "move verify -p ../../examples/kv_store  --named-addresses rooch_examples=default --address default"
+      Then assert: "{{$.move[-1].verified}} == true"
       Then cmd: "move run --function default::kv_store::add_value --args string:key1 string:value1"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
       Then cmd: "move view --function default::kv_store::get_value --args string:key1"
@@ -265,6 +270,11 @@ Feature: Rooch CLI integration tests
       #TODO change the argument `0x3` address to a user account
       Then cmd: "move run --function rooch_framework::transfer::transfer_coin --type-args default::fixed_supply_coin::FSC --args address:0x3  --args 1u256 "
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+
+      # the abort code is explained by the error constant of the aborting module
+      Then cmd: "move run --function rooch_framework::transfer::transfer_coin --type-args default::fixed_supply_coin::FSC --args address:0x3  --args 1000000000000000000000000000000u256 "
+      Then assert: "{{$.move[-1].execution_info.status.type}} == moveabort"
+      Then assert: "{{$.move[-1].execution_info.status.explain.reason_name}} == ErrorInSufficientBalance"
       Then stop the server
 
   @serial
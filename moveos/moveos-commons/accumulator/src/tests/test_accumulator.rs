@@ -271,6 +271,31 @@ fn test_flush() {
     }
 }
 
+#[test]
+fn test_pop_unsaved_nodes() {
+    let leaves = create_leaves(1000..1020);
+    let mock_store = Arc::new(MockAccumulatorStore::new());
+    let accumulator = MerkleAccumulator::new(
+        *ACCUMULATOR_PLACEHOLDER_HASH,
+        vec![],
+        0,
+        0,
+        mock_store.clone(),
+    );
+    let root_hash = accumulator.append(&leaves).unwrap();
+    let nodes = accumulator.pop_unsaved_nodes().unwrap();
+    assert!(accumulator.pop_unsaved_nodes().is_none());
+    //the popped nodes are not saved to storage
+    for node_hash in leaves.iter() {
+        assert!(mock_store.get_node(*node_hash).unwrap().is_none());
+    }
+    mock_store.save_nodes(nodes).unwrap();
+    //the accumulator reloaded from the info can read the saved nodes
+    let reloaded = MerkleAccumulator::new_with_info(accumulator.get_info(), mock_store);
+    assert_eq!(reloaded.root_hash(), root_hash);
+    proof_verify(&reloaded, root_hash, &leaves, 0);
+}
+
 #[test]
 fn test_get_leaves_batch() {
     let mock_store = MockAccumulatorStore::new();
@@ -224,6 +224,14 @@ impl AccumulatorTree {
         Ok(())
     }
 
+    /// Take the nodes which are not flushed to storage, the caller is responsible for saving them.
+    pub fn pop_unsaved_nodes(&mut self) -> Option<Vec<AccumulatorNode>> {
+        if self.update_nodes.is_empty() {
+            return None;
+        }
+        Some(self.update_nodes.drain().map(|(_, node)| node).collect())
+    }
+
     fn scan_frozen_subtree_roots(&mut self) -> Result<Vec<H256>> {
         FrozenSubTreeIterator::new(self.num_leaves)
             .map(|p| {
@@ -13,7 +13,7 @@ module moveos_std::account_storage {
     use moveos_std::table::{Self, Table};
     use moveos_std::object::{Self, ObjectID, Object};
     use moveos_std::tx_context;
-    use moveos_std::move_module::{Self, MoveModule};
+    use moveos_std::move_module::{Self, MoveModule, UpgradePolicy};
 
     friend moveos_std::context;
 
@@ -120,12 +120,32 @@ module moveos_std::account_storage {
         abort(0x0) // unreachable.
     }
 
+    /// Get the upgrade policy of the modules in the account's storage
+    public fun upgrade_policy(self: &AccountStorage): u8 {
+        if (exists_resource_at_account_storage<UpgradePolicy>(self)) {
+            move_module::upgrade_policy_value(borrow_resource_from_account_storage<UpgradePolicy>(self))
+        } else {
+            move_module::upgrade_policy_compatible()
+        }
+    }
+
+    /// Set the upgrade policy of the modules in the account's storage, the policy can only be changed to a stricter one
+    public(friend) fun set_upgrade_policy(self: &mut AccountStorage, policy: u8) {
+        if (exists_resource_at_account_storage<UpgradePolicy>(self)) {
+            let upgrade_policy = borrow_mut_resource_from_account_storage<UpgradePolicy>(self);
+            move_module::set_upgrade_policy_value(upgrade_policy, policy);
+        } else {
+            add_resource_to_account_storage(self, move_module::new_upgrade_policy(policy));
+        }
+    }
+
     /// Publish modules to the account's storage
     /// Return true if the modules are upgraded
     public(friend) fun publish_modules(self: &mut AccountStorage, account_address: address, modules: vector<MoveModule>) : bool {        
         let i = 0;
         let len = vector::length(&modules);
         let (module_names, module_names_with_init_fn) = move_module::sort_and_verify_modules(&modules, account_address);
+        let policy = upgrade_policy(self);
         
         let upgrade_flag = false;
         while (i < len) {
@@ -135,7 +155,7 @@ module moveos_std::account_storage {
             // The module already exists, which means we are upgrading the module
             if (table::contains(&self.modules, name)) {
                 let old_m = table::remove(&mut self.modules, name);
-                move_module::check_comatibility(&m, &old_m);
+                move_module::check_upgrade_policy(&m, &old_m, policy);
                 upgrade_flag = true;
             } else {
                 // request init function invoking
@@ -182,6 +202,26 @@ module moveos_std::account_storage {
         Self::drop_account_storage(account_storage);
     }
 
+    #[test(sender=@0x42)]
+    fun test_upgrade_policy(sender: address){
+        let account_storage = create_account_storage(sender);
+        assert!(upgrade_policy(&account_storage) == move_module::upgrade_policy_compatible(), 1000);
+        set_upgrade_policy(&mut account_storage, move_module::upgrade_policy_additive());
+        assert!(upgrade_policy(&account_storage) == move_module::upgrade_policy_additive(), 1001);
+        set_upgrade_policy(&mut account_storage, move_module::upgrade_policy_immutable());
+        assert!(upgrade_policy(&account_storage) == move_module::upgrade_policy_immutable(), 1002);
+        Self::drop_account_storage(account_storage);
+    }
+
+    #[test(sender=@0x42)]
+    #[expected_failure(abort_code = 0x50007, location = moveos_std::move_module)]
+    fun test_relax_upgrade_policy_failure(sender: address){
+        let account_storage = create_account_storage(sender);
+        set_upgrade_policy(&mut account_storage, move_module::upgrade_policy_immutable());
+        set_upgrade_policy(&mut account_storage, move_module::upgrade_policy_compatible());
+        Self::drop_account_storage(account_storage);
+    }
+
     #[test(sender=@0x42)]
     fun test_move_to_account_storage(sender: address){
         let account_storage = create_account_storage(sender);
@@ -184,6 +184,29 @@ module moveos_std::context {
         tx_context::set_module_upgrade_flag(&mut self.tx_context, upgrade_flag);
     }
 
+    /// Get the upgrade policy of the account's modules
+    public fun upgrade_policy(self: &Context, account: address): u8 {
+        if (exist_account_storage(self, account)) {
+            let account_storage = borrow_account_storage(self, account);
+            account_storage::upgrade_policy(account_storage)
+        }else{
+            move_module::upgrade_policy_compatible()
+        }
+    }
+
+    /// Set the upgrade policy of the account's modules, the policy can only be changed to a stricter one.
+    public fun set_upgrade_policy(self: &mut Context, account: &signer, policy: u8) {
+        let account_address = signer::address_of(account);
+        ensure_account_storage(self, account_address);
+        let account_storage = borrow_account_storage_mut(self, account_address);
+        account_storage::set_upgrade_policy(account_storage, policy);
+    }
+
+    /// Entry function to set the upgrade policy of the sender's modules
+    public entry fun set_upgrade_policy_entry(ctx: &mut Context, account: &signer, policy: u8) {
+        Self::set_upgrade_policy(ctx, account, policy);
+    }
+
     /// Check if the account has a module with the given name
     public fun exists_module(self: &Context, account: address, name: String): bool {
         if (exist_account_storage(self, account)) {
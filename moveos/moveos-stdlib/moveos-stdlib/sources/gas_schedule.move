@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module keeps the gas schedule of MoveOS in the global object storage.
+/// MoveOS loads the gas schedule at every transaction, so the gas costs can be tuned without a binary release.
+/// The entries which are not in the schedule fallback to the builtin gas costs.
+module moveos_std::gas_schedule {
+
+    use std::error;
+    use std::signer;
+    use std::string::String;
+    use std::vector;
+    use moveos_std::object;
+    use moveos_std::context::{Self, Context};
+
+    /// The gas schedule is already initialized
+    const ErrorGasScheduleAlreadyInitialized: u64 = 1;
+    /// The new schedule version must be greater than the current schedule version
+    const ErrorInvalidScheduleVersion: u64 = 2;
+    /// The max gas amount must be greater than zero
+    const ErrorInvalidMaxGasAmount: u64 = 3;
+    /// The length of the keys and the values are not equal
+    const ErrorGasEntriesLengthMismatch: u64 = 4;
+
+    struct GasEntry has store, copy, drop {
+        key: String,
+        val: u64,
+    }
+
+    /// A named object holding the gas schedule, the object owner can update it.
+    struct GasSchedule has key {
+        schedule_version: u64,
+        /// The max gas amount of a transaction
+        max_gas_amount: u64,
+        entries: vector<GasEntry>,
+    }
+
+    /// Initialize the gas schedule and transfer it to the `owner`, the gas schedule can only be initialized once.
+    /// The framework should call this function in the genesis.
+    public fun initialize(ctx: &mut Context, owner: &signer, max_gas_amount: u64, entries: vector<GasEntry>) {
+        let object_id = object::named_object_id<GasSchedule>();
+        assert!(!context::exists_object<GasSchedule>(ctx, object_id), error::already_exists(ErrorGasScheduleAlreadyInitialized));
+        assert!(max_gas_amount > 0, error::invalid_argument(ErrorInvalidMaxGasAmount));
+        let gas_schedule = GasSchedule {
+            schedule_version: 0,
+            max_gas_amount,
+            entries,
+        };
+        let obj = context::new_named_object(ctx, gas_schedule);
+        object::transfer_extend(obj, signer::address_of(owner));
+    }
+
+    /// Replace the gas schedule, only the owner of the gas schedule object can update it.
+    public fun update(ctx: &mut Context, owner: &signer, schedule_version: u64, max_gas_amount: u64, entries: vector<GasEntry>) {
+        let object_id = object::named_object_id<GasSchedule>();
+        let obj = context::borrow_mut_object<GasSchedule>(ctx, owner, object_id);
+        let gas_schedule = object::borrow_mut(obj);
+        assert!(schedule_version > gas_schedule.schedule_version, error::invalid_argument(ErrorInvalidScheduleVersion));
+        assert!(max_gas_amount > 0, error::invalid_argument(ErrorInvalidMaxGasAmount));
+        gas_schedule.schedule_version = schedule_version;
+        gas_schedule.max_gas_amount = max_gas_amount;
+        gas_schedule.entries = entries;
+    }
+
+    public fun new_gas_entry(key: String, val: u64): GasEntry {
+        GasEntry { key, val }
+    }
+
+    /// Zip the `keys` and `vals` to gas entries
+    public fun new_gas_entries(keys: vector<String>, vals: vector<u64>): vector<GasEntry> {
+        let len = vector::length(&keys);
+        assert!(len == vector::length(&vals), error::invalid_argument(ErrorGasEntriesLengthMismatch));
+        let entries = vector::empty<GasEntry>();
+        let i = 0;
+        while (i < len) {
+            let key = *vector::borrow(&keys, i);
+            let val = *vector::borrow(&vals, i);
+            vector::push_back(&mut entries, GasEntry { key, val });
+            i = i + 1;
+        };
+        entries
+    }
+
+    public fun exists_gas_schedule(ctx: &Context): bool {
+        let object_id = object::named_object_id<GasSchedule>();
+        context::exists_object<GasSchedule>(ctx, object_id)
+    }
+
+    public fun gas_schedule(ctx: &Context): &GasSchedule {
+        let object_id = object::named_object_id<GasSchedule>();
+        let obj = context::borrow_object<GasSchedule>(ctx, object_id);
+        object::borrow(obj)
+    }
+
+    public fun schedule_version(self: &GasSchedule): u64 {
+        self.schedule_version
+    }
+
+    public fun max_gas_amount(self: &GasSchedule): u64 {
+        self.max_gas_amount
+    }
+
+    public fun entries(self: &GasSchedule): &vector<GasEntry> {
+        &self.entries
+    }
+
+    public fun entry_key(self: &GasEntry): &String {
+        &self.key
+    }
+
+    public fun entry_val(self: &GasEntry): u64 {
+        self.val
+    }
+}
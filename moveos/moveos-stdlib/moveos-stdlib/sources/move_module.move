@@ -17,11 +17,64 @@ module moveos_std::move_module {
     const ErrorModuleIncompatible: u64 = 3;
     /// Vector length not match
     const ErrorLengthNotMatch: u64 = 4;
+    /// The modules are immutable, they can not be upgraded.
+    const ErrorModuleImmutable: u64 = 5;
+    /// The upgrade policy is invalid
+    const ErrorInvalidUpgradePolicy: u64 = 6;
+    /// The upgrade policy can only be changed to a stricter one
+    const ErrorUpgradePolicyCanNotBeRelaxed: u64 = 7;
+
+    /// The modules can be upgraded if the struct layouts and the public function signatures are compatible.
+    const UPGRADE_POLICY_COMPATIBLE: u8 = 0;
+    /// The modules can only add new structs and functions, the existing structs and function signatures can not be changed.
+    const UPGRADE_POLICY_ADDITIVE: u8 = 1;
+    /// The modules can not be upgraded.
+    const UPGRADE_POLICY_IMMUTABLE: u8 = 2;
     
     struct MoveModule has store, drop {
         byte_codes: vector<u8>,
     }
 
+    /// The upgrade policy of the modules under an account, all the modules of an account are treated as a package.
+    /// It is stored in the account storage, the `compatible` policy is used if it does not exist.
+    struct UpgradePolicy has key {
+        policy: u8,
+    }
+
+    public fun upgrade_policy_compatible(): u8 {
+        UPGRADE_POLICY_COMPATIBLE
+    }
+
+    public fun upgrade_policy_additive(): u8 {
+        UPGRADE_POLICY_ADDITIVE
+    }
+
+    public fun upgrade_policy_immutable(): u8 {
+        UPGRADE_POLICY_IMMUTABLE
+    }
+
+    public fun is_valid_upgrade_policy(policy: u8): bool {
+        policy <= UPGRADE_POLICY_IMMUTABLE
+    }
+
+    public(friend) fun new_upgrade_policy(policy: u8): UpgradePolicy {
+        assert!(is_valid_upgrade_policy(policy), error::invalid_argument(ErrorInvalidUpgradePolicy));
+        UpgradePolicy {
+            policy,
+        }
+    }
+
+    public fun upgrade_policy_value(self: &UpgradePolicy): u8 {
+        self.policy
+    }
+
+    /// Change the upgrade policy, the policy can only be changed to a stricter one.
+    public(friend) fun set_upgrade_policy_value(self: &mut UpgradePolicy, policy: u8) {
+        assert!(is_valid_upgrade_policy(policy), error::invalid_argument(ErrorInvalidUpgradePolicy));
+        assert!(policy >= self.policy, error::permission_denied(ErrorUpgradePolicyCanNotBeRelaxed));
+        self.policy = policy;
+    }
+
     public fun new(byte_codes: vector<u8>) : MoveModule {
         //TODO quick check the Magic number to test if it is Move bytecode
         MoveModule {
@@ -81,7 +134,15 @@ module moveos_std::move_module {
     /// Check module compatibility when upgrading
     /// Abort if the new module is not compatible with the old module.
     public fun check_comatibility(new_module: &MoveModule, old_module: &MoveModule) {
-        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes);
+        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes, UPGRADE_POLICY_COMPATIBLE);
+    }
+
+    /// Check the new module against the old module with the upgrade policy
+    /// Abort if the modules are immutable or the new module does not satisfy the policy.
+    public fun check_upgrade_policy(new_module: &MoveModule, old_module: &MoveModule, policy: u8) {
+        assert!(is_valid_upgrade_policy(policy), error::invalid_argument(ErrorInvalidUpgradePolicy));
+        assert!(policy != UPGRADE_POLICY_IMMUTABLE, error::permission_denied(ErrorModuleImmutable));
+        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes, policy);
     }
 
     /// Binding given module's address to the new address
@@ -221,7 +282,8 @@ module moveos_std::move_module {
     /// account_address: address of all the modules
     native public(friend) fun request_init_functions(module_names: vector<String>, account_address: address);
 
-    native fun check_compatibililty_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>);
+    /// Abort if the new module does not satisfy the `compatible` or the `additive` upgrade policy.
+    native fun check_compatibililty_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>, policy: u8);
 
     /// Native function to replace addresses identifier in module binary where the length of
     /// `old_addresses` must equal to that of `new_addresses`.  
@@ -0,0 +1,227 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module records the metadata of the published Move packages in a named object.
+/// The registry is keyed by the package address, so the source and the dependencies of a package
+/// can be resolved by the address of the modules.
+module moveos_std::package_registry {
+
+    use std::error;
+    use std::signer;
+    use std::string::{Self, String};
+    use std::vector;
+    use moveos_std::address;
+    use moveos_std::hex;
+    use moveos_std::object;
+    use moveos_std::table::{Self, Table};
+    use moveos_std::move_module;
+    use moveos_std::context::{Self, Context};
+
+    /// The package is not found in the registry
+    const ErrorPackageNotFound: u64 = 1;
+    /// The package name must not be empty
+    const ErrorEmptyPackageName: u64 = 2;
+
+    /// The metadata of a published package.
+    struct PackageInfo has store, copy, drop {
+        name: String,
+        version: String,
+        /// The digest of the package source files, it is empty if the digest is unknown
+        source_digest: String,
+        /// The addresses of the packages this package depends on
+        dependencies: vector<address>,
+        /// The names of the modules published in the last publication
+        modules: vector<String>,
+        /// The number of the upgrades since the package is first published
+        upgrade_number: u64,
+    }
+
+    /// A named object holding the metadata of all the published packages.
+    struct PackageRegistry has key {
+        packages: Table<address, PackageInfo>,
+    }
+
+    /// Entry function to publish the modules and record the package metadata in the registry.
+    /// The order of modules must be sorted by dependency order.
+    public entry fun publish_package_entry(
+        ctx: &mut Context,
+        account: &signer,
+        name: String,
+        version: String,
+        source_digest: String,
+        dependencies: vector<address>,
+        modules: vector<vector<u8>>,
+    ) {
+        let module_vec = move_module::new_batch(modules);
+        let module_names = vector::empty<String>();
+        let i = 0;
+        let n_modules = vector::length(&module_vec);
+        while (i < n_modules) {
+            vector::push_back(&mut module_names, move_module::module_name(vector::borrow(&module_vec, i)));
+            i = i + 1;
+        };
+        context::publish_modules(ctx, account, module_vec);
+        record_package(ctx, signer::address_of(account), name, version, source_digest, dependencies, module_names);
+    }
+
+    /// Record the modules published by a `MoveAction::ModuleBundle` transaction, called by the VM after the publication.
+    /// The bundle does not carry the package metadata, so the name, version and source digest of a recorded package are kept,
+    /// and a package published for the first time is named by its address.
+    fun record_module_bundle(
+        ctx: &mut Context,
+        package_address: address,
+        dependencies: vector<address>,
+        modules: vector<String>,
+    ) {
+        if (exists_package(ctx, package_address)) {
+            let info = *package_info(ctx, package_address);
+            record_package(ctx, package_address, info.name, info.version, info.source_digest, dependencies, modules);
+        } else {
+            let name = string::utf8(hex::encode(address::to_bytes(package_address)));
+            record_package(ctx, package_address, name, string::utf8(b""), string::utf8(b""), dependencies, modules);
+        }
+    }
+
+    fun record_package(
+        ctx: &mut Context,
+        package_address: address,
+        name: String,
+        version: String,
+        source_digest: String,
+        dependencies: vector<address>,
+        modules: vector<String>,
+    ) {
+        assert!(!string::is_empty(&name), error::invalid_argument(ErrorEmptyPackageName));
+        ensure_registry(ctx);
+        let object_id = object::named_object_id<PackageRegistry>();
+        let obj = context::borrow_mut_object_extend<PackageRegistry>(ctx, object_id);
+        let registry = object::borrow_mut(obj);
+        if (table::contains(&registry.packages, package_address)) {
+            let info = table::borrow_mut(&mut registry.packages, package_address);
+            info.name = name;
+            info.version = version;
+            info.source_digest = source_digest;
+            info.dependencies = dependencies;
+            info.modules = modules;
+            info.upgrade_number = info.upgrade_number + 1;
+        } else {
+            table::add(&mut registry.packages, package_address, PackageInfo {
+                name,
+                version,
+                source_digest,
+                dependencies,
+                modules,
+                upgrade_number: 0,
+            });
+        }
+    }
+
+    fun ensure_registry(ctx: &mut Context) {
+        let object_id = object::named_object_id<PackageRegistry>();
+        if (!context::exists_object<PackageRegistry>(ctx, object_id)) {
+            let packages = context::new_table<address, PackageInfo>(ctx);
+            let obj = context::new_named_object(ctx, PackageRegistry { packages });
+            object::transfer_extend(obj, @moveos_std);
+        }
+    }
+
+    public fun exists_package(ctx: &Context, package_address: address): bool {
+        let object_id = object::named_object_id<PackageRegistry>();
+        if (!context::exists_object<PackageRegistry>(ctx, object_id)) {
+            return false
+        };
+        let registry = object::borrow(context::borrow_object<PackageRegistry>(ctx, object_id));
+        table::contains(&registry.packages, package_address)
+    }
+
+    /// Get the package metadata by the package address, abort if the package is not recorded.
+    public fun package_info(ctx: &Context, package_address: address): &PackageInfo {
+        assert!(exists_package(ctx, package_address), error::not_found(ErrorPackageNotFound));
+        let object_id = object::named_object_id<PackageRegistry>();
+        let registry = object::borrow(context::borrow_object<PackageRegistry>(ctx, object_id));
+        table::borrow(&registry.packages, package_address)
+    }
+
+    public fun name(self: &PackageInfo): &String {
+        &self.name
+    }
+
+    public fun version(self: &PackageInfo): &String {
+        &self.version
+    }
+
+    public fun source_digest(self: &PackageInfo): &String {
+        &self.source_digest
+    }
+
+    public fun dependencies(self: &PackageInfo): &vector<address> {
+        &self.dependencies
+    }
+
+    public fun modules(self: &PackageInfo): &vector<String> {
+        &self.modules
+    }
+
+    public fun upgrade_number(self: &PackageInfo): u64 {
+        self.upgrade_number
+    }
+
+    #[test]
+    fun test_record_package() {
+        let ctx = context::new_test_context(@0x42);
+        assert!(!exists_package(&ctx, @0x42), 1000);
+        record_package(&mut ctx, @0x42, string::utf8(b"demo"), string::utf8(b"0.0.1"),
+            string::utf8(b"digest"), vector[@0x1, @0x2], vector[string::utf8(b"m")]);
+        assert!(exists_package(&ctx, @0x42), 1001);
+        {
+            let info = package_info(&ctx, @0x42);
+            assert!(*name(info) == string::utf8(b"demo"), 1002);
+            assert!(*dependencies(info) == vector[@0x1, @0x2], 1003);
+            assert!(upgrade_number(info) == 0, 1004);
+        };
+        record_package(&mut ctx, @0x42, string::utf8(b"demo"), string::utf8(b"0.0.2"),
+            string::utf8(b"digest2"), vector[@0x1], vector[string::utf8(b"m")]);
+        {
+            let info = package_info(&ctx, @0x42);
+            assert!(*version(info) == string::utf8(b"0.0.2"), 1005);
+            assert!(*dependencies(info) == vector[@0x1], 1006);
+            assert!(upgrade_number(info) == 1, 1007);
+        };
+        assert!(!exists_package(&ctx, @0x43), 1008);
+        context::drop_test_context(ctx);
+    }
+
+    #[test]
+    fun test_record_module_bundle() {
+        let ctx = context::new_test_context(@0x42);
+        record_module_bundle(&mut ctx, @0x42, vector[@0x1], vector[string::utf8(b"m")]);
+        {
+            let info = package_info(&ctx, @0x42);
+            assert!(*name(info) == string::utf8(hex::encode(address::to_bytes(@0x42))), 1000);
+            assert!(*version(info) == string::utf8(b""), 1001);
+            assert!(upgrade_number(info) == 0, 1002);
+        };
+        // The metadata recorded by `publish_package_entry` is kept by the following bundle publication
+        record_package(&mut ctx, @0x42, string::utf8(b"demo"), string::utf8(b"0.0.1"),
+            string::utf8(b"digest"), vector[@0x1], vector[string::utf8(b"m")]);
+        record_module_bundle(&mut ctx, @0x42, vector[@0x1, @0x2], vector[string::utf8(b"m"), string::utf8(b"n")]);
+        {
+            let info = package_info(&ctx, @0x42);
+            assert!(*name(info) == string::utf8(b"demo"), 1003);
+            assert!(*version(info) == string::utf8(b"0.0.1"), 1004);
+            assert!(*source_digest(info) == string::utf8(b"digest"), 1005);
+            assert!(*dependencies(info) == vector[@0x1, @0x2], 1006);
+            assert!(*modules(info) == vector[string::utf8(b"m"), string::utf8(b"n")], 1007);
+            assert!(upgrade_number(info) == 2, 1008);
+        };
+        context::drop_test_context(ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60001, location = Self)]
+    fun test_package_not_found() {
+        let ctx = context::new_test_context(@0x42);
+        package_info(&ctx, @0x42);
+        context::drop_test_context(ctx);
+    }
+}
@@ -17,6 +17,15 @@ where
     store: S,
 }
 
+impl<S> AccumulatorStore<S>
+where
+    S: CodecKVStore<H256, AccumulatorNode>,
+{
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+}
+
 impl<S> AccumulatorTreeStore for AccumulatorStore<S>
 where
     S: CodecKVStore<H256, AccumulatorNode>,
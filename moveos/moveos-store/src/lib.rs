@@ -4,19 +4,23 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{Error, Result};
+use anyhow::{format_err, Error, Result};
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::genesis_info::GenesisInfo;
 use once_cell::sync::Lazy;
-use raw_store::{ColumnFamilyName, StoreInstance};
+use raw_store::{ColumnFamilyName, SchemaStore, StoreInstance};
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::config_store::{ConfigDBStore, ConfigStore};
 use crate::event_store::{EventDBStore, EventStore};
 use crate::state_store::statedb::StateDBStore;
 use crate::state_store::NodeDBStore;
-use crate::transaction_store::{TransactionDBStore, TransactionStore};
+use crate::transaction_store::{
+    TransactionDBStore, TransactionStore, TransactionTraceDBStore, TransactionTraceStore,
+};
 use move_core_types::language_storage::StructTag;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
@@ -40,6 +44,7 @@ pub mod transaction_store;
 // pub const DEFAULT_PREFIX_NAME: ColumnFamilyName = "default";
 pub const STATE_NODE_PREFIX_NAME: ColumnFamilyName = "state_node";
 pub const TRANSACTION_PREFIX_NAME: ColumnFamilyName = "transaction";
+pub const TRANSACTION_TRACE_PREFIX_NAME: ColumnFamilyName = "transaction_trace";
 pub const EVENT_PREFIX_NAME: ColumnFamilyName = "event";
 pub const EVENT_HANDLE_PREFIX_NAME: ColumnFamilyName = "event_handle";
 pub const CONFIG_STARTUP_INFO_PREFIX_NAME: ColumnFamilyName = "config_startup_info";
@@ -51,6 +56,7 @@ static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
     vec![
         STATE_NODE_PREFIX_NAME,
         TRANSACTION_PREFIX_NAME,
+        TRANSACTION_TRACE_PREFIX_NAME,
         EVENT_PREFIX_NAME,
         EVENT_HANDLE_PREFIX_NAME,
         CONFIG_STARTUP_INFO_PREFIX_NAME,
@@ -72,6 +78,7 @@ pub struct MoveOSDB {
     pub node_store: NodeDBStore,
     pub event_store: EventDBStore,
     pub transaction_store: TransactionDBStore,
+    pub transaction_trace_store: TransactionTraceDBStore,
     pub config_store: ConfigDBStore,
 }
 
@@ -93,11 +100,30 @@ impl MoveOSDB {
         Self::new(Self::mock_store_instance())
     }
 
+    /// Create a checkpoint of the DB in the directory `checkpoint_path` and open it as a new `MoveOSDB`.
+    /// The writes to the new DB do not change this DB, so it can be used as a scratch copy of this DB.
+    pub fn checkpoint(&self, checkpoint_path: &Path) -> Result<Self> {
+        let db = self
+            .node_store
+            .get_store()
+            .store()
+            .db()
+            .ok_or_else(|| format_err!("The MoveOSDB is not backed by RocksDB"))?;
+        db.create_checkpoint(checkpoint_path)?;
+        Self::new(StoreInstance::new_db_instance(RocksDB::new(
+            checkpoint_path,
+            StoreMeta::get_column_family_names().to_vec(),
+            RocksdbConfig::default(),
+            None,
+        )?))
+    }
+
     pub fn new(instance: StoreInstance) -> Result<Self> {
         let store = Self {
             node_store: NodeDBStore::new(instance.clone()),
             event_store: EventDBStore::new(instance.clone()),
             transaction_store: TransactionDBStore::new(instance.clone()),
+            transaction_trace_store: TransactionTraceDBStore::new(instance.clone()),
             config_store: ConfigDBStore::new(instance),
         };
         Ok(store)
@@ -140,6 +166,10 @@ impl MoveOSStore {
         &self.moveosdb.transaction_store
     }
 
+    pub fn get_transaction_trace_store(&self) -> &TransactionTraceDBStore {
+        &self.moveosdb.transaction_trace_store
+    }
+
     pub fn get_state_node_store(&self) -> &NodeDBStore {
         &self.moveosdb.node_store
     }
@@ -236,6 +266,18 @@ impl TransactionStore for MoveOSStore {
     }
 }
 
+impl TransactionTraceStore for MoveOSStore {
+    fn save_tx_execution_trace(&self, tx_hash: H256, trace: ExecutionTrace) -> Result<()> {
+        self.get_transaction_trace_store()
+            .save_tx_execution_trace(tx_hash, trace)
+    }
+
+    fn get_tx_execution_trace(&self, tx_hash: H256) -> Result<Option<ExecutionTrace>> {
+        self.get_transaction_trace_store()
+            .get_tx_execution_trace(tx_hash)
+    }
+}
+
 impl ConfigStore for MoveOSStore {
     fn get_startup_info(&self) -> Result<Option<StartupInfo>> {
         self.get_config_store().get_startup_info()
@@ -1,10 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::MoveOSStore;
+use crate::{MoveOSDB, MoveOSStore, StoreMeta};
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::{AccountChangeSet, ChangeSet, Op};
+use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::random_type_tag;
@@ -12,6 +13,8 @@ use moveos_types::moveos_std::context;
 use moveos_types::moveos_std::object::{NamedTableID, ObjectID};
 use moveos_types::state::{MoveState, State, StateChangeSet, TableChange, TableTypeInfo};
 use rand::{thread_rng, Rng};
+use raw_store::rocks::RocksDB;
+use raw_store::StoreInstance;
 use smt::NodeStore;
 use std::str::FromStr;
 
@@ -197,3 +200,44 @@ fn test_state_db_dump_and_apply() -> Result<()> {
     assert_eq!(global_state_set, global_state_set2);
     Ok(())
 }
+
+#[test]
+fn test_state_db_checkpoint() -> Result<()> {
+    // Keep the temp dir of the store alive, the checkpoint links the files in it
+    let tmpdir = moveos_config::temp_dir();
+    let moveos_store = MoveOSStore::new(MoveOSDB::new(StoreInstance::new_db_instance(
+        RocksDB::new(
+            tmpdir.path(),
+            StoreMeta::get_column_family_names().to_vec(),
+            RocksdbConfig::default(),
+            None,
+        )?,
+    ))?)?;
+    let state_root = moveos_store
+        .get_state_store()
+        .apply_change_set(random_change_set(), random_state_change_set())?;
+
+    let checkpoint_dir = moveos_config::temp_dir();
+    let checkpoint_moveosdb = moveos_store
+        .moveosdb
+        .checkpoint(&checkpoint_dir.path().join("checkpoint"))?;
+    let checkpoint_store = MoveOSStore::new_with_root(checkpoint_moveosdb, Some(state_root))?;
+    assert_eq!(
+        checkpoint_store.get_state_store().dump()?,
+        moveos_store.get_state_store().dump()?
+    );
+
+    // The state nodes written to the checkpoint are not written to the original store
+    let new_state_root = checkpoint_store
+        .get_state_store()
+        .apply_change_set(random_change_set(), random_state_change_set())?;
+    assert!(checkpoint_store
+        .get_state_node_store()
+        .get(&new_state_root)?
+        .is_some());
+    assert!(moveos_store
+        .get_state_node_store()
+        .get(&new_state_root)?
+        .is_none());
+    Ok(())
+}
@@ -13,6 +13,7 @@ use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::TransactionEvent;
 use moveos_types::transaction::TransactionExecutionInfo;
+use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
 use raw_store::rocks::{RocksDB, DEFAULT_PREFIX_NAME};
 use raw_store::traits::DBStore;
 use raw_store::CodecKVStore;
@@ -148,3 +149,33 @@ fn test_iter() {
     assert_eq!(transaction_info1, transaction_info2);
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn test_write_cf_batch() {
+    let tmpdir = moveos_config::temp_dir();
+    let other_cf = "other_cf";
+    let cfs = vec![DEFAULT_PREFIX_NAME, other_cf];
+    let db = RocksDB::new(tmpdir.path(), cfs, RocksdbConfig::default(), None).unwrap();
+    let key = bcs::to_bytes(&H256::random()).unwrap();
+    let value = bcs::to_bytes(&H256::zero()).unwrap();
+    db.put(other_cf, key.clone(), value.clone()).unwrap();
+
+    let mut put_batch = WriteBatch::new();
+    put_batch.put(key.clone(), value.clone()).unwrap();
+    let mut delete_batch = WriteBatch::new();
+    delete_batch.delete(key.clone()).unwrap();
+    db.write_cf_batch(
+        vec![
+            WriteBatchCF::new(DEFAULT_PREFIX_NAME, put_batch),
+            WriteBatchCF::new(other_cf, delete_batch),
+        ],
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(
+        db.get(DEFAULT_PREFIX_NAME, key.clone()).unwrap(),
+        Some(value)
+    );
+    assert_eq!(db.get(other_cf, key).unwrap(), None);
+}
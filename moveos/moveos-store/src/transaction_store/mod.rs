@@ -2,11 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use moveos_types::execution_trace::ExecutionTrace;
 use moveos_types::h256::H256;
 use moveos_types::transaction::TransactionExecutionInfo;
 use raw_store::CodecKVStore;
 
-use crate::TRANSACTION_PREFIX_NAME;
+use crate::{TRANSACTION_PREFIX_NAME, TRANSACTION_TRACE_PREFIX_NAME};
 use raw_store::derive_store;
 
 derive_store!(
@@ -16,6 +17,13 @@ derive_store!(
     TRANSACTION_PREFIX_NAME
 );
 
+derive_store!(
+    TransactionTraceDBStore,
+    H256,
+    ExecutionTrace,
+    TRANSACTION_TRACE_PREFIX_NAME
+);
+
 pub trait TransactionStore {
     fn save_tx_execution_info(&self, tx_execution_info: TransactionExecutionInfo) -> Result<()>;
     fn get_tx_execution_info(&self, tx_hash: H256) -> Result<Option<TransactionExecutionInfo>>;
@@ -41,3 +49,19 @@ impl TransactionStore for TransactionDBStore {
         self.multiple_get(tx_hashes)
     }
 }
+
+/// The execution traces of the failed transactions, keyed by the transaction hash.
+pub trait TransactionTraceStore {
+    fn save_tx_execution_trace(&self, tx_hash: H256, trace: ExecutionTrace) -> Result<()>;
+    fn get_tx_execution_trace(&self, tx_hash: H256) -> Result<Option<ExecutionTrace>>;
+}
+
+impl TransactionTraceStore for TransactionTraceDBStore {
+    fn save_tx_execution_trace(&self, tx_hash: H256, trace: ExecutionTrace) -> Result<()> {
+        self.kv_put(tx_hash, trace)
+    }
+
+    fn get_tx_execution_trace(&self, tx_hash: H256) -> Result<Option<ExecutionTrace>> {
+        self.kv_get(tx_hash)
+    }
+}
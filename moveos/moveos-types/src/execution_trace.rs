@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// A frame of the call stack captured by the execution tracer.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TraceFrame {
+    /// The function name, eg: `0x3::coin::transfer`
+    pub function: String,
+    /// The rendered arguments of the call.
+    /// The arguments of the top level functions are the BCS bytes in hex, because they are not decoded yet.
+    pub args: Vec<String>,
+    /// The rendered values stored to the locals of the frame, in the execution order,
+    /// only the latest values are kept.
+    pub locals: Vec<String>,
+}
+
+impl TraceFrame {
+    pub fn new(function: String, args: Vec<String>) -> Self {
+        Self {
+            function,
+            args,
+            locals: vec![],
+        }
+    }
+}
+
+/// The call stack leading to the failure of a transaction, the outermost frame is the first.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExecutionTrace {
+    pub frames: Vec<TraceFrame>,
+}
+
+impl ExecutionTrace {
+    pub fn new(frames: Vec<TraceFrame>) -> Self {
+        Self { frames }
+    }
+}
@@ -1,11 +1,40 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-//TODO this config should be an on-chain config
+/// The default gas config of the client side.
+/// The upper bound of the transaction max gas amount is in the on-chain `moveos_std::gas_schedule::GasSchedule`.
 pub struct GasConfig {
     pub max_gas_amount: u64,
 }
 
 impl GasConfig {
     pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 100000000u64;
+    /// The percentage of the dry run gas used added to the estimated gas,
+    /// the state may be changed by other transactions between the dry run and the execution,
+    /// so the transaction may use more gas than the dry run.
+    pub const ESTIMATE_GAS_MARGIN_PERCENT: u64 = 20;
+
+    /// Estimate the max gas amount of a transaction from the gas used of its dry run,
+    /// the estimation does not exceed the max gas amount of the dry run.
+    pub fn estimate_max_gas_amount(gas_used: u64, max_gas_amount: u64) -> u64 {
+        let margin = gas_used.saturating_mul(Self::ESTIMATE_GAS_MARGIN_PERCENT) / 100;
+        gas_used.saturating_add(margin).min(max_gas_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GasConfig;
+
+    #[test]
+    fn test_estimate_max_gas_amount() {
+        assert_eq!(GasConfig::estimate_max_gas_amount(0, 1000), 0);
+        assert_eq!(GasConfig::estimate_max_gas_amount(100, 1000), 120);
+        // The estimation is capped by the max gas amount of the dry run
+        assert_eq!(GasConfig::estimate_max_gas_amount(900, 1000), 1000);
+        assert_eq!(
+            GasConfig::estimate_max_gas_amount(u64::MAX, GasConfig::DEFAULT_MAX_GAS_AMOUNT),
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT
+        );
+    }
 }
@@ -0,0 +1,117 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A frame of the gas profile call tree, it is a Move function call or a top level execution step.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct GasProfileFrame {
+    /// The frame name, eg: `0x3::coin::transfer`
+    pub name: String,
+    /// The gas charged in this frame, exclude the children frames, include the native calls
+    pub self_gas: u64,
+    /// The number of bytecode instructions executed in this frame
+    pub instructions: u64,
+    /// The number of native function calls in this frame
+    pub native_calls: u64,
+    /// The gas charged by the native functions in this frame
+    pub native_gas: u64,
+    /// The number of global storage reads, `borrow_global` and `exists`
+    pub storage_reads: u64,
+    /// The number of global storage writes, `move_to` and `move_from`
+    pub storage_writes: u64,
+    pub children: Vec<GasProfileFrame>,
+}
+
+impl GasProfileFrame {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    /// The gas charged in this frame and all the children frames
+    pub fn total_gas(&self) -> u64 {
+        self.children.iter().fold(self.self_gas, |acc, child| {
+            acc.saturating_add(child.total_gas())
+        })
+    }
+
+    fn fold_stacks(&self, prefix: Option<&str>, lines: &mut Vec<String>) {
+        let stack = match prefix {
+            Some(prefix) => format!("{};{}", prefix, self.name),
+            None => self.name.clone(),
+        };
+        if self.self_gas > 0 {
+            lines.push(format!("{} {}", stack, self.self_gas));
+        }
+        for child in &self.children {
+            child.fold_stacks(Some(&stack), lines);
+        }
+    }
+}
+
+/// The gas profile of a transaction, it attributes the gas used to the Move function call tree.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct GasProfile {
+    pub root: GasProfileFrame,
+}
+
+impl GasProfile {
+    pub fn new(root: GasProfileFrame) -> Self {
+        Self { root }
+    }
+
+    pub fn total_gas(&self) -> u64 {
+        self.root.total_gas()
+    }
+
+    /// Render the profile to the folded stack format, one line per frame: `root;caller;callee <self gas>`.
+    /// The output can be rendered by flamegraph tools, such as `inferno-flamegraph` or `flamegraph.pl`.
+    /// The frames without self gas are skipped.
+    pub fn to_folded_stacks(&self) -> Vec<String> {
+        let mut lines = vec![];
+        self.root.fold_stacks(None, &mut lines);
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(name: &str, self_gas: u64, children: Vec<GasProfileFrame>) -> GasProfileFrame {
+        GasProfileFrame {
+            name: name.to_owned(),
+            self_gas,
+            children,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        let profile = GasProfile::new(frame(
+            "transaction",
+            0,
+            vec![frame(
+                "0x3::coin::transfer",
+                10,
+                vec![
+                    frame("0x3::coin::withdraw", 5, vec![]),
+                    frame("0x3::coin::deposit", 0, vec![]),
+                ],
+            )],
+        ));
+        assert_eq!(profile.total_gas(), 15);
+        assert_eq!(
+            profile.to_folded_stacks(),
+            vec![
+                "transaction;0x3::coin::transfer 10".to_owned(),
+                "transaction;0x3::coin::transfer;0x3::coin::withdraw 5".to_owned(),
+            ]
+        );
+    }
+}
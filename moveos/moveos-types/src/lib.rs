@@ -3,8 +3,10 @@
 
 pub mod access_path;
 pub mod addresses;
+pub mod execution_trace;
 pub mod function_return_value;
 pub mod gas_config;
+pub mod gas_profile;
 pub mod genesis_info;
 pub mod h256;
 pub mod module_binding;
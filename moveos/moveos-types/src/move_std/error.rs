@@ -1,5 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
+use move_core_types::errmap::ErrorDescription;
+use serde::{Deserialize, Serialize};
+
 /// MoveStd's error module to rust mapping
 
 /// Caller specified an invalid argument (http: 400)
@@ -113,6 +116,61 @@ pub fn explain_category(category: u64) -> Option<String> {
     }
 }
 
+/// The explanation of a Move abort code, the reason is described by the error constant of the aborting module.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq)]
+pub struct MoveAbortExplain {
+    pub category_code: u64,
+    pub category_name: Option<String>,
+    pub reason_code: u64,
+    pub reason_name: Option<String>,
+    pub code_description: Option<String>,
+}
+
+impl MoveAbortExplain {
+    pub fn new(abort_code: u64, description: Option<ErrorDescription>) -> Self {
+        let (category_code, reason_code) = explain(abort_code);
+        let (reason_name, code_description) = match description {
+            Some(description) => (
+                Some(description.code_name),
+                Some(description.code_description),
+            ),
+            None => (None, None),
+        };
+        Self {
+            category_code,
+            category_name: explain_category(category_code),
+            reason_code,
+            reason_name,
+            code_description,
+        }
+    }
+}
+
+impl std::fmt::Display for MoveAbortExplain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Category Code: {}", self.category_code)?;
+        writeln!(
+            f,
+            "Category Name: {}",
+            self.category_name.clone().unwrap_or("Unknown".to_string())
+        )?;
+        writeln!(f, "Reason Code: {}", self.reason_code)?;
+        writeln!(
+            f,
+            "Reason Name: {}",
+            self.reason_name.clone().unwrap_or("Unknown".to_string())
+        )?;
+        writeln!(
+            f,
+            "Code Description: {}",
+            self.code_description
+                .clone()
+                .unwrap_or("Unknown".to_string())
+        )?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +184,24 @@ mod tests {
         assert_eq!(category, explain_category);
         assert_eq!(reason, explain_reason);
     }
+
+    #[test]
+    fn test_move_abort_explain() {
+        let description = ErrorDescription {
+            code_name: "ErrorInsufficientBalance".to_string(),
+            code_description: "Not enough balance to complete transaction".to_string(),
+        };
+        let explain = MoveAbortExplain::new(canonical(INVALID_ARGUMENT, 3), Some(description));
+        assert_eq!(explain.category_name, Some("INVALID_ARGUMENT".to_string()));
+        assert_eq!(explain.reason_code, 3);
+        assert_eq!(
+            explain.reason_name,
+            Some("ErrorInsufficientBalance".to_string())
+        );
+
+        let explain = MoveAbortExplain::new(3, None);
+        assert_eq!(explain.category_code, 0);
+        assert_eq!(explain.category_name, None);
+        assert_eq!(explain.reason_name, None);
+    }
 }
@@ -166,6 +166,11 @@ impl TransactionEvent {
             event_index,
         }
     }
+
+    /// The same as `Event::hash`, the event hash only depends on the event data.
+    pub fn hash(&self) -> H256 {
+        h256::sha3_256_of(&self.event_data)
+    }
 }
 
 /// The Event type in the event store
@@ -7,9 +7,11 @@ pub mod any;
 pub mod context;
 pub mod copyable_any;
 pub mod event;
+pub mod gas_schedule;
 pub mod module_upgrade_flag;
 pub mod move_module;
 pub mod object;
+pub mod package_registry;
 pub mod raw_table;
 pub mod simple_map;
 pub mod tx_context;
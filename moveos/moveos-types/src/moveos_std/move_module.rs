@@ -5,6 +5,9 @@ use crate::{
     addresses::MOVEOS_STD_ADDRESS,
     state::{MoveStructState, MoveStructType},
 };
+use move_binary_format::{
+    compatibility::Compatibility, file_format::Visibility, normalized, CompiledModule,
+};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
@@ -13,6 +16,15 @@ use move_core_types::{
 };
 use serde::{Deserialize, Serialize};
 
+pub const MODULE_NAME: &IdentStr = ident_str!("move_module");
+
+/// The modules can be upgraded if the struct layouts and the public function signatures are compatible.
+pub const UPGRADE_POLICY_COMPATIBLE: u8 = 0;
+/// The modules can only add new structs and functions, the existing structs and function signatures can not be changed.
+pub const UPGRADE_POLICY_ADDITIVE: u8 = 1;
+/// The modules can not be upgraded.
+pub const UPGRADE_POLICY_IMMUTABLE: u8 = 2;
+
 /// `MoveModule` is represented `moveos_std::move_module::MoveModule` in Move.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct MoveModule {
@@ -27,7 +39,7 @@ impl MoveModule {
 
 impl MoveStructType for MoveModule {
     const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
-    const MODULE_NAME: &'static IdentStr = ident_str!("move_module");
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
     const STRUCT_NAME: &'static IdentStr = ident_str!("MoveModule");
 }
 
@@ -36,3 +48,317 @@ impl MoveStructState for MoveModule {
         MoveStructLayout::new(vec![MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))])
     }
 }
+
+/// `UpgradePolicy` is represented `moveos_std::move_module::UpgradePolicy` in Move.
+/// It is stored in the account storage, the `compatible` policy is used if it does not exist.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct UpgradePolicy {
+    pub policy: u8,
+}
+
+impl UpgradePolicy {
+    pub fn new(policy: u8) -> Self {
+        Self { policy }
+    }
+
+    pub fn compatible() -> Self {
+        Self::new(UPGRADE_POLICY_COMPATIBLE)
+    }
+
+    pub fn additive() -> Self {
+        Self::new(UPGRADE_POLICY_ADDITIVE)
+    }
+
+    pub fn immutable() -> Self {
+        Self::new(UPGRADE_POLICY_IMMUTABLE)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.policy <= UPGRADE_POLICY_IMMUTABLE
+    }
+
+    pub fn is_immutable(&self) -> bool {
+        self.policy == UPGRADE_POLICY_IMMUTABLE
+    }
+
+    pub fn is_additive(&self) -> bool {
+        self.policy == UPGRADE_POLICY_ADDITIVE
+    }
+}
+
+impl Default for UpgradePolicy {
+    fn default() -> Self {
+        Self::compatible()
+    }
+}
+
+impl std::fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.policy {
+            UPGRADE_POLICY_COMPATIBLE => write!(f, "compatible"),
+            UPGRADE_POLICY_ADDITIVE => write!(f, "additive"),
+            UPGRADE_POLICY_IMMUTABLE => write!(f, "immutable"),
+            policy => write!(f, "unknown({})", policy),
+        }
+    }
+}
+
+impl std::str::FromStr for UpgradePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "compatible" => Ok(Self::compatible()),
+            "additive" => Ok(Self::additive()),
+            "immutable" => Ok(Self::immutable()),
+            _ => Err(anyhow::anyhow!(
+                "Invalid upgrade policy: {}, expected compatible, additive or immutable",
+                s
+            )),
+        }
+    }
+}
+
+impl MoveStructType for UpgradePolicy {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("UpgradePolicy");
+}
+
+impl MoveStructState for UpgradePolicy {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U8])
+    }
+}
+
+/// Check the new module against the old module with the upgrade policy.
+/// Return the descriptions of the incompatibilities, the upgrade is allowed if it is empty.
+pub fn check_module_upgrade(
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+    policy: UpgradePolicy,
+) -> Vec<String> {
+    let module_id = old_module.self_id();
+    if policy.is_immutable() {
+        return vec![format!(
+            "module {} is immutable and can not be upgraded",
+            module_id.short_str_lossless()
+        )];
+    }
+    let old_m = normalized::Module::new(old_module);
+    let new_m = normalized::Module::new(new_module);
+    let mut incompatibilities = vec![];
+
+    for (name, old_struct) in &old_m.structs {
+        match new_m.structs.get(name) {
+            None => incompatibilities.push(format!("struct {} is removed", name)),
+            Some(new_struct) => {
+                let changed = if policy.is_additive() {
+                    old_struct != new_struct
+                } else {
+                    old_struct.fields != new_struct.fields
+                        || old_struct.type_parameters != new_struct.type_parameters
+                        || !old_struct.abilities.is_subset(new_struct.abilities)
+                };
+                if changed {
+                    incompatibilities.push(format!("struct {} is changed", name));
+                }
+            }
+        }
+    }
+
+    for (name, old_func) in &old_m.exposed_functions {
+        // The private entry functions are not linked by other modules.
+        let checked = policy.is_additive() || old_func.visibility != Visibility::Private;
+        if !checked {
+            continue;
+        }
+        match new_m.exposed_functions.get(name) {
+            None => incompatibilities.push(format!(
+                "{} function {} is removed",
+                visibility_name(old_func.visibility),
+                name
+            )),
+            Some(new_func) => {
+                let changed = if policy.is_additive() {
+                    old_func != new_func
+                } else {
+                    old_func.parameters != new_func.parameters
+                        || old_func.return_ != new_func.return_
+                        || old_func.type_parameters != new_func.type_parameters
+                };
+                if changed {
+                    incompatibilities.push(format!(
+                        "signature of {} function {} is changed",
+                        visibility_name(old_func.visibility),
+                        name
+                    ));
+                }
+            }
+        }
+    }
+
+    if policy.is_additive() {
+        for friend in &old_m.friends {
+            if !new_m.friends.contains(friend) {
+                incompatibilities
+                    .push(format!("friend {} is removed", friend.short_str_lossless()));
+            }
+        }
+    }
+
+    // The Move bytecode compatibility checker is the final judge, the descriptions above are best effort.
+    if Compatibility::full_check().check(&old_m, &new_m).is_err() && incompatibilities.is_empty() {
+        incompatibilities.push(format!(
+            "module {} is not compatible with the published one",
+            module_id.short_str_lossless()
+        ));
+    }
+    incompatibilities
+}
+
+fn visibility_name(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public",
+        Visibility::Friend => "friend",
+        Visibility::Private => "entry",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::{
+        basic_test_module, empty_module, Ability, AbilitySet, SignatureToken,
+        StructFieldInformation, TypeSignature,
+    };
+
+    const ALL_POLICIES: [UpgradePolicy; 3] = [
+        UpgradePolicy {
+            policy: UPGRADE_POLICY_COMPATIBLE,
+        },
+        UpgradePolicy {
+            policy: UPGRADE_POLICY_ADDITIVE,
+        },
+        UpgradePolicy {
+            policy: UPGRADE_POLICY_IMMUTABLE,
+        },
+    ];
+
+    fn immutable_incompatibilities(module: &CompiledModule) -> Vec<String> {
+        vec![format!(
+            "module {} is immutable and can not be upgraded",
+            module.self_id().short_str_lossless()
+        )]
+    }
+
+    /// The module with the struct `Bar { x: u64 }` and the function `foo`, `foo` is public if `public_foo` is true.
+    fn test_module(public_foo: bool) -> CompiledModule {
+        let mut module = basic_test_module();
+        if public_foo {
+            module.function_defs[0].visibility = Visibility::Public;
+        }
+        module
+    }
+
+    #[test]
+    fn test_check_module_upgrade() {
+        let module = empty_module();
+        assert!(check_module_upgrade(&module, &module, UpgradePolicy::compatible()).is_empty());
+        assert!(check_module_upgrade(&module, &module, UpgradePolicy::additive()).is_empty());
+        assert_eq!(
+            check_module_upgrade(&module, &module, UpgradePolicy::immutable()).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_check_module_upgrade_struct_layout_changed() {
+        let old_module = test_module(true);
+        let mut new_module = test_module(true);
+        match &mut new_module.struct_defs[0].field_information {
+            StructFieldInformation::Declared(fields) => {
+                fields[0].signature = TypeSignature(SignatureToken::Bool)
+            }
+            StructFieldInformation::Native => unreachable!("Bar is a declared struct"),
+        }
+
+        for policy in ALL_POLICIES {
+            let expected = if policy.is_immutable() {
+                immutable_incompatibilities(&old_module)
+            } else {
+                vec!["struct Bar is changed".to_string()]
+            };
+            assert_eq!(
+                check_module_upgrade(&old_module, &new_module, policy),
+                expected,
+                "policy: {}",
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_module_upgrade_struct_ability_added() {
+        let old_module = test_module(true);
+        let mut new_module = test_module(true);
+        new_module.struct_handles[0].abilities = AbilitySet::singleton(Ability::Copy);
+
+        // Adding an ability keeps the struct compatible, but the additive policy forbids any change of the existing struct.
+        assert!(
+            check_module_upgrade(&old_module, &new_module, UpgradePolicy::compatible()).is_empty()
+        );
+        assert_eq!(
+            check_module_upgrade(&old_module, &new_module, UpgradePolicy::additive()),
+            vec!["struct Bar is changed".to_string()]
+        );
+        assert_eq!(
+            check_module_upgrade(&old_module, &new_module, UpgradePolicy::immutable()),
+            immutable_incompatibilities(&old_module)
+        );
+    }
+
+    #[test]
+    fn test_check_module_upgrade_public_function_removed() {
+        let old_module = test_module(true);
+        let mut new_module = test_module(true);
+        new_module.function_defs.clear();
+
+        for policy in ALL_POLICIES {
+            let expected = if policy.is_immutable() {
+                immutable_incompatibilities(&old_module)
+            } else {
+                vec!["public function foo is removed".to_string()]
+            };
+            assert_eq!(
+                check_module_upgrade(&old_module, &new_module, policy),
+                expected,
+                "policy: {}",
+                policy
+            );
+        }
+
+        // The private function is not linked by other modules, it can be removed by the upgrade.
+        let old_module = test_module(false);
+        let mut new_module = test_module(false);
+        new_module.function_defs.clear();
+        assert!(
+            check_module_upgrade(&old_module, &new_module, UpgradePolicy::compatible()).is_empty()
+        );
+        assert!(
+            check_module_upgrade(&old_module, &new_module, UpgradePolicy::additive()).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_policy_from_str() {
+        for policy in [
+            UpgradePolicy::compatible(),
+            UpgradePolicy::additive(),
+            UpgradePolicy::immutable(),
+        ] {
+            assert_eq!(policy.to_string().parse::<UpgradePolicy>().unwrap(), policy);
+        }
+        assert!("arbitrary".parse::<UpgradePolicy>().is_err());
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    access_path::AccessPath,
+    addresses::MOVEOS_STD_ADDRESS,
+    move_std::string::MoveString,
+    moveos_std::object::{self, ObjectID},
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use move_binary_format::CompiledModule;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("package_registry");
+/// The function called by the VM to record the modules published by `MoveAction::ModuleBundle`.
+pub const RECORD_MODULE_BUNDLE_FUNCTION_NAME: &IdentStr = ident_str!("record_module_bundle");
+
+/// The addresses of the packages which the modules of the package at `package_address` immediately depend on,
+/// they are recorded as the `dependencies` of the `PackageInfo`.
+pub fn package_dependencies<'a>(
+    package_address: AccountAddress,
+    modules: impl IntoIterator<Item = &'a CompiledModule>,
+) -> Vec<AccountAddress> {
+    modules
+        .into_iter()
+        .flat_map(|module| module.immediate_dependencies())
+        .map(|module_id| *module_id.address())
+        .filter(|address| *address != package_address)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// `PackageInfo` is represented `moveos_std::package_registry::PackageInfo` in Move.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PackageInfo {
+    pub name: MoveString,
+    pub version: MoveString,
+    pub source_digest: MoveString,
+    pub dependencies: Vec<AccountAddress>,
+    pub modules: Vec<MoveString>,
+    pub upgrade_number: u64,
+}
+
+impl MoveStructType for PackageInfo {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageInfo");
+}
+
+impl MoveStructState for PackageInfo {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            MoveString::type_layout(),
+            MoveString::type_layout(),
+            Vec::<AccountAddress>::type_layout(),
+            Vec::<MoveString>::type_layout(),
+            MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// `PackageRegistry` is represented `moveos_std::package_registry::PackageRegistry` in Move.
+/// It is a named object, the `packages` is the handle of the `Table<address, PackageInfo>`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PackageRegistry {
+    pub packages: ObjectID,
+}
+
+impl MoveStructType for PackageRegistry {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageRegistry");
+}
+
+impl MoveStructState for PackageRegistry {
+    fn struct_layout() -> MoveStructLayout {
+        // The `Table` struct only contains the handle
+        MoveStructLayout::new(vec![MoveTypeLayout::Struct(MoveStructLayout::new(vec![
+            MoveTypeLayout::Struct(ObjectID::struct_layout()),
+        ]))])
+    }
+}
+
+impl PackageRegistry {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+
+    /// The access path of the `PackageInfo` of the package at `package_address`
+    pub fn package_info_access_path(&self, package_address: AccountAddress) -> AccessPath {
+        AccessPath::table(self.packages, vec![package_address.to_vec()])
+    }
+}
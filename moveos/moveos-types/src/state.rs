@@ -509,6 +509,58 @@ impl StateChangeSet {
         let table_change = self.get_or_insert_table_change(handle);
         table_change.entries.insert(key, op);
     }
+
+    /// Decode all the state values in the change set with the annotator.
+    pub fn into_annotated_state_changeset<T: MoveResolver + ?Sized>(
+        self,
+        annotator: &MoveValueAnnotator<T>,
+    ) -> Result<AnnotatedStateChangeSet> {
+        let changes = self
+            .changes
+            .into_iter()
+            .map(|(handle, table_change)| {
+                let entries = table_change
+                    .entries
+                    .into_iter()
+                    .map(|(key, op)| {
+                        let op = match op {
+                            Op::New(state) => Op::New(state.into_annotated_state(annotator)?),
+                            Op::Modify(state) => Op::Modify(state.into_annotated_state(annotator)?),
+                            Op::Delete => Op::Delete,
+                        };
+                        Ok((key, op))
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()?;
+                Ok((
+                    handle,
+                    AnnotatedTableChange {
+                        entries,
+                        size_increment: table_change.size_increment,
+                    },
+                ))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        Ok(AnnotatedStateChangeSet {
+            new_tables: self.new_tables,
+            removed_tables: self.removed_tables,
+            changes,
+        })
+    }
+}
+
+/// The StateChangeSet with the decoded state values.
+#[derive(Default, Clone, Debug)]
+pub struct AnnotatedStateChangeSet {
+    pub new_tables: BTreeMap<ObjectID, TableTypeInfo>,
+    pub removed_tables: BTreeSet<ObjectID>,
+    pub changes: BTreeMap<ObjectID, AnnotatedTableChange>,
+}
+
+/// The TableChange with the decoded state values.
+#[derive(Default, Clone, Debug)]
+pub struct AnnotatedTableChange {
+    pub entries: BTreeMap<Vec<u8>, Op<AnnotatedState>>,
+    pub size_increment: i64,
 }
 
 /// A change of a single table.
@@ -587,3 +639,19 @@ impl SplitStateChangeSet {
         table_change_set.removed_tables.insert(table_handle);
     }
 }
+
+impl From<StateChangeSet> for SplitStateChangeSet {
+    fn from(state_change_set: StateChangeSet) -> Self {
+        let mut split_state_change_set = SplitStateChangeSet::default();
+        for (table_handle, table_info) in state_change_set.new_tables {
+            split_state_change_set.add_new_table(table_handle, table_info);
+        }
+        for (table_handle, table_change) in state_change_set.changes {
+            split_state_change_set.add_table_change(table_handle, table_change);
+        }
+        for table_handle in state_change_set.removed_tables {
+            split_state_change_set.add_remove_table(table_handle);
+        }
+        split_state_change_set
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     access_path::AccessPath,
     moveos_std::move_module::MoveModule,
     moveos_std::object::{AnnotatedObject, NamedTableID, ObjectID},
-    state::{AnnotatedState, State},
+    state::{AnnotatedState, AnnotatedStateChangeSet, State, StateChangeSet},
 };
 use anyhow::{ensure, Result};
 use move_core_types::{
@@ -228,6 +228,14 @@ pub trait AnnotatedStateReader: StateReader + MoveResolver {
         let annotator = MoveValueAnnotator::new(self);
         annotator.view_resource(tag, blob)
     }
+
+    fn annotate_state_changeset(
+        &self,
+        state_changeset: StateChangeSet,
+    ) -> Result<AnnotatedStateChangeSet> {
+        let annotator = MoveValueAnnotator::new(self);
+        state_changeset.into_annotated_state_changeset(&annotator)
+    }
 }
 
 impl<T> AnnotatedStateReader for T where T: StateReader + MoveResolver {}
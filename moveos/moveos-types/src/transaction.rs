@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    gas_config::GasConfig, h256, h256::H256, move_types::FunctionId,
-    moveos_std::event::TransactionEvent, moveos_std::tx_context::TxContext,
-    moveos_std::tx_meta::TxMeta, state::StateChangeSet,
+    execution_trace::ExecutionTrace, gas_config::GasConfig, gas_profile::GasProfile, h256,
+    h256::H256, move_types::FunctionId, moveos_std::event::TransactionEvent,
+    moveos_std::tx_context::TxContext, moveos_std::tx_meta::TxMeta, state::StateChangeSet,
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -275,6 +275,10 @@ pub struct RawTransactionOutput {
     pub state_changeset: StateChangeSet,
     pub events: Vec<TransactionEvent>,
     pub gas_used: u64,
+    /// The gas profile of the transaction, only available when the gas profiling is enabled
+    pub gas_profile: Option<GasProfile>,
+    /// The call stack leading to the failure, only available when the tracing is enabled and the execution failed
+    pub execution_trace: Option<ExecutionTrace>,
 }
 
 /// TransactionOutput is the execution result of a MoveOS transaction, and pack TransactionEvent to Event
@@ -286,6 +290,7 @@ pub struct TransactionOutput {
     pub state_changeset: StateChangeSet,
     pub events: Vec<Event>,
     pub gas_used: u64,
+    pub execution_trace: Option<ExecutionTrace>,
 }
 
 impl TransactionOutput {
@@ -309,6 +314,7 @@ impl TransactionOutput {
             state_changeset: transaction_output.state_changeset,
             events,
             gas_used: transaction_output.gas_used,
+            execution_trace: transaction_output.execution_trace,
         }
     }
 }
@@ -241,6 +241,10 @@ pub const BYTECODE_VERSION: u32 = 6;
 /// Rooch specific metadata (`rooch::` here).
 pub static ROOCH_METADATA_KEY: &[u8] = "rooch::metadata_v0".as_bytes();
 
+/// The key of the error constants of the module, they are kept apart from `ROOCH_METADATA_KEY`
+/// so the layout of the `RuntimeModuleMetadataV1` stays compatible with the published modules.
+pub static ROOCH_ERROR_MAP_KEY: &[u8] = "rooch::error_map_v0".as_bytes();
+
 pub fn build_model(
     package_path: &Path,
     additional_named_addresses: BTreeMap<String, AccountAddress>,
@@ -342,6 +346,7 @@ pub fn inject_runtime_metadata<P: AsRef<Path>>(
         match &mut unit_with_source.unit {
             CompiledUnit::Module(named_module) => {
                 if let Some(module_metadata) = metadata.get(&named_module.module.self_id()) {
+                    let mut updated = false;
                     if !module_metadata.is_empty() {
                         let serialized_metadata =
                             bcs::to_bytes(&module_metadata).expect("BCS for RuntimeModuleMetadata");
@@ -349,7 +354,19 @@ pub fn inject_runtime_metadata<P: AsRef<Path>>(
                             key: ROOCH_METADATA_KEY.to_vec(),
                             value: serialized_metadata,
                         });
+                        updated = true;
+                    }
+                    if !module_metadata.error_map.is_empty() {
+                        let serialized_error_map = bcs::to_bytes(&module_metadata.error_map)
+                            .expect("BCS for module error map");
+                        named_module.module.metadata.push(Metadata {
+                            key: ROOCH_ERROR_MAP_KEY.to_vec(),
+                            value: serialized_error_map,
+                        });
+                        updated = true;
+                    }
 
+                    if updated {
                         // Also need to update the .mv file on disk.
                         let path = package_path
                             .as_ref()
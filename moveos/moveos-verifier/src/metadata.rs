@@ -4,7 +4,7 @@
 #![allow(clippy::redundant_closure)]
 #![allow(clippy::map_clone)]
 
-use crate::build::ROOCH_METADATA_KEY;
+use crate::build::{ROOCH_ERROR_MAP_KEY, ROOCH_METADATA_KEY};
 use crate::verifier::INIT_FN_NAME_IDENTIFIER;
 use itertools::Itertools;
 use move_binary_format::binary_views::BinaryIndexedView;
@@ -13,10 +13,11 @@ use move_binary_format::file_format::{
     Ability, Bytecode, FunctionInstantiation, SignatureToken, Visibility,
 };
 use move_binary_format::CompiledModule;
+use move_core_types::errmap::ErrorDescription;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::metadata::Metadata;
 use move_core_types::vm_status::StatusCode;
-use move_model::ast::{Attribute, AttributeValue};
+use move_model::ast::{Attribute, AttributeValue, Value};
 use move_model::model::{FunctionEnv, GlobalEnv, Loc, ModuleEnv, StructEnv};
 use move_model::ty::PrimitiveType;
 use move_model::ty::Type;
@@ -24,7 +25,7 @@ use moveos_types::moveos_std::context::Context;
 use moveos_types::state::MoveStructType;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Deref;
 use thiserror::Error;
 
@@ -49,6 +50,9 @@ const GAS_FREE_CHARGE_POST: &str = "gas_charge_post";
 
 const DATA_STRUCT_ATTRIBUTE: &str = "data_struct";
 
+/// The prefix of the error constants in the `ErrorXxx` naming style.
+const ERROR_CONSTANT_PREFIX: &str = "Error";
+
 /// Enumeration of potentially known attributes
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct KnownAttribute {
@@ -82,6 +86,12 @@ pub struct RuntimeModuleMetadataV1 {
 
     /// Save information for the data_struct in the Move function.
     pub data_struct_func_map: BTreeMap<String, Vec<usize>>,
+
+    /// The error constants of the module, by the abort code.
+    /// It is stored under the `ROOCH_ERROR_MAP_KEY` metadata, not in the BCS of this struct,
+    /// so the modules published before the error map was introduced can still be decoded.
+    #[serde(skip)]
+    pub error_map: BTreeMap<u64, ErrorDescription>,
 }
 
 impl RuntimeModuleMetadataV1 {
@@ -91,6 +101,15 @@ impl RuntimeModuleMetadataV1 {
             && self.private_generics_indices.is_empty()
             && self.gas_free_function_map.is_empty()
     }
+
+    /// Return the description of the error constant raised by the abort code.
+    /// The abort code may be the error constant itself or a canonical code built from it.
+    pub fn get_error_description(&self, abort_code: u64) -> Option<&ErrorDescription> {
+        let (_, reason_code) = moveos_types::move_std::error::explain(abort_code);
+        self.error_map
+            .get(&abort_code)
+            .or_else(|| self.error_map.get(&reason_code))
+    }
 }
 
 fn find_metadata<'a>(module: &'a CompiledModule, key: &[u8]) -> Option<&'a Metadata> {
@@ -101,11 +120,18 @@ fn find_metadata<'a>(module: &'a CompiledModule, key: &[u8]) -> Option<&'a Metad
 pub fn get_metadata_from_compiled_module(
     module: &CompiledModule,
 ) -> Option<RuntimeModuleMetadataV1> {
-    if let Some(data) = find_metadata(module, ROOCH_METADATA_KEY) {
-        bcs::from_bytes::<RuntimeModuleMetadataV1>(&data.value).ok()
-    } else {
-        None
-    }
+    let error_map = find_metadata(module, ROOCH_ERROR_MAP_KEY)
+        .and_then(|data| bcs::from_bytes::<BTreeMap<u64, ErrorDescription>>(&data.value).ok());
+    let metadata = match find_metadata(module, ROOCH_METADATA_KEY) {
+        Some(data) => Some(bcs::from_bytes::<RuntimeModuleMetadataV1>(&data.value).ok()?),
+        // The module only carries the error constants.
+        None if error_map.is_some() => Some(RuntimeModuleMetadataV1::default()),
+        None => None,
+    };
+    metadata.map(|mut metadata| {
+        metadata.error_map = error_map.unwrap_or_default();
+        metadata
+    })
 }
 
 /// Run the extended context checker on target modules in the environment and returns a map
@@ -141,11 +167,66 @@ impl<'a> ExtendedChecker<'a> {
                 self.check_global_storage_access(module);
                 self.check_gas_free_function(module);
                 self.check_data_struct(module);
+                self.check_error_constants(module);
             }
         }
     }
 }
 
+// ----------------------------------------------------------------------------------
+// Error Constants
+
+impl<'a> ExtendedChecker<'a> {
+    /// Collect the error constants of the module, so the abort codes can be explained on chain.
+    fn check_error_constants(&mut self, module: &ModuleEnv) {
+        let mut error_map = BTreeMap::new();
+        // The codes taken by the `ErrorXxx` constants, they are preferred over the `EXXX` constants.
+        let mut error_style_codes = BTreeSet::new();
+        for constant in module.get_named_constants() {
+            let name = self.env.symbol_pool().string(constant.get_name());
+            let is_error_style = name.starts_with(ERROR_CONSTANT_PREFIX);
+            if !is_error_style && !is_upper_case_error_constant(&name) {
+                continue;
+            }
+            // Only the u64 constants can be used as the abort code.
+            if let Value::Number(value) = constant.get_value() {
+                if let Ok(abort_code) = u64::try_from(value) {
+                    let description = ErrorDescription {
+                        code_name: name.trim().to_string(),
+                        code_description: constant.get_doc().trim().to_string(),
+                    };
+                    // Keep the first declared constant if several constants of the same style share the same code.
+                    if is_error_style {
+                        if error_style_codes.insert(abort_code) {
+                            error_map.insert(abort_code, description);
+                        }
+                    } else {
+                        error_map.entry(abort_code).or_insert(description);
+                    }
+                }
+            }
+        }
+
+        if !error_map.is_empty() {
+            let module_metadata = self
+                .output
+                .entry(module.get_verified_module().self_id())
+                .or_default();
+            module_metadata.error_map = error_map;
+        }
+    }
+}
+
+/// Whether the constant is named in the move-stdlib `EXXX` style, such as `ENOT_FOUND`.
+fn is_upper_case_error_constant(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('E')
+        && chars.next().map_or(false, |c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
 // ----------------------------------------------------------------------------------
 // Private Generic Functions
 
@@ -1384,6 +1465,7 @@ pub enum MalformedError {
 /// Check if the metadata has unknown key/data types
 pub fn check_metadata_format(module: &CompiledModule) -> Result<(), MalformedError> {
     let mut exist = false;
+    let mut error_map_exist = false;
     for data in module.metadata.iter() {
         if data.key == ROOCH_METADATA_KEY {
             if exist {
@@ -1395,6 +1477,14 @@ pub fn check_metadata_format(module: &CompiledModule) -> Result<(), MalformedErr
                 bcs::from_bytes::<RuntimeModuleMetadataV1>(&data.value)
                     .map_err(|e| MalformedError::DeserializedError(data.key.clone(), e))?;
             }
+        } else if data.key == ROOCH_ERROR_MAP_KEY {
+            if error_map_exist {
+                return Err(MalformedError::DuplicateKey);
+            }
+            error_map_exist = true;
+
+            bcs::from_bytes::<BTreeMap<u64, ErrorDescription>>(&data.value)
+                .map_err(|e| MalformedError::DeserializedError(data.key.clone(), e))?;
         } else {
             return Err(MalformedError::UnknownKey(data.key.clone()));
         }
@@ -2,14 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_vm_types::gas::{GasMeter, UnmeteredGasMeter};
+use moveos_types::execution_trace::ExecutionTrace;
+use moveos_types::gas_profile::GasProfile;
 
 pub mod parameter;
+pub mod profiler;
 pub mod table;
+pub mod tracer;
 
 pub trait SwitchableGasMeter: GasMeter {
     fn stop_metering(&mut self);
     fn start_metering(&mut self);
     fn is_metering(&self) -> bool;
+
+    /// Enter a top level frame of the gas profile and the execution trace, the VM only reports the calls from Move code.
+    /// The `args` are the BCS bytes of the arguments.
+    /// It does nothing if the gas meter does not support profiling and tracing.
+    fn enter_top_level_frame(&mut self, _name: String, _args: &[Vec<u8>]) {}
+
+    fn exit_top_level_frame(&mut self, _is_success: bool) {}
+
+    /// Take the recorded gas profile, return None if the profiling is not enabled.
+    fn take_gas_profile(&mut self) -> Option<GasProfile> {
+        None
+    }
+
+    /// Take the call stack of the failed execution, return None if the tracing is not enabled or no failure.
+    fn take_execution_trace(&mut self) -> Option<ExecutionTrace> {
+        None
+    }
 }
 
 impl SwitchableGasMeter for UnmeteredGasMeter {
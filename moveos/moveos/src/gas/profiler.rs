@@ -0,0 +1,125 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::language_storage::ModuleId;
+use moveos_types::gas_profile::{GasProfile, GasProfileFrame};
+
+/// The root frame name of a transaction
+pub const TRANSACTION_FRAME_NAME: &str = "transaction";
+/// The top level frame name of a script execution
+pub const SCRIPT_FRAME_NAME: &str = "script";
+/// The top level frame name of a module bundle publishing
+pub const PUBLISH_MODULES_FRAME_NAME: &str = "publish_modules";
+
+/// The frame name of a Move function, eg: `0x3::coin::transfer`
+pub fn function_frame_name(module_id: &ModuleId, func_name: &str) -> String {
+    format!(
+        "{}::{}::{}",
+        module_id.address().to_hex_literal(),
+        module_id.name(),
+        func_name
+    )
+}
+
+/// The GasProfiler records the call tree of a transaction and attributes the gas to the frames.
+/// The first frame of the stack is the root frame, it is never popped before `finish`.
+#[derive(Debug)]
+pub struct GasProfiler {
+    frames: Vec<GasProfileFrame>,
+    // The stack depths when entering the top level frames, the frames above the depth are popped when exiting.
+    top_level_depths: Vec<usize>,
+}
+
+impl GasProfiler {
+    pub fn new(root_name: String) -> Self {
+        Self {
+            frames: vec![GasProfileFrame::new(root_name)],
+            top_level_depths: vec![],
+        }
+    }
+
+    fn current_frame(&mut self) -> &mut GasProfileFrame {
+        self.frames
+            .last_mut()
+            .expect("The root frame should always exist")
+    }
+
+    // Pop the current frame and attach it to the parent frame.
+    fn pop_frame(&mut self) {
+        if self.frames.len() > 1 {
+            let frame = self.frames.pop().expect("The frame should exist");
+            self.current_frame().children.push(frame);
+        }
+    }
+
+    /// Enter a top level frame, such as the entry function or the pre/post execute functions.
+    /// The VM does not charge the call of the top level function, so the frame is entered by the session.
+    pub fn enter_top_level_frame(&mut self, name: String) {
+        self.top_level_depths.push(self.frames.len());
+        self.frames.push(GasProfileFrame::new(name));
+    }
+
+    /// Exit the top level frame, the frames left by an aborted execution are exited too.
+    pub fn exit_top_level_frame(&mut self) {
+        if let Some(depth) = self.top_level_depths.pop() {
+            while self.frames.len() > depth {
+                self.pop_frame();
+            }
+        }
+    }
+
+    pub fn enter_frame(&mut self, name: String) {
+        self.frames.push(GasProfileFrame::new(name));
+    }
+
+    /// Exit the current function frame.
+    /// The VM also drops the frame of the top level function, it is kept until `exit_top_level_frame`.
+    pub fn exit_frame(&mut self) {
+        let min_depth = self
+            .top_level_depths
+            .last()
+            .map(|depth| depth + 1)
+            .unwrap_or(1);
+        if self.frames.len() > min_depth {
+            self.pop_frame();
+        }
+    }
+
+    pub fn record_instructions(&mut self, instructions: u64) {
+        let frame = self.current_frame();
+        frame.instructions = frame.instructions.saturating_add(instructions);
+    }
+
+    pub fn record_gas(&mut self, gas: u64) {
+        let frame = self.current_frame();
+        frame.self_gas = frame.self_gas.saturating_add(gas);
+    }
+
+    pub fn record_native_call(&mut self, gas: u64) {
+        let frame = self.current_frame();
+        frame.native_calls = frame.native_calls.saturating_add(1);
+        frame.native_gas = frame.native_gas.saturating_add(gas);
+    }
+
+    pub fn record_storage_read(&mut self) {
+        let frame = self.current_frame();
+        frame.storage_reads = frame.storage_reads.saturating_add(1);
+    }
+
+    pub fn record_storage_write(&mut self) {
+        let frame = self.current_frame();
+        frame.storage_writes = frame.storage_writes.saturating_add(1);
+    }
+
+    /// Exit all the frames and return the profile.
+    pub fn finish(mut self) -> GasProfile {
+        while self.frames.len() > 1 {
+            self.pop_frame();
+        }
+        GasProfile::new(
+            self.frames
+                .pop()
+                .expect("The root frame should always exist"),
+        )
+    }
+}
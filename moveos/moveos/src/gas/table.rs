@@ -15,7 +15,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::ops::{Add, Bound};
 
+use super::profiler::{function_frame_name, GasProfiler};
+use super::tracer::{render_value, ExecutionTracer};
 use super::SwitchableGasMeter;
+use moveos_types::execution_trace::ExecutionTrace;
+use moveos_types::gas_profile::GasProfile;
 
 /// The size in bytes for a reference on the stack
 pub const REFERENCE_SIZE: AbstractMemorySize = AbstractMemorySize::new(8);
@@ -30,6 +34,12 @@ pub const INSTRUCTION_TIER_DEFAULT: u64 = 1;
 pub const STACK_HEIGHT_TIER_DEFAULT: u64 = 1;
 pub const STACK_SIZE_TIER_DEFAULT: u64 = 1;
 
+/// The on-chain gas schedule key prefixes of the tiers, the key is `<prefix>.<tier start>`,
+/// and the value is the tier multiplier, eg: `instruction_tier.3000 = 2`.
+pub const INSTRUCTION_TIER_KEY_PREFIX: &str = "instruction_tier";
+pub const STACK_HEIGHT_TIER_KEY_PREFIX: &str = "stack_height_tier";
+pub const STACK_SIZE_TIER_KEY_PREFIX: &str = "stack_size_tier";
+
 pub static ZERO_COST_SCHEDULE: Lazy<CostTable> = Lazy::new(zero_cost_schedule);
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
@@ -79,6 +89,66 @@ impl CostTable {
             STACK_SIZE_TIER_DEFAULT,
         )
     }
+
+    /// Build the cost table from the on-chain gas schedule entries.
+    /// If the gas schedule does not contain any entry of a tier kind, the initial tiers of this kind are used,
+    /// so an empty gas schedule is equivalent to the `initial_cost_schedule`.
+    pub fn from_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Self {
+        let initial = initial_cost_schedule();
+        Self {
+            instruction_tiers: Self::tiers_from_gas_schedule(
+                gas_schedule,
+                INSTRUCTION_TIER_KEY_PREFIX,
+            )
+            .unwrap_or(initial.instruction_tiers),
+            stack_height_tiers: Self::tiers_from_gas_schedule(
+                gas_schedule,
+                STACK_HEIGHT_TIER_KEY_PREFIX,
+            )
+            .unwrap_or(initial.stack_height_tiers),
+            stack_size_tiers: Self::tiers_from_gas_schedule(
+                gas_schedule,
+                STACK_SIZE_TIER_KEY_PREFIX,
+            )
+            .unwrap_or(initial.stack_size_tiers),
+        }
+    }
+
+    fn tiers_from_gas_schedule(
+        gas_schedule: &BTreeMap<String, u64>,
+        prefix: &str,
+    ) -> Option<BTreeMap<u64, u64>> {
+        let tiers: BTreeMap<u64, u64> = gas_schedule
+            .iter()
+            .filter_map(|(key, multiplier)| {
+                key.strip_prefix(prefix)
+                    .and_then(|suffix| suffix.strip_prefix('.'))
+                    .and_then(|start| start.parse::<u64>().ok())
+                    .map(|start| (start, *multiplier))
+            })
+            .collect();
+        if tiers.is_empty() {
+            None
+        } else {
+            Some(tiers)
+        }
+    }
+
+    /// Convert the cost table to the on-chain gas schedule entries.
+    pub fn to_gas_schedule_entries(&self) -> Vec<(String, u64)> {
+        [
+            (INSTRUCTION_TIER_KEY_PREFIX, &self.instruction_tiers),
+            (STACK_HEIGHT_TIER_KEY_PREFIX, &self.stack_height_tiers),
+            (STACK_SIZE_TIER_KEY_PREFIX, &self.stack_size_tiers),
+        ]
+        .into_iter()
+        .flat_map(|(prefix, tiers)| {
+            tiers
+                .iter()
+                .map(move |(start, multiplier)| (format!("{}.{}", prefix, start), *multiplier))
+        })
+        .collect()
+    }
 }
 
 /// The  `GasCost` tracks:
@@ -203,6 +273,11 @@ pub struct MoveOSGasMeter {
     instructions_executed: u64,
     instructions_next_tier_start: Option<u64>,
     instructions_current_tier_mult: u64,
+
+    // The gas profiler, it is only enabled when the caller requests the gas profile.
+    profiler: Option<GasProfiler>,
+    // The execution tracer, it is only enabled when the caller requests the execution trace.
+    tracer: Option<ExecutionTracer>,
 }
 
 impl MoveOSGasMeter {
@@ -235,6 +310,8 @@ impl MoveOSGasMeter {
             stack_height_next_tier_start,
             stack_size_next_tier_start,
             instructions_next_tier_start,
+            profiler: None,
+            tracer: None,
         }
     }
 
@@ -258,6 +335,43 @@ impl MoveOSGasMeter {
             instructions_executed: 0,
             instructions_next_tier_start: None,
             instructions_current_tier_mult: 0,
+            profiler: None,
+            tracer: None,
+        }
+    }
+
+    /// Enable the gas profiler, the gas used is attributed to the Move functions under the `root_name` frame.
+    pub fn enable_profiling(&mut self, root_name: String) {
+        self.profiler = Some(GasProfiler::new(root_name));
+    }
+
+    pub fn is_profiling(&self) -> bool {
+        self.profiler.is_some()
+    }
+
+    /// Enable the execution tracer, the call stack is captured when the execution fails.
+    pub fn enable_tracing(&mut self) {
+        self.tracer = Some(ExecutionTracer::new());
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.is_some()
+    }
+
+    fn with_profiler<F: FnOnce(&mut GasProfiler)>(&mut self, f: F) {
+        if self.charge {
+            if let Some(profiler) = self.profiler.as_mut() {
+                f(profiler);
+            }
+        }
+    }
+
+    fn enter_call_frame(&mut self, module_id: &ModuleId, func_name: &str, args: Vec<String>) {
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.enter_frame(function_frame_name(module_id, func_name));
+        }
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.enter_frame(function_frame_name(module_id, func_name), args);
         }
     }
 
@@ -342,6 +456,7 @@ impl MoveOSGasMeter {
     ) -> PartialVMResult<()> {
         self.push_stack(pushes)?;
         self.increase_instruction_count(num_instructions)?;
+        self.with_profiler(|profiler| profiler.record_instructions(num_instructions));
         self.increase_stack_size(incr_size)?;
 
         self.deduct_gas(
@@ -371,6 +486,8 @@ impl MoveOSGasMeter {
             return Ok(());
         }
 
+        let gas_charged = amount.min(self.gas_left);
+        self.with_profiler(|profiler| profiler.record_gas(gas_charged));
         match self.gas_left.checked_sub(amount) {
             Some(gas_left) => {
                 self.gas_left = gas_left;
@@ -451,8 +568,8 @@ impl GasMeter for MoveOSGasMeter {
 
     fn charge_call(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         _num_locals: NumArgs,
     ) -> PartialVMResult<()> {
@@ -460,16 +577,24 @@ impl GasMeter for MoveOSGasMeter {
         let pops = args.len() as u64;
         // Size stays the same -- we're just moving it from the operand stack to the locals. But
         // the size on the operand stack is reduced by sum_{args} arg.size().
+        // Render the arguments for the execution trace.
+        let is_tracing = self.tracer.is_some();
+        let mut rendered_args = vec![];
         let stack_reduction_size = args.fold(AbstractMemorySize::new(0), |acc, elem| {
+            if is_tracing {
+                rendered_args.push(render_value(&elem));
+            }
             acc + elem.legacy_abstract_memory_size()
         });
-        self.charge(1, 0, pops, 0, stack_reduction_size.into())
+        self.charge(1, 0, pops, 0, stack_reduction_size.into())?;
+        self.enter_call_frame(module_id, func_name, rendered_args);
+        Ok(())
     }
 
     fn charge_call_generic(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         _ty_args: impl ExactSizeIterator<Item = impl TypeView>,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         _num_locals: NumArgs,
@@ -477,12 +602,20 @@ impl GasMeter for MoveOSGasMeter {
         // We have to perform this many pops from the operand stack for this function call.
         let pops = args.len() as u64;
         // Calculate the size reduction on the operand stack.
+        // Render the arguments for the execution trace.
+        let is_tracing = self.tracer.is_some();
+        let mut rendered_args = vec![];
         let stack_reduction_size = args.fold(AbstractMemorySize::new(0), |acc, elem| {
+            if is_tracing {
+                rendered_args.push(render_value(&elem));
+            }
             acc + elem.legacy_abstract_memory_size()
         });
         // Charge for the pops, no pushes, and account for the stack size decrease. Also track the
         // `CallGeneric` instruction we must have encountered for this.
-        self.charge(1, 0, pops, 0, stack_reduction_size.into())
+        self.charge(1, 0, pops, 0, stack_reduction_size.into())?;
+        self.enter_call_frame(module_id, func_name, rendered_args);
+        Ok(())
     }
 
     fn charge_ld_const(&mut self, size: NumBytes) -> PartialVMResult<()> {
@@ -514,6 +647,9 @@ impl GasMeter for MoveOSGasMeter {
         // Charge for the storing of the value on the stack into a local. Note here that if we were
         // also accounting for the size of the locals that this would be a net-zero operation in
         // terms of memory.
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record_local(&val);
+        }
         self.charge(1, 0, 1, 0, val.legacy_abstract_memory_size().into())
     }
 
@@ -592,6 +728,7 @@ impl GasMeter for MoveOSGasMeter {
         _ty: impl TypeView,
         _is_success: bool,
     ) -> PartialVMResult<()> {
+        self.with_profiler(|profiler| profiler.record_storage_read());
         self.charge(1, 1, 1, REFERENCE_SIZE.into(), Type::Address.size().into())
     }
 
@@ -602,6 +739,7 @@ impl GasMeter for MoveOSGasMeter {
         // TODO(Gas): see if we can get rid of this param
         _exists: bool,
     ) -> PartialVMResult<()> {
+        self.with_profiler(|profiler| profiler.record_storage_read());
         self.charge(
             1,
             1,
@@ -617,6 +755,7 @@ impl GasMeter for MoveOSGasMeter {
         _ty: impl TypeView,
         val: Option<impl ValueView>,
     ) -> PartialVMResult<()> {
+        self.with_profiler(|profiler| profiler.record_storage_write());
         let size = val
             .map(|val| val.legacy_abstract_memory_size())
             .unwrap_or_else(AbstractMemorySize::zero);
@@ -630,6 +769,7 @@ impl GasMeter for MoveOSGasMeter {
         _val: impl ValueView,
         _is_success: bool,
     ) -> PartialVMResult<()> {
+        self.with_profiler(|profiler| profiler.record_storage_write());
         self.charge(1, 0, 2, 0, Type::Address.size().into())
     }
 
@@ -711,6 +851,8 @@ impl GasMeter for MoveOSGasMeter {
         amount: InternalGas,
         ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
     ) -> PartialVMResult<()> {
+        // The native function returns values only if it succeeds.
+        let is_success = ret_vals.is_some();
         // Charge for the number of pushes on to the stack that the return of this function is
         // going to cause.
         let pushes = ret_vals
@@ -730,7 +872,20 @@ impl GasMeter for MoveOSGasMeter {
         // `charge_native_function_before_execution` call.
         self.charge(0, pushes, 0, size_increase.into(), 0)?;
         // Now charge the gas that the native function told us to charge.
-        self.deduct_gas(amount.into())
+        let amount: u64 = amount.into();
+        let gas_charged = amount.min(self.gas_left);
+        self.with_profiler(|profiler| profiler.record_native_call(gas_charged));
+        // The VM does not drop a frame for the native function, so exit the frame entered by the call here.
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.exit_frame();
+        }
+        // The frame of an aborted native function is kept in the call stack of the failure.
+        if is_success {
+            if let Some(tracer) = self.tracer.as_mut() {
+                tracer.exit_frame();
+            }
+        }
+        self.deduct_gas(amount)
     }
 
     fn charge_native_function_before_execution(
@@ -755,6 +910,12 @@ impl GasMeter for MoveOSGasMeter {
         &mut self,
         _locals: impl Iterator<Item = impl ValueView>,
     ) -> PartialVMResult<()> {
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.exit_frame();
+        }
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.exit_frame();
+        }
         Ok(())
     }
 }
@@ -771,4 +932,30 @@ impl SwitchableGasMeter for MoveOSGasMeter {
     fn is_metering(&self) -> bool {
         self.charge
     }
+
+    fn enter_top_level_frame(&mut self, name: String, args: &[Vec<u8>]) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.enter_top_level_frame(name.clone(), args);
+        }
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.enter_top_level_frame(name);
+        }
+    }
+
+    fn exit_top_level_frame(&mut self, is_success: bool) {
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.exit_top_level_frame();
+        }
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.exit_top_level_frame(is_success);
+        }
+    }
+
+    fn take_gas_profile(&mut self) -> Option<GasProfile> {
+        self.profiler.take().map(GasProfiler::finish)
+    }
+
+    fn take_execution_trace(&mut self) -> Option<ExecutionTrace> {
+        self.tracer.take().and_then(ExecutionTracer::finish)
+    }
 }
@@ -0,0 +1,199 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{account_address::AccountAddress, u256::U256};
+use move_vm_types::views::{ValueView, ValueVisitor};
+use moveos_types::execution_trace::{ExecutionTrace, TraceFrame};
+use std::fmt::{Display, Write};
+
+/// The max length of a rendered value, the longer values are truncated.
+pub const MAX_TRACE_VALUE_LENGTH: usize = 256;
+/// The max number of the local values kept in a frame.
+pub const MAX_TRACE_LOCALS: usize = 16;
+
+/// The ExecutionTracer records the call stack of the execution,
+/// the call stack is captured when a top level function fails.
+#[derive(Debug, Default)]
+pub struct ExecutionTracer {
+    frames: Vec<TraceFrame>,
+    // The stack depths when entering the top level frames.
+    top_level_depths: Vec<usize>,
+    // The call stack of the first failure.
+    failed_frames: Option<Vec<TraceFrame>>,
+}
+
+impl ExecutionTracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter a top level frame, the `args` are the BCS bytes of the arguments.
+    pub fn enter_top_level_frame(&mut self, function: String, args: &[Vec<u8>]) {
+        self.top_level_depths.push(self.frames.len());
+        let args = args
+            .iter()
+            .map(|arg| truncate(format!("0x{}", hex::encode(arg))))
+            .collect();
+        self.frames.push(TraceFrame::new(function, args));
+    }
+
+    /// Exit the top level frame, the call stack is captured if the execution failed.
+    /// The frames left by the failed execution are exited too.
+    pub fn exit_top_level_frame(&mut self, is_success: bool) {
+        if let Some(depth) = self.top_level_depths.pop() {
+            if !is_success && self.failed_frames.is_none() {
+                self.failed_frames = Some(self.frames.clone());
+            }
+            self.frames.truncate(depth);
+        }
+    }
+
+    pub fn enter_frame(&mut self, function: String, args: Vec<String>) {
+        self.frames.push(TraceFrame::new(function, args));
+    }
+
+    /// Exit the current function frame.
+    /// The VM also drops the frame of the top level function, it is kept until `exit_top_level_frame`.
+    pub fn exit_frame(&mut self) {
+        let min_depth = self
+            .top_level_depths
+            .last()
+            .map(|depth| depth + 1)
+            .unwrap_or(0);
+        if self.frames.len() > min_depth {
+            self.frames.pop();
+        }
+    }
+
+    pub fn record_local(&mut self, val: impl ValueView) {
+        if let Some(frame) = self.frames.last_mut() {
+            if frame.locals.len() >= MAX_TRACE_LOCALS {
+                frame.locals.remove(0);
+            }
+            frame.locals.push(render_value(val));
+        }
+    }
+
+    /// Return the call stack of the first failure, None if there is no failure.
+    pub fn finish(self) -> Option<ExecutionTrace> {
+        self.failed_frames.map(ExecutionTrace::new)
+    }
+}
+
+/// Render the value to a compact string, the structs are rendered as `{..}` and the vectors as `[..]`.
+pub fn render_value(val: impl ValueView) -> String {
+    let mut printer = ValuePrinter::default();
+    val.visit(&mut printer);
+    truncate(printer.finish())
+}
+
+fn truncate(mut s: String) -> String {
+    if s.len() > MAX_TRACE_VALUE_LENGTH {
+        // The rendered values are ASCII, so the truncation is on a char boundary.
+        s.truncate(MAX_TRACE_VALUE_LENGTH);
+        s.push_str("..");
+    }
+    s
+}
+
+#[derive(Default)]
+struct ValuePrinter {
+    out: String,
+    // The depth and the close token of the open containers.
+    containers: Vec<(usize, &'static str)>,
+    need_separator: bool,
+}
+
+impl ValuePrinter {
+    fn is_full(&self) -> bool {
+        self.out.len() > MAX_TRACE_VALUE_LENGTH
+    }
+
+    // Close the containers which are not the ancestors of the value at `depth`.
+    fn begin(&mut self, depth: usize) {
+        while let Some((container_depth, close)) = self.containers.last() {
+            if *container_depth < depth {
+                break;
+            }
+            self.out.push_str(close);
+            self.containers.pop();
+            self.need_separator = true;
+        }
+        if self.need_separator {
+            self.out.push_str(", ");
+        }
+    }
+
+    fn value(&mut self, depth: usize, val: impl Display) {
+        if self.is_full() {
+            return;
+        }
+        self.begin(depth);
+        let _ = write!(self.out, "{}", val);
+        self.need_separator = true;
+    }
+
+    fn open(&mut self, depth: usize, open: &str, close: &'static str) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.begin(depth);
+        self.out.push_str(open);
+        self.containers.push((depth, close));
+        self.need_separator = false;
+        true
+    }
+
+    fn finish(mut self) -> String {
+        while let Some((_, close)) = self.containers.pop() {
+            self.out.push_str(close);
+        }
+        self.out
+    }
+}
+
+impl ValueVisitor for ValuePrinter {
+    fn visit_u8(&mut self, depth: usize, val: u8) {
+        self.value(depth, val)
+    }
+
+    fn visit_u16(&mut self, depth: usize, val: u16) {
+        self.value(depth, val)
+    }
+
+    fn visit_u32(&mut self, depth: usize, val: u32) {
+        self.value(depth, val)
+    }
+
+    fn visit_u64(&mut self, depth: usize, val: u64) {
+        self.value(depth, val)
+    }
+
+    fn visit_u128(&mut self, depth: usize, val: u128) {
+        self.value(depth, val)
+    }
+
+    fn visit_u256(&mut self, depth: usize, val: U256) {
+        self.value(depth, val)
+    }
+
+    fn visit_bool(&mut self, depth: usize, val: bool) {
+        self.value(depth, val)
+    }
+
+    fn visit_address(&mut self, depth: usize, val: AccountAddress) {
+        self.value(depth, val.to_hex_literal())
+    }
+
+    fn visit_struct(&mut self, depth: usize, _len: usize) -> bool {
+        self.open(depth, "{", "}")
+    }
+
+    fn visit_vec(&mut self, depth: usize, _len: usize) -> bool {
+        self.open(depth, "[", "]")
+    }
+
+    fn visit_ref(&mut self, depth: usize, _is_global: bool) -> bool {
+        self.open(depth, "&", "")
+    }
+}
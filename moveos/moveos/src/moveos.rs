@@ -1,7 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::gas::table::{initial_cost_schedule, MoveOSGasMeter};
+use crate::gas::profiler::TRANSACTION_FRAME_NAME;
+use crate::gas::table::{initial_cost_schedule, CostTable, MoveOSGasMeter};
 use crate::vm::moveos_vm::{MoveOSSession, MoveOSVM};
 use anyhow::{bail, ensure, Result};
 use backtrace::Backtrace;
@@ -19,13 +20,14 @@ use move_vm_runtime::native_functions::NativeFunction;
 use moveos_store::config_store::ConfigDBStore;
 use moveos_store::event_store::EventDBStore;
 use moveos_store::state_store::statedb::StateDBStore;
-use moveos_store::transaction_store::TransactionDBStore;
+use moveos_store::transaction_store::{TransactionDBStore, TransactionTraceDBStore};
 use moveos_store::MoveOSStore;
 use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::FunctionId;
 use moveos_types::moveos_std::event::EventID;
+use moveos_types::moveos_std::gas_schedule::GasSchedule;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
 use moveos_types::startup_info::StartupInfo;
@@ -64,6 +66,31 @@ pub struct MoveOSConfig {
     pub vm_config: VMConfig,
 }
 
+/// The options of the transaction execution, all the options are disabled by default.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ExecuteOptions {
+    /// Attribute the gas used to the Move function call tree, the profile is returned in the output.
+    pub profile_gas: bool,
+    /// Capture the call stack when the execution fails, the trace is returned in the output.
+    pub trace: bool,
+}
+
+impl ExecuteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn profile_gas(mut self, profile_gas: bool) -> Self {
+        self.profile_gas = profile_gas;
+        self
+    }
+
+    pub fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+}
+
 impl std::fmt::Debug for MoveOSConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MoveOSConfig")
@@ -92,8 +119,24 @@ impl Clone for MoveOSConfig {
     }
 }
 
+pub type NativeFunctions = Vec<(AccountAddress, Identifier, Identifier, NativeFunction)>;
+
+/// Build the natives with the native gas parameters in the on-chain gas schedule,
+/// the gas schedule is None before the genesis is initialized.
+pub type NativesBuilder = Box<dyn Fn(Option<&GasSchedule>) -> NativeFunctions + Send + Sync>;
+
+/// The natives builder and the version of the gas schedule which the natives of the VM are built with.
+struct GasScheduleNatives {
+    builder: NativesBuilder,
+    schedule_version: Option<u64>,
+}
+
 pub struct MoveOS {
     vm: MoveOSVM,
+    config: MoveOSConfig,
+    natives: Option<GasScheduleNatives>,
+    /// Re-execute the failed transactions with the tracer, the trace is returned in the output.
+    trace_failed_txs: bool,
     db: MoveOSResolverProxy<MoveOSStore>,
     system_pre_execute_functions: Vec<FunctionCall>,
     system_post_execute_functions: Vec<FunctionCall>,
@@ -107,15 +150,80 @@ impl MoveOS {
         system_pre_execute_functions: Vec<FunctionCall>,
         system_post_execute_functions: Vec<FunctionCall>,
     ) -> Result<Self> {
-        let vm = MoveOSVM::new(natives, config.vm_config)?;
+        let vm = MoveOSVM::new(natives, config.clone().vm_config)?;
         Ok(Self {
             vm,
+            config,
+            natives: None,
+            trace_failed_txs: false,
             db: MoveOSResolverProxy(db),
             system_pre_execute_functions,
             system_post_execute_functions,
         })
     }
 
+    /// Create the MoveOS with the natives built from the on-chain gas schedule,
+    /// the natives are rebuilt when a transaction updates the gas schedule.
+    pub fn new_with_natives_builder(
+        db: MoveOSStore,
+        natives_builder: NativesBuilder,
+        config: MoveOSConfig,
+        system_pre_execute_functions: Vec<FunctionCall>,
+        system_post_execute_functions: Vec<FunctionCall>,
+    ) -> Result<Self> {
+        let gas_schedule = db
+            .get_state_store()
+            .get_as_object::<GasSchedule>(GasSchedule::object_id())?
+            .map(|object| object.value);
+        let mut moveos = Self::new(
+            db,
+            natives_builder(gas_schedule.as_ref()),
+            config,
+            system_pre_execute_functions,
+            system_post_execute_functions,
+        )?;
+        moveos.natives = Some(GasScheduleNatives {
+            builder: natives_builder,
+            schedule_version: gas_schedule.map(|gas_schedule| gas_schedule.schedule_version),
+        });
+        Ok(moveos)
+    }
+
+    /// Enable or disable the tracing of the failed transactions in `execute_and_apply`.
+    /// The failed transaction is executed twice when tracing is enabled, so it is disabled by default.
+    pub fn set_trace_failed_txs(&mut self, trace_failed_txs: bool) {
+        self.trace_failed_txs = trace_failed_txs;
+    }
+
+    /// Rebuild the VM if the gas schedule version differs from the one the natives are built with.
+    /// The schedule version only increases, so the natives are rebuilt once per gas schedule update.
+    fn reload_natives_if_gas_schedule_changed(&mut self) -> Result<()> {
+        let gas_schedule = match &self.natives {
+            Some(natives) => {
+                let gas_schedule = self.gas_schedule()?;
+                if gas_schedule
+                    .as_ref()
+                    .map(|gas_schedule| gas_schedule.schedule_version)
+                    == natives.schedule_version
+                {
+                    return Ok(());
+                }
+                gas_schedule
+            }
+            None => return Ok(()),
+        };
+        let natives = self
+            .natives
+            .as_mut()
+            .expect("The natives builder should exist");
+        self.vm = MoveOSVM::new(
+            (natives.builder)(gas_schedule.as_ref()),
+            self.config.clone().vm_config,
+        )?;
+        natives.schedule_version = gas_schedule.map(|gas_schedule| gas_schedule.schedule_version);
+        Ok(())
+    }
+
     pub fn init_genesis<T: Into<MoveOSTransaction>, GT: MoveState + Clone>(
         &mut self,
         genesis_txs: Vec<T>,
@@ -125,10 +233,13 @@ impl MoveOS {
             self.db.0.get_state_store().is_genesis(),
             "genesis already initialized"
         );
-        genesis_txs
+        let genesis_result = genesis_txs
             .into_iter()
             .map(|tx| self.verify_and_execute_genesis_tx(tx.into(), genesis_ctx.clone()))
-            .collect::<Result<Vec<_>>>()
+            .collect::<Result<Vec<_>>>()?;
+        // The genesis initializes the gas schedule
+        self.reload_natives_if_gas_schedule_changed()?;
+        Ok(genesis_result)
     }
 
     fn verify_and_execute_genesis_tx<GT: MoveState>(
@@ -160,6 +271,8 @@ impl MoveOS {
             bail!("genesis tx should success, error: {:?}", raw_output.status);
         }
         let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone())?;
+        // The transaction may update the gas schedule, the next transaction uses the new native gas costs.
+        self.reload_natives_if_gas_schedule_changed()?;
         let output = TransactionOutput::new(raw_output, event_ids);
         Ok((state_root, output))
     }
@@ -180,10 +293,43 @@ impl MoveOS {
         self.db.0.get_transaction_store()
     }
 
+    pub fn transaction_trace_store(&self) -> &TransactionTraceDBStore {
+        self.db.0.get_transaction_trace_store()
+    }
+
     pub fn config_store(&self) -> &ConfigDBStore {
         self.db.0.get_config_store()
     }
 
+    /// Load the on-chain gas schedule, return None if the gas schedule is not initialized.
+    pub fn gas_schedule(&self) -> Result<Option<GasSchedule>> {
+        Ok(self
+            .state()
+            .get_as_object::<GasSchedule>(GasSchedule::object_id())?
+            .map(|object| object.value))
+    }
+
+    /// Load the cost table from the on-chain gas schedule at every transaction,
+    /// fallback to the builtin cost table if the gas schedule is not initialized.
+    pub fn cost_table(&self) -> VMResult<CostTable> {
+        let gas_schedule = self.load_gas_schedule()?;
+        Ok(Self::cost_table_of(gas_schedule.as_ref()))
+    }
+
+    fn cost_table_of(gas_schedule: Option<&GasSchedule>) -> CostTable {
+        gas_schedule
+            .map(|gas_schedule| CostTable::from_gas_schedule(&gas_schedule.to_btree_map()))
+            .unwrap_or_else(initial_cost_schedule)
+    }
+
+    fn load_gas_schedule(&self) -> VMResult<Option<GasSchedule>> {
+        self.gas_schedule().map_err(|e| {
+            PartialVMError::new(StatusCode::STORAGE_ERROR)
+                .with_message(format!("Load gas schedule error: {}", e))
+                .finish(Location::Undefined)
+        })
+    }
+
     pub fn verify(&self, tx: MoveOSTransaction) -> VMResult<VerifiedMoveOSTransaction> {
         let MoveOSTransaction {
             ctx,
@@ -192,7 +338,20 @@ impl MoveOS {
             post_execute_functions,
         } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let gas_schedule = self.load_gas_schedule()?;
+        if let Some(gas_schedule) = &gas_schedule {
+            if ctx.max_gas_amount > gas_schedule.max_gas_amount {
+                return Err(PartialVMError::new(
+                    StatusCode::MAX_GAS_UNITS_EXCEEDS_MAX_GAS_UNITS_BOUND,
+                )
+                .with_message(format!(
+                    "The max gas amount {} exceeds the bound {}",
+                    ctx.max_gas_amount, gas_schedule.max_gas_amount
+                ))
+                .finish(Location::Undefined));
+            }
+        }
+        let cost_table = Self::cost_table_of(gas_schedule.as_ref());
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
         let session = self
@@ -210,6 +369,15 @@ impl MoveOS {
     }
 
     pub fn execute(&self, tx: VerifiedMoveOSTransaction) -> Result<RawTransactionOutput> {
+        self.execute_with_options(tx, ExecuteOptions::default())
+    }
+
+    /// Execute the transaction with the gas profiler or the execution tracer enabled by the `options`.
+    pub fn execute_with_options(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        options: ExecuteOptions,
+    ) -> Result<RawTransactionOutput> {
         let VerifiedMoveOSTransaction {
             ctx,
             action,
@@ -230,8 +398,14 @@ impl MoveOS {
         // The variables in TxContext kv store before this executions should not be cleaned,
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
-        let cost_table = initial_cost_schedule();
-        let gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        let cost_table = self.cost_table()?;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        if options.profile_gas {
+            gas_meter.enable_profiling(TRANSACTION_FRAME_NAME.to_owned());
+        }
+        if options.trace {
+            gas_meter.enable_tracing();
+        }
         let mut session = self.vm.new_session(&self.db, ctx, gas_meter);
 
         // system pre_execute
@@ -377,8 +551,15 @@ impl MoveOS {
         &mut self,
         tx: VerifiedMoveOSTransaction,
     ) -> Result<(H256, TransactionOutput)> {
-        let raw_output = self.execute(tx)?;
+        let mut raw_output = self.execute(tx.clone())?;
+        if self.trace_failed_txs && raw_output.status != KeptVMStatus::Executed {
+            // Re-execute the failed transaction with the tracer before the output is applied,
+            // the execution is deterministic, so the successful transactions do not pay for the tracing.
+            raw_output = self.execute_with_options(tx, ExecuteOptions::new().trace(true))?;
+        }
         let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone())?;
+        // The transaction may update the gas schedule, the next transaction uses the new native gas costs.
+        self.reload_natives_if_gas_schedule_changed()?;
         let output = TransactionOutput::new(raw_output, event_ids);
 
         Ok((state_root, output))
@@ -395,6 +576,8 @@ impl MoveOS {
             state_changeset,
             events,
             gas_used: _,
+            gas_profile: _,
+            execution_trace: _,
         } = output;
         let new_state_root = self
             .db
@@ -442,7 +625,10 @@ impl MoveOS {
         function_call: FunctionCall,
     ) -> FunctionResult {
         //TODO limit the view function max gas usage
-        let cost_table = initial_cost_schedule();
+        let cost_table = match self.cost_table() {
+            Ok(cost_table) => cost_table,
+            Err(e) => return FunctionResult::err(e),
+        };
         let mut gas_meter = MoveOSGasMeter::new(cost_table, tx_context.max_gas_amount);
         gas_meter.set_metering(false);
         let mut session = self
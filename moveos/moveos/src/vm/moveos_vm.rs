@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::data_cache::{into_change_set, MoveosDataCache};
+use crate::gas::profiler::{function_frame_name, PUBLISH_MODULES_FRAME_NAME, SCRIPT_FRAME_NAME};
 use crate::gas::table::initial_cost_schedule;
 use crate::gas::{table::MoveOSGasMeter, SwitchableGasMeter};
 use crate::vm::tx_argument_resolver;
@@ -15,6 +16,7 @@ use move_core_types::{
     account_address::AccountAddress,
     identifier::Identifier,
     language_storage::{ModuleId, TypeTag},
+    resolver::{ModuleResolver, ResourceResolver},
     value::MoveTypeLayout,
     vm_status::{KeptVMStatus, StatusCode},
 };
@@ -37,14 +39,18 @@ use moveos_stdlib::natives::moveos_stdlib::{
 };
 use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::{
+    addresses::MOVEOS_STD_ADDRESS,
     function_return_value::FunctionReturnValue,
     move_std::string::MoveString,
     move_types::FunctionId,
     moveos_std::context::Context,
     moveos_std::copyable_any::Any,
+    moveos_std::move_module::{check_module_upgrade, UpgradePolicy},
+    moveos_std::package_registry,
     moveos_std::simple_map::SimpleMap,
     moveos_std::tx_context::TxContext,
     moveos_std::{event::TransactionEvent, module_upgrade_flag::ModuleUpgradeFlag},
+    state::MoveStructType,
     state_resolver::MoveOSResolver,
     transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
 };
@@ -237,15 +243,22 @@ where
 
                 let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
                 self.load_argument(&loaded_function, &resolved_args);
-                self.session
-                    .execute_script(call.code, call.ty_args, resolved_args, &mut self.gas_meter)
-                    .map(|ret| {
-                        debug_assert!(
-                            ret.return_values.is_empty(),
-                            "Script function should not return values"
-                        );
-                        self.update_storage_context_via_return_values(&loaded_function, &ret);
-                    })
+                self.gas_meter
+                    .enter_top_level_frame(SCRIPT_FRAME_NAME.to_owned(), &resolved_args);
+                let result = self.session.execute_script(
+                    call.code,
+                    call.ty_args,
+                    resolved_args,
+                    &mut self.gas_meter,
+                );
+                self.gas_meter.exit_top_level_frame(result.is_ok());
+                result.map(|ret| {
+                    debug_assert!(
+                        ret.return_values.is_empty(),
+                        "Script function should not return values"
+                    );
+                    self.update_storage_context_via_return_values(&loaded_function, &ret);
+                })
             }
             VerifiedMoveAction::Function { call } => {
                 let loaded_function = self.session.load_function(
@@ -256,21 +269,28 @@ where
 
                 let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
                 self.load_argument(&loaded_function, &resolved_args);
-                self.session
-                    .execute_entry_function(
+                self.gas_meter.enter_top_level_frame(
+                    function_frame_name(
                         &call.function_id.module_id,
-                        &call.function_id.function_name,
-                        call.ty_args.clone(),
-                        resolved_args,
-                        &mut self.gas_meter,
-                    )
-                    .map(|ret| {
-                        debug_assert!(
-                            ret.return_values.is_empty(),
-                            "Entry function should not return values"
-                        );
-                        self.update_storage_context_via_return_values(&loaded_function, &ret);
-                    })
+                        call.function_id.function_name.as_str(),
+                    ),
+                    &resolved_args,
+                );
+                let result = self.session.execute_entry_function(
+                    &call.function_id.module_id,
+                    &call.function_id.function_name,
+                    call.ty_args.clone(),
+                    resolved_args,
+                    &mut self.gas_meter,
+                );
+                self.gas_meter.exit_top_level_frame(result.is_ok());
+                result.map(|ret| {
+                    debug_assert!(
+                        ret.return_values.is_empty(),
+                        "Entry function should not return values"
+                    );
+                    self.update_storage_context_via_return_values(&loaded_function, &ret);
+                })
             }
             VerifiedMoveAction::ModuleBundle {
                 module_bundle,
@@ -283,14 +303,20 @@ where
                     .into_iter()
                     .filter(|m| self.session.get_data_store().exists_module(m) == Ok(false))
                     .collect();
-                //TODO check the compatiblity
+                self.check_module_upgrade_policy(sender, &module_bundle)?;
+                let record_call = Self::record_module_bundle_call(sender, &module_bundle)?;
                 let compat_config = Compatibility::full_check();
-                self.session.publish_module_bundle_with_compat_config(
+                self.gas_meter
+                    .enter_top_level_frame(PUBLISH_MODULES_FRAME_NAME.to_owned(), &[]);
+                let result = self.session.publish_module_bundle_with_compat_config(
                     module_bundle,
                     sender,
                     &mut self.gas_meter,
                     compat_config,
-                )?;
+                );
+                self.gas_meter.exit_top_level_frame(result.is_ok());
+                result?;
+                self.record_module_bundle(record_call)?;
                 self.execute_init_modules(modules_with_init)
             }
         };
@@ -316,6 +342,96 @@ where
         action_result
     }
 
+    /// Check the upgrading modules against the upgrade policy of the sender's modules.
+    /// The `moveos_std::context::publish_modules` path is checked in Move by `moveos_std::account_storage`.
+    fn check_module_upgrade_policy(
+        &self,
+        sender: AccountAddress,
+        module_bundle: &[Vec<u8>],
+    ) -> VMResult<()> {
+        let policy = self
+            .remote
+            .get_resource(&sender, &UpgradePolicy::struct_tag())
+            .and_then(|bytes| {
+                bytes
+                    .map(|bytes| bcs::from_bytes::<UpgradePolicy>(&bytes))
+                    .transpose()
+                    .map_err(Into::into)
+            })
+            .map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR)
+                    .with_message(e.to_string())
+                    .finish(Location::Undefined)
+            })?
+            .unwrap_or_default();
+        for new_module in deserialize_modules(module_bundle)? {
+            let module_id = new_module.self_id();
+            let old_bytes = self.remote.get_module(&module_id).map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR)
+                    .with_message(e.to_string())
+                    .finish(Location::Undefined)
+            })?;
+            if let Some(old_bytes) = old_bytes {
+                let old_module = CompiledModule::deserialize(&old_bytes)
+                    .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+                let incompatibilities = check_module_upgrade(&old_module, &new_module, policy);
+                if !incompatibilities.is_empty() {
+                    return Err(PartialVMError::new(
+                        StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+                    )
+                    .with_message(incompatibilities.join("; "))
+                    .finish(Location::Module(module_id)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the call of `moveos_std::package_registry::record_module_bundle` for the modules in the bundle.
+    fn record_module_bundle_call(
+        package_address: AccountAddress,
+        module_bundle: &[Vec<u8>],
+    ) -> VMResult<FunctionCall> {
+        let modules = deserialize_modules(module_bundle)?;
+        let dependencies = package_registry::package_dependencies(package_address, &modules);
+        let module_names = modules
+            .iter()
+            .map(|module| module.self_id().name().to_string())
+            .collect::<Vec<_>>();
+        let function_id = FunctionId::new(
+            ModuleId::new(MOVEOS_STD_ADDRESS, package_registry::MODULE_NAME.to_owned()),
+            package_registry::RECORD_MODULE_BUNDLE_FUNCTION_NAME.to_owned(),
+        );
+        let args = vec![
+            bcs::to_bytes(&package_address),
+            bcs::to_bytes(&dependencies),
+            bcs::to_bytes(&module_names),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            PartialVMError::new(StatusCode::VALUE_SERIALIZATION_ERROR)
+                .with_message(e.to_string())
+                .finish(Location::Undefined)
+        })?;
+        Ok(FunctionCall::new(function_id, vec![], args))
+    }
+
+    /// Record the published modules in the `moveos_std::package_registry::PackageRegistry`, so the packages
+    /// published by `MoveAction::ModuleBundle` are recorded as the ones published by `publish_package_entry`.
+    /// It is skipped before the `package_registry` module is published in genesis.
+    fn record_module_bundle(&mut self, record_call: FunctionCall) -> VMResult<()> {
+        let registry_exists = self
+            .session
+            .get_data_store()
+            .exists_module(&record_call.function_id.module_id)?;
+        if !registry_exists {
+            return Ok(());
+        }
+        self.execute_function_bypass_visibility(record_call)
+            .map(|result| debug_assert!(result.is_empty(), "Record function must not return value"))
+    }
+
     /// Resolve pending init functions request registered via the NativeModuleContext.
     fn resolve_pending_init_functions(&mut self) -> VMResult<()> {
         let ctx = self
@@ -389,13 +505,22 @@ where
         )?;
         let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
         self.load_argument(&loaded_function, &resolved_args);
-        let return_values = self.session.execute_function_bypass_visibility(
+        self.gas_meter.enter_top_level_frame(
+            function_frame_name(
+                &call.function_id.module_id,
+                call.function_id.function_name.as_str(),
+            ),
+            &resolved_args,
+        );
+        let result = self.session.execute_function_bypass_visibility(
             &call.function_id.module_id,
             &call.function_id.function_name,
             call.ty_args,
             resolved_args,
             &mut self.gas_meter,
-        )?;
+        );
+        self.gas_meter.exit_top_level_frame(result.is_ok());
+        let return_values = result?;
         self.update_storage_context_via_return_values(&loaded_function, &return_values);
         return_values
             .return_values
@@ -437,10 +562,12 @@ where
     }
 
     pub fn finish_with_extensions(
-        self,
+        mut self,
         status: KeptVMStatus,
     ) -> VMResult<(TxContext, RawTransactionOutput)> {
         let gas_used = self.query_gas_used();
+        let gas_profile = self.gas_meter.take_gas_profile();
+        let execution_trace = self.gas_meter.take_execution_trace();
         let MoveOSSession {
             vm: _,
             remote: _,
@@ -497,6 +624,8 @@ where
                 state_changeset,
                 events,
                 gas_used,
+                gas_profile,
+                execution_trace,
             },
         ))
     }
@@ -8,17 +8,11 @@ use move_binary_format::CompiledModule;
 use move_core_types::resolver::MoveResolver;
 use move_core_types::vm_status::AbortLocation;
 use move_core_types::vm_status::VMStatus;
+use moveos_types::move_std::error::MoveAbortExplain;
+use moveos_verifier::metadata::get_metadata_from_compiled_module;
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq)]
-pub struct MoveAbortExplain {
-    pub category_code: u64,
-    pub category_name: Option<String>,
-    pub reason_code: u64,
-    pub reason_name: Option<String>,
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
 pub enum VMStatusExplainView {
     /// The VM status corresponding to an EXECUTED status code
@@ -33,6 +27,10 @@ pub enum VMStatusExplainView {
         location: AbortLocation,
         category_code: u64,
         reason_code: u64,
+        /// The name of the error constant in the aborting module, eg: `ErrorInsufficientBalance`
+        reason_name: Option<String>,
+        /// The doc comment of the error constant
+        code_description: Option<String>,
     },
 
     /// Indicates an failure from inside Move code, where the VM could not continue execution, e.g.
@@ -59,13 +57,13 @@ where
         VMStatus::Executed => VMStatusExplainView::Executed,
         VMStatus::Error(c) => VMStatusExplainView::Error(format!("{:?}", c)),
         VMStatus::MoveAbort(location, abort_code) => {
-            //TODO find a way to include the description
-            //Define a error code description trait, and let caller pass the error description files as argument.
-            let (category_code, reason_code) = moveos_types::move_std::error::explain(*abort_code);
+            let explain = explain_move_abort(&module_resolver, location, *abort_code);
             VMStatusExplainView::MoveAbort {
                 location: location.clone(),
-                category_code,
-                reason_code,
+                category_code: explain.category_code,
+                reason_code: explain.reason_code,
+                reason_name: explain.reason_name,
+                code_description: explain.code_description,
             }
         }
         VMStatus::ExecutionFailure {
@@ -85,6 +83,29 @@ where
     Ok(vm_status_explain)
 }
 
+/// Explain the abort code with the error constants recorded in the metadata of the aborting module.
+/// The error constants are collected by the extended checker when the module is built.
+pub fn explain_move_abort<T>(
+    module_resolver: &T,
+    location: &AbortLocation,
+    abort_code: u64,
+) -> MoveAbortExplain
+where
+    T: MoveResolver,
+{
+    let description = match location {
+        AbortLocation::Module(module_id) => module_resolver
+            .get_module(module_id)
+            .ok()
+            .flatten()
+            .and_then(|module_bytes| CompiledModule::deserialize(&module_bytes).ok())
+            .and_then(|module| get_metadata_from_compiled_module(&module))
+            .and_then(|metadata| metadata.get_error_description(abort_code).cloned()),
+        AbortLocation::Script => None,
+    };
+    MoveAbortExplain::new(abort_code, description)
+}
+
 fn extract_func_name<T>(
     location: &AbortLocation,
     function: &u16,
@@ -10,7 +10,7 @@ pub mod rocks;
 pub mod store_macros;
 pub mod traits;
 
-use crate::rocks::batch::WriteBatch;
+use crate::rocks::batch::{WriteBatch, WriteBatchCF};
 use crate::rocks::{RocksDB, SchemaIterator};
 use crate::traits::{DBStore, KVStore};
 use anyhow::{bail, format_err, Result};
@@ -106,6 +106,12 @@ impl DBStore for StoreInstance {
             StoreInstance::DB { db } => db.multi_get(prefix_name, keys),
         }
     }
+
+    fn write_cf_batch(&self, cf_batches: Vec<WriteBatchCF>, sync: bool) -> Result<()> {
+        match self {
+            StoreInstance::DB { db } => db.write_cf_batch(cf_batches, sync),
+        }
+    }
 }
 
 pub trait ColumnFamily: Send + Sync {
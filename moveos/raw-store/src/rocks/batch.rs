@@ -4,7 +4,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{CodecWriteBatch, WriteOp};
+use crate::{CodecWriteBatch, ColumnFamilyName, WriteOp};
 use anyhow::Result;
 use moveos_common::utils::to_bytes;
 use serde::de::DeserializeOwned;
@@ -60,3 +60,28 @@ where
         Ok(WriteBatch::new_with_rows(rows))
     }
 }
+
+/// The write batch of a column family, the batches of several column families
+/// are written in one atomic write by `DBStore::write_cf_batch`.
+#[derive(Debug, Clone)]
+pub struct WriteBatchCF {
+    pub cf_name: ColumnFamilyName,
+    pub batch: WriteBatch,
+}
+
+impl WriteBatchCF {
+    pub fn new(cf_name: ColumnFamilyName, batch: WriteBatch) -> Self {
+        Self { cf_name, batch }
+    }
+
+    pub fn new_with_codec<K, V>(
+        cf_name: ColumnFamilyName,
+        batch: CodecWriteBatch<K, V>,
+    ) -> Result<Self>
+    where
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+    {
+        Ok(Self::new(cf_name, WriteBatch::try_from(batch)?))
+    }
+}
@@ -8,7 +8,7 @@ pub mod batch;
 
 use crate::errors::RawStoreError;
 use crate::metrics::{record_metrics, StoreMetrics};
-use crate::rocks::batch::WriteBatch;
+use crate::rocks::batch::{WriteBatch, WriteBatchCF};
 use crate::traits::DBStore;
 use crate::{ColumnFamilyName, WriteOp};
 use anyhow::{ensure, format_err, Error, Result};
@@ -170,6 +170,14 @@ impl RocksDB {
         Ok(())
     }
 
+    /// Create a checkpoint of the DB in the directory `checkpoint_path`, which must not exist.
+    /// The checkpoint is a consistent snapshot of the DB and can be opened as another DB,
+    /// its SST files are hard links of the DB files if they are on the same filesystem.
+    pub fn create_checkpoint(&self, checkpoint_path: impl AsRef<Path>) -> Result<()> {
+        rocksdb::checkpoint::Checkpoint::new(&self.db)?.create_checkpoint(checkpoint_path)?;
+        Ok(())
+    }
+
     /// List cf
     pub fn list_cf(path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
         Ok(rocksdb::DB::list_cf(&rocksdb::Options::default(), path)?)
@@ -431,6 +439,26 @@ impl DBStore for RocksDB {
         })
     }
 
+    fn write_cf_batch(&self, cf_batches: Vec<WriteBatchCF>, sync: bool) -> Result<()> {
+        let mut db_batch = DBWriteBatch::default();
+        for cf_batch in &cf_batches {
+            let cf_handle = self.get_cf_handle(cf_batch.cf_name);
+            for (key, write_op) in &cf_batch.batch.rows {
+                match write_op {
+                    WriteOp::Value(value) => db_batch.put_cf(&cf_handle, key, value),
+                    WriteOp::Deletion => db_batch.delete_cf(&cf_handle, key),
+                };
+            }
+        }
+        let write_opts = if sync {
+            Self::sync_write_options()
+        } else {
+            Self::default_write_options()
+        };
+        self.db.write_opt(db_batch, &write_opts)?;
+        Ok(())
+    }
+
     fn multi_get(&self, prefix_name: &str, keys: Vec<Vec<u8>>) -> Result<Vec<Option<Vec<u8>>>> {
         record_metrics("db", prefix_name, "multi_get", self.metrics.as_ref()).call(|| {
             let cf_handle = self.get_cf_handle(prefix_name);